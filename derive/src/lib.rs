@@ -32,10 +32,54 @@ pub fn cmake_derive(input: TokenStream) -> TokenStream {
     };
 
     let trait_cmake_positional = cmake_impl.trait_cmake_positional_regular();
+    let trait_visitable = cmake_impl.trait_visitable();
+    let trait_documented = cmake_impl.trait_documented();
 
     quote! {
         #trait_cmake_parse
         #trait_cmake_positional
+        #trait_visitable
+        #trait_documented
+    }
+    .into()
+}
+
+/// Declares the method `visit_<name>`, or `visit_<name>_mut` with a leading
+/// `mut`, of the `Visit` and `VisitMut` traits of `cmake-parser` for a type,
+/// which walks into the visited value:
+///
+/// ```text
+/// visit_method!(mut "add_executable": project::AddExecutable<'t>);
+/// ```
+#[doc(hidden)]
+#[proc_macro]
+#[proc_macro_error]
+pub fn visit_method(input: TokenStream) -> TokenStream {
+    let parser = |input: syn::parse::ParseStream| {
+        let mutable: Option<Token![mut]> = input.parse()?;
+        let name: syn::LitStr = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty: syn::Type = input.parse()?;
+        Ok((mutable.is_some(), name, ty))
+    };
+    let (mutable, name, ty) = match syn::parse::Parser::parse(parser, input) {
+        Ok(method) => method,
+        Err(err) => abort!(err.span(), "{}", err),
+    };
+    if mutable {
+        let visit_mut = format_ident!("visit_{}_mut", name.value(), span = name.span());
+        quote! {
+            fn #visit_mut(&mut self, node: &mut #ty) {
+                node.walk_mut(self)
+            }
+        }
+    } else {
+        let visit = format_ident!("visit_{}", name.value(), span = name.span());
+        quote! {
+            fn #visit(&mut self, node: &#ty) {
+                node.walk(self)
+            }
+        }
     }
     .into()
 }
@@ -72,7 +116,7 @@ fn positional_var_defs(
             } else {
                 quote! { tokens }
             };
-            let keyword_after = keyword_after.as_ref().map(|bstr| { quote! { ; let (_, #def_mut #tokens) = Keyword::positional(#bstr, #tokens, false)? } });
+            let keyword_after = keyword_after.as_ref().map(|keyword| proc_macro2::Literal::byte_string(keyword.as_bytes())).map(|bstr| { quote! { ; let (_, #def_mut #tokens) = Keyword::positional(#bstr, #tokens, false)? } });
             if *in_range && index != fields.len() - 1 {
                 let allow_empty = *allow_empty;
                 let range_to_keyword = &fields[index + 1].lit_bstr;
@@ -235,6 +279,7 @@ impl CMakeOption {
 }
 
 struct StrBStr {
+    lit_str: proc_macro2::Literal,
     lit_bstr: proc_macro2::Literal,
 }
 
@@ -253,8 +298,10 @@ impl CMakeEnum {
                     f.ident.clone(),
                     cmake_attribute(&f.attrs).unwrap_or_default(),
                     match &f.fields {
-                        syn::Fields::Unit => false,
-                        syn::Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => true,
+                        syn::Fields::Unit => None,
+                        syn::Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
+                            Some(unnamed.unnamed[0].ty.clone())
+                        }
                         _ => abort!(
                             f,
                             "only unit enums and unnamed enums with one field supported"
@@ -262,7 +309,7 @@ impl CMakeEnum {
                     },
                 )
             })
-            .map(|(ident, attr, unnamed)| {
+            .map(|(ident, attr, ty)| {
                 let id = ident.to_string();
                 use inflections::Inflect;
                 let ident_mode = quote::format_ident!("{}", id.to_pascal_case());
@@ -274,6 +321,7 @@ impl CMakeEnum {
                         keywords
                             .iter()
                             .map(|keyword| StrBStr {
+                                lit_str: proc_macro2::Literal::string(keyword),
                                 lit_bstr: proc_macro2::Literal::byte_string(keyword.as_bytes()),
                             })
                             .collect()
@@ -284,9 +332,9 @@ impl CMakeEnum {
                         ident_mode,
                         lit_str,
                         lit_bstr,
-                        ty: None,
+                        ty: ty.clone(),
                     },
-                    unnamed,
+                    unnamed: ty.is_some(),
                 }
             })
             .collect()
@@ -322,10 +370,14 @@ impl CMakeImpl {
         let type_params = generics.type_params();
         let (_, ty_generics, where_clause) = generics.split_for_impl();
 
+        let fn_keywords = self.fn_keywords();
+
         quote! {
             #[automatically_derived]
             impl <'t #(, #type_params)*> #crate_path::CMakeParse<'t> for #name #ty_generics #where_clause {
                 #content
+
+                #fn_keywords
             }
         }
     }
@@ -837,6 +889,186 @@ impl CMakeImpl {
         )
     }
 
+    fn fn_keywords(&self) -> proc_macro2::TokenStream {
+        let crate_path = &self.crate_path;
+        let transparent = self.cmake_attr.transparent;
+        let insert = |lit_str: &proc_macro2::Literal| quote! { keywords.insert(#lit_str); };
+        let (keywords, types): (Vec<_>, Vec<_>) = match self.to_cmake_fields() {
+            CMakeFields::StructNamedFields(fields) => fields
+                .into_iter()
+                .map(|field| {
+                    let CMakeOption {
+                        attr,
+                        ident,
+                        lit_str,
+                        lit_bstr,
+                        ty,
+                        ..
+                    } = field;
+                    let positional = self.cmake_attr.positional || attr.positional;
+                    let mut keywords = vec![];
+                    if positional {
+                        if transparent || attr.transparent || ty.as_ref().is_some_and(is_flag) {
+                            keywords.push(insert(&lit_str));
+                        }
+                    } else if self.cmake_attr.default.as_deref() != Some(&ident.to_string()) {
+                        // nested parsers may match other keywords than the field name
+                        keywords.push(quote! {
+                            if <#ty as #crate_path::CMakeParse<'t>>::matches_type(#lit_bstr, #lit_bstr, &[]) {
+                                keywords.insert(#lit_str);
+                            }
+                        });
+                    }
+                    if let Some(keyword_after) = attr.keyword_after.as_deref() {
+                        keywords.push(insert(&proc_macro2::Literal::string(keyword_after)));
+                    }
+                    (keywords, ty)
+                })
+                .unzip(),
+            CMakeFields::EnumVariants(variants) => variants
+                .into_iter()
+                .map(|variant| {
+                    let keywords = if !self.cmake_attr.untagged
+                        || !variant.unnamed
+                        || transparent
+                        || variant.option.attr.transparent
+                    {
+                        variant
+                            .renames
+                            .map(|renames| renames.iter().map(|r| insert(&r.lit_str)).collect())
+                            .unwrap_or_else(|| vec![insert(&variant.option.lit_str)])
+                    } else {
+                        vec![]
+                    };
+                    (keywords, variant.option.ty)
+                })
+                .unzip(),
+            CMakeFields::Unit => (vec![], vec![]),
+        };
+        let keywords = keywords.into_iter().flatten();
+        let types = types.into_iter().flatten();
+
+        quote! {
+            fn keywords(keywords: &mut #crate_path::KeywordSet) {
+                #(#keywords)*
+                #(keywords.visit::<#types>();)*
+            }
+        }
+    }
+
+    fn trait_documented(&self) -> proc_macro2::TokenStream {
+        let Self {
+            ast, crate_path, ..
+        } = self;
+
+        let name = &ast.ident;
+        let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+        let documentation = ast
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"))
+            .filter_map(|attr| match &attr.meta {
+                Meta::NameValue(MetaNameValue {
+                    value:
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(s), ..
+                        }),
+                    ..
+                }) => Some(s.value()),
+                _ => None,
+            })
+            .map(|line| line.strip_prefix(' ').map(str::to_string).unwrap_or(line))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #crate_path::Documented for #name #ty_generics #where_clause {
+                const DOCUMENTATION: &'static str = #documentation;
+            }
+        }
+    }
+
+    fn trait_visitable(&self) -> proc_macro2::TokenStream {
+        let Self {
+            ast, crate_path, ..
+        } = self;
+
+        let name = &ast.ident;
+        let (_, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+        let (walk, walk_mut) = match &ast.data {
+            syn::Data::Struct(data_struct) => {
+                let fields: Vec<_> = data_struct
+                    .fields
+                    .iter()
+                    .filter_map(|field| field.ident.as_ref())
+                    .collect();
+                (
+                    quote! { #(#crate_path::Visitable::accept(&self.#fields, visitor);)* },
+                    quote! { #(#crate_path::Visitable::accept_mut(&mut self.#fields, visitor);)* },
+                )
+            }
+            syn::Data::Enum(DataEnum { variants, .. }) => {
+                let arms: Vec<_> = variants
+                    .iter()
+                    .map(|variant| {
+                        let ident = &variant.ident;
+                        match &variant.fields {
+                            syn::Fields::Unit => quote! { Self::#ident => () },
+                            _ => quote! { Self::#ident(node) => #crate_path::Visitable::accept(node, visitor) },
+                        }
+                    })
+                    .collect();
+                let arms_mut: Vec<_> = variants
+                    .iter()
+                    .map(|variant| {
+                        let ident = &variant.ident;
+                        match &variant.fields {
+                            syn::Fields::Unit => quote! { Self::#ident => () },
+                            _ => quote! { Self::#ident(node) => #crate_path::Visitable::accept_mut(node, visitor) },
+                        }
+                    })
+                    .collect();
+                (
+                    quote! { match self { #(#arms,)* } },
+                    quote! { match self { #(#arms_mut,)* } },
+                )
+            }
+            syn::Data::Union(_) => abort!(name, "unions are not supported"),
+        };
+
+        let accept = self.cmake_attr.visit.as_ref().map(|visit| {
+            let visit_mut = format_ident!("{}_mut", visit);
+            quote! {
+                fn accept<V: #crate_path::Visit<'t> + ?Sized>(&self, visitor: &mut V) {
+                    visitor.#visit(self)
+                }
+
+                fn accept_mut<V: #crate_path::VisitMut<'t> + ?Sized>(&mut self, visitor: &mut V) {
+                    visitor.#visit_mut(self)
+                }
+            }
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl <'t> #crate_path::Visitable<'t> for #name #ty_generics #where_clause {
+                #accept
+
+                #[allow(unused_variables)]
+                fn walk<V: #crate_path::Visit<'t> + ?Sized>(&self, visitor: &mut V) {
+                    #walk
+                }
+
+                #[allow(unused_variables)]
+                fn walk_mut<V: #crate_path::VisitMut<'t> + ?Sized>(&mut self, visitor: &mut V) {
+                    #walk_mut
+                }
+            }
+        }
+    }
+
     fn regular_except_if_stmt(&self) -> Option<proc_macro2::TokenStream> {
         self.cmake_attr.except.as_deref().map(|except| {
             let except = except
@@ -856,7 +1088,7 @@ impl CMakeImpl {
 #[derive(Default)]
 struct CMakeAttribute {
     default: Option<String>,
-    keyword_after: Option<proc_macro2::Literal>,
+    keyword_after: Option<String>,
     list: bool,
     match_fields: bool,
     pkg: Option<syn::Path>,
@@ -870,6 +1102,7 @@ struct CMakeAttribute {
     except: Option<Vec<String>>,
     in_range: bool,
     last: bool,
+    visit: Option<syn::Ident>,
 }
 
 fn cmake_attribute(attrs: &[syn::Attribute]) -> Option<CMakeAttribute> {
@@ -894,6 +1127,7 @@ fn cmake_attribute(attrs: &[syn::Attribute]) -> Option<CMakeAttribute> {
     let mut except = None;
     let mut in_range = false;
     let mut last = false;
+    let mut visit = None;
 
     for meta in nested {
         match meta {
@@ -928,11 +1162,13 @@ fn cmake_attribute(attrs: &[syn::Attribute]) -> Option<CMakeAttribute> {
                 if path.is_ident("default") {
                     default = Some(s.value());
                 } else if path.is_ident("keyword_after") {
-                    keyword_after = Some(proc_macro2::Literal::byte_string(s.value().as_bytes()));
+                    keyword_after = Some(s.value());
                 } else if path.is_ident("pkg") {
                     pkg = s.parse().ok();
                 } else if path.is_ident("rename") {
                     rename = Some(s.value());
+                } else if path.is_ident("visit") {
+                    visit = Some(format_ident!("visit_{}", s.value()));
                 }
             }
             _ => (),
@@ -955,9 +1191,16 @@ fn cmake_attribute(attrs: &[syn::Attribute]) -> Option<CMakeAttribute> {
         except,
         in_range,
         last,
+        visit,
     })
 }
 
+/// Positional fields of these types are set by their own keyword.
+fn is_flag(ty: &syn::Type) -> bool {
+    let ty = quote! { #ty }.to_string();
+    ["bool", "()", "Option < bool >", "Keyword"].contains(&ty.as_str())
+}
+
 fn to_vec_string(elems: Punctuated<Expr, syn::token::Comma>) -> Vec<String> {
     elems
         .iter()
@@ -998,6 +1241,7 @@ mod tests {
                 positional,
                 match_fields,
                 list,
+                visit = "add_executable",
             )]
         };
 
@@ -1009,6 +1253,10 @@ mod tests {
         assert!(cmake_attr.transparent);
         assert!(cmake_attr.match_fields);
         assert!(cmake_attr.list);
+        assert_eq!(
+            Some("visit_add_executable".to_string()),
+            cmake_attr.visit.map(|ident| ident.to_string())
+        );
     }
 
     #[test]
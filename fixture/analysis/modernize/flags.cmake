@@ -0,0 +1 @@
+link_libraries(m)
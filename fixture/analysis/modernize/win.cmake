@@ -0,0 +1 @@
+add_definitions(-DWIN)
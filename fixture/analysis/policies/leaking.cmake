@@ -0,0 +1 @@
+cmake_policy(SET CMP0092 OLD)
@@ -0,0 +1,2 @@
+cmake_policy(SET CMP0091 OLD)
+message(scoped)
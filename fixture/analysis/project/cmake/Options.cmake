@@ -0,0 +1 @@
+option(WITH_X "Build with X" ON)
@@ -0,0 +1 @@
+set(SOURCES a.c b.c)
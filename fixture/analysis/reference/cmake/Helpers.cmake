@@ -0,0 +1,27 @@
+#[=======================================================================[.rst:
+Helpers
+-------
+
+Functions to declare demo targets.
+#]=======================================================================]
+
+include_guard(GLOBAL)
+
+# Add a demo application.
+#
+# The sources are compiled with the demo warnings.
+function(demo_add_app name)
+  set(options WIN32)
+  cmake_parse_arguments(PARSE_ARGV 1 ARG "${options}" "OUTPUT_NAME" "SOURCES")
+  add_executable(${name} ${ARG_SOURCES})
+endfunction()
+
+#[[
+Apply the demo warnings to a target.
+]]
+macro(demo_warnings target)
+  target_compile_options(${target} PRIVATE -Wall)
+endmacro()
+
+function(demo_undocumented)
+endfunction()
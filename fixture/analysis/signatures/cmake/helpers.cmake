@@ -0,0 +1,11 @@
+function(project_add_app target)
+  cmake_parse_arguments(PARSE_ARGV 1 ARG "WIN32" "OUTPUT_NAME" "SOURCES;LIBRARIES")
+  add_executable(${target} ${ARG_SOURCES})
+  if(ARG_WIN32)
+    set_target_properties(${target} PROPERTIES WIN32_EXECUTABLE ON)
+  endif()
+  if(ARG_OUTPUT_NAME)
+    set_target_properties(${target} PROPERTIES OUTPUT_NAME ${ARG_OUTPUT_NAME})
+  endif()
+  target_link_libraries(${target} PRIVATE ${ARG_LIBRARIES})
+endfunction()
@@ -0,0 +1,4 @@
+function(symbols_add_app target)
+  add_executable(${target} ${ARGN} ${COMMON_SOURCES})
+  target_compile_definitions(${target} PRIVATE VERSION=${APP_VERSION})
+endfunction()
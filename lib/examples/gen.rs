@@ -108,10 +108,7 @@ impl Gen {
                     "Could not update {}!",
                     command_mod_rs_path.to_string_lossy()
                 );
-                return Err(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    "failed to update Command struct",
-                ));
+                return Err(std::io::Error::other("failed to update Command struct"));
             }
             self.write_if_changed(lines, content, &command_mod_rs_path)?;
         }
@@ -181,30 +178,41 @@ impl Gen {
         }
 
         {
-            let doc_mod_rs_path = Path::new("lib").join("src").join("doc").join("mod.rs");
-            let content = std::fs::read_to_string(&doc_mod_rs_path)?;
+            let command_mod_rs_path = Path::new("lib")
+                .join("src")
+                .join("doc")
+                .join("command")
+                .join("mod.rs");
+            let content = std::fs::read_to_string(&command_mod_rs_path)?;
 
             let mut lines = content.lines().map(str::to_string).collect::<Vec<_>>();
 
-            let to_command = format!("to_command(tokens, Command::{command_name})");
-            let match_case = format!("                b\"{command}\" => {to_command},");
+            let entry = format!(
+                "                \"{command}\" => {command_name}({command_type}::{command_name}<'t>),"
+            );
 
-            if lines.iter().any(|l| l.contains(&to_command)) {
-                eprintln!("Match is found, skipping... ok");
+            if lines.contains(&entry) {
+                eprintln!("Commands entry is found, skipping... ok");
             } else {
-                eprint!("Match is not found, adding...");
-                if let Some(unknown_pos) = lines
+                eprint!("Commands entry is not found, adding...");
+                let table_end = lines
                     .iter()
-                    .position(|l| l.trim_start().starts_with("unknown =>"))
-                {
-                    lines.insert(unknown_pos, match_case);
+                    .position(|l| l == "macro_rules! commands {")
+                    .and_then(|start| {
+                        lines[start..]
+                            .iter()
+                            .position(|l| l == "        }")
+                            .map(|end| start + end)
+                    });
+                if let Some(table_end) = table_end {
+                    lines.insert(table_end, entry);
                     eprintln!(" ok");
                 } else {
-                    eprintln!(" fail: `unknown =>` not found");
+                    eprintln!(" fail: `macro_rules! commands` not found");
                 }
             }
 
-            self.write_if_changed(lines, content, &doc_mod_rs_path)?;
+            self.write_if_changed(lines, content, &command_mod_rs_path)?;
         }
 
         {
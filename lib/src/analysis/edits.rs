@@ -0,0 +1,65 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    analysis::{project::path_value, FileId, Project},
+    lint::{apply_edits, unified_diff, Edit},
+};
+
+/// Source edits of the files of a project, or of a single document for the
+/// `None` file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProjectEdits {
+    edits: BTreeMap<Option<FileId>, Vec<Edit>>,
+}
+
+impl ProjectEdits {
+    /// Edited files in order.
+    pub fn files(&self) -> impl Iterator<Item = Option<FileId>> + '_ {
+        self.edits.keys().copied()
+    }
+
+    /// Edits of a file sorted by range.
+    pub fn edits(&self, file: Option<FileId>) -> &[Edit] {
+        self.edits.get(&file).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+
+    /// Source of a file with the edits applied.
+    pub fn apply(&self, file: Option<FileId>, source: &str) -> String {
+        apply_edits(source, self.edits(file))
+    }
+
+    /// Unified diff of a file.
+    pub fn diff(&self, file: Option<FileId>, path: &str, source: &str) -> String {
+        unified_diff(path, source, &self.apply(file, source))
+    }
+
+    /// Unified diff of the files of a project, with paths relative to the
+    /// root directory.
+    pub fn project_diff(&self, project: &Project) -> String {
+        let root = project.root().map(|root| root.source_dir.clone());
+        self.edits
+            .keys()
+            .filter_map(|file| project.file((*file)?))
+            .map(|file| {
+                let path = root
+                    .as_ref()
+                    .and_then(|root| file.path.strip_prefix(root).ok())
+                    .unwrap_or(&file.path);
+                let source = String::from_utf8_lossy(file.source());
+                self.diff(Some(file.id), &path_value(path), &source)
+            })
+            .collect()
+    }
+
+    pub(crate) fn push(&mut self, file: Option<FileId>, edit: Edit) {
+        let edits = self.edits.entry(file).or_default();
+        let index = edits.partition_point(|other| {
+            (other.range.start, other.range.end) <= (edit.range.start, edit.range.end)
+        });
+        edits.insert(index, edit);
+    }
+}
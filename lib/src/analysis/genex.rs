@@ -0,0 +1,915 @@
+use std::collections::HashMap;
+
+use crate::{doc::named_bool_constant, eval::CMakeVersion};
+
+/// Parsed string possibly containing generator expressions.
+///
+/// ```
+/// use cmake_parser::analysis::{Evaluation, Genex, GenexContext};
+///
+/// let genex = Genex::parse("$<$<CONFIG:Debug>:DEBUG_BUILD>").unwrap();
+/// let debug = GenexContext::new().config("Debug");
+/// let release = GenexContext::new().config("Release");
+///
+/// assert_eq!(genex.evaluate(&debug), Ok(Evaluation::Known("DEBUG_BUILD".to_string())));
+/// assert_eq!(genex.evaluate(&release), Ok(Evaluation::Known("".to_string())));
+/// assert_eq!(genex.evaluate(&GenexContext::new()), Ok(Evaluation::Unknown));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Genex {
+    pub nodes: Vec<GenexNode>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenexNode {
+    Text(String),
+    Expression(GenexExpression),
+}
+
+/// `$<name:arguments>` expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenexExpression {
+    pub name: Genex,
+    /// Comma separated arguments, `None` when there is no `:`.
+    pub arguments: Option<Vec<Genex>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum GenexError {
+    #[error("unterminated generator expression")]
+    Unterminated,
+    #[error("wrong number of arguments of $<{name}>: {actual}")]
+    Arguments { name: String, actual: usize },
+    #[error("condition must be 0 or 1: {0}")]
+    Condition(String),
+}
+
+/// Result of the evaluation of a generator expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Evaluation {
+    Known(String),
+    /// The value depends on something missing in the [`GenexContext`] or on
+    /// an unsupported expression.
+    Unknown,
+}
+
+/// Properties of targets used by `$<TARGET_PROPERTY>`, `$<TARGET_EXISTS>`
+/// and `$<TARGET_FILE>` expressions.
+///
+/// Methods return `None` when the value is not known.
+pub trait TargetProperties {
+    /// Value of the property, an empty string for properties which are not set.
+    fn target_property(&self, target: &str, property: &str) -> Option<String>;
+
+    fn target_exists(&self, _target: &str) -> Option<bool> {
+        None
+    }
+
+    /// Full path of the main file of the target.
+    fn target_file(&self, _target: &str) -> Option<String> {
+        None
+    }
+}
+
+/// Build configuration generator expressions are evaluated for.
+///
+/// Values which are not set evaluate to [`Evaluation::Unknown`].
+#[derive(Clone, Default)]
+pub struct GenexContext<'p> {
+    config: Option<String>,
+    platform_id: Option<String>,
+    compilers: HashMap<String, Compiler>,
+    language: Option<String>,
+    target: Option<String>,
+    properties: Option<&'p dyn TargetProperties>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Compiler {
+    id: String,
+    version: String,
+}
+
+type Result<T> = std::result::Result<T, GenexError>;
+
+impl Genex {
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut chars = input.chars().peekable();
+        let (genex, _) = parse_genex(&mut chars, &[])?;
+        Ok(genex)
+    }
+
+    /// Whether the string contains no generator expressions.
+    pub fn is_literal(&self) -> bool {
+        self.nodes
+            .iter()
+            .all(|node| matches!(node, GenexNode::Text(_)))
+    }
+
+    pub fn evaluate(&self, context: &GenexContext) -> Result<Evaluation> {
+        Ok(match context.evaluate(self)? {
+            Some(value) => Evaluation::Known(value),
+            None => Evaluation::Unknown,
+        })
+    }
+}
+
+impl std::fmt::Display for Genex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for node in &self.nodes {
+            match node {
+                GenexNode::Text(text) => write!(f, "{text}")?,
+                GenexNode::Expression(expression) => {
+                    write!(f, "$<{}", expression.name)?;
+                    if let Some(arguments) = &expression.arguments {
+                        write!(f, ":")?;
+                        for (index, argument) in arguments.iter().enumerate() {
+                            if index > 0 {
+                                write!(f, ",")?;
+                            }
+                            write!(f, "{argument}")?;
+                        }
+                    }
+                    write!(f, ">")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Evaluation {
+    pub fn known(&self) -> Option<&str> {
+        match self {
+            Evaluation::Known(value) => Some(value),
+            Evaluation::Unknown => None,
+        }
+    }
+}
+
+type Chars<'a> = std::iter::Peekable<std::str::Chars<'a>>;
+
+/// Parses until one of `stop` characters outside of nested expressions.
+fn parse_genex(chars: &mut Chars, stop: &[char]) -> Result<(Genex, Option<char>)> {
+    let mut nodes = vec![];
+    let mut text = String::new();
+    while let Some(ch) = chars.next() {
+        if stop.contains(&ch) {
+            push_text(&mut nodes, &mut text);
+            return Ok((Genex { nodes }, Some(ch)));
+        }
+        if ch == '$' && chars.peek() == Some(&'<') {
+            chars.next();
+            push_text(&mut nodes, &mut text);
+            nodes.push(GenexNode::Expression(parse_expression(chars)?));
+        } else {
+            text.push(ch);
+        }
+    }
+    if !stop.is_empty() {
+        return Err(GenexError::Unterminated);
+    }
+    push_text(&mut nodes, &mut text);
+    Ok((Genex { nodes }, None))
+}
+
+fn parse_expression(chars: &mut Chars) -> Result<GenexExpression> {
+    let (name, end) = parse_genex(chars, &[':', '>'])?;
+    if end == Some('>') {
+        return Ok(GenexExpression {
+            name,
+            arguments: None,
+        });
+    }
+    let mut arguments = vec![];
+    loop {
+        let (argument, end) = parse_genex(chars, &[',', '>'])?;
+        arguments.push(argument);
+        if end == Some('>') {
+            return Ok(GenexExpression {
+                name,
+                arguments: Some(arguments),
+            });
+        }
+    }
+}
+
+fn push_text(nodes: &mut Vec<GenexNode>, text: &mut String) {
+    if !text.is_empty() {
+        nodes.push(GenexNode::Text(std::mem::take(text)));
+    }
+}
+
+impl<'p> GenexContext<'p> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build configuration, e.g. `Debug`.
+    pub fn config(mut self, config: impl Into<String>) -> Self {
+        self.config = Some(config.into());
+        self
+    }
+
+    /// Value of `CMAKE_SYSTEM_NAME`, e.g. `Linux`.
+    pub fn platform_id(mut self, platform_id: impl Into<String>) -> Self {
+        self.platform_id = Some(platform_id.into());
+        self
+    }
+
+    /// Compiler id and version of a language, e.g. `CXX`, `GNU`, `12.2.0`.
+    pub fn compiler(
+        mut self,
+        language: impl Into<String>,
+        id: impl Into<String>,
+        version: impl Into<String>,
+    ) -> Self {
+        self.compilers.insert(
+            language.into(),
+            Compiler {
+                id: id.into(),
+                version: version.into(),
+            },
+        );
+        self
+    }
+
+    /// Language of the compiled source file.
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Target the expression is evaluated on.
+    pub fn target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    pub fn properties(mut self, properties: &'p dyn TargetProperties) -> Self {
+        self.properties = Some(properties);
+        self
+    }
+
+    fn evaluate(&self, genex: &Genex) -> Result<Option<String>> {
+        let mut result = String::new();
+        for node in &genex.nodes {
+            match node {
+                GenexNode::Text(text) => result.push_str(text),
+                GenexNode::Expression(expression) => {
+                    let Some(value) = self.expression(expression)? else {
+                        return Ok(None);
+                    };
+                    result.push_str(&value);
+                }
+            }
+        }
+        Ok(Some(result))
+    }
+
+    fn expression(&self, expression: &GenexExpression) -> Result<Option<String>> {
+        let Some(name) = self.evaluate(&expression.name)? else {
+            return Ok(None);
+        };
+        let arguments = expression.arguments.as_deref().unwrap_or_default();
+        let args = Arguments {
+            context: self,
+            name: &name,
+            arguments,
+        };
+
+        let value = match name.as_str() {
+            "0" => {
+                args.content(1)?;
+                String::new()
+            }
+            "1" | "BUILD_INTERFACE" | "LINK_ONLY" | "COMPILE_ONLY" => {
+                return self.evaluate(&args.content(1)?);
+            }
+            "INSTALL_INTERFACE" => {
+                args.content(1)?;
+                String::new()
+            }
+            "ANGLE-R" | "COMMA" | "SEMICOLON" => {
+                args.count(0..=0)?;
+                match name.as_str() {
+                    "ANGLE-R" => ">",
+                    "COMMA" => ",",
+                    _ => ";",
+                }
+                .to_string()
+            }
+            "BOOL" => {
+                let Some(value) = self.evaluate(&args.content(1)?)? else {
+                    return Ok(None);
+                };
+                bool_string(named_bool_constant(&value) != Some(false))
+            }
+            "NOT" => {
+                let Some([value]) = args.values()? else {
+                    return Ok(None);
+                };
+                bool_string(!condition(&value)?)
+            }
+            "AND" | "OR" => {
+                args.count(1..=usize::MAX)?;
+                // the result is known when any known value decides it
+                let decisive = name == "OR";
+                let mut unknown = false;
+                for argument in arguments {
+                    match self.evaluate(argument)? {
+                        Some(value) if condition(&value)? == decisive => {
+                            return Ok(Some(bool_string(decisive)));
+                        }
+                        Some(_) => (),
+                        None => unknown = true,
+                    }
+                }
+                if unknown {
+                    return Ok(None);
+                }
+                bool_string(!decisive)
+            }
+            "IF" => {
+                args.count(3..=3)?;
+                let Some(value) = self.evaluate(&arguments[0])? else {
+                    return Ok(None);
+                };
+                let branch = if condition(&value)? { 1 } else { 2 };
+                return self.evaluate(&arguments[branch]);
+            }
+            "STREQUAL" => {
+                let Some([left, right]) = args.values()? else {
+                    return Ok(None);
+                };
+                bool_string(left == right)
+            }
+            "EQUAL" => {
+                let Some([left, right]) = args.values()? else {
+                    return Ok(None);
+                };
+                let left = left.trim().parse::<i64>().ok();
+                bool_string(left.is_some() && left == right.trim().parse::<i64>().ok())
+            }
+            "IN_LIST" => {
+                let Some([value, list]) = args.values()? else {
+                    return Ok(None);
+                };
+                bool_string(list.split(';').any(|item| item == value))
+            }
+            "VERSION_LESS"
+            | "VERSION_GREATER"
+            | "VERSION_EQUAL"
+            | "VERSION_LESS_EQUAL"
+            | "VERSION_GREATER_EQUAL" => {
+                let Some([left, right]) = args.values()? else {
+                    return Ok(None);
+                };
+                let ordering = CMakeVersion::lenient(&left).cmp(&CMakeVersion::lenient(&right));
+                bool_string(match name.as_str() {
+                    "VERSION_LESS" => ordering.is_lt(),
+                    "VERSION_GREATER" => ordering.is_gt(),
+                    "VERSION_EQUAL" => ordering.is_eq(),
+                    "VERSION_LESS_EQUAL" => ordering.is_le(),
+                    _ => ordering.is_ge(),
+                })
+            }
+            "LOWER_CASE" | "UPPER_CASE" | "MAKE_C_IDENTIFIER" => {
+                let Some(value) = self.evaluate(&args.content(1)?)? else {
+                    return Ok(None);
+                };
+                match name.as_str() {
+                    "LOWER_CASE" => value.to_lowercase(),
+                    "UPPER_CASE" => value.to_uppercase(),
+                    _ => c_identifier(&value),
+                }
+            }
+            "JOIN" => {
+                let glue = args.content(2)?;
+                let (Some(list), Some(glue)) =
+                    (self.evaluate(&arguments[0])?, self.evaluate(&glue)?)
+                else {
+                    return Ok(None);
+                };
+                list.split(';').collect::<Vec<_>>().join(&glue)
+            }
+            "REMOVE_DUPLICATES" => {
+                let Some([list]) = args.values()? else {
+                    return Ok(None);
+                };
+                let mut items: Vec<&str> = vec![];
+                for item in list.split(';') {
+                    if !items.contains(&item) {
+                        items.push(item);
+                    }
+                }
+                items.join(";")
+            }
+            "CONFIG" => {
+                let Some(config) = &self.config else {
+                    return Ok(None);
+                };
+                if arguments.is_empty() {
+                    config.clone()
+                } else {
+                    let Some(configs) = args.list()? else {
+                        return Ok(None);
+                    };
+                    bool_string(configs.iter().any(|c| c.eq_ignore_ascii_case(config)))
+                }
+            }
+            "PLATFORM_ID" => return args.matches(self.platform_id.as_deref()),
+            "COMPILE_LANGUAGE" | "LINK_LANGUAGE" => {
+                return args.matches(self.language.as_deref());
+            }
+            "COMPILE_LANG_AND_ID" => {
+                args.count(2..=usize::MAX)?;
+                let Some(values) = args.list()? else {
+                    return Ok(None);
+                };
+                let (Some(language), Some(compiler)) =
+                    (&self.language, self.compilers.get(&values[0]))
+                else {
+                    return Ok(None);
+                };
+                bool_string(*language == values[0] && values[1..].contains(&compiler.id))
+            }
+            "TARGET_EXISTS" | "TARGET_NAME_IF_EXISTS" => {
+                let Some([target]) = args.values()? else {
+                    return Ok(None);
+                };
+                let Some(exists) = self.properties.and_then(|p| p.target_exists(&target)) else {
+                    return Ok(None);
+                };
+                match (name.as_str(), exists) {
+                    ("TARGET_EXISTS", _) => bool_string(exists),
+                    (_, true) => target,
+                    (_, false) => String::new(),
+                }
+            }
+            "TARGET_NAME" => {
+                let Some([target]) = args.values()? else {
+                    return Ok(None);
+                };
+                target
+            }
+            "TARGET_PROPERTY" => {
+                args.count(1..=2)?;
+                let Some(mut values) = args.list()? else {
+                    return Ok(None);
+                };
+                let property = values.pop().unwrap_or_default();
+                let Some(target) = values.pop().or_else(|| self.target.clone()) else {
+                    return Ok(None);
+                };
+                return Ok(self
+                    .properties
+                    .and_then(|p| p.target_property(&target, &property)));
+            }
+            "TARGET_FILE" | "TARGET_FILE_NAME" | "TARGET_FILE_DIR" => {
+                let Some([target]) = args.values()? else {
+                    return Ok(None);
+                };
+                let Some(file) = self.properties.and_then(|p| p.target_file(&target)) else {
+                    return Ok(None);
+                };
+                let (dir, file_name) = file.rsplit_once('/').unwrap_or(("", &file));
+                match name.as_str() {
+                    "TARGET_FILE" => file.clone(),
+                    "TARGET_FILE_NAME" => file_name.to_string(),
+                    _ => dir.to_string(),
+                }
+            }
+            _ => {
+                if let Some(language) = name.strip_suffix("_COMPILER_ID") {
+                    return args.matches(self.compilers.get(language).map(|c| c.id.as_str()));
+                }
+                if let Some(language) = name.strip_suffix("_COMPILER_VERSION") {
+                    let Some(compiler) = self.compilers.get(language) else {
+                        return Ok(None);
+                    };
+                    if arguments.is_empty() {
+                        return Ok(Some(compiler.version.clone()));
+                    }
+                    let Some([version]) = args.values()? else {
+                        return Ok(None);
+                    };
+                    return Ok(Some(bool_string(
+                        CMakeVersion::lenient(&compiler.version) == CMakeVersion::lenient(&version),
+                    )));
+                }
+                if expression.arguments.is_some() && !is_expression_name(&name) {
+                    return Err(GenexError::Condition(name));
+                }
+                // expressions which are not supported
+                return Ok(None);
+            }
+        };
+
+        Ok(Some(value))
+    }
+}
+
+impl std::fmt::Debug for GenexContext<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GenexContext")
+            .field("config", &self.config)
+            .field("platform_id", &self.platform_id)
+            .field("compilers", &self.compilers)
+            .field("language", &self.language)
+            .field("target", &self.target)
+            .finish_non_exhaustive()
+    }
+}
+
+struct Arguments<'a, 'p> {
+    context: &'a GenexContext<'p>,
+    name: &'a str,
+    arguments: &'a [Genex],
+}
+
+impl Arguments<'_, '_> {
+    fn count(&self, range: std::ops::RangeInclusive<usize>) -> Result<()> {
+        if range.contains(&self.arguments.len()) {
+            Ok(())
+        } else {
+            Err(GenexError::Arguments {
+                name: self.name.to_string(),
+                actual: self.arguments.len(),
+            })
+        }
+    }
+
+    /// The last of `count` arguments for expressions accepting arbitrary
+    /// content, it includes the rest of the arguments joined with commas.
+    fn content(&self, count: usize) -> Result<Genex> {
+        self.count(count..=usize::MAX)?;
+        let mut nodes = vec![];
+        for (index, argument) in self.arguments[count - 1..].iter().enumerate() {
+            if index > 0 {
+                nodes.push(GenexNode::Text(",".to_string()));
+            }
+            nodes.extend(argument.nodes.iter().cloned());
+        }
+        Ok(Genex { nodes })
+    }
+
+    /// Exactly `N` evaluated arguments, `None` when any of them is unknown.
+    fn values<const N: usize>(&self) -> Result<Option<[String; N]>> {
+        self.count(N..=N)?;
+        Ok(self
+            .list()?
+            .map(|values| values.try_into().expect("checked count")))
+    }
+
+    /// Evaluated arguments, `None` when any of them is unknown.
+    fn list(&self) -> Result<Option<Vec<String>>> {
+        let mut values = vec![];
+        for argument in self.arguments {
+            let Some(value) = self.context.evaluate(argument)? else {
+                return Ok(None);
+            };
+            values.push(value);
+        }
+        Ok(Some(values))
+    }
+
+    /// The value itself without arguments, otherwise whether it is one of the
+    /// arguments.
+    fn matches(&self, value: Option<&str>) -> Result<Option<String>> {
+        let Some(value) = value else {
+            return Ok(None);
+        };
+        if self.arguments.is_empty() {
+            return Ok(Some(value.to_string()));
+        }
+        Ok(self
+            .list()?
+            .map(|values| bool_string(values.iter().any(|v| v == value))))
+    }
+}
+
+fn condition(value: &str) -> Result<bool> {
+    match value {
+        "0" => Ok(false),
+        "1" => Ok(true),
+        _ => Err(GenexError::Condition(value.to_string())),
+    }
+}
+
+fn bool_string(value: bool) -> String {
+    if value { "1" } else { "0" }.to_string()
+}
+
+/// Whether the name looks like an expression rather than a condition value.
+fn is_expression_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_uppercase() || ch.is_ascii_digit() || ch == '_' || ch == '-')
+}
+
+/// Removes `$<...>` generator expressions including the nested ones.
+pub(crate) fn strip_genex(value: &str) -> String {
+    let mut result = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find("$<") {
+        result.push_str(&rest[..start]);
+        let mut nesting = 0;
+        let mut end = None;
+        let bytes = rest.as_bytes();
+        let mut position = start;
+        while position < bytes.len() {
+            if bytes[position..].starts_with(b"$<") {
+                nesting += 1;
+                position += 2;
+                continue;
+            }
+            if bytes[position] == b'>' {
+                nesting -= 1;
+                if nesting == 0 {
+                    end = Some(position + 1);
+                    break;
+                }
+            }
+            position += 1;
+        }
+        let Some(end) = end else {
+            // unterminated expressions are kept
+            result.push_str(&rest[start..]);
+            return result;
+        };
+        rest = &rest[end..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Replaces characters which are not valid in C identifiers with `_`.
+pub(crate) fn c_identifier(value: &str) -> String {
+    let mut identifier: String = value
+        .chars()
+        .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '_' })
+        .collect();
+    if identifier.starts_with(|ch: char| ch.is_ascii_digit()) {
+        identifier.insert(0, '_');
+    }
+    identifier
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        analysis::{UsageRequirements, Value},
+        *,
+    };
+    use pretty_assertions::assert_eq;
+
+    struct Targets;
+
+    impl TargetProperties for Targets {
+        fn target_property(&self, target: &str, property: &str) -> Option<String> {
+            match (target, property) {
+                ("app", "TYPE") => Some("EXECUTABLE".to_string()),
+                ("app", "OUTPUT_NAME") => Some("application".to_string()),
+                ("app", _) => Some(String::new()),
+                _ => None,
+            }
+        }
+
+        fn target_exists(&self, target: &str) -> Option<bool> {
+            Some(target == "app")
+        }
+
+        fn target_file(&self, target: &str) -> Option<String> {
+            (target == "app").then(|| "/build/bin/app".to_string())
+        }
+    }
+
+    fn eval(src: &str, context: &GenexContext) -> Option<String> {
+        Genex::parse(src)
+            .unwrap()
+            .evaluate(context)
+            .unwrap()
+            .known()
+            .map(str::to_string)
+    }
+
+    fn known(value: &str) -> Option<String> {
+        Some(value.to_string())
+    }
+
+    #[test]
+    fn genex_parse() {
+        let genex = Genex::parse("-D$<$<CONFIG:Debug,RelWithDebInfo>:DEBUG>=1").unwrap();
+        assert_eq!(
+            genex,
+            Genex {
+                nodes: vec![
+                    GenexNode::Text("-D".to_string()),
+                    GenexNode::Expression(GenexExpression {
+                        name: Genex {
+                            nodes: vec![GenexNode::Expression(GenexExpression {
+                                name: Genex {
+                                    nodes: vec![GenexNode::Text("CONFIG".to_string())]
+                                },
+                                arguments: Some(vec![
+                                    Genex {
+                                        nodes: vec![GenexNode::Text("Debug".to_string())]
+                                    },
+                                    Genex {
+                                        nodes: vec![GenexNode::Text("RelWithDebInfo".to_string())]
+                                    },
+                                ]),
+                            })]
+                        },
+                        arguments: Some(vec![Genex {
+                            nodes: vec![GenexNode::Text("DEBUG".to_string())]
+                        }]),
+                    }),
+                    GenexNode::Text("=1".to_string()),
+                ]
+            }
+        );
+        assert_eq!(
+            genex.to_string(),
+            "-D$<$<CONFIG:Debug,RelWithDebInfo>:DEBUG>=1"
+        );
+        assert!(!genex.is_literal());
+        assert!(Genex::parse("a,b>c").unwrap().is_literal());
+        assert_eq!(
+            Genex::parse("$<CONFIG:Debug"),
+            Err(GenexError::Unterminated)
+        );
+        assert_eq!(Genex::parse("$<1:$<CONFIG>"), Err(GenexError::Unterminated));
+    }
+
+    #[test]
+    fn genex_evaluate() {
+        let context = GenexContext::new()
+            .config("Debug")
+            .platform_id("Linux")
+            .compiler("CXX", "GNU", "12.2.0")
+            .compiler("C", "Clang", "15.0.7")
+            .language("CXX");
+
+        assert_eq!(eval("$<CONFIG>", &context), known("Debug"));
+        assert_eq!(eval("$<CONFIG:debug>", &context), known("1"));
+        assert_eq!(eval("$<CONFIG:Release,MinSizeRel>", &context), known("0"));
+        assert_eq!(eval("$<PLATFORM_ID:Darwin,Linux>", &context), known("1"));
+        assert_eq!(eval("$<CXX_COMPILER_ID>", &context), known("GNU"));
+        assert_eq!(eval("$<C_COMPILER_ID:GNU>", &context), known("0"));
+        assert_eq!(eval("$<CXX_COMPILER_VERSION:12.2>", &context), known("1"));
+        assert_eq!(
+            eval(
+                "$<VERSION_GREATER_EQUAL:$<C_COMPILER_VERSION>,15>",
+                &context
+            ),
+            known("1")
+        );
+        assert_eq!(eval("$<COMPILE_LANGUAGE:C,CXX>", &context), known("1"));
+        assert_eq!(
+            eval("$<COMPILE_LANG_AND_ID:CXX,Clang,GNU>", &context),
+            known("1")
+        );
+        assert_eq!(eval("$<COMPILE_LANG_AND_ID:C,Clang>", &context), known("0"));
+        assert_eq!(
+            eval(
+                "$<$<AND:$<CONFIG:Debug>,$<NOT:$<PLATFORM_ID:Windows>>>:-g,-O0>",
+                &context
+            ),
+            known("-g,-O0")
+        );
+        assert_eq!(eval("$<IF:$<BOOL:OFF>,yes,no>", &context), known("no"));
+        assert_eq!(eval("$<BOOL:foo-NOTFOUND>", &context), known("0"));
+        assert_eq!(
+            eval("$<STREQUAL:a,a>$<EQUAL:010,10>", &context),
+            known("11")
+        );
+        assert_eq!(eval("$<IN_LIST:b,a;b;c>", &context), known("1"));
+        assert_eq!(eval("$<JOIN:a;b;c,-I>", &context), known("a-Ib-Ic"));
+        assert_eq!(eval("$<REMOVE_DUPLICATES:a;b;a>", &context), known("a;b"));
+        assert_eq!(eval("$<UPPER_CASE:a,b>$<ANGLE-R>", &context), known("A,B>"));
+        assert_eq!(
+            eval("$<MAKE_C_IDENTIFIER:1-foo.h>", &context),
+            known("_1_foo_h")
+        );
+        assert_eq!(
+            eval(
+                "$<BUILD_INTERFACE:include>$<INSTALL_INTERFACE:inc>",
+                &context
+            ),
+            known("include")
+        );
+    }
+
+    #[test]
+    fn genex_unknown() {
+        let context = GenexContext::new().platform_id("Linux");
+
+        assert_eq!(eval("$<CONFIG:Debug>", &context), None);
+        assert_eq!(eval("a$<$<CONFIG:Debug>:-g>", &context), None);
+        assert_eq!(eval("$<0:$<CONFIG>>", &context), known(""));
+        assert_eq!(
+            eval("$<AND:$<CONFIG:Debug>,$<PLATFORM_ID:Windows>>", &context),
+            known("0")
+        );
+        assert_eq!(
+            eval("$<OR:$<CONFIG:Debug>,$<PLATFORM_ID:Linux>>", &context),
+            known("1")
+        );
+        assert_eq!(eval("$<OR:$<CONFIG:Debug>,0>", &context), None);
+        assert_eq!(eval("$<CXX_COMPILER_ID>", &context), None);
+        assert_eq!(eval("$<TARGET_OBJECTS:lib>", &context), None);
+        assert_eq!(eval("$<TARGET_FILE:app>", &context), None);
+
+        let error = |src| Genex::parse(src).unwrap().evaluate(&context).unwrap_err();
+        assert_eq!(error("$<yes:a>"), GenexError::Condition("yes".to_string()));
+        assert_eq!(error("$<NOT:2>"), GenexError::Condition("2".to_string()));
+        assert_eq!(
+            error("$<IF:1,a>"),
+            GenexError::Arguments {
+                name: "IF".to_string(),
+                actual: 2
+            }
+        );
+    }
+
+    #[test]
+    fn genex_target_properties() {
+        let targets = Targets;
+        let context = GenexContext::new().target("app").properties(&targets);
+
+        assert_eq!(
+            eval("$<TARGET_PROPERTY:TYPE>", &context),
+            known("EXECUTABLE")
+        );
+        assert_eq!(
+            eval("$<TARGET_PROPERTY:app,OUTPUT_NAME>", &context),
+            known("application")
+        );
+        assert_eq!(eval("$<TARGET_PROPERTY:app,FOLDER>", &context), known(""));
+        assert_eq!(eval("$<TARGET_PROPERTY:lib,TYPE>", &context), None);
+        assert_eq!(eval("$<TARGET_EXISTS:lib>", &context), known("0"));
+        assert_eq!(eval("$<TARGET_NAME_IF_EXISTS:app>", &context), known("app"));
+        assert_eq!(
+            eval("$<TARGET_FILE:app>", &context),
+            known("/build/bin/app")
+        );
+        assert_eq!(eval("$<TARGET_FILE_NAME:app>", &context), known("app"));
+        assert_eq!(
+            eval("$<TARGET_FILE_DIR:app>", &context),
+            known("/build/bin")
+        );
+    }
+
+    #[test]
+    fn genex_usage_requirements() {
+        let cmakelists = parse_cmakelists(
+            b"add_executable(app main.c)\n\
+              add_executable(tool tool.c)\n\
+              target_compile_definitions(app PRIVATE $<$<CONFIG:Debug>:DEBUG> APP)\n\
+              target_link_libraries(app PRIVATE $<TARGET_FILE:tool>)\n",
+        )
+        .unwrap();
+        let doc = Doc::from(cmakelists);
+        let usage = UsageRequirements::from_commands(&doc.commands().unwrap());
+        let app = usage.effective("app").unwrap();
+
+        struct Tool;
+        impl TargetProperties for Tool {
+            fn target_property(&self, _target: &str, _property: &str) -> Option<String> {
+                None
+            }
+
+            fn target_file(&self, target: &str) -> Option<String> {
+                Some(format!("bin/{target}"))
+            }
+        }
+
+        let evaluate = |values: &[Value], context: &GenexContext| -> Vec<Evaluation> {
+            values
+                .iter()
+                .map(|value| value.evaluate(context).unwrap())
+                .collect()
+        };
+
+        let debug = GenexContext::new().config("Debug").properties(&Tool);
+        assert_eq!(
+            evaluate(&app.compile_definitions, &debug),
+            vec![
+                Evaluation::Known("DEBUG".to_string()),
+                Evaluation::Known("APP".to_string())
+            ]
+        );
+        assert_eq!(
+            evaluate(&app.link_libraries, &debug),
+            vec![Evaluation::Known("bin/tool".to_string())]
+        );
+        assert_eq!(
+            evaluate(&app.compile_definitions, &GenexContext::new()),
+            vec![Evaluation::Unknown, Evaluation::Known("APP".to_string())]
+        );
+    }
+}
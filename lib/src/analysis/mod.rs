@@ -0,0 +1,83 @@
+mod edits;
+mod genex;
+mod modernize;
+mod policies;
+mod policy_catalog;
+mod project;
+mod properties;
+mod property_catalog;
+mod property_values;
+mod reference;
+mod rename;
+mod signatures;
+mod symbols;
+mod target_graph;
+mod usage;
+mod variable_catalog;
+mod variables;
+
+use crate::{Span, Token};
+
+pub use edits::ProjectEdits;
+#[cfg(feature = "eval")]
+pub(crate) use genex::c_identifier;
+pub(crate) use genex::strip_genex;
+pub use genex::{
+    Evaluation, Genex, GenexContext, GenexError, GenexExpression, GenexNode, TargetProperties,
+};
+pub use modernize::{Modernization, ModernizeIssue, ModernizeIssueKind, Proposal};
+pub use policies::{EffectivePolicies, PolicyInfo, PolicySetting, PolicySettings};
+pub use project::{
+    Diagnostic, DiagnosticKind, FileId, FileKind, ParseError, Project, ProjectFile, ProjectLoader,
+};
+pub use properties::{
+    check_properties, AssignMode, PropertyAssignment, PropertyInfo, PropertyIssue,
+    PropertyIssueKind, PropertyScope, PropertyType,
+};
+pub use property_values::{PropertyOrigin, PropertyValue, PropertyValues};
+pub use reference::{DocEntry, DocEntryKind, FileDocs, ReferenceDocs};
+pub use rename::{ReferenceKind, RenameIssue, RenameIssueKind, TargetReference, TargetRename};
+pub use signatures::{CallIssue, CallIssueKind, Keywords, Signature, SignatureKind, Signatures};
+pub use symbols::{
+    DefinitionKind, SymbolIssue, SymbolIssueKind, UseKind, VariableDefinition, VariableIndex,
+    VariableScope, VariableUse,
+};
+pub use target_graph::{Edge, Node, NodeId, TargetGraph, TargetKind, Visibility};
+pub use usage::{Origin, Requirements, TargetUsage, UsageRequirements, Value};
+pub use variables::{
+    check_variables, VariableCategory, VariableInfo, VariableIssue, VariableIssueKind,
+};
+
+/// Index of the argument a typed token is parsed from.
+pub(crate) fn argument_position(token: &Token, arguments: &[Token]) -> Option<usize> {
+    // Typed tokens share the bytes of the argument tokens they are parsed
+    // from.
+    let bytes = token.as_bytes();
+    arguments.iter().position(|argument| {
+        !bytes.is_empty()
+            && argument.as_bytes().as_ptr() == bytes.as_ptr()
+            && argument.as_bytes().len() == bytes.len()
+    })
+}
+
+/// Offset in `source` of the text of an unquoted or quoted argument, `None`
+/// for bracket arguments and arguments with escape sequences.
+pub(crate) fn text_offset(source: &str, argument: Span, text: &str) -> Option<usize> {
+    let argument_source = &source[argument.range()];
+    if argument_source == text {
+        Some(argument.start.offset)
+    } else if argument_source.starts_with('"')
+        && argument_source[1..argument_source.len() - 1] == *text
+    {
+        Some(argument.start.offset + 1)
+    } else {
+        None
+    }
+}
+
+/// Normalized path of a project of `fixture/analysis`.
+#[cfg(test)]
+pub(crate) fn fixture(name: &str) -> std::path::PathBuf {
+    let fixtures = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../fixture/analysis");
+    project::normalize(&fixtures.join(name))
+}
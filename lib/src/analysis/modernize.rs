@@ -0,0 +1,760 @@
+use std::{collections::HashSet, path::Path};
+
+use crate::{
+    analysis::{
+        project::path_value, FileId, FileKind, Project, ProjectEdits, ProjectFile, PropertyOrigin,
+    },
+    command::{
+        common::Append,
+        project::{add_executable::Executable, add_library::Library, TargetLinkLibraries},
+    },
+    lint::{line_range, Edit},
+    Command, Doc, Span, Token,
+};
+
+/// Rewrite of the directory-scoped `include_directories()`,
+/// `add_definitions()`, `add_compile_options()`, `link_directories()` and
+/// `link_libraries()` commands into `target_*()` commands for each target
+/// they affect.
+///
+/// The target-scoped commands are inserted after the command creating each
+/// executable, static, shared, module and object library, and the directory
+/// commands are removed. Like in CMake, `include_directories()` and
+/// `add_definitions()` affect all targets of their directory, the other
+/// commands only the targets created after them. Directory commands are
+/// kept, and not rewritten, when they are nested in control flow blocks or
+/// functions, also through `include()`, when they affect targets created in
+/// functions and macros, and when they come before subdirectories which are
+/// not analyzed. Relative paths inherited from a parent directory are made
+/// absolute with `${CMAKE_SOURCE_DIR}`.
+///
+/// ```
+/// use cmake_parser::{analysis::Modernization, parse_cmakelists, Doc};
+///
+/// let source = "include_directories(include)\nadd_executable(app main.c)\n";
+/// let doc = Doc::from(parse_cmakelists(source.as_bytes()).unwrap());
+/// let modernization = Modernization::from_doc(&doc);
+/// assert_eq!(
+///     modernization.edits().apply(None, source),
+///     "add_executable(app main.c)\ntarget_include_directories(app PRIVATE include)\n"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Modernization {
+    proposals: Vec<Proposal>,
+    issues: Vec<ModernizeIssue>,
+    edits: ProjectEdits,
+}
+
+/// Target-scoped commands replacing the directory commands affecting a
+/// target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Proposal {
+    pub target: String,
+    /// Command creating the target.
+    pub creation: PropertyOrigin,
+    /// Directory commands replaced by the proposal in order.
+    pub origins: Vec<PropertyOrigin>,
+    /// Source of the target-scoped commands.
+    pub commands: Vec<String>,
+}
+
+/// Reason directory commands are kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModernizeIssue {
+    /// File of the command, `None` for [`Modernization::from_doc`].
+    pub file: Option<FileId>,
+    /// Index of the command in the file.
+    pub command: usize,
+    pub kind: ModernizeIssueKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ModernizeIssueKind {
+    #[error("directory command in a control flow block or function is kept")]
+    Nested,
+    #[error("target created in a function or macro, the directory commands affecting it are kept")]
+    FunctionTarget,
+    #[error("subdirectory is not analyzed, the directory commands before it are kept")]
+    Subdirectory,
+}
+
+impl Modernization {
+    /// Rewrite of the commands of a single directory. Subdirectories are
+    /// not analyzed.
+    pub fn from_doc(doc: &Doc) -> Self {
+        let mut walker = Walker::default();
+        let mut directory = Directory::default();
+        walker.file(
+            None,
+            None,
+            doc,
+            &doc.source(),
+            &mut directory,
+            Nesting::default(),
+        );
+        walker.finish_directory(directory);
+        walker.finish()
+    }
+
+    /// Rewrite of all files of a project.
+    pub fn from_project(project: &Project) -> Self {
+        let mut walker = Walker {
+            root: project.root().map(|root| path_value(&root.source_dir)),
+            ..Default::default()
+        };
+        if let Some(root) = project.root() {
+            walker.project_file(project, root, Directory::default());
+        }
+        walker.finish()
+    }
+
+    pub fn proposals(&self) -> &[Proposal] {
+        &self.proposals
+    }
+
+    pub fn issues(&self) -> &[ModernizeIssue] {
+        &self.issues
+    }
+
+    /// Edits of the files, of the `None` file for
+    /// [`Modernization::from_doc`].
+    pub fn edits(&self) -> &ProjectEdits {
+        &self.edits
+    }
+}
+
+/// Directory command in effect.
+#[derive(Debug, Clone)]
+struct Setting {
+    kind: SettingKind,
+    origin: PropertyOrigin,
+    /// `CMAKE_CURRENT_SOURCE_DIR` of the command.
+    directory: String,
+}
+
+#[derive(Debug, Clone)]
+enum SettingKind {
+    IncludeDirectories {
+        system: bool,
+        before: bool,
+        dirs: Vec<Argument>,
+    },
+    Definitions {
+        definitions: Vec<Argument>,
+        options: Vec<Argument>,
+    },
+    CompileOptions(Vec<Argument>),
+    LinkDirectories {
+        before: bool,
+        dirs: Vec<Argument>,
+    },
+    LinkLibraries(Vec<Argument>),
+}
+
+impl SettingKind {
+    /// Whether the setting applies to all targets of its directory, and not
+    /// only to those created after it.
+    fn all_targets(&self) -> bool {
+        matches!(
+            self,
+            Self::IncludeDirectories { .. } | Self::Definitions { .. }
+        )
+    }
+}
+
+/// Argument of a directory command.
+#[derive(Debug, Clone)]
+struct Argument {
+    text: String,
+    quoted: bool,
+}
+
+/// State of a directory being processed.
+#[derive(Debug, Clone, Default)]
+struct Directory {
+    path: String,
+    settings: Vec<Setting>,
+    /// Targets created in the directory.
+    targets: Vec<usize>,
+    /// Whether targets are created in functions and macros.
+    function_targets: bool,
+}
+
+#[derive(Debug)]
+struct Target {
+    name: String,
+    creation: PropertyOrigin,
+    directory: String,
+    /// Settings in effect, those of the directory when it is created until
+    /// the directory is finished.
+    settings: Vec<Setting>,
+    /// Offset after the creating command and indentation of its line.
+    insert: usize,
+    indent: String,
+}
+
+/// Depths of the control flow blocks and of the functions and macros
+/// enclosing a command.
+#[derive(Debug, Clone, Copy, Default)]
+struct Nesting {
+    blocks: i32,
+    functions: i32,
+}
+
+#[derive(Debug, Default)]
+struct Walker {
+    root: Option<String>,
+    targets: Vec<Target>,
+    /// Targets linked with the plain `target_link_libraries()` signature.
+    plain: HashSet<String>,
+    /// Directory commands which are not rewritten.
+    kept: HashSet<PropertyOrigin>,
+    /// Rewritten directory commands with their deletion ranges.
+    deleted: Vec<(PropertyOrigin, std::ops::Range<usize>)>,
+    issues: Vec<ModernizeIssue>,
+}
+
+impl Walker {
+    fn project_file(&mut self, project: &Project, file: &ProjectFile, mut directory: Directory) {
+        directory.path = path_value(&file.source_dir);
+        let Ok(doc) = file.doc() else {
+            return;
+        };
+        let source = String::from_utf8_lossy(file.source());
+        let nesting = Nesting::default();
+        self.file(
+            Some(project),
+            Some(file),
+            &doc,
+            &source,
+            &mut directory,
+            nesting,
+        );
+        self.finish_directory(directory);
+    }
+
+    fn file(
+        &mut self,
+        project: Option<&Project>,
+        file: Option<&ProjectFile>,
+        doc: &Doc,
+        source: &str,
+        directory: &mut Directory,
+        mut nesting: Nesting,
+    ) {
+        let spans = doc.spans();
+        let commands = doc.to_commands_iter().zip(doc.arguments()).zip(&spans);
+        for (index, ((command, tokens), span)) in commands.enumerate() {
+            let origin = PropertyOrigin {
+                file: file.map(|file| file.id),
+                command: index,
+            };
+            let issue = |kind| ModernizeIssue {
+                file: origin.file,
+                command: index,
+                kind,
+            };
+            let command = command.ok();
+            match &command {
+                Some(
+                    Command::If(_) | Command::ForEach(_) | Command::While(_) | Command::Block(_),
+                ) => nesting.blocks += 1,
+                Some(
+                    Command::EndIf(_)
+                    | Command::EndForEach(_)
+                    | Command::EndWhile(_)
+                    | Command::EndBlock(_),
+                ) => nesting.blocks -= 1,
+                Some(Command::Function(_) | Command::Macro(_)) => nesting.functions += 1,
+                Some(Command::EndFunction(_) | Command::EndMacro(_)) => nesting.functions -= 1,
+                _ => (),
+            }
+            if let Some(kind) = command
+                .as_ref()
+                .and_then(|command| setting(command, &tokens))
+            {
+                if nesting.blocks > 0 || nesting.functions > 0 {
+                    self.issues.push(issue(ModernizeIssueKind::Nested));
+                } else {
+                    directory.settings.push(Setting {
+                        kind,
+                        origin,
+                        directory: directory.path.clone(),
+                    });
+                    self.deleted.push((origin, line_range(source, *span)));
+                }
+            }
+            let target = match &command {
+                Some(Command::AddExecutable(add_executable))
+                    if matches!(add_executable.executable, Executable::Normal(_)) =>
+                {
+                    Some(&add_executable.name)
+                }
+                Some(Command::AddLibrary(add_library))
+                    if matches!(add_library.library, Library::Normal(_) | Library::Object(_)) =>
+                {
+                    Some(&add_library.name)
+                }
+                Some(Command::TargetLinkLibraries(target_link_libraries)) => {
+                    if let TargetLinkLibraries::TargetAndDependents(tll) =
+                        target_link_libraries.as_ref()
+                    {
+                        self.plain.insert(tll.target.to_string());
+                    }
+                    None
+                }
+                _ => None,
+            };
+            match target {
+                Some(_) if nesting.functions > 0 => {
+                    self.keep(&directory.settings);
+                    directory.function_targets = true;
+                    self.issues.push(issue(ModernizeIssueKind::FunctionTarget));
+                }
+                Some(name) => self.create_target(name, origin, span, source, directory),
+                None => (),
+            }
+
+            let children: Vec<&ProjectFile> = match (project, file) {
+                (Some(project), Some(file)) => file
+                    .children
+                    .iter()
+                    .filter_map(|&child| project.file(child))
+                    .filter(|child| child.parent == Some(file.id) && child.command == Some(index))
+                    .collect(),
+                _ => vec![],
+            };
+            let adds_subdirectory = matches!(
+                command,
+                Some(Command::AddSubdirectory(_) | Command::Subdirs(_))
+            );
+            if adds_subdirectory
+                && !children
+                    .iter()
+                    .any(|child| child.kind == FileKind::Subdirectory)
+            {
+                self.keep(&directory.settings);
+                self.issues.push(issue(ModernizeIssueKind::Subdirectory));
+            }
+            for child in children {
+                let (Some(project), FileKind::Include) = (project, child.kind) else {
+                    let subdirectory = Directory {
+                        settings: directory.settings.clone(),
+                        ..Default::default()
+                    };
+                    if let Some(project) = project {
+                        self.project_file(project, child, subdirectory);
+                    }
+                    continue;
+                };
+                if let Ok(child_doc) = child.doc() {
+                    let child_source = String::from_utf8_lossy(child.source());
+                    self.file(
+                        Some(project),
+                        Some(child),
+                        &child_doc,
+                        &child_source,
+                        directory,
+                        nesting,
+                    );
+                }
+            }
+        }
+    }
+
+    fn create_target(
+        &mut self,
+        name: &Token,
+        creation: PropertyOrigin,
+        span: &Span,
+        source: &str,
+        directory: &mut Directory,
+    ) {
+        let line_start = span.start.offset - (span.start.column - 1);
+        directory.targets.push(self.targets.len());
+        self.targets.push(Target {
+            name: name.to_string(),
+            creation,
+            directory: directory.path.clone(),
+            settings: directory.settings.clone(),
+            insert: span.end.offset,
+            indent: source[line_start..span.start.offset].to_string(),
+        });
+    }
+
+    /// Adds the settings of a processed directory applying to all its
+    /// targets, whether they are created before or after them, in the order
+    /// of the directory commands.
+    fn finish_directory(&mut self, directory: Directory) {
+        for &target in &directory.targets {
+            let target = &mut self.targets[target];
+            let created = target.settings.len();
+            target.settings = directory
+                .settings
+                .iter()
+                .enumerate()
+                .filter(|(index, setting)| *index < created || setting.kind.all_targets())
+                .map(|(_, setting)| setting.clone())
+                .collect();
+        }
+        if directory.function_targets {
+            let settings: Vec<_> = directory
+                .settings
+                .into_iter()
+                .filter(|setting| setting.kind.all_targets())
+                .collect();
+            self.keep(&settings);
+        }
+    }
+
+    fn keep(&mut self, settings: &[Setting]) {
+        self.kept
+            .extend(settings.iter().map(|setting| setting.origin));
+    }
+
+    fn finish(self) -> Modernization {
+        let mut modernization = Modernization {
+            issues: self.issues.clone(),
+            ..Default::default()
+        };
+        for target in &self.targets {
+            let settings: Vec<&Setting> = target
+                .settings
+                .iter()
+                .filter(|setting| !self.kept.contains(&setting.origin))
+                .collect();
+            if settings.is_empty() {
+                continue;
+            }
+            let commands: Vec<String> = settings
+                .iter()
+                .flat_map(|setting| self.commands(target, setting))
+                .collect();
+            let insertion: String = commands
+                .iter()
+                .map(|command| format!("\n{}{command}", target.indent))
+                .collect();
+            modernization.edits.push(
+                target.creation.file,
+                Edit::new(target.insert..target.insert, insertion),
+            );
+            modernization.proposals.push(Proposal {
+                target: target.name.clone(),
+                creation: target.creation,
+                origins: settings.iter().map(|setting| setting.origin).collect(),
+                commands,
+            });
+        }
+        for (origin, range) in self.deleted {
+            if !self.kept.contains(&origin) {
+                modernization.edits.push(origin.file, Edit::new(range, ""));
+            }
+        }
+        modernization
+    }
+
+    /// Target-scoped commands equivalent to a setting.
+    fn commands(&self, target: &Target, setting: &Setting) -> Vec<String> {
+        let name = Token::text_node(target.name.as_bytes(), false).to_source();
+        let arguments = |arguments: &[Argument], paths: bool| {
+            arguments
+                .iter()
+                .map(|argument| {
+                    let mut text = argument.text.clone();
+                    if paths && setting.directory != target.directory && is_relative(&text) {
+                        text = format!("{}/{text}", self.source_dir(&setting.directory));
+                    }
+                    Token::text_node(text.as_bytes(), argument.quoted).to_source()
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let flag = |enabled: bool, flag: &str| {
+            if enabled {
+                format!("{flag} ")
+            } else {
+                String::new()
+            }
+        };
+        match &setting.kind {
+            SettingKind::IncludeDirectories {
+                system,
+                before,
+                dirs,
+            } => vec![format!(
+                "target_include_directories({name} {}{}PRIVATE {})",
+                flag(*system, "SYSTEM"),
+                flag(*before, "BEFORE"),
+                arguments(dirs, true)
+            )],
+            SettingKind::Definitions {
+                definitions,
+                options,
+            } => [
+                ("target_compile_definitions", definitions),
+                ("target_compile_options", options),
+            ]
+            .into_iter()
+            .filter(|(_, arguments)| !arguments.is_empty())
+            .map(|(command, values)| {
+                format!("{command}({name} PRIVATE {})", arguments(values, false))
+            })
+            .collect(),
+            SettingKind::CompileOptions(options) => vec![format!(
+                "target_compile_options({name} PRIVATE {})",
+                arguments(options, false)
+            )],
+            SettingKind::LinkDirectories { before, dirs } => vec![format!(
+                "target_link_directories({name} {}PRIVATE {})",
+                flag(*before, "BEFORE"),
+                arguments(dirs, true)
+            )],
+            SettingKind::LinkLibraries(libraries) => vec![format!(
+                "target_link_libraries({name} {}{})",
+                flag(!self.plain.contains(&target.name), "PRIVATE"),
+                arguments(libraries, false)
+            )],
+        }
+    }
+
+    /// Directory relative to `${CMAKE_SOURCE_DIR}`.
+    fn source_dir(&self, directory: &str) -> String {
+        let relative = self
+            .root
+            .as_ref()
+            .and_then(|root| Path::new(directory).strip_prefix(root).ok())
+            .map(path_value)
+            .unwrap_or_default();
+        if relative.is_empty() {
+            "${CMAKE_SOURCE_DIR}".to_string()
+        } else {
+            format!("${{CMAKE_SOURCE_DIR}}/{relative}")
+        }
+    }
+}
+
+/// Setting of a directory-scoped command.
+fn setting(command: &Command, tokens: &[Token]) -> Option<SettingKind> {
+    let arguments = |tokens: &[Token]| {
+        tokens
+            .iter()
+            .map(|token| Argument {
+                text: token.to_string(),
+                quoted: token.is_quoted(),
+            })
+            .collect::<Vec<_>>()
+    };
+    Some(match command {
+        Command::IncludeDirectories(include_directories) => SettingKind::IncludeDirectories {
+            system: include_directories.system,
+            before: include_directories.append == Some(Append::Before),
+            dirs: arguments(&include_directories.dirs),
+        },
+        Command::AddDefinitions(add_definitions) => {
+            let (definitions, options): (Vec<_>, Vec<_>) = add_definitions
+                .definitions
+                .iter()
+                .partition(|definition| is_definition(definition));
+            let definitions: Vec<_> = definitions
+                .iter()
+                .filter_map(|definition| {
+                    definition
+                        .strip_prefix(b"-D")
+                        .or_else(|| definition.strip_prefix(b"/D"))
+                })
+                .filter(|definition| !definition.as_bytes().is_empty())
+                .collect();
+            let options: Vec<_> = options.into_iter().cloned().collect();
+            SettingKind::Definitions {
+                definitions: arguments(&definitions),
+                options: arguments(&options),
+            }
+        }
+        Command::AddCompileOptions(add_compile_options) => {
+            SettingKind::CompileOptions(arguments(&add_compile_options.compile_options))
+        }
+        Command::LinkDirectories(link_directories) => SettingKind::LinkDirectories {
+            before: link_directories.append == Some(Append::Before),
+            dirs: arguments(&link_directories.dirs),
+        },
+        // The libraries and their keywords are kept as written.
+        Command::LinkLibraries(_) => SettingKind::LinkLibraries(arguments(tokens)),
+        _ => return None,
+    })
+}
+
+/// Whether an `add_definitions()` argument is a preprocessor definition,
+/// which CMake moves to `COMPILE_DEFINITIONS`.
+fn is_definition(token: &Token) -> bool {
+    let bytes = token.as_bytes();
+    (bytes.starts_with(b"-D") || bytes.starts_with(b"/D"))
+        && bytes[2..]
+            .iter()
+            .take_while(|&&ch| ch != b'=')
+            .all(|ch| ch.is_ascii_alphanumeric() || *ch == b'_')
+}
+
+fn is_relative(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    !(path.is_empty()
+        || path.starts_with(['/', '$', '\\'])
+        || bytes.len() > 1 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::{fixture, ProjectLoader};
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn modernize_doc() {
+        let source = r#"include_directories(SYSTEM /opt/include "with space")
+add_definitions(-DDEBUG /DWIN -fno-rtti)
+add_library(lib OBJECT lib.c)
+if(UNIX)
+  link_directories(/opt/lib)
+endif()
+add_compile_options(-O2) # optimize
+  add_executable(tool tool.c)
+target_link_libraries(tool lib)
+link_libraries(pthread)
+add_library(imported SHARED IMPORTED)
+"#;
+        let doc = Doc::from(parse_cmakelists(source.as_bytes()).unwrap());
+        let modernization = Modernization::from_doc(&doc);
+        assert_eq!(
+            modernization.issues(),
+            [ModernizeIssue {
+                file: None,
+                command: 4,
+                kind: ModernizeIssueKind::Nested,
+            }]
+        );
+        assert_eq!(
+            modernization.proposals()[0].commands,
+            [
+                r#"target_include_directories(lib SYSTEM PRIVATE /opt/include "with space")"#,
+                "target_compile_definitions(lib PRIVATE DEBUG WIN)",
+                "target_compile_options(lib PRIVATE -fno-rtti)",
+            ]
+        );
+        assert_eq!(
+            modernization.edits().apply(None, source),
+            r#"add_library(lib OBJECT lib.c)
+target_include_directories(lib SYSTEM PRIVATE /opt/include "with space")
+target_compile_definitions(lib PRIVATE DEBUG WIN)
+target_compile_options(lib PRIVATE -fno-rtti)
+if(UNIX)
+  link_directories(/opt/lib)
+endif()
+# optimize
+  add_executable(tool tool.c)
+  target_include_directories(tool SYSTEM PRIVATE /opt/include "with space")
+  target_compile_definitions(tool PRIVATE DEBUG WIN)
+  target_compile_options(tool PRIVATE -fno-rtti)
+  target_compile_options(tool PRIVATE -O2)
+target_link_libraries(tool lib)
+add_library(imported SHARED IMPORTED)
+"#
+        );
+
+        let source = "add_definitions(-DDEBUG)
+function(make name)
+  add_executable(${name} main.c)
+endfunction()
+add_executable(app main.c)
+";
+        let doc = Doc::from(parse_cmakelists(source.as_bytes()).unwrap());
+        let modernization = Modernization::from_doc(&doc);
+        assert_eq!(
+            modernization.issues(),
+            [ModernizeIssue {
+                file: None,
+                command: 2,
+                kind: ModernizeIssueKind::FunctionTarget,
+            }]
+        );
+        assert_eq!(modernization.proposals(), []);
+        assert_eq!(modernization.edits().apply(None, source), source);
+
+        let source = "add_executable(a a.c)
+include_directories(inc)
+add_compile_options(-O2)
+add_executable(b b.c)
+";
+        let doc = Doc::from(parse_cmakelists(source.as_bytes()).unwrap());
+        let modernization = Modernization::from_doc(&doc);
+        assert_eq!(
+            modernization.edits().apply(None, source),
+            "add_executable(a a.c)
+target_include_directories(a PRIVATE inc)
+add_executable(b b.c)
+target_include_directories(b PRIVATE inc)
+target_compile_options(b PRIVATE -O2)
+"
+        );
+    }
+
+    #[test]
+    fn modernize_project() {
+        let project = ProjectLoader::new(fixture("modernize")).load();
+        let modernization = Modernization::from_project(&project);
+        assert_eq!(
+            modernization.issues(),
+            [
+                ModernizeIssue {
+                    file: project.file_id(&fixture("modernize").join("win.cmake")),
+                    command: 0,
+                    kind: ModernizeIssueKind::Nested,
+                },
+                ModernizeIssue {
+                    file: project.file_id(&fixture("modernize").join("lib/CMakeLists.txt")),
+                    command: 3,
+                    kind: ModernizeIssueKind::Nested,
+                },
+            ]
+        );
+        assert_eq!(
+            modernization.edits().project_diff(&project),
+            "--- a/CMakeLists.txt
++++ b/CMakeLists.txt
+@@ -1,11 +1,13 @@
+ cmake_minimum_required(VERSION 3.15)
+ project(modernize C)
+-include_directories(include)
+-add_definitions(-DVERSION=2 -Wall)
+ include(flags.cmake)
+ if(WIN32)
+   include(win.cmake)
+ endif()
+ add_subdirectory(lib)
+ add_executable(app main.c)
++target_include_directories(app PRIVATE include)
++target_compile_definitions(app PRIVATE VERSION=2)
++target_compile_options(app PRIVATE -Wall)
++target_link_libraries(app PRIVATE m)
+ target_link_libraries(app PRIVATE util)
+--- a/flags.cmake
++++ b/flags.cmake
+@@ -1,1 +0,0 @@
+-link_libraries(m)
+--- a/lib/CMakeLists.txt
++++ b/lib/CMakeLists.txt
+@@ -1,5 +1,9 @@
+-include_directories(BEFORE private)
+ add_library(util STATIC util.c)
++target_include_directories(util PRIVATE ${CMAKE_SOURCE_DIR}/include)
++target_compile_definitions(util PRIVATE VERSION=2)
++target_compile_options(util PRIVATE -Wall)
++target_link_libraries(util PRIVATE m)
++target_include_directories(util BEFORE PRIVATE private)
+ if(WIN32)
+   add_compile_options(/W4)
+ endif()
+"
+        );
+    }
+}
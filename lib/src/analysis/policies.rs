@@ -0,0 +1,353 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+use crate::{
+    analysis::{policy_catalog::POLICIES, FileId, FileKind, Project, ProjectFile},
+    command::scripting::cmake_policy::{Behavior, CMakePolicy},
+    eval::{CMakeVersion, VersionBound, VersionRange},
+    Command,
+};
+
+/// Policy documented by CMake.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PolicyInfo {
+    /// Identifier like `CMP0077`.
+    pub id: &'static str,
+    /// Version of CMake the policy was introduced in.
+    pub introduced: CMakeVersion,
+    pub summary: &'static str,
+    /// Behavior of the `OLD` setting, used for unset policies.
+    pub old: &'static str,
+    /// Behavior of the `NEW` setting.
+    pub new: &'static str,
+}
+
+/// Setting of a policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PolicySetting {
+    Old,
+    New,
+}
+
+/// Policy settings of a policy scope. Unset policies use the `OLD`
+/// behavior, CMake warns about most of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PolicySettings {
+    settings: BTreeMap<String, PolicySetting>,
+}
+
+/// Policy settings in effect at every command of a file or project.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EffectivePolicies {
+    files: HashMap<Option<FileId>, Vec<PolicySettings>>,
+}
+
+impl PolicyInfo {
+    /// All policies of the catalog.
+    pub fn all() -> &'static [PolicyInfo] {
+        POLICIES
+    }
+
+    pub fn find(id: &str) -> Option<&'static PolicyInfo> {
+        POLICIES.iter().find(|info| info.id == id)
+    }
+}
+
+impl fmt::Display for PolicySetting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Old => "OLD",
+            Self::New => "NEW",
+        })
+    }
+}
+
+impl From<&Behavior> for PolicySetting {
+    fn from(behavior: &Behavior) -> Self {
+        match behavior {
+            Behavior::Old => Self::Old,
+            Behavior::New => Self::New,
+        }
+    }
+}
+
+impl PolicySettings {
+    /// Setting of a policy, `None` if it is unset.
+    pub fn get(&self, id: &str) -> Option<PolicySetting> {
+        self.settings.get(id).copied()
+    }
+
+    pub fn set(&mut self, id: &str, setting: PolicySetting) {
+        self.settings.insert(id.to_string(), setting);
+    }
+
+    /// Sets the policies introduced up to a version to `NEW` and unsets the
+    /// later ones, like `cmake_policy(VERSION)`.
+    pub fn version(&mut self, version: &CMakeVersion) {
+        for info in POLICIES {
+            if info.introduced <= *version {
+                self.set(info.id, PolicySetting::New);
+            } else {
+                self.settings.remove(info.id);
+            }
+        }
+    }
+
+    /// Set policies ordered by identifier.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, PolicySetting)> {
+        self.settings
+            .iter()
+            .map(|(id, setting)| (id.as_str(), *setting))
+    }
+}
+
+impl EffectivePolicies {
+    /// Settings of the commands of a file, starting with all policies
+    /// unset. Files are keyed by `None`.
+    ///
+    /// ```
+    /// use cmake_parser::{analysis::{EffectivePolicies, PolicySetting}, parse_cmakelists, Doc};
+    ///
+    /// let cmakelists = parse_cmakelists(b"cmake_minimum_required(VERSION 3.12)\nmessage(hi)\n").unwrap();
+    /// let policies = EffectivePolicies::from_commands(&Doc::from(cmakelists).commands().unwrap());
+    /// let settings = policies.at(None, 1).unwrap();
+    /// assert_eq!(settings.get("CMP0074"), Some(PolicySetting::New));
+    /// assert_eq!(settings.get("CMP0077"), None);
+    /// ```
+    pub fn from_commands(commands: &[Command]) -> Self {
+        let mut stack = PolicyStack::default();
+        let settings = commands
+            .iter()
+            .map(|command| {
+                let settings = stack.current.clone();
+                stack.command(command);
+                settings
+            })
+            .collect();
+        Self {
+            files: HashMap::from([(None, settings)]),
+        }
+    }
+
+    /// Settings of the commands of all files of a project. Subdirectories
+    /// start with the settings of their `add_subdirectory()` command,
+    /// included files with those of their `include()` command and have
+    /// their own policy scope unless `NO_POLICY_SCOPE` is given.
+    pub fn from_project(project: &Project) -> Self {
+        let mut policies = Self::default();
+        if let Some(root) = project.root() {
+            policies.file(project, root, &mut PolicyStack::default());
+        }
+        policies
+    }
+
+    /// Settings in effect when a command of a file runs.
+    pub fn at(&self, file: Option<FileId>, command: usize) -> Option<&PolicySettings> {
+        self.files.get(&file)?.get(command)
+    }
+
+    fn file(&mut self, project: &Project, file: &ProjectFile, stack: &mut PolicyStack) {
+        let Ok(doc) = file.doc() else {
+            return;
+        };
+        let mut settings = vec![];
+        for (index, command) in doc.to_commands_iter().enumerate() {
+            settings.push(stack.current.clone());
+            let command = command.ok();
+            if let Some(command) = &command {
+                stack.command(command);
+            }
+            let children = file
+                .children
+                .iter()
+                .filter_map(|&child| project.file(child))
+                .filter(|child| child.parent == Some(file.id) && child.command == Some(index));
+            for child in children {
+                match child.kind {
+                    FileKind::Include => {
+                        let scoped = !matches!(
+                            &command,
+                            Some(Command::Include(include)) if include.no_policy_scope
+                        );
+                        let saved = scoped.then(|| stack.clone());
+                        self.file(project, child, stack);
+                        if let Some(saved) = saved {
+                            *stack = saved;
+                        }
+                    }
+                    FileKind::Root | FileKind::Subdirectory => {
+                        let mut directory = PolicyStack {
+                            current: stack.current.clone(),
+                            ..Default::default()
+                        };
+                        self.file(project, child, &mut directory);
+                    }
+                }
+            }
+        }
+        self.files.insert(Some(file.id), settings);
+    }
+}
+
+/// Current policy settings with the settings of the enclosing scopes.
+#[derive(Debug, Clone, Default)]
+struct PolicyStack {
+    current: PolicySettings,
+    saved: Vec<PolicySettings>,
+    /// Whether the enclosing `function()` and `block()` commands have a
+    /// policy scope.
+    blocks: Vec<bool>,
+}
+
+impl PolicyStack {
+    fn command(&mut self, command: &Command) {
+        match command {
+            Command::CMakeMinimumRequired(required) => {
+                if let Ok(range) = required.version_range() {
+                    self.current.version(&policy_version(&range));
+                }
+            }
+            Command::CMakePolicy(policy) => match policy.as_ref() {
+                CMakePolicy::Version(version) => {
+                    if let Ok(range) = version.min.to_version_range() {
+                        self.current.version(&policy_version(&range));
+                    }
+                }
+                CMakePolicy::Set(set) => {
+                    self.current
+                        .set(&set.policy.to_string(), (&set.behavior).into());
+                }
+                CMakePolicy::Push => self.push(),
+                CMakePolicy::Pop => self.pop(),
+                CMakePolicy::Get(_) => (),
+            },
+            Command::Function(_) => self.open(true),
+            Command::Block(block) => {
+                self.open(block.scope_for.as_ref().is_none_or(|scope| scope.policies));
+            }
+            Command::EndFunction(_) | Command::EndBlock(_) => self.close(),
+            _ => (),
+        }
+    }
+
+    fn open(&mut self, scoped: bool) {
+        if scoped {
+            self.push();
+        }
+        self.blocks.push(scoped);
+    }
+
+    fn close(&mut self) {
+        if self.blocks.pop() == Some(true) {
+            self.pop();
+        }
+    }
+
+    fn push(&mut self) {
+        self.saved.push(self.current.clone());
+    }
+
+    fn pop(&mut self) {
+        if let Some(saved) = self.saved.pop() {
+            self.current = saved;
+        }
+    }
+}
+
+/// Version policies are set to by `min[...max]`.
+fn policy_version(range: &VersionRange) -> CMakeVersion {
+    match range.max {
+        Some(VersionBound::Inclusive(max) | VersionBound::Exclusive(max)) => max,
+        None => range.min,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::{fixture, ProjectLoader};
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn policy_catalog() {
+        let policies = PolicyInfo::all();
+        assert_eq!(policies.len(), 151);
+        for (number, info) in policies.iter().enumerate() {
+            assert_eq!(info.id, format!("CMP{number:04}"));
+        }
+        let info = PolicyInfo::find("CMP0077").unwrap();
+        assert_eq!(info.introduced, "3.13".parse().unwrap());
+        assert_eq!(info.summary, "option() honors normal variables.");
+        assert_eq!(PolicyInfo::find("CMP0151"), None);
+    }
+
+    #[test]
+    fn effective_policies() {
+        let cmakelists = parse_cmakelists(
+            b"cmake_minimum_required(VERSION 3.10...3.20)
+cmake_policy(SET CMP0135 NEW)
+function(f)
+  cmake_policy(SET CMP0077 OLD)
+  message(x)
+endfunction()
+block(SCOPE_FOR VARIABLES)
+  cmake_policy(SET CMP0143 OLD)
+endblock()
+cmake_policy(PUSH)
+cmake_policy(VERSION 2.8)
+cmake_policy(POP)
+message(done)
+",
+        )
+        .unwrap();
+        let policies = EffectivePolicies::from_commands(&Doc::from(cmakelists).commands().unwrap());
+        let get = |command, id| policies.at(None, command).unwrap().get(id);
+        assert_eq!(get(0, "CMP0077"), None);
+        assert_eq!(get(1, "CMP0077"), Some(PolicySetting::New));
+        assert_eq!(get(1, "CMP0121"), None);
+        assert_eq!(get(2, "CMP0135"), Some(PolicySetting::New));
+        assert_eq!(get(4, "CMP0077"), Some(PolicySetting::Old));
+        assert_eq!(get(6, "CMP0077"), Some(PolicySetting::New));
+        assert_eq!(get(7, "CMP0143"), None);
+        assert_eq!(get(9, "CMP0143"), Some(PolicySetting::Old));
+        assert_eq!(get(11, "CMP0048"), None);
+        assert_eq!(get(11, "CMP0011"), Some(PolicySetting::New));
+        assert_eq!(get(12, "CMP0048"), Some(PolicySetting::New));
+        assert_eq!(get(12, "CMP0143"), Some(PolicySetting::Old));
+        assert_eq!(policies.at(None, 13), None);
+    }
+
+    #[test]
+    fn project_policies() {
+        let project = ProjectLoader::new(fixture("policies")).load();
+        let policies = EffectivePolicies::from_project(&project);
+        let file = |path: &str| project.file_id(&fixture("policies").join(path));
+        let get = |path, command, id| policies.at(file(path), command).unwrap().get(id);
+
+        assert_eq!(
+            get("CMakeLists.txt", 5, "CMP0091"),
+            Some(PolicySetting::New)
+        );
+        assert_eq!(
+            get("CMakeLists.txt", 5, "CMP0092"),
+            Some(PolicySetting::Old)
+        );
+        assert_eq!(
+            get("CMakeLists.txt", 5, "CMP0093"),
+            Some(PolicySetting::New)
+        );
+        assert_eq!(get("scoped.cmake", 0, "CMP0091"), Some(PolicySetting::New));
+        assert_eq!(get("scoped.cmake", 1, "CMP0091"), Some(PolicySetting::Old));
+        assert_eq!(
+            get("sub/CMakeLists.txt", 0, "CMP0092"),
+            Some(PolicySetting::Old)
+        );
+        assert_eq!(
+            get("sub/CMakeLists.txt", 2, "CMP0093"),
+            Some(PolicySetting::Old)
+        );
+    }
+}
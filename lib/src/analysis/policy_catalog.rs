@@ -0,0 +1,1078 @@
+use crate::{analysis::PolicyInfo, eval::CMakeVersion};
+
+const fn policy(
+    id: &'static str,
+    introduced: &[u64],
+    summary: &'static str,
+    old: &'static str,
+    new: &'static str,
+) -> PolicyInfo {
+    PolicyInfo {
+        id,
+        introduced: CMakeVersion::new(introduced),
+        summary,
+        old,
+        new,
+    }
+}
+
+/// Policies CMP0000 to CMP0150.
+pub(crate) const POLICIES: &[PolicyInfo] = &[
+    policy(
+        "CMP0000",
+        &[2, 6],
+        "A minimum required CMake version must be specified.",
+        "Assume version 2.4 without cmake_minimum_required().",
+        "Require cmake_minimum_required() in the top level CMakeLists.txt.",
+    ),
+    policy(
+        "CMP0001",
+        &[2, 6],
+        "CMAKE_BACKWARDS_COMPATIBILITY should no longer be used.",
+        "Honor CMAKE_BACKWARDS_COMPATIBILITY.",
+        "Ignore CMAKE_BACKWARDS_COMPATIBILITY.",
+    ),
+    policy(
+        "CMP0002",
+        &[2, 6],
+        "Logical target names must be globally unique.",
+        "Allow duplicate target names in different directories.",
+        "Report duplicate target names as errors.",
+    ),
+    policy(
+        "CMP0003",
+        &[2, 6],
+        "Libraries linked via full path no longer produce linker search paths.",
+        "Add the directories of full path libraries to the linker search path.",
+        "Link libraries by full path without extra search paths.",
+    ),
+    policy(
+        "CMP0004",
+        &[2, 6],
+        "Libraries linked may not have leading or trailing whitespace.",
+        "Strip the whitespace of linked library names.",
+        "Report whitespace around linked library names as an error.",
+    ),
+    policy(
+        "CMP0005",
+        &[2, 6],
+        "Preprocessor definition values are now escaped automatically.",
+        "Pass definition values to the compiler as written.",
+        "Escape definition values for the native build tool.",
+    ),
+    policy(
+        "CMP0006",
+        &[2, 6],
+        "Installing MACOSX_BUNDLE targets requires a BUNDLE DESTINATION.",
+        "Install bundles with the RUNTIME DESTINATION.",
+        "Require a BUNDLE DESTINATION for bundles.",
+    ),
+    policy(
+        "CMP0007",
+        &[2, 6],
+        "list() command no longer ignores empty elements.",
+        "Ignore empty list elements.",
+        "Keep empty list elements.",
+    ),
+    policy(
+        "CMP0008",
+        &[2, 6, 1],
+        "Libraries linked by full path must have a valid library file name.",
+        "Split invalid full path library names into a directory and a name.",
+        "Link libraries with invalid file names by full path.",
+    ),
+    policy(
+        "CMP0009",
+        &[2, 6, 2],
+        "file(GLOB_RECURSE) calls should not follow symlinks by default.",
+        "Follow symbolic links.",
+        "Follow symbolic links only with FOLLOW_SYMLINKS.",
+    ),
+    policy(
+        "CMP0010",
+        &[2, 6, 3],
+        "Bad variable reference syntax is an error.",
+        "Warn about bad variable references.",
+        "Report bad variable references as errors.",
+    ),
+    policy(
+        "CMP0011",
+        &[2, 6, 3],
+        "Included scripts do automatic cmake_policy() PUSH and POP.",
+        "Let policies set in included files leak into the includer.",
+        "Scope policies set in included files unless NO_POLICY_SCOPE is given.",
+    ),
+    policy(
+        "CMP0012",
+        &[2, 8],
+        "if() recognizes numbers and boolean constants.",
+        "Dereference numbers and boolean constants named like variables.",
+        "Treat numbers and boolean constants as values.",
+    ),
+    policy(
+        "CMP0013",
+        &[2, 8],
+        "Duplicate binary directories are not allowed.",
+        "Allow several source directories to share a binary directory.",
+        "Report shared binary directories as errors.",
+    ),
+    policy(
+        "CMP0014",
+        &[2, 8],
+        "Input directories must have CMakeLists.txt.",
+        "Silently ignore subdirectories without CMakeLists.txt.",
+        "Report subdirectories without CMakeLists.txt as errors.",
+    ),
+    policy(
+        "CMP0015",
+        &[2, 8, 1],
+        "link_directories() treats paths relative to the source dir.",
+        "Pass relative link directories to the linker unchanged.",
+        "Interpret relative link directories relative to the source directory.",
+    ),
+    policy(
+        "CMP0016",
+        &[2, 8, 3],
+        "target_link_libraries() reports an error if its only argument is not a target.",
+        "Silently ignore a single non-target argument.",
+        "Report a single non-target argument as an error.",
+    ),
+    policy(
+        "CMP0017",
+        &[2, 8, 4],
+        "Prefer files from the CMake module directory when including from there.",
+        "Search CMAKE_MODULE_PATH first.",
+        "Prefer the CMake module directory for includes from CMake modules.",
+    ),
+    policy(
+        "CMP0018",
+        &[2, 8, 9],
+        "Ignore the CMAKE_SHARED_LIBRARY_<LANG>_FLAGS variable.",
+        "Add the shared library flags to position independent code.",
+        "Use POSITION_INDEPENDENT_CODE instead of the shared library flags.",
+    ),
+    policy(
+        "CMP0019",
+        &[2, 8, 11],
+        "Do not re-expand variables in include and link information.",
+        "Expand variable references in include and link information again.",
+        "Use include and link information as given.",
+    ),
+    policy(
+        "CMP0020",
+        &[2, 8, 11],
+        "Automatically link Qt executables to the qtmain target on Windows.",
+        "Do not link qtmain automatically.",
+        "Link WIN32 executables using Qt to qtmain automatically.",
+    ),
+    policy(
+        "CMP0021",
+        &[2, 8, 12],
+        "Fatal error on relative paths in the INCLUDE_DIRECTORIES target property.",
+        "Allow relative include directories.",
+        "Report relative include directories as errors.",
+    ),
+    policy(
+        "CMP0022",
+        &[2, 8, 12],
+        "INTERFACE_LINK_LIBRARIES defines the link interface.",
+        "Use LINK_INTERFACE_LIBRARIES and its configuration variants.",
+        "Use INTERFACE_LINK_LIBRARIES.",
+    ),
+    policy(
+        "CMP0023",
+        &[2, 8, 12],
+        "Plain and keyword target_link_libraries() signatures cannot be mixed.",
+        "Allow mixing the plain and keyword signatures.",
+        "Report mixed signatures as errors.",
+    ),
+    policy(
+        "CMP0024",
+        &[3, 0],
+        "Disallow including the result of export().",
+        "Allow including files generated by export().",
+        "Report including files generated by export() as an error.",
+    ),
+    policy(
+        "CMP0025",
+        &[3, 0],
+        "Compiler id for Apple Clang is now AppleClang.",
+        "Identify Apple Clang as Clang.",
+        "Identify Apple Clang as AppleClang.",
+    ),
+    policy(
+        "CMP0026",
+        &[3, 0],
+        "Disallow use of the LOCATION property for build targets.",
+        "Allow reading LOCATION at configure time.",
+        "Report reading LOCATION of build targets as an error.",
+    ),
+    policy(
+        "CMP0027",
+        &[3, 0],
+        "Conditionally linked imported targets with missing include directories.",
+        "Warn about missing include directories of imported targets.",
+        "Report missing include directories of imported targets as errors.",
+    ),
+    policy(
+        "CMP0028",
+        &[3, 0],
+        "Double colon in target name means ALIAS or IMPORTED target.",
+        "Link names with :: as libraries when no target exists.",
+        "Report names with :: that are not targets as errors.",
+    ),
+    policy(
+        "CMP0029",
+        &[3, 0],
+        "The subdir_depends() command should not be called.",
+        "Allow subdir_depends().",
+        "Report subdir_depends() as an error.",
+    ),
+    policy(
+        "CMP0030",
+        &[3, 0],
+        "The use_mangled_mesa() command should not be called.",
+        "Allow use_mangled_mesa().",
+        "Report use_mangled_mesa() as an error.",
+    ),
+    policy(
+        "CMP0031",
+        &[3, 0],
+        "The load_command() command should not be called.",
+        "Allow load_command().",
+        "Report load_command() as an error.",
+    ),
+    policy(
+        "CMP0032",
+        &[3, 0],
+        "The output_required_files() command should not be called.",
+        "Allow output_required_files().",
+        "Report output_required_files() as an error.",
+    ),
+    policy(
+        "CMP0033",
+        &[3, 0],
+        "The export_library_dependencies() command should not be called.",
+        "Allow export_library_dependencies().",
+        "Report export_library_dependencies() as an error.",
+    ),
+    policy(
+        "CMP0034",
+        &[3, 0],
+        "The utility_source() command should not be called.",
+        "Allow utility_source().",
+        "Report utility_source() as an error.",
+    ),
+    policy(
+        "CMP0035",
+        &[3, 0],
+        "The variable_requires() command should not be called.",
+        "Allow variable_requires().",
+        "Report variable_requires() as an error.",
+    ),
+    policy(
+        "CMP0036",
+        &[3, 0],
+        "The build_name() command should not be called.",
+        "Allow build_name().",
+        "Report build_name() as an error.",
+    ),
+    policy(
+        "CMP0037",
+        &[3, 0],
+        "Target names should not be reserved and should match a validity pattern.",
+        "Allow reserved and invalid target names.",
+        "Report reserved and invalid target names as errors.",
+    ),
+    policy(
+        "CMP0038",
+        &[3, 0],
+        "Targets may not link directly to themselves.",
+        "Ignore targets linking to themselves.",
+        "Report targets linking to themselves as errors.",
+    ),
+    policy(
+        "CMP0039",
+        &[3, 0],
+        "Utility targets may not have link dependencies.",
+        "Ignore link dependencies of utility targets.",
+        "Report link dependencies of utility targets as errors.",
+    ),
+    policy(
+        "CMP0040",
+        &[3, 0],
+        "The target in the TARGET signature of add_custom_command() must exist.",
+        "Ignore custom commands of unknown targets.",
+        "Report custom commands of unknown targets as errors.",
+    ),
+    policy(
+        "CMP0041",
+        &[3, 0],
+        "Error on relative include with generator expression.",
+        "Allow relative include directories with generator expressions.",
+        "Report relative include directories with generator expressions as errors.",
+    ),
+    policy(
+        "CMP0042",
+        &[3, 0],
+        "MACOSX_RPATH is enabled by default.",
+        "Leave MACOSX_RPATH off.",
+        "Enable MACOSX_RPATH by default.",
+    ),
+    policy(
+        "CMP0043",
+        &[3, 0],
+        "Ignore COMPILE_DEFINITIONS_<CONFIG> properties.",
+        "Honor COMPILE_DEFINITIONS_<CONFIG> properties.",
+        "Ignore COMPILE_DEFINITIONS_<CONFIG> properties.",
+    ),
+    policy(
+        "CMP0044",
+        &[3, 0],
+        "Case sensitive <LANG>_COMPILER_ID generator expressions.",
+        "Compare compiler ids case-insensitively.",
+        "Compare compiler ids case-sensitively.",
+    ),
+    policy(
+        "CMP0045",
+        &[3, 0],
+        "Error on non-existent target in get_target_property().",
+        "Set the result to <var>-NOTFOUND for unknown targets.",
+        "Report unknown targets as errors.",
+    ),
+    policy(
+        "CMP0046",
+        &[3, 0],
+        "Error on non-existent dependency in add_dependencies().",
+        "Ignore unknown dependencies.",
+        "Report unknown dependencies as errors.",
+    ),
+    policy(
+        "CMP0047",
+        &[3, 0],
+        "Use QCC compiler id for the qcc drivers on QNX.",
+        "Identify qcc as GNU.",
+        "Identify qcc as QCC.",
+    ),
+    policy(
+        "CMP0048",
+        &[3, 0],
+        "The project() command manages VERSION variables.",
+        "Leave the version variables unchanged.",
+        "Set or clear the version variables in project().",
+    ),
+    policy(
+        "CMP0049",
+        &[3, 0],
+        "Do not expand variables in target source entries.",
+        "Expand variable references in target sources again.",
+        "Use target sources as given.",
+    ),
+    policy(
+        "CMP0050",
+        &[3, 0],
+        "Disallow add_custom_command() SOURCE signatures.",
+        "Allow the SOURCE signature.",
+        "Report the SOURCE signature as an error.",
+    ),
+    policy(
+        "CMP0051",
+        &[3, 1],
+        "List TARGET_OBJECTS in the SOURCES target property.",
+        "Omit $<TARGET_OBJECTS> from SOURCES.",
+        "Include $<TARGET_OBJECTS> in SOURCES.",
+    ),
+    policy(
+        "CMP0052",
+        &[3, 1],
+        "Reject source and build dirs in installed INTERFACE_INCLUDE_DIRECTORIES.",
+        "Allow source and build directories in installed include directories.",
+        "Report source and build directories in installed include directories as errors.",
+    ),
+    policy(
+        "CMP0053",
+        &[3, 1],
+        "Simplify variable reference and escape sequence evaluation.",
+        "Use the old, slower evaluation allowing more characters in names.",
+        "Use the simplified evaluation.",
+    ),
+    policy(
+        "CMP0054",
+        &[3, 1],
+        "Only interpret if() arguments as variables or keywords when unquoted.",
+        "Dereference quoted arguments named like variables.",
+        "Treat quoted arguments as strings.",
+    ),
+    policy(
+        "CMP0055",
+        &[3, 2],
+        "Strict checking for the break() command.",
+        "Allow break() outside of loops and with arguments.",
+        "Report break() outside of loops or with arguments as an error.",
+    ),
+    policy(
+        "CMP0056",
+        &[3, 2],
+        "Honor link flags in the try_compile() source-file signature.",
+        "Ignore CMAKE_EXE_LINKER_FLAGS in try_compile().",
+        "Pass CMAKE_EXE_LINKER_FLAGS to try_compile().",
+    ),
+    policy(
+        "CMP0057",
+        &[3, 3],
+        "Support the new if() IN_LIST operator.",
+        "Treat IN_LIST as an argument.",
+        "Interpret IN_LIST as an operator.",
+    ),
+    policy(
+        "CMP0058",
+        &[3, 3],
+        "Ninja requires custom command byproducts to be explicit.",
+        "Add phony rules for unknown custom command dependencies.",
+        "Require BYPRODUCTS for generated dependencies.",
+    ),
+    policy(
+        "CMP0059",
+        &[3, 3],
+        "Do not treat DEFINITIONS as a built-in directory property.",
+        "Provide the DEFINITIONS directory property.",
+        "Treat DEFINITIONS as a user property.",
+    ),
+    policy(
+        "CMP0060",
+        &[3, 3],
+        "Link libraries by full path even in implicit directories.",
+        "Link libraries in implicit directories with -l.",
+        "Link libraries by full path.",
+    ),
+    policy(
+        "CMP0061",
+        &[3, 4],
+        "CTest does not by default tell make to ignore errors (-i).",
+        "Pass -i to make.",
+        "Do not pass -i to make.",
+    ),
+    policy(
+        "CMP0062",
+        &[3, 4],
+        "Disallow install() of export() result.",
+        "Allow installing files generated by export().",
+        "Report installing files generated by export() as an error.",
+    ),
+    policy(
+        "CMP0063",
+        &[3, 3],
+        "Honor visibility properties for all target types.",
+        "Honor visibility properties only for shared libraries and modules.",
+        "Honor visibility properties for all target types.",
+    ),
+    policy(
+        "CMP0064",
+        &[3, 4],
+        "Recognize TEST as an operator for the if() command.",
+        "Treat TEST as an argument.",
+        "Interpret TEST as an operator.",
+    ),
+    policy(
+        "CMP0065",
+        &[3, 4],
+        "Do not add flags to export symbols from executables without ENABLE_EXPORTS.",
+        "Always add the flags exporting symbols.",
+        "Add the flags exporting symbols only with ENABLE_EXPORTS.",
+    ),
+    policy(
+        "CMP0066",
+        &[3, 7],
+        "Honor per-config flags in the try_compile() source-file signature.",
+        "Ignore per-configuration flags in try_compile().",
+        "Pass the flags of the try_compile() configuration.",
+    ),
+    policy(
+        "CMP0067",
+        &[3, 8],
+        "Honor language standard in the try_compile() source-file signature.",
+        "Ignore language standard variables in try_compile().",
+        "Pass language standard variables to try_compile().",
+    ),
+    policy(
+        "CMP0068",
+        &[3, 9],
+        "RPATH settings on macOS do not affect install_name.",
+        "Use RPATH settings for install_name.",
+        "Use INSTALL_NAME_DIR and BUILD_WITH_INSTALL_NAME_DIR only.",
+    ),
+    policy(
+        "CMP0069",
+        &[3, 9],
+        "INTERPROCEDURAL_OPTIMIZATION is enforced when enabled.",
+        "Add IPO flags only for the Intel compiler on Linux.",
+        "Add IPO flags for supported compilers and report unsupported ones as errors.",
+    ),
+    policy(
+        "CMP0070",
+        &[3, 10],
+        "Define file(GENERATE) behavior for relative paths.",
+        "Interpret relative paths as the working directory of CMake.",
+        "Interpret relative inputs to the source and outputs to the binary directory.",
+    ),
+    policy(
+        "CMP0071",
+        &[3, 10],
+        "Let AUTOMOC and AUTOUIC process GENERATED files.",
+        "Ignore generated files.",
+        "Process generated files.",
+    ),
+    policy(
+        "CMP0072",
+        &[3, 11],
+        "FindOpenGL prefers GLVND by default when available.",
+        "Prefer the legacy OpenGL libraries.",
+        "Prefer the GLVND libraries.",
+    ),
+    policy(
+        "CMP0073",
+        &[3, 12],
+        "Do not produce legacy _LIB_DEPENDS cache entries.",
+        "Set <target>_LIB_DEPENDS cache entries.",
+        "Do not set <target>_LIB_DEPENDS cache entries.",
+    ),
+    policy(
+        "CMP0074",
+        &[3, 12],
+        "find_package() uses <PackageName>_ROOT variables.",
+        "Ignore <PackageName>_ROOT variables.",
+        "Search the prefixes of <PackageName>_ROOT variables.",
+    ),
+    policy(
+        "CMP0075",
+        &[3, 12],
+        "Include file check macros honor CMAKE_REQUIRED_LIBRARIES.",
+        "Ignore CMAKE_REQUIRED_LIBRARIES.",
+        "Link CMAKE_REQUIRED_LIBRARIES.",
+    ),
+    policy(
+        "CMP0076",
+        &[3, 13],
+        "The target_sources() command converts relative paths to absolute.",
+        "Keep relative source paths.",
+        "Convert relative source paths to absolute paths.",
+    ),
+    policy(
+        "CMP0077",
+        &[3, 13],
+        "option() honors normal variables.",
+        "Create the cache entry and remove a normal variable of the same name.",
+        "Do nothing when a normal variable of the same name exists.",
+    ),
+    policy(
+        "CMP0078",
+        &[3, 13],
+        "UseSWIG generates standard target names.",
+        "Prefix target names with an underscore.",
+        "Use the module name as target name.",
+    ),
+    policy(
+        "CMP0079",
+        &[3, 13],
+        "target_link_libraries() allows use with targets in other directories.",
+        "Report linking targets of other directories as an error.",
+        "Allow linking targets of other directories.",
+    ),
+    policy(
+        "CMP0080",
+        &[3, 13],
+        "BundleUtilities cannot be included at configure time.",
+        "Allow including BundleUtilities at configure time.",
+        "Report including BundleUtilities at configure time as an error.",
+    ),
+    policy(
+        "CMP0081",
+        &[3, 13],
+        "Relative paths not allowed in the LINK_DIRECTORIES target property.",
+        "Allow relative link directories.",
+        "Report relative link directories as errors.",
+    ),
+    policy(
+        "CMP0082",
+        &[3, 14],
+        "Install rules from add_subdirectory() calls are interleaved with those in caller.",
+        "Run the install rules of subdirectories last.",
+        "Run install rules in the order of their commands.",
+    ),
+    policy(
+        "CMP0083",
+        &[3, 14],
+        "Control generation of Position Independent Executable (PIE).",
+        "Do not add link flags for POSITION_INDEPENDENT_CODE.",
+        "Add PIE link flags when CheckPIESupported was used.",
+    ),
+    policy(
+        "CMP0084",
+        &[3, 14],
+        "The FindQt module does not exist for find_package().",
+        "Provide the FindQt module.",
+        "Do not provide the FindQt module.",
+    ),
+    policy(
+        "CMP0085",
+        &[3, 14],
+        "$<IN_LIST:...> handles empty list items.",
+        "Evaluate to 0 for empty items.",
+        "Evaluate to 1 for empty items in the list.",
+    ),
+    policy(
+        "CMP0086",
+        &[3, 14],
+        "UseSWIG honors SWIG_MODULE_NAME via the -module flag.",
+        "Do not pass -module.",
+        "Pass SWIG_MODULE_NAME with -module.",
+    ),
+    policy(
+        "CMP0087",
+        &[3, 14],
+        "install(CODE) and install(SCRIPT) support generator expressions.",
+        "Do not evaluate generator expressions.",
+        "Evaluate generator expressions.",
+    ),
+    policy(
+        "CMP0088",
+        &[3, 14],
+        "FindBISON runs bison in CMAKE_CURRENT_BINARY_DIR when executing.",
+        "Run bison in the source directory.",
+        "Run bison in the binary directory.",
+    ),
+    policy(
+        "CMP0089",
+        &[3, 15],
+        "Compiler id for IBM Clang-based XL compilers is now XLClang.",
+        "Identify the compilers as XL.",
+        "Identify the compilers as XLClang.",
+    ),
+    policy(
+        "CMP0090",
+        &[3, 15],
+        "export(PACKAGE) does not populate the package registry by default.",
+        "Populate the registry unless CMAKE_EXPORT_NO_PACKAGE_REGISTRY is set.",
+        "Populate the registry only with CMAKE_EXPORT_PACKAGE_REGISTRY.",
+    ),
+    policy(
+        "CMP0091",
+        &[3, 15],
+        "MSVC runtime library flags are selected by an abstraction.",
+        "Add runtime library flags to CMAKE_<LANG>_FLAGS_<CONFIG>.",
+        "Select runtime library flags with MSVC_RUNTIME_LIBRARY.",
+    ),
+    policy(
+        "CMP0092",
+        &[3, 15],
+        "MSVC warning flags are not in CMAKE_<LANG>_FLAGS by default.",
+        "Add /W3 to the default flags.",
+        "Do not add /W3 to the default flags.",
+    ),
+    policy(
+        "CMP0093",
+        &[3, 15],
+        "FindBoost reports Boost_VERSION in x.y.z format.",
+        "Report Boost_VERSION in the xyyyzz format.",
+        "Report Boost_VERSION in the x.y.z format.",
+    ),
+    policy(
+        "CMP0094",
+        &[3, 15],
+        "FindPython3, FindPython2 and FindPython use LOCATION for lookup strategy.",
+        "Find the highest version.",
+        "Stop at the first location with a suitable version.",
+    ),
+    policy(
+        "CMP0095",
+        &[3, 16],
+        "RPATH entries are properly escaped in the intermediary CMake install script.",
+        "Do not escape RPATH entries.",
+        "Escape RPATH entries.",
+    ),
+    policy(
+        "CMP0096",
+        &[3, 16],
+        "The project() command preserves leading zeros in version components.",
+        "Strip leading zeros of version components.",
+        "Keep leading zeros of version components.",
+    ),
+    policy(
+        "CMP0097",
+        &[3, 16],
+        "ExternalProject_Add() with GIT_SUBMODULES \"\" initializes no submodules.",
+        "Initialize all submodules.",
+        "Initialize no submodules.",
+    ),
+    policy(
+        "CMP0098",
+        &[3, 17],
+        "FindFLEX runs flex in CMAKE_CURRENT_BINARY_DIR when executing.",
+        "Run flex in the source directory.",
+        "Run flex in the binary directory.",
+    ),
+    policy(
+        "CMP0099",
+        &[3, 17],
+        "Link properties are transitive over private dependencies of static libraries.",
+        "Do not propagate link properties of private dependencies.",
+        "Propagate link properties of private dependencies of static libraries.",
+    ),
+    policy(
+        "CMP0100",
+        &[3, 17],
+        "Let AUTOMOC and AUTOUIC process header files that end with a .hh extension.",
+        "Ignore .hh headers.",
+        "Process .hh headers.",
+    ),
+    policy(
+        "CMP0101",
+        &[3, 17],
+        "target_compile_options() now always honors the BEFORE keyword.",
+        "Ignore BEFORE in some cases.",
+        "Always honor BEFORE.",
+    ),
+    policy(
+        "CMP0102",
+        &[3, 17],
+        "mark_as_advanced() no longer creates a cache entry if one does not already exist.",
+        "Create missing cache entries.",
+        "Ignore missing cache entries.",
+    ),
+    policy(
+        "CMP0103",
+        &[3, 18],
+        "Multiple calls to export() with the same FILE without APPEND are no longer allowed.",
+        "Let later calls overwrite the file.",
+        "Report repeated calls as errors.",
+    ),
+    policy(
+        "CMP0104",
+        &[3, 18],
+        "Initialize CMAKE_CUDA_ARCHITECTURES when CMAKE_CUDA_COMPILER_ID is NVIDIA.",
+        "Leave CMAKE_CUDA_ARCHITECTURES empty.",
+        "Initialize CMAKE_CUDA_ARCHITECTURES and require CUDA_ARCHITECTURES.",
+    ),
+    policy(
+        "CMP0105",
+        &[3, 18],
+        "LINK_OPTIONS and INTERFACE_LINK_OPTIONS are used for the device link step.",
+        "Ignore link options for the device link step.",
+        "Use link options for the device link step.",
+    ),
+    policy(
+        "CMP0106",
+        &[3, 18],
+        "The Documentation module is removed.",
+        "Provide the Documentation module.",
+        "Do not provide the Documentation module.",
+    ),
+    policy(
+        "CMP0107",
+        &[3, 18],
+        "It is not allowed to create an ALIAS target with the same name as another target.",
+        "Allow aliases named like other targets.",
+        "Report aliases named like other targets as errors.",
+    ),
+    policy(
+        "CMP0108",
+        &[3, 18],
+        "A target is not allowed to link to itself even through an ALIAS target.",
+        "Allow linking to itself through an alias.",
+        "Report linking to itself through an alias as an error.",
+    ),
+    policy(
+        "CMP0109",
+        &[3, 19],
+        "find_program() requires permission to execute but not to read.",
+        "Require read and execute permissions.",
+        "Require execute permission only.",
+    ),
+    policy(
+        "CMP0110",
+        &[3, 19],
+        "add_test() supports arbitrary characters in test names.",
+        "Reject test names with special characters.",
+        "Allow arbitrary characters in test names.",
+    ),
+    policy(
+        "CMP0111",
+        &[3, 19],
+        "An imported target missing its location property fails during generation.",
+        "Use the target name as location.",
+        "Report missing locations as errors.",
+    ),
+    policy(
+        "CMP0112",
+        &[3, 19],
+        "Target file component generator expressions do not add target dependencies.",
+        "Add target dependencies for file component expressions.",
+        "Do not add target dependencies for file component expressions.",
+    ),
+    policy(
+        "CMP0113",
+        &[3, 19],
+        "Makefile generators do not repeat custom commands from target dependencies.",
+        "Repeat custom commands of dependencies.",
+        "Do not repeat custom commands of dependencies.",
+    ),
+    policy(
+        "CMP0114",
+        &[3, 19],
+        "ExternalProject step targets fully adopt their steps.",
+        "Let step targets depend only on their step.",
+        "Let step targets depend on the targets of dependent steps.",
+    ),
+    policy(
+        "CMP0115",
+        &[3, 20],
+        "Source file extensions must be explicit.",
+        "Try known extensions for sources without extension.",
+        "Require the full source file names.",
+    ),
+    policy(
+        "CMP0116",
+        &[3, 20],
+        "Ninja generators transform DEPFILEs from add_custom_command().",
+        "Use DEPFILEs as written.",
+        "Make DEPFILE paths relative to the top level binary directory.",
+    ),
+    policy(
+        "CMP0117",
+        &[3, 20],
+        "MSVC RTTI flag /GR is not added to CMAKE_CXX_FLAGS by default.",
+        "Add /GR to the default flags.",
+        "Do not add /GR to the default flags.",
+    ),
+    policy(
+        "CMP0118",
+        &[3, 20],
+        "The GENERATED source file property is now visible in all directories.",
+        "Make GENERATED visible in the directory setting it only.",
+        "Make GENERATED visible in all directories.",
+    ),
+    policy(
+        "CMP0119",
+        &[3, 20],
+        "The LANGUAGE source file property explicitly compiles as specified language.",
+        "Compile with the compiler of the language only.",
+        "Tell the compiler to compile the source as the language.",
+    ),
+    policy(
+        "CMP0120",
+        &[3, 20],
+        "The WriteCompilerDetectionHeader module is removed.",
+        "Provide the WriteCompilerDetectionHeader module.",
+        "Do not provide the WriteCompilerDetectionHeader module.",
+    ),
+    policy(
+        "CMP0121",
+        &[3, 21],
+        "The list() command now detects invalid indices.",
+        "Accept invalid indices.",
+        "Report invalid indices as errors.",
+    ),
+    policy(
+        "CMP0122",
+        &[3, 21],
+        "UseSWIG uses library name conventions for the CSharp language.",
+        "Use the default library name conventions.",
+        "Use the CSharp library name conventions.",
+    ),
+    policy(
+        "CMP0123",
+        &[3, 21],
+        "ARMClang cpu/arch compile and link flags must be set explicitly.",
+        "Derive the flags from CMAKE_SYSTEM_PROCESSOR.",
+        "Require the flags to be set explicitly.",
+    ),
+    policy(
+        "CMP0124",
+        &[3, 21],
+        "foreach() loop variables are only available in the loop scope.",
+        "Leave loop variables set after the loop.",
+        "Restore loop variables after the loop.",
+    ),
+    policy(
+        "CMP0125",
+        &[3, 21],
+        "find_file(), find_path(), find_library() and find_program() cache their result.",
+        "Handle normal variables of the result name inconsistently.",
+        "Ignore normal variables and cache the result.",
+    ),
+    policy(
+        "CMP0126",
+        &[3, 21],
+        "set(CACHE) does not remove any normal variable of the same name.",
+        "Remove the normal variable of the same name.",
+        "Keep the normal variable of the same name.",
+    ),
+    policy(
+        "CMP0127",
+        &[3, 22],
+        "cmake_dependent_option() supports full Condition Syntax.",
+        "Split conditions at semicolons and evaluate each as a variable.",
+        "Evaluate conditions with the if() syntax.",
+    ),
+    policy(
+        "CMP0128",
+        &[3, 22],
+        "Compiler extensions are initialized from the compiler default.",
+        "Initialize <LANG>_EXTENSIONS to ON and add flags as before.",
+        "Initialize <LANG>_EXTENSIONS from CMAKE_<LANG>_EXTENSIONS_DEFAULT.",
+    ),
+    policy(
+        "CMP0129",
+        &[3, 23],
+        "Compiler id for MCST LCC compilers is now LCC, not GNU.",
+        "Identify the compilers as GNU.",
+        "Identify the compilers as LCC.",
+    ),
+    policy(
+        "CMP0130",
+        &[3, 24],
+        "while() diagnoses condition evaluation errors.",
+        "Ignore condition evaluation errors.",
+        "Report condition evaluation errors.",
+    ),
+    policy(
+        "CMP0131",
+        &[3, 24],
+        "LINK_LIBRARIES supports the $<LINK_ONLY:...> generator expression.",
+        "Use LINK_ONLY items for usage requirements.",
+        "Use LINK_ONLY items for linking only.",
+    ),
+    policy(
+        "CMP0132",
+        &[3, 24],
+        "Do not set compiler environment variables on first run.",
+        "Set the CC and CXX environment variables on first run.",
+        "Do not set compiler environment variables.",
+    ),
+    policy(
+        "CMP0133",
+        &[3, 24],
+        "The CPack module disables SLA by default in the CPack DragNDrop generator.",
+        "Enable the license agreement by default.",
+        "Disable the license agreement by default.",
+    ),
+    policy(
+        "CMP0134",
+        &[3, 24],
+        "The default registry view is TARGET for find commands and BOTH for find_program().",
+        "Use the host registry view.",
+        "Use the TARGET and BOTH registry views.",
+    ),
+    policy(
+        "CMP0135",
+        &[3, 24],
+        "Timestamps of archives extracted by ExternalProject and FetchContent.",
+        "Keep the timestamps of the archive.",
+        "Set the timestamps to the extraction time.",
+    ),
+    policy(
+        "CMP0136",
+        &[3, 24],
+        "Watcom runtime library flags are selected by an abstraction.",
+        "Add runtime library flags to CMAKE_<LANG>_FLAGS.",
+        "Select runtime library flags with WATCOM_RUNTIME_LIBRARY.",
+    ),
+    policy(
+        "CMP0137",
+        &[3, 24],
+        "try_compile() passes platform variables in project mode.",
+        "Pass platform variables only to source-file signatures.",
+        "Pass platform variables to project signatures too.",
+    ),
+    policy(
+        "CMP0138",
+        &[3, 24],
+        "CheckIPOSupported uses flags from the calling project.",
+        "Ignore the flags of the calling project.",
+        "Pass the flags of the calling project.",
+    ),
+    policy(
+        "CMP0139",
+        &[3, 24],
+        "The if() command supports path comparisons using the PATH_EQUAL operator.",
+        "Treat PATH_EQUAL as an argument.",
+        "Interpret PATH_EQUAL as an operator.",
+    ),
+    policy(
+        "CMP0140",
+        &[3, 25],
+        "The return() command checks its parameters.",
+        "Ignore the parameters of return().",
+        "Report invalid parameters of return() as errors.",
+    ),
+    policy(
+        "CMP0141",
+        &[3, 25],
+        "MSVC debug information format flags are selected by an abstraction.",
+        "Add debug information flags to CMAKE_<LANG>_FLAGS_<CONFIG>.",
+        "Select debug information flags with MSVC_DEBUG_INFORMATION_FORMAT.",
+    ),
+    policy(
+        "CMP0142",
+        &[3, 25],
+        "The Xcode generator does not append per-config suffixes to library search paths.",
+        "Append per-configuration suffixes.",
+        "Do not append per-configuration suffixes.",
+    ),
+    policy(
+        "CMP0143",
+        &[3, 26],
+        "The USE_FOLDERS global property is treated as ON by default.",
+        "Treat an unset USE_FOLDERS as OFF.",
+        "Treat an unset USE_FOLDERS as ON.",
+    ),
+    policy(
+        "CMP0144",
+        &[3, 27],
+        "find_package() uses upper-case <PACKAGENAME>_ROOT variables.",
+        "Ignore upper-case <PACKAGENAME>_ROOT variables.",
+        "Search the prefixes of upper-case <PACKAGENAME>_ROOT variables.",
+    ),
+    policy(
+        "CMP0145",
+        &[3, 27],
+        "The Dart and FindDart modules are removed.",
+        "Provide the Dart and FindDart modules.",
+        "Do not provide the Dart and FindDart modules.",
+    ),
+    policy(
+        "CMP0146",
+        &[3, 27],
+        "The FindCUDA module is removed.",
+        "Provide the FindCUDA module.",
+        "Do not provide the FindCUDA module.",
+    ),
+    policy(
+        "CMP0147",
+        &[3, 27],
+        "Visual Studio generators build custom commands in parallel.",
+        "Build custom commands sequentially.",
+        "Build custom commands in parallel.",
+    ),
+    policy(
+        "CMP0148",
+        &[3, 27],
+        "The FindPythonInterp and FindPythonLibs modules are removed.",
+        "Provide the FindPythonInterp and FindPythonLibs modules.",
+        "Do not provide the FindPythonInterp and FindPythonLibs modules.",
+    ),
+    policy(
+        "CMP0149",
+        &[3, 27],
+        "Visual Studio generators select the latest Windows SDK by default.",
+        "Select the SDK matching the host version.",
+        "Select the latest SDK.",
+    ),
+    policy(
+        "CMP0150",
+        &[3, 27],
+        "Relative GIT_REPOSITORY paths are relative to the parent project's remote.",
+        "Treat relative repositories as local paths.",
+        "Resolve relative repositories against the remote of the parent project.",
+    ),
+];
@@ -0,0 +1,700 @@
+use std::{
+    collections::HashMap,
+    io,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::{
+    command::scripting::{
+        list::{ListModification, ListPrepend},
+        set::SetNormal,
+        List, Set,
+    },
+    parse_cmakelists, CMakeListsParseError, Command, Doc, Token,
+};
+
+/// Index of a file in a [`Project`].
+pub type FileId = usize;
+
+const LISTS_FILE: &str = "CMakeLists.txt";
+
+/// Loads a whole project starting from the root `CMakeLists.txt`.
+///
+/// `add_subdirectory()`, `subdirs()` and `include()` commands are followed
+/// recursively. Paths are resolved relative to the current source directory,
+/// modules are searched in `CMAKE_MODULE_PATH`. Only plain `set()` and
+/// `list(APPEND|PREPEND)` of literal values are tracked to resolve variable
+/// references, and commands are followed regardless of the conditions
+/// they are nested in.
+#[derive(Debug, Clone)]
+pub struct ProjectLoader {
+    root: PathBuf,
+    module_path: Vec<PathBuf>,
+}
+
+/// Files of a project loaded with [`ProjectLoader`].
+#[derive(Debug, Default)]
+pub struct Project {
+    files: Vec<ProjectFile>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+/// Listfile of a [`Project`].
+#[derive(Debug)]
+pub struct ProjectFile {
+    pub id: FileId,
+    pub path: PathBuf,
+    pub kind: FileKind,
+    /// Value of `CMAKE_CURRENT_SOURCE_DIR` while the file is processed.
+    pub source_dir: PathBuf,
+    /// The file which added this file first.
+    pub parent: Option<FileId>,
+    /// Index of the command of the parent file which added this file.
+    pub command: Option<usize>,
+    /// Subdirectories and included files in order of appearance.
+    pub children: Vec<FileId>,
+    source: Vec<u8>,
+}
+
+/// How a file was added to the project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FileKind {
+    Root,
+    /// `add_subdirectory()` or `subdirs()`.
+    Subdirectory,
+    /// `include()`.
+    Include,
+}
+
+/// Problem found while loading a project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// File the problem was found in.
+    pub file: Option<FileId>,
+    /// Index of the command invocation in the file.
+    pub command: Option<usize>,
+    pub kind: DiagnosticKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DiagnosticKind {
+    #[error("file not found: {}", .0.display())]
+    MissingFile(PathBuf),
+    #[error("include cycle: {}", .0.display())]
+    IncludeCycle(PathBuf),
+    #[error("unresolved variable reference: {0}")]
+    Unresolved(String),
+    #[error("failed to read {}: {1}", .0.display())]
+    Io(PathBuf, String),
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+}
+
+/// [`CMakeListsParseError`] which can be cloned and compared.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{0}")]
+pub struct ParseError(String);
+
+impl From<CMakeListsParseError> for ParseError {
+    fn from(value: CMakeListsParseError) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl ProjectLoader {
+    /// `root` is either the top-level source directory or the path of its `CMakeLists.txt`.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            module_path: vec![],
+        }
+    }
+
+    /// Add a directory to the initial `CMAKE_MODULE_PATH`.
+    pub fn module_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.module_path.push(path.into());
+        self
+    }
+
+    pub fn load(&self) -> Project {
+        let root = if self.root.is_dir() {
+            self.root.join(LISTS_FILE)
+        } else {
+            self.root.clone()
+        };
+        let root = normalize(&root);
+        let source_dir = root.parent().map(Path::to_path_buf).unwrap_or_default();
+
+        let mut vars = Vars::new();
+        vars.insert("CMAKE_SOURCE_DIR".to_string(), path_value(&source_dir));
+        vars.insert("PROJECT_SOURCE_DIR".to_string(), path_value(&source_dir));
+        vars.insert(
+            "CMAKE_MODULE_PATH".to_string(),
+            self.module_path
+                .iter()
+                .map(|path| path_value(path))
+                .collect::<Vec<_>>()
+                .join(";"),
+        );
+
+        let mut loader = Loader::default();
+        if let Err(err) =
+            loader.load_file(root.clone(), FileKind::Root, None, source_dir, &mut vars)
+        {
+            loader.project.diagnostics.push(Diagnostic {
+                file: None,
+                command: None,
+                kind: io_diagnostic(root, err),
+            });
+        }
+        loader.project
+    }
+}
+
+impl Project {
+    pub fn files(&self) -> &[ProjectFile] {
+        &self.files
+    }
+
+    pub fn file(&self, id: FileId) -> Option<&ProjectFile> {
+        self.files.get(id)
+    }
+
+    pub fn file_id(&self, path: &Path) -> Option<FileId> {
+        self.files.iter().position(|file| file.path == path)
+    }
+
+    /// The root `CMakeLists.txt` if it was found.
+    pub fn root(&self) -> Option<&ProjectFile> {
+        self.files.first()
+    }
+
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+}
+
+impl ProjectFile {
+    pub fn source(&self) -> &[u8] {
+        &self.source
+    }
+
+    /// Parse the file contents.
+    pub fn doc(&self) -> Result<Doc<'_>, CMakeListsParseError> {
+        parse_cmakelists(&self.source).map(Doc::from)
+    }
+}
+
+type Vars = HashMap<String, String>;
+
+#[derive(Default)]
+struct Loader {
+    project: Project,
+    stack: Vec<PathBuf>,
+}
+
+impl Loader {
+    fn load_file(
+        &mut self,
+        path: PathBuf,
+        kind: FileKind,
+        parent: Option<(FileId, usize)>,
+        source_dir: PathBuf,
+        vars: &mut Vars,
+    ) -> io::Result<FileId> {
+        if let Some(id) = self.project.file_id(&path) {
+            return Ok(id);
+        }
+
+        let source = std::fs::read(&path)?;
+        let id = self.project.files.len();
+        self.project.files.push(ProjectFile {
+            id,
+            path: path.clone(),
+            kind,
+            source_dir: source_dir.clone(),
+            parent: parent.map(|(parent, _)| parent),
+            command: parent.map(|(_, command)| command),
+            children: vec![],
+            source: vec![],
+        });
+
+        let list_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        let saved_list_dir =
+            vars.insert("CMAKE_CURRENT_LIST_DIR".to_string(), path_value(&list_dir));
+        let saved_list_file = vars.insert("CMAKE_CURRENT_LIST_FILE".to_string(), path_value(&path));
+        vars.insert(
+            "CMAKE_CURRENT_SOURCE_DIR".to_string(),
+            path_value(&source_dir),
+        );
+
+        self.stack.push(path);
+        match parse_cmakelists(&source) {
+            Ok(tokens) => {
+                let doc = Doc::from(tokens);
+                for (index, command) in doc.to_commands_iter().enumerate() {
+                    if let Ok(command) = command {
+                        self.command(id, index, &command, &source_dir, vars);
+                    }
+                }
+            }
+            Err(err) => self.project.diagnostics.push(Diagnostic {
+                file: Some(id),
+                command: None,
+                kind: ParseError::from(err).into(),
+            }),
+        }
+        self.stack.pop();
+
+        restore(vars, "CMAKE_CURRENT_LIST_DIR", saved_list_dir);
+        restore(vars, "CMAKE_CURRENT_LIST_FILE", saved_list_file);
+        self.project.files[id].source = source;
+        Ok(id)
+    }
+
+    fn command(
+        &mut self,
+        id: FileId,
+        index: usize,
+        command: &Command,
+        source_dir: &Path,
+        vars: &mut Vars,
+    ) {
+        match command {
+            Command::AddSubdirectory(add_subdirectory) => {
+                self.subdirectory(id, index, &add_subdirectory.source_dir, source_dir, vars);
+            }
+            Command::Subdirs(subdirs) => {
+                for dir in subdirs
+                    .dirs
+                    .iter()
+                    .chain(subdirs.exclude_from_all.iter().flatten())
+                {
+                    self.subdirectory(id, index, dir, source_dir, vars);
+                }
+            }
+            Command::Include(include) => {
+                let Some(file) = self.expand(id, index, &include.file, vars) else {
+                    return;
+                };
+                let path = if !file.contains('/') && !file.ends_with(".cmake") {
+                    let module = format!("{file}.cmake");
+                    let found = vars
+                        .get("CMAKE_MODULE_PATH")
+                        .into_iter()
+                        .flat_map(|module_path| module_path.split(';'))
+                        .filter(|dir| !dir.is_empty())
+                        .map(|dir| normalize(&source_dir.join(dir).join(&module)))
+                        .find(|path| path.is_file());
+                    match found {
+                        Some(path) => path,
+                        // CMake's own module
+                        None => return,
+                    }
+                } else {
+                    normalize(&source_dir.join(file))
+                };
+
+                if self.stack.contains(&path) {
+                    self.project.diagnostics.push(Diagnostic {
+                        file: Some(id),
+                        command: Some(index),
+                        kind: DiagnosticKind::IncludeCycle(path),
+                    });
+                    return;
+                }
+
+                match self.load_file(
+                    path.clone(),
+                    FileKind::Include,
+                    Some((id, index)),
+                    source_dir.to_path_buf(),
+                    vars,
+                ) {
+                    Ok(child) => self.add_child(id, child),
+                    Err(err) if err.kind() == io::ErrorKind::NotFound && include.optional => (),
+                    Err(err) => self.project.diagnostics.push(Diagnostic {
+                        file: Some(id),
+                        command: Some(index),
+                        kind: io_diagnostic(path, err),
+                    }),
+                }
+            }
+            Command::Project(_) => {
+                vars.insert("PROJECT_SOURCE_DIR".to_string(), path_value(source_dir));
+            }
+            Command::Set(set) => {
+                if let Set::Normal(SetNormal {
+                    variable,
+                    value,
+                    parent_scope: false,
+                }) = set.as_ref()
+                {
+                    self.assign(id, index, variable, value, Assign::Set, vars);
+                }
+            }
+            Command::List(list) => match list.as_ref() {
+                List::Modification(ListModification::Append(append)) => {
+                    let elements = append.element.as_deref().unwrap_or_default();
+                    self.assign(id, index, &append.list, elements, Assign::Append, vars);
+                }
+                List::Modification(ListModification::Prepend(ListPrepend {
+                    list,
+                    element: Some(elements),
+                })) => {
+                    self.assign(id, index, list, elements, Assign::Prepend, vars);
+                }
+                _ => (),
+            },
+            _ => (),
+        }
+    }
+
+    fn subdirectory(
+        &mut self,
+        id: FileId,
+        index: usize,
+        dir: &Token,
+        source_dir: &Path,
+        vars: &Vars,
+    ) {
+        let Some(dir) = self.expand(id, index, dir, vars) else {
+            return;
+        };
+        let dir = normalize(&source_dir.join(dir));
+        let path = dir.join(LISTS_FILE);
+        let mut vars = vars.clone();
+        match self.load_file(
+            path.clone(),
+            FileKind::Subdirectory,
+            Some((id, index)),
+            dir,
+            &mut vars,
+        ) {
+            Ok(child) => self.add_child(id, child),
+            Err(err) => self.project.diagnostics.push(Diagnostic {
+                file: Some(id),
+                command: Some(index),
+                kind: io_diagnostic(path, err),
+            }),
+        }
+    }
+
+    fn assign(
+        &mut self,
+        id: FileId,
+        index: usize,
+        variable: &Token,
+        value: &[Token],
+        assign: Assign,
+        vars: &mut Vars,
+    ) {
+        let Some(variable) = self.expand(id, index, variable, vars) else {
+            return;
+        };
+        let value: Option<Vec<_>> = value
+            .iter()
+            .map(|token| expand(token.as_bytes(), vars).ok())
+            .collect();
+        let Some(value) = value else {
+            // the value is unknown from now on
+            vars.remove(&variable);
+            return;
+        };
+        let value = value.join(";");
+        let old = vars.get(&variable).filter(|old| !old.is_empty());
+        let value = match (assign, old) {
+            (Assign::Set, _) | (_, None) => value,
+            (Assign::Append, Some(old)) => format!("{old};{value}"),
+            (Assign::Prepend, Some(old)) => format!("{value};{old}"),
+        };
+        vars.insert(variable, value);
+    }
+
+    fn add_child(&mut self, id: FileId, child: FileId) {
+        let children = &mut self.project.files[id].children;
+        if !children.contains(&child) {
+            children.push(child);
+        }
+    }
+
+    fn expand(&mut self, id: FileId, index: usize, token: &Token, vars: &Vars) -> Option<String> {
+        expand(token.as_bytes(), vars)
+            .map_err(|reference| {
+                self.project.diagnostics.push(Diagnostic {
+                    file: Some(id),
+                    command: Some(index),
+                    kind: DiagnosticKind::Unresolved(reference),
+                })
+            })
+            .ok()
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Assign {
+    Set,
+    Append,
+    Prepend,
+}
+
+/// Replace `${VAR}` references, returns the first reference which cannot be resolved.
+fn expand(value: &[u8], vars: &Vars) -> Result<String, String> {
+    let value = String::from_utf8_lossy(value);
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value.as_ref();
+    while let Some(start) = rest.find("${") {
+        if let Some(reference) = env_or_cache(&rest[..start]) {
+            return Err(reference);
+        }
+        result.push_str(&rest[..start]);
+        let reference = &rest[start..];
+        let Some(end) = reference.find('}') else {
+            return Err(reference.to_string());
+        };
+        let name = &reference[2..end];
+        match vars.get(name) {
+            Some(value) if !name.contains('$') => result.push_str(value),
+            _ => return Err(reference[..=end].to_string()),
+        }
+        rest = &reference[end + 1..];
+    }
+    if let Some(reference) = env_or_cache(rest) {
+        return Err(reference);
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+/// First `$ENV{VAR}` or `$CACHE{VAR}` reference of a text, which the loader
+/// cannot resolve.
+fn env_or_cache(text: &str) -> Option<String> {
+    let start = ["$ENV{", "$CACHE{"]
+        .into_iter()
+        .filter_map(|prefix| text.find(prefix))
+        .min()?;
+    let reference = &text[start..];
+    let end = reference.find('}').map_or(reference.len(), |end| end + 1);
+    Some(reference[..end].to_string())
+}
+
+fn restore(vars: &mut Vars, name: &str, value: Option<String>) {
+    match value {
+        Some(value) => vars.insert(name.to_string(), value),
+        None => vars.remove(name),
+    };
+}
+
+fn io_diagnostic(path: PathBuf, err: io::Error) -> DiagnosticKind {
+    if err.kind() == io::ErrorKind::NotFound {
+        DiagnosticKind::MissingFile(path)
+    } else {
+        DiagnosticKind::Io(path, err.to_string())
+    }
+}
+
+pub(crate) fn path_value(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Lexically remove `.` and `..` components.
+pub(crate) fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => (),
+            Component::ParentDir => match normalized.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    normalized.pop();
+                }
+                Some(Component::RootDir | Component::Prefix(_)) => (),
+                _ => normalized.push(component),
+            },
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::fixture;
+    use pretty_assertions::assert_eq;
+
+    fn relative(project: &Project, id: FileId) -> String {
+        let path = &project.file(id).unwrap().path;
+        path_value(path.strip_prefix(fixture("project")).unwrap())
+    }
+
+    #[test]
+    fn project_loader() {
+        let project = ProjectLoader::new(fixture("project"))
+            .module_path(fixture("project").join("modules"))
+            .load();
+
+        let files: Vec<_> = project
+            .files()
+            .iter()
+            .map(|file| {
+                (
+                    relative(&project, file.id),
+                    file.kind,
+                    file.parent,
+                    file.children.clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            files,
+            vec![
+                (
+                    "CMakeLists.txt".to_string(),
+                    FileKind::Root,
+                    None,
+                    vec![1, 2, 3, 5, 7]
+                ),
+                (
+                    "modules/Helpers.cmake".to_string(),
+                    FileKind::Include,
+                    Some(0),
+                    vec![]
+                ),
+                (
+                    "cmake/Options.cmake".to_string(),
+                    FileKind::Include,
+                    Some(0),
+                    vec![]
+                ),
+                (
+                    "src/CMakeLists.txt".to_string(),
+                    FileKind::Subdirectory,
+                    Some(0),
+                    vec![4]
+                ),
+                (
+                    "src/sources.cmake".to_string(),
+                    FileKind::Include,
+                    Some(3),
+                    vec![]
+                ),
+                (
+                    "cmake/a.cmake".to_string(),
+                    FileKind::Include,
+                    Some(0),
+                    vec![6]
+                ),
+                (
+                    "cmake/b.cmake".to_string(),
+                    FileKind::Include,
+                    Some(5),
+                    vec![]
+                ),
+                (
+                    "legacy/CMakeLists.txt".to_string(),
+                    FileKind::Subdirectory,
+                    Some(0),
+                    vec![]
+                ),
+            ]
+        );
+
+        assert_eq!(
+            project.file(3).unwrap().source_dir,
+            fixture("project").join("src")
+        );
+        assert_eq!(
+            project.file(4).unwrap().source_dir,
+            fixture("project").join("src")
+        );
+        assert!(matches!(
+            project
+                .file(4)
+                .unwrap()
+                .doc()
+                .unwrap()
+                .commands()
+                .unwrap()
+                .as_slice(),
+            [Command::Set(_)]
+        ));
+
+        let diagnostics: Vec<_> = project
+            .diagnostics()
+            .iter()
+            .map(|diagnostic| {
+                (
+                    diagnostic.file.map(|id| relative(&project, id)),
+                    diagnostic.command,
+                    match &diagnostic.kind {
+                        DiagnosticKind::MissingFile(path) | DiagnosticKind::IncludeCycle(path) => {
+                            path_value(path.strip_prefix(fixture("project")).unwrap())
+                        }
+                        kind => kind.to_string(),
+                    },
+                )
+            })
+            .collect();
+        assert_eq!(
+            diagnostics,
+            vec![
+                (
+                    Some("cmake/b.cmake".to_string()),
+                    Some(0),
+                    "cmake/a.cmake".to_string()
+                ),
+                (
+                    Some("CMakeLists.txt".to_string()),
+                    Some(8),
+                    "missing/CMakeLists.txt".to_string()
+                ),
+                (
+                    Some("CMakeLists.txt".to_string()),
+                    Some(9),
+                    "cmake/Missing.cmake".to_string()
+                ),
+                (
+                    Some("CMakeLists.txt".to_string()),
+                    Some(11),
+                    "unresolved variable reference: ${UNKNOWN_DIR}".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn project_loader_missing_root() {
+        let project = ProjectLoader::new(fixture("project").join("missing")).load();
+        assert!(project.files().is_empty());
+        assert_eq!(
+            project.diagnostics(),
+            &[Diagnostic {
+                file: None,
+                command: None,
+                kind: DiagnosticKind::MissingFile(fixture("project").join("missing")),
+            }]
+        );
+    }
+
+    #[test]
+    fn expand_references() {
+        let vars = Vars::from([("X".to_string(), "x".to_string())]);
+        assert_eq!(expand(b"a/${X}", &vars), Ok("a/x".to_string()));
+        assert_eq!(expand(b"a/${Y}/b", &vars), Err("${Y}".to_string()));
+        assert_eq!(
+            expand(b"$ENV{HOME}/${X}", &vars),
+            Err("$ENV{HOME}".to_string())
+        );
+        assert_eq!(
+            expand(b"${X}/$CACHE{Y}/b", &vars),
+            Err("$CACHE{Y}".to_string())
+        );
+    }
+
+    #[test]
+    fn normalize_path() {
+        assert_eq!(normalize(Path::new("/a/./b/../c")), Path::new("/a/c"));
+        assert_eq!(normalize(Path::new("a/../../b")), Path::new("../b"));
+        assert_eq!(normalize(Path::new("/../a")), Path::new("/a"));
+    }
+}
@@ -0,0 +1,530 @@
+use std::{collections::HashSet, fmt};
+
+use crate::{
+    analysis::property_catalog::{LANGUAGES, PROPERTIES},
+    command::{
+        common::Property,
+        project::define_property,
+        scripting::set_property::{Scope, Source},
+    },
+    doc::bool_constant,
+    Command, Token,
+};
+
+/// Kind of entities a property belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PropertyScope {
+    Global,
+    Directory,
+    Target,
+    Source,
+    Test,
+    /// Cache entries.
+    Cache,
+    /// Installed files.
+    Install,
+}
+
+/// Type of property values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PropertyType {
+    Bool,
+    Integer,
+    String,
+    List,
+    Path,
+    /// One of the given values.
+    Enum(&'static [&'static str]),
+}
+
+/// Property documented by CMake 3.26.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PropertyInfo {
+    /// Name, families of properties have `<CONFIG>`, `<LANG>` or `<NAME>`
+    /// placeholders like `OUTPUT_NAME_<CONFIG>`.
+    pub name: &'static str,
+    pub scope: PropertyScope,
+    pub value_type: PropertyType,
+    /// Whether values may contain generator expressions.
+    pub genex: bool,
+}
+
+/// How a command assigns a property value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AssignMode {
+    Set,
+    /// `APPEND`: values are appended as list elements.
+    Append,
+    /// `APPEND_STRING`: values are appended to the string.
+    AppendString,
+}
+
+/// Property value set by a command.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PropertyAssignment {
+    /// Index of the command in the analyzed commands.
+    pub command: usize,
+    pub scope: PropertyScope,
+    /// Targets, sources, tests, cache entries, installed files or
+    /// directories the property is set on. Empty for the global scope and
+    /// the current directory.
+    pub entities: Vec<String>,
+    pub name: String,
+    /// Values of the command, no values unset the property.
+    pub values: Vec<String>,
+    pub mode: AssignMode,
+}
+
+/// Problem with a property set by a command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyIssue {
+    /// Index of the command in the analyzed commands.
+    pub command: usize,
+    pub property: String,
+    pub kind: PropertyIssueKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PropertyIssueKind {
+    #[error(
+        "unknown {scope} property{}",
+        .suggestion.as_ref().map(|name| format!(", did you mean {name}?")).unwrap_or_default()
+    )]
+    Unknown {
+        scope: PropertyScope,
+        suggestion: Option<String>,
+    },
+    #[error("not a {scope} property but a {} property", display_scopes(.scopes))]
+    WrongScope {
+        scope: PropertyScope,
+        scopes: Vec<PropertyScope>,
+    },
+    #[error("invalid value {value:?}, expected {expected}")]
+    InvalidValue {
+        value: String,
+        expected: PropertyType,
+    },
+}
+
+impl PropertyInfo {
+    /// All properties of the catalog.
+    pub fn all() -> &'static [PropertyInfo] {
+        PROPERTIES
+    }
+
+    /// Finds the property a name of a scope refers to. Exact names take
+    /// precedence over families of properties.
+    pub fn find(scope: PropertyScope, name: &str) -> Option<&'static PropertyInfo> {
+        let candidates = || PROPERTIES.iter().filter(move |info| info.scope == scope);
+        candidates()
+            .find(|info| info.name == name)
+            .or_else(|| candidates().find(|info| info.matches(name)))
+    }
+
+    /// Whether a property name is this property or belongs to its family.
+    pub fn matches(&self, name: &str) -> bool {
+        pattern_match(self.name, name)
+    }
+
+    /// Checks a value against the property type. Values with variable
+    /// references are not checked, neither are values with generator
+    /// expressions of properties supporting them.
+    pub fn accepts(&self, value: &str) -> bool {
+        if value.contains("${") || (self.genex && value.contains("$<")) {
+            return true;
+        }
+        match self.value_type {
+            PropertyType::Bool => bool_constant(value).is_some(),
+            PropertyType::Integer => value.parse::<i64>().is_ok(),
+            PropertyType::Enum(values) => values.contains(&value),
+            PropertyType::String | PropertyType::List | PropertyType::Path => true,
+        }
+    }
+}
+
+impl PropertyAssignment {
+    /// Property assignments of `set_property()`, `set_target_properties()`,
+    /// `set_source_files_properties()`, `set_tests_properties()` and
+    /// `set_directory_properties()` commands in order.
+    pub fn from_commands(commands: &[Command]) -> Vec<Self> {
+        let mut assignments = vec![];
+        for (index, command) in commands.iter().enumerate() {
+            let mut properties = |scope, entities: &[Token], properties: &[Property]| {
+                for property in properties {
+                    assignments.push(Self {
+                        command: index,
+                        scope,
+                        entities: strings(entities),
+                        name: property.prop.to_string(),
+                        values: vec![property.value.to_string()],
+                        mode: AssignMode::Set,
+                    });
+                }
+            };
+            match command {
+                Command::SetTargetProperties(command) => {
+                    properties(PropertyScope::Target, &command.targets, &command.properties);
+                }
+                Command::SetSourceFileProperties(command) => {
+                    properties(PropertyScope::Source, &command.files, &command.properties);
+                }
+                Command::SetTestsProperties(command) => {
+                    properties(PropertyScope::Test, &command.tests, &command.properties);
+                }
+                Command::SetDirectoryProperties(command) => {
+                    properties(PropertyScope::Directory, &[], &command.properties);
+                }
+                Command::SetProperty(command) => {
+                    let (scope, entities) = match &command.scope {
+                        Scope::Global => (PropertyScope::Global, vec![]),
+                        Scope::Directory(directory) => (
+                            PropertyScope::Directory,
+                            directory.iter().map(Token::to_string).collect(),
+                        ),
+                        Scope::Target(targets) => (PropertyScope::Target, optional(targets)),
+                        Scope::Source(Source { source, .. }) => {
+                            (PropertyScope::Source, optional(source))
+                        }
+                        Scope::Install(files) => (PropertyScope::Install, optional(files)),
+                        Scope::Test(tests) => (PropertyScope::Test, optional(tests)),
+                        Scope::Cache(entries) => (PropertyScope::Cache, optional(entries)),
+                    };
+                    let mode = if command.append_string {
+                        AssignMode::AppendString
+                    } else if command.append {
+                        AssignMode::Append
+                    } else {
+                        AssignMode::Set
+                    };
+                    assignments.push(Self {
+                        command: index,
+                        scope,
+                        entities,
+                        name: command.property.to_string(),
+                        values: optional(&command.values),
+                        mode,
+                    });
+                }
+                _ => (),
+            }
+        }
+        assignments
+    }
+
+    /// Values joined into a list.
+    pub fn value(&self) -> String {
+        self.values.join(";")
+    }
+}
+
+/// Checks the properties set by commands against the catalog.
+///
+/// Properties declared with `define_property()` are accepted with any
+/// value, other unknown properties are reported with the most similar
+/// known name.
+///
+/// ```
+/// use cmake_parser::{analysis, parse_cmakelists, Doc};
+///
+/// let cmakelists = parse_cmakelists(b"set_target_properties(app PROPERTIES CXX_STANDART 17)\n").unwrap();
+/// let issues = analysis::check_properties(&Doc::from(cmakelists).commands().unwrap());
+/// assert_eq!(
+///     issues[0].kind.to_string(),
+///     "unknown TARGET property, did you mean CXX_STANDARD?"
+/// );
+/// ```
+pub fn check_properties(commands: &[Command]) -> Vec<PropertyIssue> {
+    let defined: HashSet<_> = commands
+        .iter()
+        .filter_map(|command| match command {
+            Command::DefineProperty(define) => define_scope(&define.property_scope)
+                .map(|scope| (scope, define.property_name.to_string())),
+            _ => None,
+        })
+        .collect();
+
+    let mut issues = vec![];
+    for assignment in PropertyAssignment::from_commands(commands) {
+        if defined.contains(&(assignment.scope, assignment.name.clone())) {
+            continue;
+        }
+        let kind = match PropertyInfo::find(assignment.scope, &assignment.name) {
+            Some(info) => {
+                let values = match assignment.mode {
+                    AssignMode::Set if !assignment.values.is_empty() => vec![assignment.value()],
+                    AssignMode::Append => assignment.values.clone(),
+                    AssignMode::Set | AssignMode::AppendString => vec![],
+                };
+                values
+                    .into_iter()
+                    .find(|value| !info.accepts(value))
+                    .map(|value| PropertyIssueKind::InvalidValue {
+                        value,
+                        expected: info.value_type,
+                    })
+            }
+            None => {
+                let mut scopes: Vec<_> = PROPERTIES
+                    .iter()
+                    .filter(|info| info.matches(&assignment.name))
+                    .map(|info| info.scope)
+                    .collect();
+                scopes.dedup();
+                if scopes.is_empty() {
+                    Some(PropertyIssueKind::Unknown {
+                        scope: assignment.scope,
+                        suggestion: suggestion(assignment.scope, &assignment.name),
+                    })
+                } else {
+                    Some(PropertyIssueKind::WrongScope {
+                        scope: assignment.scope,
+                        scopes,
+                    })
+                }
+            }
+        };
+        if let Some(kind) = kind {
+            issues.push(PropertyIssue {
+                command: assignment.command,
+                property: assignment.name,
+                kind,
+            });
+        }
+    }
+    issues
+}
+
+impl fmt::Display for PropertyScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PropertyScope::Global => "GLOBAL",
+            PropertyScope::Directory => "DIRECTORY",
+            PropertyScope::Target => "TARGET",
+            PropertyScope::Source => "SOURCE",
+            PropertyScope::Test => "TEST",
+            PropertyScope::Cache => "CACHE",
+            PropertyScope::Install => "INSTALL",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl fmt::Display for PropertyType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PropertyType::Bool => write!(f, "a boolean"),
+            PropertyType::Integer => write!(f, "an integer"),
+            PropertyType::String => write!(f, "a string"),
+            PropertyType::List => write!(f, "a list"),
+            PropertyType::Path => write!(f, "a path"),
+            PropertyType::Enum(values) => write!(f, "one of {}", values.join(", ")),
+        }
+    }
+}
+
+/// Scope of properties declared with `define_property()`, `None` for
+/// variables.
+pub(crate) fn define_scope(scope: &define_property::PropertyScope) -> Option<PropertyScope> {
+    match scope {
+        define_property::PropertyScope::Global => Some(PropertyScope::Global),
+        define_property::PropertyScope::Directory => Some(PropertyScope::Directory),
+        define_property::PropertyScope::Target => Some(PropertyScope::Target),
+        define_property::PropertyScope::Source => Some(PropertyScope::Source),
+        define_property::PropertyScope::Test => Some(PropertyScope::Test),
+        define_property::PropertyScope::CachedVariable => Some(PropertyScope::Cache),
+        define_property::PropertyScope::Variable => None,
+    }
+}
+
+fn display_scopes(scopes: &[PropertyScope]) -> String {
+    scopes
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" or ")
+}
+
+fn strings(tokens: &[Token]) -> Vec<String> {
+    tokens.iter().map(Token::to_string).collect()
+}
+
+fn optional(tokens: &Option<Vec<Token>>) -> Vec<String> {
+    tokens.as_deref().map(strings).unwrap_or_default()
+}
+
+/// Matches a name against a property or variable name with placeholders. `<LANG>`
+/// stands for a known language, other placeholders for any identifier.
+pub(crate) fn pattern_match(pattern: &str, name: &str) -> bool {
+    let Some(start) = pattern.find('<') else {
+        return pattern == name;
+    };
+    let Some(rest) = name.strip_prefix(&pattern[..start]) else {
+        return false;
+    };
+    let end = pattern[start..]
+        .find('>')
+        .map_or(pattern.len(), |end| start + end + 1);
+    let (placeholder, tail) = (&pattern[start..end], &pattern[end..]);
+    if placeholder == "<LANG>" {
+        return LANGUAGES.iter().any(|language| {
+            rest.strip_prefix(language)
+                .is_some_and(|rest| pattern_match(tail, rest))
+        });
+    }
+    let identifier = rest
+        .find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_' || ch == '-'))
+        .unwrap_or(rest.len());
+    (1..=identifier).any(|length| pattern_match(tail, &rest[length..]))
+}
+
+/// Most similar known property of a scope within two edits.
+fn suggestion(scope: PropertyScope, name: &str) -> Option<String> {
+    PROPERTIES
+        .iter()
+        .filter(|info| info.scope == scope)
+        .filter_map(|info| match info.name.strip_prefix("<LANG>") {
+            None if !info.name.contains('<') => Some(info.name.to_string()),
+            Some(suffix) if !suffix.contains('<') => LANGUAGES
+                .iter()
+                .find(|language| name.starts_with(&format!("{language}_")))
+                .map(|language| format!("{language}{suffix}")),
+            _ => None,
+        })
+        .map(|candidate| (edit_distance(name, &candidate), candidate))
+        .filter(|(distance, _)| *distance <= 2)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn property_catalog() {
+        let find = |scope, name| PropertyInfo::find(scope, name).map(|info| info.name);
+        assert_eq!(
+            find(PropertyScope::Target, "OUTPUT_NAME_DEBUG"),
+            Some("OUTPUT_NAME_<CONFIG>")
+        );
+        assert_eq!(
+            find(PropertyScope::Target, "DEBUG_POSTFIX"),
+            Some("DEBUG_POSTFIX")
+        );
+        assert_eq!(
+            find(PropertyScope::Target, "RELWITHDEBINFO_POSTFIX"),
+            Some("<CONFIG>_POSTFIX")
+        );
+        assert_eq!(
+            find(PropertyScope::Target, "CXX_CLANG_TIDY"),
+            Some("<LANG>_CLANG_TIDY")
+        );
+        assert_eq!(find(PropertyScope::Target, "PERL_CLANG_TIDY"), None);
+        assert_eq!(
+            find(
+                PropertyScope::Target,
+                "VS_DOTNET_REFERENCEPROP_Foo_TAG_Private"
+            ),
+            Some("VS_DOTNET_REFERENCEPROP_<NAME>_TAG_<NAME>")
+        );
+        assert_eq!(find(PropertyScope::Test, "OUTPUT_NAME"), None);
+
+        let names: HashSet<_> = PROPERTIES
+            .iter()
+            .map(|info| (info.scope, info.name))
+            .collect();
+        assert_eq!(names.len(), PROPERTIES.len());
+    }
+
+    #[test]
+    fn property_issues() {
+        let src = include_bytes!("../../../fixture/analysis/properties/check");
+        let cmakelists = parse_cmakelists(src).unwrap();
+        let doc = Doc::from(cmakelists);
+        let issues: Vec<_> = check_properties(&doc.commands().unwrap())
+            .into_iter()
+            .map(|issue| (issue.command, issue.property, issue.kind.to_string()))
+            .collect();
+        assert_eq!(
+            issues,
+            vec![
+                (
+                    1,
+                    "CXX_STANDART".to_string(),
+                    "unknown TARGET property, did you mean CXX_STANDARD?".to_string()
+                ),
+                (
+                    1,
+                    "POSITION_INDEPENDENT_CODE".to_string(),
+                    "invalid value \"maybe\", expected a boolean".to_string()
+                ),
+                (
+                    2,
+                    "CXX_STANDARD".to_string(),
+                    "invalid value \"18\", expected one of 98, 11, 14, 17, 20, 23, 26".to_string()
+                ),
+                (
+                    4,
+                    "WILL_FAIL".to_string(),
+                    "not a TARGET property but a TEST property".to_string()
+                ),
+                (
+                    5,
+                    "TIMEOUTS".to_string(),
+                    "unknown TEST property, did you mean TIMEOUT?".to_string()
+                ),
+                (
+                    8,
+                    "MY_CUSTOM".to_string(),
+                    "unknown SOURCE property".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn property_assignments() {
+        let cmakelists = parse_cmakelists(
+            b"set_property(TARGET a b APPEND PROPERTY LABELS x y)\nset_directory_properties(PROPERTIES LABELS z)\n",
+        )
+        .unwrap();
+        let doc = Doc::from(cmakelists);
+        assert_eq!(
+            PropertyAssignment::from_commands(&doc.commands().unwrap()),
+            vec![
+                PropertyAssignment {
+                    command: 0,
+                    scope: PropertyScope::Target,
+                    entities: vec!["a".to_string(), "b".to_string()],
+                    name: "LABELS".to_string(),
+                    values: vec!["x".to_string(), "y".to_string()],
+                    mode: AssignMode::Append,
+                },
+                PropertyAssignment {
+                    command: 1,
+                    scope: PropertyScope::Directory,
+                    entities: vec![],
+                    name: "LABELS".to_string(),
+                    values: vec!["z".to_string()],
+                    mode: AssignMode::Set,
+                },
+            ]
+        );
+    }
+}
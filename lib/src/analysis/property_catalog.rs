@@ -0,0 +1,579 @@
+use crate::analysis::{PropertyInfo, PropertyScope, PropertyType};
+
+const GLOBAL: PropertyScope = PropertyScope::Global;
+const DIRECTORY: PropertyScope = PropertyScope::Directory;
+const TARGET: PropertyScope = PropertyScope::Target;
+const SOURCE: PropertyScope = PropertyScope::Source;
+const TEST: PropertyScope = PropertyScope::Test;
+const CACHE: PropertyScope = PropertyScope::Cache;
+const INSTALL: PropertyScope = PropertyScope::Install;
+
+const BOOL: PropertyType = PropertyType::Bool;
+const INTEGER: PropertyType = PropertyType::Integer;
+const STRING: PropertyType = PropertyType::String;
+const LIST: PropertyType = PropertyType::List;
+const PATH: PropertyType = PropertyType::Path;
+
+const C_STANDARDS: PropertyType = PropertyType::Enum(&["90", "99", "11", "17", "23"]);
+const CXX_STANDARDS: PropertyType = PropertyType::Enum(&["98", "11", "14", "17", "20", "23", "26"]);
+const VISIBILITY: PropertyType =
+    PropertyType::Enum(&["default", "hidden", "protected", "internal"]);
+const UNITY_BUILD_MODE: PropertyType = PropertyType::Enum(&["BATCH", "GROUP"]);
+const CACHE_TYPE: PropertyType = PropertyType::Enum(&[
+    "BOOL",
+    "FILEPATH",
+    "PATH",
+    "STRING",
+    "INTERNAL",
+    "STATIC",
+    "UNINITIALIZED",
+]);
+const FORTRAN_FORMAT: PropertyType = PropertyType::Enum(&["FIXED", "FREE"]);
+const CUDA_RUNTIME: PropertyType = PropertyType::Enum(&["None", "Shared", "Static"]);
+const LINK_BUILD_PHASE_MODE: PropertyType =
+    PropertyType::Enum(&["NONE", "BUILT_ONLY", "KNOWN_LOCATION"]);
+
+const fn prop(scope: PropertyScope, name: &'static str, value_type: PropertyType) -> PropertyInfo {
+    PropertyInfo {
+        name,
+        scope,
+        value_type,
+        genex: false,
+    }
+}
+
+const fn genex(scope: PropertyScope, name: &'static str, value_type: PropertyType) -> PropertyInfo {
+    PropertyInfo {
+        name,
+        scope,
+        value_type,
+        genex: true,
+    }
+}
+
+/// Properties documented by CMake 3.26.
+pub(crate) const PROPERTIES: &[PropertyInfo] = &[
+    // Global properties
+    prop(GLOBAL, "ALLOW_DUPLICATE_CUSTOM_TARGETS", BOOL),
+    prop(GLOBAL, "AUTOGEN_SOURCE_GROUP", STRING),
+    prop(GLOBAL, "AUTOGEN_TARGETS_FOLDER", STRING),
+    prop(GLOBAL, "AUTOMOC_SOURCE_GROUP", STRING),
+    prop(GLOBAL, "AUTOMOC_TARGETS_FOLDER", STRING),
+    prop(GLOBAL, "AUTORCC_SOURCE_GROUP", STRING),
+    prop(GLOBAL, "AUTOUIC_SOURCE_GROUP", STRING),
+    prop(GLOBAL, "CMAKE_C_KNOWN_FEATURES", LIST),
+    prop(GLOBAL, "CMAKE_CUDA_KNOWN_FEATURES", LIST),
+    prop(GLOBAL, "CMAKE_CXX_KNOWN_FEATURES", LIST),
+    prop(GLOBAL, "CMAKE_ROLE", STRING),
+    prop(GLOBAL, "DEBUG_CONFIGURATIONS", LIST),
+    prop(GLOBAL, "DISABLED_FEATURES", LIST),
+    prop(GLOBAL, "ECLIPSE_EXTRA_CPROJECT_CONTENTS", STRING),
+    prop(GLOBAL, "ECLIPSE_EXTRA_NATURES", LIST),
+    prop(GLOBAL, "ENABLED_FEATURES", LIST),
+    prop(GLOBAL, "ENABLED_LANGUAGES", LIST),
+    prop(GLOBAL, "FIND_LIBRARY_USE_LIB32_PATHS", BOOL),
+    prop(GLOBAL, "FIND_LIBRARY_USE_LIB64_PATHS", BOOL),
+    prop(GLOBAL, "FIND_LIBRARY_USE_LIBX32_PATHS", BOOL),
+    prop(GLOBAL, "FIND_LIBRARY_USE_OPENBSD_VERSIONING", BOOL),
+    prop(GLOBAL, "GENERATOR_IS_MULTI_CONFIG", BOOL),
+    prop(GLOBAL, "GLOBAL_DEPENDS_DEBUG_MODE", BOOL),
+    prop(GLOBAL, "GLOBAL_DEPENDS_NO_CYCLES", BOOL),
+    prop(GLOBAL, "IN_TRY_COMPILE", BOOL),
+    prop(GLOBAL, "JOB_POOLS", LIST),
+    prop(GLOBAL, "PACKAGES_FOUND", LIST),
+    prop(GLOBAL, "PACKAGES_NOT_FOUND", LIST),
+    prop(GLOBAL, "PREDEFINED_TARGETS_FOLDER", STRING),
+    prop(GLOBAL, "REPORT_UNDEFINED_PROPERTIES", PATH),
+    prop(GLOBAL, "RULE_LAUNCH_COMPILE", STRING),
+    prop(GLOBAL, "RULE_LAUNCH_CUSTOM", STRING),
+    prop(GLOBAL, "RULE_LAUNCH_LINK", STRING),
+    prop(GLOBAL, "RULE_MESSAGES", BOOL),
+    prop(GLOBAL, "TARGET_ARCHIVES_MAY_BE_SHARED_LIBS", BOOL),
+    prop(GLOBAL, "TARGET_MESSAGES", BOOL),
+    prop(GLOBAL, "TARGET_SUPPORTS_SHARED_LIBS", BOOL),
+    prop(GLOBAL, "USE_FOLDERS", BOOL),
+    prop(GLOBAL, "XCODE_EMIT_EFFECTIVE_PLATFORM_NAME", BOOL),
+    // Directory properties
+    genex(DIRECTORY, "ADDITIONAL_CLEAN_FILES", LIST),
+    prop(DIRECTORY, "ADDITIONAL_MAKE_CLEAN_FILES", LIST),
+    prop(DIRECTORY, "BINARY_DIR", PATH),
+    prop(DIRECTORY, "BUILDSYSTEM_TARGETS", LIST),
+    prop(DIRECTORY, "CACHE_VARIABLES", LIST),
+    prop(DIRECTORY, "CLEAN_NO_CUSTOM", BOOL),
+    prop(DIRECTORY, "CMAKE_CONFIGURE_DEPENDS", LIST),
+    genex(DIRECTORY, "COMPILE_DEFINITIONS", LIST),
+    prop(DIRECTORY, "COMPILE_DEFINITIONS_<CONFIG>", LIST),
+    genex(DIRECTORY, "COMPILE_OPTIONS", LIST),
+    prop(DIRECTORY, "DEFINITIONS", STRING),
+    prop(DIRECTORY, "EXCLUDE_FROM_ALL", BOOL),
+    prop(DIRECTORY, "IMPLICIT_DEPENDS_INCLUDE_TRANSFORM", LIST),
+    prop(DIRECTORY, "IMPORTED_TARGETS", LIST),
+    genex(DIRECTORY, "INCLUDE_DIRECTORIES", LIST),
+    prop(DIRECTORY, "INCLUDE_REGULAR_EXPRESSION", STRING),
+    prop(DIRECTORY, "INTERPROCEDURAL_OPTIMIZATION", BOOL),
+    prop(DIRECTORY, "INTERPROCEDURAL_OPTIMIZATION_<CONFIG>", BOOL),
+    prop(DIRECTORY, "LABELS", LIST),
+    genex(DIRECTORY, "LINK_DIRECTORIES", LIST),
+    genex(DIRECTORY, "LINK_OPTIONS", LIST),
+    prop(DIRECTORY, "LISTFILE_STACK", LIST),
+    prop(DIRECTORY, "MACROS", LIST),
+    prop(DIRECTORY, "PARENT_DIRECTORY", PATH),
+    prop(DIRECTORY, "RULE_LAUNCH_COMPILE", STRING),
+    prop(DIRECTORY, "RULE_LAUNCH_CUSTOM", STRING),
+    prop(DIRECTORY, "RULE_LAUNCH_LINK", STRING),
+    prop(DIRECTORY, "SOURCE_DIR", PATH),
+    prop(DIRECTORY, "SUBDIRECTORIES", LIST),
+    prop(DIRECTORY, "SYSTEM", BOOL),
+    prop(DIRECTORY, "TESTS", LIST),
+    prop(DIRECTORY, "TEST_INCLUDE_FILES", LIST),
+    prop(DIRECTORY, "VARIABLES", LIST),
+    prop(DIRECTORY, "VS_GLOBAL_SECTION_POST_<NAME>", LIST),
+    prop(DIRECTORY, "VS_GLOBAL_SECTION_PRE_<NAME>", LIST),
+    prop(DIRECTORY, "VS_STARTUP_PROJECT", STRING),
+    // Target properties
+    genex(TARGET, "ADDITIONAL_CLEAN_FILES", LIST),
+    prop(TARGET, "AIX_EXPORT_ALL_SYMBOLS", BOOL),
+    prop(TARGET, "ALIAS_GLOBAL", BOOL),
+    prop(TARGET, "ALIASED_TARGET", STRING),
+    prop(TARGET, "ANDROID_ANT_ADDITIONAL_OPTIONS", LIST),
+    prop(TARGET, "ANDROID_API", INTEGER),
+    prop(TARGET, "ANDROID_API_MIN", INTEGER),
+    prop(TARGET, "ANDROID_ARCH", STRING),
+    prop(TARGET, "ANDROID_ASSETS_DIRECTORIES", LIST),
+    prop(TARGET, "ANDROID_GUI", BOOL),
+    prop(TARGET, "ANDROID_JAR_DEPENDENCIES", LIST),
+    prop(TARGET, "ANDROID_JAR_DIRECTORIES", LIST),
+    prop(TARGET, "ANDROID_JAVA_SOURCE_DIR", LIST),
+    prop(TARGET, "ANDROID_NATIVE_LIB_DEPENDENCIES", LIST),
+    prop(TARGET, "ANDROID_NATIVE_LIB_DIRECTORIES", LIST),
+    prop(TARGET, "ANDROID_PROCESS_MAX", INTEGER),
+    prop(TARGET, "ANDROID_PROGUARD", BOOL),
+    prop(TARGET, "ANDROID_PROGUARD_CONFIG_PATH", PATH),
+    prop(TARGET, "ANDROID_SECURE_PROPS_PATH", PATH),
+    prop(TARGET, "ANDROID_SKIP_ANT_STEP", BOOL),
+    prop(TARGET, "ANDROID_STL_TYPE", STRING),
+    genex(TARGET, "ARCHIVE_OUTPUT_DIRECTORY", PATH),
+    genex(TARGET, "ARCHIVE_OUTPUT_DIRECTORY_<CONFIG>", PATH),
+    prop(TARGET, "ARCHIVE_OUTPUT_NAME", STRING),
+    prop(TARGET, "ARCHIVE_OUTPUT_NAME_<CONFIG>", STRING),
+    prop(TARGET, "AUTOGEN_BUILD_DIR", PATH),
+    prop(TARGET, "AUTOGEN_ORIGIN_DEPENDS", BOOL),
+    prop(TARGET, "AUTOGEN_PARALLEL", STRING),
+    prop(TARGET, "AUTOGEN_TARGET_DEPENDS", LIST),
+    prop(TARGET, "AUTOGEN_USE_SYSTEM_INCLUDE", BOOL),
+    prop(TARGET, "AUTOMOC", BOOL),
+    prop(TARGET, "AUTOMOC_COMPILER_PREDEFINES", BOOL),
+    prop(TARGET, "AUTOMOC_DEPEND_FILTERS", LIST),
+    prop(TARGET, "AUTOMOC_EXECUTABLE", PATH),
+    prop(TARGET, "AUTOMOC_MACRO_NAMES", LIST),
+    genex(TARGET, "AUTOMOC_MOC_OPTIONS", LIST),
+    prop(TARGET, "AUTOMOC_PATH_PREFIX", BOOL),
+    prop(TARGET, "AUTORCC", BOOL),
+    prop(TARGET, "AUTORCC_EXECUTABLE", PATH),
+    prop(TARGET, "AUTORCC_OPTIONS", LIST),
+    prop(TARGET, "AUTOUIC", BOOL),
+    prop(TARGET, "AUTOUIC_EXECUTABLE", PATH),
+    prop(TARGET, "AUTOUIC_OPTIONS", LIST),
+    prop(TARGET, "AUTOUIC_SEARCH_PATHS", LIST),
+    prop(TARGET, "BINARY_DIR", PATH),
+    genex(TARGET, "BUILD_RPATH", LIST),
+    prop(TARGET, "BUILD_RPATH_USE_ORIGIN", BOOL),
+    prop(TARGET, "BUILD_WITH_INSTALL_NAME_DIR", BOOL),
+    prop(TARGET, "BUILD_WITH_INSTALL_RPATH", BOOL),
+    prop(TARGET, "BUNDLE", BOOL),
+    prop(TARGET, "BUNDLE_EXTENSION", STRING),
+    prop(TARGET, "C_EXTENSIONS", BOOL),
+    prop(TARGET, "C_STANDARD", C_STANDARDS),
+    prop(TARGET, "C_STANDARD_REQUIRED", BOOL),
+    prop(TARGET, "COMMON_LANGUAGE_RUNTIME", STRING),
+    prop(TARGET, "COMPATIBLE_INTERFACE_BOOL", LIST),
+    prop(TARGET, "COMPATIBLE_INTERFACE_NUMBER_MAX", LIST),
+    prop(TARGET, "COMPATIBLE_INTERFACE_NUMBER_MIN", LIST),
+    prop(TARGET, "COMPATIBLE_INTERFACE_STRING", LIST),
+    genex(TARGET, "COMPILE_DEFINITIONS", LIST),
+    prop(TARGET, "COMPILE_DEFINITIONS_<CONFIG>", LIST),
+    genex(TARGET, "COMPILE_FEATURES", LIST),
+    genex(TARGET, "COMPILE_FLAGS", STRING),
+    genex(TARGET, "COMPILE_OPTIONS", LIST),
+    prop(TARGET, "COMPILE_PDB_NAME", STRING),
+    prop(TARGET, "COMPILE_PDB_NAME_<CONFIG>", STRING),
+    genex(TARGET, "COMPILE_PDB_OUTPUT_DIRECTORY", PATH),
+    genex(TARGET, "COMPILE_PDB_OUTPUT_DIRECTORY_<CONFIG>", PATH),
+    prop(TARGET, "COMPILE_WARNING_AS_ERROR", BOOL),
+    genex(TARGET, "CROSSCOMPILING_EMULATOR", LIST),
+    prop(TARGET, "CUDA_ARCHITECTURES", LIST),
+    prop(TARGET, "CUDA_EXTENSIONS", BOOL),
+    prop(TARGET, "CUDA_PTX_COMPILATION", BOOL),
+    prop(TARGET, "CUDA_RESOLVE_DEVICE_SYMBOLS", BOOL),
+    genex(TARGET, "CUDA_RUNTIME_LIBRARY", CUDA_RUNTIME),
+    prop(TARGET, "CUDA_SEPARABLE_COMPILATION", BOOL),
+    prop(TARGET, "CUDA_STANDARD", CXX_STANDARDS),
+    prop(TARGET, "CUDA_STANDARD_REQUIRED", BOOL),
+    prop(TARGET, "CXX_EXTENSIONS", BOOL),
+    prop(TARGET, "CXX_STANDARD", CXX_STANDARDS),
+    prop(TARGET, "CXX_STANDARD_REQUIRED", BOOL),
+    prop(TARGET, "DEBUG_POSTFIX", STRING),
+    genex(TARGET, "DEFINE_SYMBOL", STRING),
+    prop(TARGET, "DEPLOYMENT_ADDITIONAL_FILES", LIST),
+    prop(TARGET, "DEPLOYMENT_REMOTE_DIRECTORY", PATH),
+    prop(TARGET, "DEPRECATION", STRING),
+    prop(TARGET, "DISABLE_PRECOMPILE_HEADERS", BOOL),
+    prop(TARGET, "DOTNET_SDK", STRING),
+    prop(TARGET, "DOTNET_TARGET_FRAMEWORK", STRING),
+    prop(TARGET, "DOTNET_TARGET_FRAMEWORK_VERSION", STRING),
+    prop(TARGET, "EchoString", STRING),
+    prop(TARGET, "ENABLE_EXPORTS", BOOL),
+    prop(TARGET, "EXCLUDE_FROM_ALL", BOOL),
+    genex(TARGET, "EXCLUDE_FROM_DEFAULT_BUILD", BOOL),
+    genex(TARGET, "EXCLUDE_FROM_DEFAULT_BUILD_<CONFIG>", BOOL),
+    prop(TARGET, "EXPORT_COMPILE_COMMANDS", BOOL),
+    prop(TARGET, "EXPORT_NAME", STRING),
+    prop(TARGET, "EXPORT_NO_SYSTEM", BOOL),
+    prop(TARGET, "EXPORT_PROPERTIES", LIST),
+    prop(TARGET, "FOLDER", STRING),
+    prop(TARGET, "Fortran_FORMAT", FORTRAN_FORMAT),
+    prop(TARGET, "Fortran_MODULE_DIRECTORY", PATH),
+    genex(TARGET, "Fortran_PREPROCESS", BOOL),
+    prop(TARGET, "FRAMEWORK", BOOL),
+    prop(TARGET, "FRAMEWORK_MULTI_CONFIG_POSTFIX_<CONFIG>", STRING),
+    prop(TARGET, "FRAMEWORK_VERSION", STRING),
+    prop(TARGET, "GENERATOR_FILE_NAME", STRING),
+    prop(TARGET, "GHS_INTEGRITY_APP", BOOL),
+    prop(TARGET, "GHS_NO_SOURCE_GROUP_FILE", BOOL),
+    prop(TARGET, "GNUtoMS", BOOL),
+    prop(TARGET, "HAS_CXX", BOOL),
+    genex(TARGET, "HEADER_DIRS", LIST),
+    genex(TARGET, "HEADER_DIRS_<NAME>", LIST),
+    genex(TARGET, "HEADER_SET", LIST),
+    genex(TARGET, "HEADER_SET_<NAME>", LIST),
+    prop(TARGET, "HEADER_SETS", LIST),
+    prop(TARGET, "HIP_ARCHITECTURES", LIST),
+    prop(TARGET, "HIP_EXTENSIONS", BOOL),
+    prop(TARGET, "HIP_STANDARD", CXX_STANDARDS),
+    prop(TARGET, "HIP_STANDARD_REQUIRED", BOOL),
+    prop(TARGET, "IMPLICIT_DEPENDS_INCLUDE_TRANSFORM", LIST),
+    prop(TARGET, "IMPORTED", BOOL),
+    prop(TARGET, "IMPORTED_COMMON_LANGUAGE_RUNTIME", STRING),
+    prop(TARGET, "IMPORTED_CONFIGURATIONS", LIST),
+    prop(TARGET, "IMPORTED_GLOBAL", BOOL),
+    prop(TARGET, "IMPORTED_IMPLIB", PATH),
+    prop(TARGET, "IMPORTED_IMPLIB_<CONFIG>", PATH),
+    prop(TARGET, "IMPORTED_LIBNAME", LIST),
+    prop(TARGET, "IMPORTED_LIBNAME_<CONFIG>", LIST),
+    prop(TARGET, "IMPORTED_LINK_DEPENDENT_LIBRARIES", LIST),
+    prop(TARGET, "IMPORTED_LINK_DEPENDENT_LIBRARIES_<CONFIG>", LIST),
+    prop(TARGET, "IMPORTED_LINK_INTERFACE_LANGUAGES", LIST),
+    prop(TARGET, "IMPORTED_LINK_INTERFACE_LANGUAGES_<CONFIG>", LIST),
+    prop(TARGET, "IMPORTED_LINK_INTERFACE_LIBRARIES", LIST),
+    prop(TARGET, "IMPORTED_LINK_INTERFACE_LIBRARIES_<CONFIG>", LIST),
+    prop(TARGET, "IMPORTED_LINK_INTERFACE_MULTIPLICITY", INTEGER),
+    prop(
+        TARGET,
+        "IMPORTED_LINK_INTERFACE_MULTIPLICITY_<CONFIG>",
+        INTEGER,
+    ),
+    prop(TARGET, "IMPORTED_LOCATION", PATH),
+    prop(TARGET, "IMPORTED_LOCATION_<CONFIG>", PATH),
+    prop(TARGET, "IMPORTED_NO_SONAME", BOOL),
+    prop(TARGET, "IMPORTED_NO_SONAME_<CONFIG>", BOOL),
+    prop(TARGET, "IMPORTED_NO_SYSTEM", BOOL),
+    genex(TARGET, "IMPORTED_OBJECTS", LIST),
+    genex(TARGET, "IMPORTED_OBJECTS_<CONFIG>", LIST),
+    prop(TARGET, "IMPORTED_SONAME", STRING),
+    prop(TARGET, "IMPORTED_SONAME_<CONFIG>", STRING),
+    prop(TARGET, "IMPORT_PREFIX", STRING),
+    prop(TARGET, "IMPORT_SUFFIX", STRING),
+    genex(TARGET, "INCLUDE_DIRECTORIES", LIST),
+    genex(TARGET, "INSTALL_NAME_DIR", PATH),
+    prop(TARGET, "INSTALL_REMOVE_ENVIRONMENT_RPATH", BOOL),
+    genex(TARGET, "INSTALL_RPATH", LIST),
+    prop(TARGET, "INSTALL_RPATH_USE_LINK_PATH", BOOL),
+    genex(TARGET, "INTERFACE_AUTOUIC_OPTIONS", LIST),
+    genex(TARGET, "INTERFACE_COMPILE_DEFINITIONS", LIST),
+    genex(TARGET, "INTERFACE_COMPILE_FEATURES", LIST),
+    genex(TARGET, "INTERFACE_COMPILE_OPTIONS", LIST),
+    prop(TARGET, "INTERFACE_HEADER_SETS", LIST),
+    prop(TARGET, "INTERFACE_HEADER_SETS_TO_VERIFY", LIST),
+    genex(TARGET, "INTERFACE_INCLUDE_DIRECTORIES", LIST),
+    genex(TARGET, "INTERFACE_LINK_DEPENDS", LIST),
+    genex(TARGET, "INTERFACE_LINK_DIRECTORIES", LIST),
+    genex(TARGET, "INTERFACE_LINK_LIBRARIES", LIST),
+    genex(TARGET, "INTERFACE_LINK_LIBRARIES_DIRECT", LIST),
+    genex(TARGET, "INTERFACE_LINK_LIBRARIES_DIRECT_EXCLUDE", LIST),
+    genex(TARGET, "INTERFACE_LINK_OPTIONS", LIST),
+    prop(TARGET, "INTERFACE_POSITION_INDEPENDENT_CODE", BOOL),
+    genex(TARGET, "INTERFACE_PRECOMPILE_HEADERS", LIST),
+    genex(TARGET, "INTERFACE_SOURCES", LIST),
+    genex(TARGET, "INTERFACE_SYSTEM_INCLUDE_DIRECTORIES", LIST),
+    prop(TARGET, "INTERPROCEDURAL_OPTIMIZATION", BOOL),
+    prop(TARGET, "INTERPROCEDURAL_OPTIMIZATION_<CONFIG>", BOOL),
+    prop(TARGET, "IOS_INSTALL_COMBINED", BOOL),
+    prop(TARGET, "ISPC_HEADER_DIRECTORY", PATH),
+    prop(TARGET, "ISPC_HEADER_SUFFIX", STRING),
+    prop(TARGET, "ISPC_INSTRUCTION_SETS", LIST),
+    prop(TARGET, "JOB_POOL_COMPILE", STRING),
+    prop(TARGET, "JOB_POOL_LINK", STRING),
+    prop(TARGET, "JOB_POOL_PRECOMPILE_HEADER", STRING),
+    prop(TARGET, "LABELS", LIST),
+    genex(TARGET, "<LANG>_CLANG_TIDY", LIST),
+    prop(TARGET, "<LANG>_CLANG_TIDY_EXPORT_FIXES_DIR", PATH),
+    genex(TARGET, "<LANG>_COMPILER_LAUNCHER", LIST),
+    genex(TARGET, "<LANG>_CPPCHECK", LIST),
+    genex(TARGET, "<LANG>_CPPLINT", LIST),
+    prop(TARGET, "<LANG>_EXTENSIONS", BOOL),
+    genex(TARGET, "<LANG>_INCLUDE_WHAT_YOU_USE", LIST),
+    genex(TARGET, "<LANG>_LINKER_LAUNCHER", LIST),
+    prop(TARGET, "<LANG>_STANDARD_REQUIRED", BOOL),
+    prop(TARGET, "<LANG>_VISIBILITY_PRESET", VISIBILITY),
+    genex(TARGET, "LIBRARY_OUTPUT_DIRECTORY", PATH),
+    genex(TARGET, "LIBRARY_OUTPUT_DIRECTORY_<CONFIG>", PATH),
+    prop(TARGET, "LIBRARY_OUTPUT_NAME", STRING),
+    prop(TARGET, "LIBRARY_OUTPUT_NAME_<CONFIG>", STRING),
+    prop(TARGET, "LINK_DEPENDS", LIST),
+    prop(TARGET, "LINK_DEPENDS_NO_SHARED", BOOL),
+    genex(TARGET, "LINK_DIRECTORIES", LIST),
+    prop(TARGET, "LINK_FLAGS", STRING),
+    prop(TARGET, "LINK_FLAGS_<CONFIG>", STRING),
+    prop(TARGET, "LINK_INTERFACE_LIBRARIES", LIST),
+    prop(TARGET, "LINK_INTERFACE_LIBRARIES_<CONFIG>", LIST),
+    prop(TARGET, "LINK_INTERFACE_MULTIPLICITY", INTEGER),
+    prop(TARGET, "LINK_INTERFACE_MULTIPLICITY_<CONFIG>", INTEGER),
+    genex(TARGET, "LINK_LIBRARIES", LIST),
+    prop(TARGET, "LINK_LIBRARIES_ONLY_TARGETS", BOOL),
+    genex(TARGET, "LINK_LIBRARY_OVERRIDE", LIST),
+    genex(TARGET, "LINK_LIBRARY_OVERRIDE_<NAME>", STRING),
+    genex(TARGET, "LINK_OPTIONS", LIST),
+    prop(TARGET, "LINK_SEARCH_END_STATIC", BOOL),
+    prop(TARGET, "LINK_SEARCH_START_STATIC", BOOL),
+    prop(TARGET, "LINK_WHAT_YOU_USE", BOOL),
+    prop(TARGET, "LINKER_LANGUAGE", STRING),
+    prop(TARGET, "LOCATION", PATH),
+    prop(TARGET, "LOCATION_<CONFIG>", PATH),
+    prop(TARGET, "MACHO_COMPATIBILITY_VERSION", STRING),
+    prop(TARGET, "MACHO_CURRENT_VERSION", STRING),
+    prop(TARGET, "MACOSX_BUNDLE", BOOL),
+    prop(TARGET, "MACOSX_BUNDLE_INFO_PLIST", PATH),
+    prop(TARGET, "MACOSX_FRAMEWORK_INFO_PLIST", PATH),
+    prop(TARGET, "MACOSX_RPATH", BOOL),
+    prop(TARGET, "MANUALLY_ADDED_DEPENDENCIES", LIST),
+    prop(TARGET, "MAP_IMPORTED_CONFIG_<CONFIG>", LIST),
+    genex(TARGET, "MSVC_DEBUG_INFORMATION_FORMAT", STRING),
+    genex(TARGET, "MSVC_RUNTIME_LIBRARY", STRING),
+    prop(TARGET, "NAME", STRING),
+    prop(TARGET, "NO_SONAME", BOOL),
+    prop(TARGET, "NO_SYSTEM_FROM_IMPORTED", BOOL),
+    prop(TARGET, "OBJC_EXTENSIONS", BOOL),
+    prop(TARGET, "OBJC_STANDARD", C_STANDARDS),
+    prop(TARGET, "OBJC_STANDARD_REQUIRED", BOOL),
+    prop(TARGET, "OBJCXX_EXTENSIONS", BOOL),
+    prop(TARGET, "OBJCXX_STANDARD", CXX_STANDARDS),
+    prop(TARGET, "OBJCXX_STANDARD_REQUIRED", BOOL),
+    prop(TARGET, "OPTIMIZE_DEPENDENCIES", BOOL),
+    prop(TARGET, "OSX_ARCHITECTURES", LIST),
+    prop(TARGET, "OSX_ARCHITECTURES_<CONFIG>", LIST),
+    genex(TARGET, "OUTPUT_NAME", STRING),
+    genex(TARGET, "OUTPUT_NAME_<CONFIG>", STRING),
+    prop(TARGET, "<CONFIG>_OUTPUT_NAME", STRING),
+    prop(TARGET, "<CONFIG>_POSTFIX", STRING),
+    prop(TARGET, "PCH_INSTANTIATE_TEMPLATES", BOOL),
+    prop(TARGET, "PCH_WARN_INVALID", BOOL),
+    prop(TARGET, "PDB_NAME", STRING),
+    prop(TARGET, "PDB_NAME_<CONFIG>", STRING),
+    genex(TARGET, "PDB_OUTPUT_DIRECTORY", PATH),
+    genex(TARGET, "PDB_OUTPUT_DIRECTORY_<CONFIG>", PATH),
+    prop(TARGET, "POSITION_INDEPENDENT_CODE", BOOL),
+    prop(TARGET, "POST_INSTALL_SCRIPT", PATH),
+    genex(TARGET, "PRECOMPILE_HEADERS", LIST),
+    prop(TARGET, "PRECOMPILE_HEADERS_REUSE_FROM", STRING),
+    prop(TARGET, "PREFIX", STRING),
+    prop(TARGET, "PRE_INSTALL_SCRIPT", PATH),
+    prop(TARGET, "PRIVATE_HEADER", LIST),
+    prop(TARGET, "PROJECT_LABEL", STRING),
+    prop(TARGET, "PUBLIC_HEADER", LIST),
+    prop(TARGET, "RESOURCE", LIST),
+    prop(TARGET, "RULE_LAUNCH_COMPILE", STRING),
+    prop(TARGET, "RULE_LAUNCH_CUSTOM", STRING),
+    prop(TARGET, "RULE_LAUNCH_LINK", STRING),
+    genex(TARGET, "RUNTIME_OUTPUT_DIRECTORY", PATH),
+    genex(TARGET, "RUNTIME_OUTPUT_DIRECTORY_<CONFIG>", PATH),
+    prop(TARGET, "RUNTIME_OUTPUT_NAME", STRING),
+    prop(TARGET, "RUNTIME_OUTPUT_NAME_<CONFIG>", STRING),
+    prop(TARGET, "SKIP_BUILD_RPATH", BOOL),
+    prop(TARGET, "SOURCE_DIR", PATH),
+    genex(TARGET, "SOURCES", LIST),
+    prop(TARGET, "SOVERSION", STRING),
+    prop(TARGET, "STATIC_LIBRARY_FLAGS", STRING),
+    prop(TARGET, "STATIC_LIBRARY_FLAGS_<CONFIG>", STRING),
+    genex(TARGET, "STATIC_LIBRARY_OPTIONS", LIST),
+    prop(TARGET, "SUFFIX", STRING),
+    genex(TARGET, "Swift_COMPILATION_MODE", STRING),
+    prop(TARGET, "Swift_DEPENDENCIES_FILE", PATH),
+    prop(TARGET, "Swift_LANGUAGE_VERSION", STRING),
+    prop(TARGET, "Swift_MODULE_DIRECTORY", PATH),
+    prop(TARGET, "Swift_MODULE_NAME", STRING),
+    prop(TARGET, "SYSTEM", BOOL),
+    prop(TARGET, "TYPE", STRING),
+    prop(TARGET, "UNITY_BUILD", BOOL),
+    prop(TARGET, "UNITY_BUILD_BATCH_SIZE", INTEGER),
+    prop(TARGET, "UNITY_BUILD_CODE_AFTER_INCLUDE", STRING),
+    prop(TARGET, "UNITY_BUILD_CODE_BEFORE_INCLUDE", STRING),
+    prop(TARGET, "UNITY_BUILD_MODE", UNITY_BUILD_MODE),
+    prop(TARGET, "UNITY_BUILD_UNIQUE_ID", STRING),
+    prop(TARGET, "VERIFY_INTERFACE_HEADER_SETS", BOOL),
+    prop(TARGET, "VERSION", STRING),
+    prop(TARGET, "VISIBILITY_INLINES_HIDDEN", BOOL),
+    prop(TARGET, "VS_CONFIGURATION_TYPE", STRING),
+    genex(TARGET, "VS_DEBUGGER_COMMAND", PATH),
+    genex(TARGET, "VS_DEBUGGER_COMMAND_ARGUMENTS", STRING),
+    genex(TARGET, "VS_DEBUGGER_ENVIRONMENT", STRING),
+    genex(TARGET, "VS_DEBUGGER_WORKING_DIRECTORY", PATH),
+    prop(TARGET, "VS_DESKTOP_EXTENSIONS_VERSION", STRING),
+    prop(TARGET, "VS_DOTNET_DOCUMENTATION_FILE", PATH),
+    prop(TARGET, "VS_DOTNET_REFERENCE_<NAME>", PATH),
+    prop(TARGET, "VS_DOTNET_REFERENCEPROP_<NAME>_TAG_<NAME>", STRING),
+    prop(TARGET, "VS_DOTNET_REFERENCES", LIST),
+    prop(TARGET, "VS_DOTNET_REFERENCES_COPY_LOCAL", BOOL),
+    prop(TARGET, "VS_DOTNET_STARTUP_OBJECT", STRING),
+    prop(TARGET, "VS_DOTNET_TARGET_FRAMEWORK_VERSION", STRING),
+    genex(TARGET, "VS_DPI_AWARE", STRING),
+    prop(TARGET, "VS_GLOBAL_KEYWORD", STRING),
+    prop(TARGET, "VS_GLOBAL_PROJECT_TYPES", LIST),
+    prop(TARGET, "VS_GLOBAL_ROOTNAMESPACE", STRING),
+    prop(TARGET, "VS_GLOBAL_<NAME>", STRING),
+    prop(TARGET, "VS_IOT_EXTENSIONS_VERSION", STRING),
+    prop(TARGET, "VS_IOT_STARTUP_TASK", BOOL),
+    prop(TARGET, "VS_JUST_MY_CODE_DEBUGGING", BOOL),
+    prop(TARGET, "VS_KEYWORD", STRING),
+    prop(TARGET, "VS_MOBILE_EXTENSIONS_VERSION", STRING),
+    prop(TARGET, "VS_NO_COMPILE_BATCHING", BOOL),
+    prop(TARGET, "VS_NO_SOLUTION_DEPLOY", BOOL),
+    prop(TARGET, "VS_PACKAGE_REFERENCES", LIST),
+    prop(TARGET, "VS_PLATFORM_TOOLSET", STRING),
+    prop(TARGET, "VS_PROJECT_IMPORT", LIST),
+    prop(TARGET, "VS_SCC_AUXPATH", STRING),
+    prop(TARGET, "VS_SCC_LOCALPATH", STRING),
+    prop(TARGET, "VS_SCC_PROJECTNAME", STRING),
+    prop(TARGET, "VS_SCC_PROVIDER", STRING),
+    prop(TARGET, "VS_SDK_REFERENCES", LIST),
+    genex(TARGET, "VS_SOLUTION_DEPLOY", BOOL),
+    genex(TARGET, "VS_SOURCE_SETTINGS_<NAME>", LIST),
+    genex(TARGET, "VS_USER_PROPS", PATH),
+    prop(TARGET, "VS_WINDOWS_TARGET_PLATFORM_MIN_VERSION", STRING),
+    prop(TARGET, "VS_WINRT_COMPONENT", BOOL),
+    prop(TARGET, "VS_WINRT_EXTENSIONS", BOOL),
+    prop(TARGET, "VS_WINRT_REFERENCES", LIST),
+    prop(TARGET, "WATCOM_RUNTIME_LIBRARY", STRING),
+    prop(TARGET, "WIN32_EXECUTABLE", BOOL),
+    prop(TARGET, "WINDOWS_EXPORT_ALL_SYMBOLS", BOOL),
+    genex(TARGET, "XCODE_ATTRIBUTE_<NAME>", STRING),
+    prop(TARGET, "XCODE_EMBED_<NAME>", LIST),
+    prop(TARGET, "XCODE_EMBED_<NAME>_CODE_SIGN_ON_COPY", BOOL),
+    prop(TARGET, "XCODE_EMBED_<NAME>_PATH", PATH),
+    prop(TARGET, "XCODE_EMBED_<NAME>_REMOVE_HEADERS_ON_COPY", BOOL),
+    prop(TARGET, "XCODE_EXPLICIT_FILE_TYPE", STRING),
+    prop(TARGET, "XCODE_GENERATE_SCHEME", BOOL),
+    prop(TARGET, "XCODE_LINK_BUILD_PHASE_MODE", LINK_BUILD_PHASE_MODE),
+    prop(TARGET, "XCODE_PRODUCT_TYPE", STRING),
+    prop(TARGET, "XCODE_SCHEME_<NAME>", STRING),
+    prop(TARGET, "XCTEST", BOOL),
+    // Source file properties
+    prop(SOURCE, "ABSTRACT", BOOL),
+    prop(SOURCE, "AUTORCC_OPTIONS", LIST),
+    prop(SOURCE, "AUTOUIC_OPTIONS", LIST),
+    genex(SOURCE, "COMPILE_DEFINITIONS", LIST),
+    prop(SOURCE, "COMPILE_DEFINITIONS_<CONFIG>", LIST),
+    genex(SOURCE, "COMPILE_FLAGS", STRING),
+    genex(SOURCE, "COMPILE_OPTIONS", LIST),
+    prop(SOURCE, "EXTERNAL_OBJECT", BOOL),
+    prop(SOURCE, "Fortran_FORMAT", FORTRAN_FORMAT),
+    genex(SOURCE, "Fortran_PREPROCESS", BOOL),
+    prop(SOURCE, "GENERATED", BOOL),
+    prop(SOURCE, "HEADER_FILE_ONLY", BOOL),
+    genex(SOURCE, "INCLUDE_DIRECTORIES", LIST),
+    prop(SOURCE, "KEEP_EXTENSION", BOOL),
+    prop(SOURCE, "LABELS", LIST),
+    prop(SOURCE, "LANGUAGE", STRING),
+    prop(SOURCE, "LOCATION", PATH),
+    prop(SOURCE, "MACOSX_PACKAGE_LOCATION", PATH),
+    prop(SOURCE, "OBJECT_DEPENDS", LIST),
+    prop(SOURCE, "OBJECT_OUTPUTS", LIST),
+    prop(SOURCE, "SKIP_AUTOGEN", BOOL),
+    prop(SOURCE, "SKIP_AUTOMOC", BOOL),
+    prop(SOURCE, "SKIP_AUTORCC", BOOL),
+    prop(SOURCE, "SKIP_AUTOUIC", BOOL),
+    prop(SOURCE, "SKIP_PRECOMPILE_HEADERS", BOOL),
+    prop(SOURCE, "SKIP_UNITY_BUILD_INCLUSION", BOOL),
+    prop(SOURCE, "Swift_DEPENDENCIES_FILE", PATH),
+    prop(SOURCE, "Swift_DIAGNOSTICS_FILE", PATH),
+    prop(SOURCE, "SYMBOLIC", BOOL),
+    prop(SOURCE, "UNITY_GROUP", STRING),
+    prop(SOURCE, "VS_COPY_TO_OUT_DIR", STRING),
+    prop(SOURCE, "VS_CSHARP_<NAME>", STRING),
+    prop(SOURCE, "VS_DEPLOYMENT_CONTENT", BOOL),
+    prop(SOURCE, "VS_DEPLOYMENT_LOCATION", PATH),
+    prop(SOURCE, "VS_INCLUDE_IN_VSIX", BOOL),
+    prop(SOURCE, "VS_RESOURCE_GENERATOR", STRING),
+    genex(SOURCE, "VS_SETTINGS", LIST),
+    genex(SOURCE, "VS_SHADER_DISABLE_OPTIMIZATIONS", BOOL),
+    genex(SOURCE, "VS_SHADER_ENABLE_DEBUG", BOOL),
+    prop(SOURCE, "VS_SHADER_ENTRYPOINT", STRING),
+    prop(SOURCE, "VS_SHADER_FLAGS", STRING),
+    prop(SOURCE, "VS_SHADER_MODEL", STRING),
+    genex(SOURCE, "VS_SHADER_OBJECT_FILE_NAME", PATH),
+    prop(SOURCE, "VS_SHADER_OUTPUT_HEADER_FILE", PATH),
+    prop(SOURCE, "VS_SHADER_TYPE", STRING),
+    prop(SOURCE, "VS_SHADER_VARIABLE_NAME", STRING),
+    prop(SOURCE, "VS_TOOL_OVERRIDE", STRING),
+    prop(SOURCE, "VS_XAML_TYPE", STRING),
+    prop(SOURCE, "WRAP_EXCLUDE", BOOL),
+    prop(SOURCE, "XCODE_EXPLICIT_FILE_TYPE", STRING),
+    prop(SOURCE, "XCODE_FILE_ATTRIBUTES", LIST),
+    prop(SOURCE, "XCODE_LAST_KNOWN_FILE_TYPE", STRING),
+    // Test properties
+    prop(TEST, "ATTACHED_FILES", LIST),
+    prop(TEST, "ATTACHED_FILES_ON_FAIL", LIST),
+    prop(TEST, "COST", STRING),
+    prop(TEST, "DEPENDS", LIST),
+    prop(TEST, "DISABLED", BOOL),
+    genex(TEST, "ENVIRONMENT", LIST),
+    genex(TEST, "ENVIRONMENT_MODIFICATION", LIST),
+    prop(TEST, "FAIL_REGULAR_EXPRESSION", LIST),
+    prop(TEST, "FIXTURES_CLEANUP", LIST),
+    prop(TEST, "FIXTURES_REQUIRED", LIST),
+    prop(TEST, "FIXTURES_SETUP", LIST),
+    prop(TEST, "LABELS", LIST),
+    prop(TEST, "MEASUREMENT", STRING),
+    prop(TEST, "PASS_REGULAR_EXPRESSION", LIST),
+    prop(TEST, "PROCESSOR_AFFINITY", BOOL),
+    prop(TEST, "PROCESSORS", INTEGER),
+    prop(TEST, "REQUIRED_FILES", LIST),
+    prop(TEST, "RESOURCE_GROUPS", LIST),
+    prop(TEST, "RESOURCE_LOCK", LIST),
+    prop(TEST, "RUN_SERIAL", BOOL),
+    prop(TEST, "SKIP_REGULAR_EXPRESSION", LIST),
+    prop(TEST, "SKIP_RETURN_CODE", INTEGER),
+    prop(TEST, "TIMEOUT", STRING),
+    prop(TEST, "TIMEOUT_AFTER_MATCH", LIST),
+    prop(TEST, "WILL_FAIL", BOOL),
+    genex(TEST, "WORKING_DIRECTORY", PATH),
+    // Cache entry properties
+    prop(CACHE, "ADVANCED", BOOL),
+    prop(CACHE, "HELPSTRING", STRING),
+    prop(CACHE, "MODIFIED", BOOL),
+    prop(CACHE, "STRINGS", LIST),
+    prop(CACHE, "TYPE", CACHE_TYPE),
+    prop(CACHE, "VALUE", STRING),
+    // Installed file properties
+    prop(INSTALL, "CPACK_DESKTOP_SHORTCUTS", LIST),
+    prop(INSTALL, "CPACK_NEVER_OVERWRITE", BOOL),
+    prop(INSTALL, "CPACK_PERMANENT", BOOL),
+    prop(INSTALL, "CPACK_START_MENU_SHORTCUTS", LIST),
+    prop(INSTALL, "CPACK_STARTUP_SHORTCUTS", LIST),
+    prop(INSTALL, "CPACK_WIX_ACL", LIST),
+];
+
+/// Languages `<LANG>` placeholders stand for.
+pub(crate) const LANGUAGES: &[&str] = &[
+    "ASM", "ASM_MASM", "ASM_NASM", "ASM-ATT", "C", "CSharp", "CUDA", "CXX", "Fortran", "HIP",
+    "ISPC", "Java", "OBJC", "OBJCXX", "RC", "Swift",
+];
@@ -0,0 +1,415 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+
+use crate::{
+    analysis::{
+        project::{normalize, path_value},
+        properties::define_scope,
+        AssignMode, FileId, FileKind, Project, ProjectFile, PropertyAssignment, PropertyScope,
+    },
+    command::project::{add_executable::Executable, add_library::Library},
+    Command,
+};
+
+/// Directory properties a subdirectory starts with.
+const SUBDIRECTORY_PROPERTIES: &[&str] = &[
+    "COMPILE_DEFINITIONS",
+    "COMPILE_OPTIONS",
+    "INCLUDE_DIRECTORIES",
+    "INCLUDE_REGULAR_EXPRESSION",
+    "LINK_DIRECTORIES",
+    "LINK_OPTIONS",
+];
+
+/// Directory properties a target starts with.
+const TARGET_PROPERTIES: &[&str] = &[
+    "COMPILE_OPTIONS",
+    "INCLUDE_DIRECTORIES",
+    "LINK_DIRECTORIES",
+    "LINK_OPTIONS",
+];
+
+/// Property values at the end of processing.
+///
+/// Values are computed from `set_property()`, `set_target_properties()`,
+/// `set_source_files_properties()`, `set_tests_properties()` and
+/// `set_directory_properties()` commands in order. Subdirectories start with
+/// the build properties of their parent directory and targets with the
+/// build properties of the directory they are created in. Properties
+/// declared `INHERITED` with `define_property()` which are not set fall back
+/// to the directory and then to the global scope the way `get_property()`
+/// does. Other commands changing properties, like
+/// `target_include_directories()`, are not taken into account.
+///
+/// Directories are identified with `CMAKE_CURRENT_SOURCE_DIR`, an empty
+/// string for the single directory of [`PropertyValues::from_commands`].
+/// Relative source files are resolved against it.
+///
+/// ```
+/// use cmake_parser::{analysis::PropertyValues, parse_cmakelists, Doc};
+///
+/// let cmakelists = parse_cmakelists(b"add_executable(app main.c)\nset_target_properties(app PROPERTIES OUTPUT_NAME tool)\n").unwrap();
+/// let values = PropertyValues::from_commands(&Doc::from(cmakelists).commands().unwrap());
+/// assert_eq!(values.target("app", "OUTPUT_NAME").unwrap().value, "tool");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PropertyValues {
+    values: HashMap<(PropertyScope, String, String), PropertyValue>,
+    /// Parent of each directory.
+    directories: HashMap<String, Option<String>>,
+    /// Directory each entity was created or set in.
+    locations: HashMap<(PropertyScope, String), String>,
+    inherited: HashSet<(PropertyScope, String)>,
+}
+
+/// Value of a property with the commands it comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyValue {
+    pub value: String,
+    /// Commands setting and appending to the value in order.
+    pub origins: Vec<PropertyOrigin>,
+}
+
+/// Command contributing to a [`PropertyValue`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PropertyOrigin {
+    /// File of the command, `None` for [`PropertyValues::from_commands`].
+    pub file: Option<FileId>,
+    /// Index of the command in the file.
+    pub command: usize,
+}
+
+impl PropertyValues {
+    /// Values of the commands of a single directory.
+    pub fn from_commands(commands: &[Command]) -> Self {
+        let mut values = Self::default();
+        values.directories.insert(String::new(), None);
+        for (index, command) in commands.iter().enumerate() {
+            values.command(None, "", index, command);
+        }
+        values
+    }
+
+    /// Values of a project processing the files in the order CMake does.
+    pub fn from_project(project: &Project) -> Self {
+        let mut values = Self::default();
+        if let Some(root) = project.root() {
+            values
+                .directories
+                .insert(path_value(&root.source_dir), None);
+            values.file(project, root);
+        }
+        values
+    }
+
+    /// Value of a property of an entity, inherited properties fall back to
+    /// the enclosing directories and the global scope.
+    pub fn get(&self, scope: PropertyScope, entity: &str, name: &str) -> Option<&PropertyValue> {
+        let key = (scope, entity.to_string(), name.to_string());
+        if let Some(value) = self.values.get(&key) {
+            return Some(value);
+        }
+        if !self.inherited.contains(&(scope, name.to_string())) {
+            return None;
+        }
+        let mut directory = match scope {
+            PropertyScope::Global => return None,
+            PropertyScope::Directory => self.parent(entity),
+            _ => self.locations.get(&(scope, entity.to_string())).cloned(),
+        };
+        while let Some(current) = directory {
+            let key = (PropertyScope::Directory, current, name.to_string());
+            if let Some(value) = self.values.get(&key) {
+                return Some(value);
+            }
+            directory = self.parent(&key.1);
+        }
+        self.global(name)
+    }
+
+    pub fn global(&self, name: &str) -> Option<&PropertyValue> {
+        self.values
+            .get(&(PropertyScope::Global, String::new(), name.to_string()))
+    }
+
+    pub fn directory(&self, directory: &str, name: &str) -> Option<&PropertyValue> {
+        self.get(PropertyScope::Directory, directory, name)
+    }
+
+    pub fn target(&self, target: &str, name: &str) -> Option<&PropertyValue> {
+        self.get(PropertyScope::Target, target, name)
+    }
+
+    pub fn source(&self, source: &str, name: &str) -> Option<&PropertyValue> {
+        self.get(PropertyScope::Source, source, name)
+    }
+
+    pub fn test(&self, test: &str, name: &str) -> Option<&PropertyValue> {
+        self.get(PropertyScope::Test, test, name)
+    }
+
+    fn parent(&self, directory: &str) -> Option<String> {
+        self.directories.get(directory).cloned().flatten()
+    }
+
+    fn file(&mut self, project: &Project, file: &ProjectFile) {
+        let directory = path_value(&file.source_dir);
+        if file.kind == FileKind::Subdirectory {
+            let parent = file
+                .parent
+                .and_then(|parent| project.file(parent))
+                .map(|parent| path_value(&parent.source_dir));
+            if let Some(parent) = &parent {
+                for name in SUBDIRECTORY_PROPERTIES {
+                    self.copy(PropertyScope::Directory, parent, &directory, name);
+                }
+            }
+            self.directories.insert(directory.clone(), parent);
+        }
+        let Ok(doc) = file.doc() else {
+            return;
+        };
+        for (index, command) in doc.to_commands_iter().enumerate() {
+            if let Ok(command) = command {
+                self.command(Some(file.id), &directory, index, &command);
+            }
+            let children = file
+                .children
+                .iter()
+                .filter_map(|&child| project.file(child))
+                .filter(|child| child.parent == Some(file.id) && child.command == Some(index));
+            for child in children {
+                self.file(project, child);
+            }
+        }
+    }
+
+    fn command(&mut self, file: Option<FileId>, directory: &str, index: usize, command: &Command) {
+        let origin = PropertyOrigin {
+            file,
+            command: index,
+        };
+        match command {
+            Command::AddExecutable(add_executable)
+                if !matches!(add_executable.executable, Executable::Alias(_)) =>
+            {
+                self.create_target(directory, &add_executable.name.to_string());
+            }
+            Command::AddLibrary(add_library)
+                if !matches!(add_library.library, Library::Alias(_)) =>
+            {
+                self.create_target(directory, &add_library.name.to_string());
+            }
+            Command::AddCustomTarget(add_custom_target) => {
+                self.create_target(directory, &add_custom_target.name.to_string());
+            }
+            Command::DefineProperty(define) if define.inherited => {
+                if let Some(scope) = define_scope(&define.property_scope) {
+                    self.inherited
+                        .insert((scope, define.property_name.to_string()));
+                }
+            }
+            _ => {
+                for assignment in PropertyAssignment::from_commands(std::slice::from_ref(command)) {
+                    let entities = match assignment.scope {
+                        PropertyScope::Global => vec![String::new()],
+                        PropertyScope::Directory if assignment.entities.is_empty() => {
+                            vec![directory.to_string()]
+                        }
+                        PropertyScope::Directory | PropertyScope::Source => assignment
+                            .entities
+                            .iter()
+                            .map(|entity| resolve(directory, entity))
+                            .collect(),
+                        _ => assignment.entities.clone(),
+                    };
+                    for entity in entities {
+                        self.locations
+                            .entry((assignment.scope, entity.clone()))
+                            .or_insert_with(|| directory.to_string());
+                        self.assign(&assignment, entity, origin);
+                    }
+                }
+            }
+        }
+    }
+
+    fn create_target(&mut self, directory: &str, name: &str) {
+        self.locations.insert(
+            (PropertyScope::Target, name.to_string()),
+            directory.to_string(),
+        );
+        for property in TARGET_PROPERTIES {
+            if let Some(value) = self
+                .values
+                .get(&(
+                    PropertyScope::Directory,
+                    directory.to_string(),
+                    property.to_string(),
+                ))
+                .cloned()
+            {
+                self.values.insert(
+                    (
+                        PropertyScope::Target,
+                        name.to_string(),
+                        property.to_string(),
+                    ),
+                    value,
+                );
+            }
+        }
+    }
+
+    fn copy(&mut self, scope: PropertyScope, from: &str, to: &str, name: &str) {
+        if let Some(value) = self
+            .values
+            .get(&(scope, from.to_string(), name.to_string()))
+            .cloned()
+        {
+            self.values
+                .insert((scope, to.to_string(), name.to_string()), value);
+        }
+    }
+
+    fn assign(&mut self, assignment: &PropertyAssignment, entity: String, origin: PropertyOrigin) {
+        let key = (assignment.scope, entity, assignment.name.clone());
+        let joined = assignment.value();
+        match (assignment.mode, self.values.get_mut(&key)) {
+            (AssignMode::Set, _) if assignment.values.is_empty() => {
+                self.values.remove(&key);
+            }
+            (AssignMode::Append, Some(current)) if !current.value.is_empty() => {
+                if !joined.is_empty() {
+                    current.value.push(';');
+                    current.value.push_str(&joined);
+                }
+                current.origins.push(origin);
+            }
+            (AssignMode::Append | AssignMode::AppendString, Some(current)) => {
+                current.value.push_str(&joined);
+                current.origins.push(origin);
+            }
+            (AssignMode::Set, _) | (_, None) => {
+                self.values.insert(
+                    key,
+                    PropertyValue {
+                        value: joined,
+                        origins: vec![origin],
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Resolves a path against a directory, paths of the unnamed directory
+/// are kept relative.
+fn resolve(directory: &str, path: &str) -> String {
+    if directory.is_empty() {
+        return path.to_string();
+    }
+    path_value(&normalize(&Path::new(directory).join(path)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        analysis::{fixture, ProjectLoader},
+        *,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn value(value: Option<&PropertyValue>) -> Option<(&str, Vec<(usize, usize)>)> {
+        value.map(|value| {
+            (
+                value.value.as_str(),
+                value
+                    .origins
+                    .iter()
+                    .map(|origin| (origin.file.unwrap(), origin.command))
+                    .collect(),
+            )
+        })
+    }
+
+    #[test]
+    fn property_values_project() {
+        let project = ProjectLoader::new(fixture("properties")).load();
+        let values = PropertyValues::from_project(&project);
+        let root = path_value(&fixture("properties"));
+        let lib = format!("{root}/lib");
+
+        assert_eq!(
+            value(values.directory(&root, "COMPILE_OPTIONS")),
+            Some(("-Wall;-Wextra", vec![(0, 4), (0, 6)]))
+        );
+        assert_eq!(
+            value(values.directory(&lib, "COMPILE_OPTIONS")),
+            Some(("-Wall", vec![(0, 4)]))
+        );
+        assert_eq!(
+            value(values.directory(&lib, "INCLUDE_DIRECTORIES")),
+            Some(("include;lib/include", vec![(0, 4), (1, 0)]))
+        );
+        assert_eq!(
+            value(values.target("core", "INCLUDE_DIRECTORIES")),
+            Some(("include;lib/include", vec![(0, 4), (1, 0)]))
+        );
+        assert_eq!(
+            value(values.target("core", "COMPILE_OPTIONS")),
+            Some(("-Wall;-O2", vec![(0, 4), (0, 10)]))
+        );
+        assert_eq!(
+            value(values.target("app", "COMPILE_OPTIONS")),
+            Some(("-Wall;-Wextra", vec![(0, 4), (0, 6)]))
+        );
+        assert_eq!(
+            value(values.target("app", "OUTPUT_NAME")),
+            Some(("tool-cli", vec![(0, 8), (0, 9)]))
+        );
+        assert_eq!(
+            value(values.target("core", "OWNER")),
+            Some(("storage", vec![(1, 1)]))
+        );
+        assert_eq!(
+            value(values.target("app", "OWNER")),
+            Some(("platform", vec![(0, 3)]))
+        );
+        assert_eq!(values.target("app", "LINK_OPTIONS"), None);
+        assert_eq!(
+            value(values.source(&format!("{lib}/core.c"), "COMPILE_DEFINITIONS")),
+            Some(("MAIN", vec![(0, 11)]))
+        );
+        assert_eq!(values.test("core_test", "TIMEOUT"), None);
+    }
+
+    #[test]
+    fn property_values_commands() {
+        let cmakelists = parse_cmakelists(
+            b"set_property(TEST t APPEND PROPERTY LABELS a)\nset_property(TEST t APPEND PROPERTY LABELS b c)\nset_property(SOURCE x.c PROPERTY LANGUAGE CXX)\n",
+        )
+        .unwrap();
+        let doc = Doc::from(cmakelists);
+        let values = PropertyValues::from_commands(&doc.commands().unwrap());
+        let labels = values.test("t", "LABELS").unwrap();
+        assert_eq!(labels.value, "a;b;c");
+        assert_eq!(
+            labels.origins,
+            vec![
+                PropertyOrigin {
+                    file: None,
+                    command: 0
+                },
+                PropertyOrigin {
+                    file: None,
+                    command: 1
+                }
+            ]
+        );
+        assert_eq!(values.source("x.c", "LANGUAGE").unwrap().value, "CXX");
+    }
+}
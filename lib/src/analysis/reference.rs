@@ -0,0 +1,490 @@
+use std::{collections::HashMap, fmt::Write};
+
+use crate::{
+    analysis::{project::path_value, FileId, Project, Signature, Signatures},
+    command::scripting::{set::Cache, Set},
+    Command, Doc, Span,
+};
+
+/// Reference documentation of the functions, macros, options and cache
+/// variables defined by CMake files.
+///
+/// Definitions are documented by the bracket comment or the block of line
+/// comments directly above them. A leading bracket comment, like the
+/// `#[=[.rst:` comments of the CMake modules, or a block of line comments
+/// at the top of a file documents the file itself.
+///
+/// ```
+/// use cmake_parser::{analysis::ReferenceDocs, parse_cmakelists, Doc};
+///
+/// let source = "# Add an application.\nfunction(add_app name)\nendfunction()\n";
+/// let doc = Doc::from(parse_cmakelists(source.as_bytes()).unwrap());
+/// let docs = ReferenceDocs::from_doc(&doc, "helpers.cmake");
+/// let entry = &docs.files()[0].entries[0];
+/// assert_eq!(entry.signature, "add_app(<name>)");
+/// assert_eq!(entry.documentation, "Add an application.");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReferenceDocs {
+    files: Vec<FileDocs>,
+}
+
+/// Documentation of a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDocs {
+    /// File, `None` for [`ReferenceDocs::from_doc`].
+    pub file: Option<FileId>,
+    /// Path relative to the root directory of the project.
+    pub path: String,
+    /// Documentation of the file itself.
+    pub module: Option<String>,
+    /// Definitions in source order.
+    pub entries: Vec<DocEntry>,
+}
+
+/// Documented definition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocEntry {
+    pub name: String,
+    pub kind: DocEntryKind,
+    /// Call syntax of functions and macros, `<name>:<type>=<default>` for
+    /// options and cache variables.
+    pub signature: String,
+    /// Comment above the definition, or the help string of options and
+    /// cache variables without comment.
+    pub documentation: String,
+    /// Index of the command in the file.
+    pub command: usize,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DocEntryKind {
+    Function,
+    Macro,
+    Option,
+    Cache,
+}
+
+impl DocEntryKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Function => "function",
+            Self::Macro => "macro",
+            Self::Option => "option",
+            Self::Cache => "cache",
+        }
+    }
+
+    fn heading(&self) -> &'static str {
+        match self {
+            Self::Function => "Functions",
+            Self::Macro => "Macros",
+            Self::Option => "Options",
+            Self::Cache => "Cache variables",
+        }
+    }
+}
+
+impl ReferenceDocs {
+    /// Documentation of a single document with a path for its title.
+    pub fn from_doc(doc: &Doc, path: &str) -> Self {
+        let signatures = Signatures::from_doc(doc);
+        let mut docs = Self::default();
+        docs.file(None, path.to_string(), doc, &doc.source(), &signatures);
+        docs
+    }
+
+    /// Documentation of the files of a project defining or documenting
+    /// anything.
+    pub fn from_project(project: &Project) -> Self {
+        let signatures = Signatures::from_project(project);
+        let root = project.root().map(|root| root.source_dir.clone());
+        let mut docs = Self::default();
+        for file in project.files() {
+            let Ok(doc) = file.doc() else {
+                continue;
+            };
+            let path = root
+                .as_ref()
+                .and_then(|root| file.path.strip_prefix(root).ok())
+                .unwrap_or(&file.path);
+            let source = String::from_utf8_lossy(file.source());
+            docs.file(Some(file.id), path_value(path), &doc, &source, &signatures);
+        }
+        docs
+    }
+
+    pub fn files(&self) -> &[FileDocs] {
+        &self.files
+    }
+
+    /// Markdown with a section per file and a subsection per definition,
+    /// grouped by kind.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        for file in &self.files {
+            if !markdown.is_empty() {
+                markdown.push('\n');
+            }
+            writeln!(markdown, "# {}", file.path).unwrap();
+            if let Some(module) = &file.module {
+                write!(markdown, "\n{module}\n").unwrap();
+            }
+            for kind in [
+                DocEntryKind::Function,
+                DocEntryKind::Macro,
+                DocEntryKind::Option,
+                DocEntryKind::Cache,
+            ] {
+                let mut entries = file.entries.iter().filter(|entry| entry.kind == kind);
+                let Some(first) = entries.next() else {
+                    continue;
+                };
+                write!(markdown, "\n## {}\n", kind.heading()).unwrap();
+                for entry in std::iter::once(first).chain(entries) {
+                    write!(
+                        markdown,
+                        "\n### `{}`\n\n```cmake\n{}\n```\n",
+                        entry.name, entry.signature
+                    )
+                    .unwrap();
+                    if !entry.documentation.is_empty() {
+                        write!(markdown, "\n{}\n", entry.documentation).unwrap();
+                    }
+                }
+            }
+        }
+        markdown
+    }
+
+    /// JSON representation with a `files` array.
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> String {
+        let files: Vec<_> = self
+            .files
+            .iter()
+            .map(|file| {
+                let entries: Vec<_> = file
+                    .entries
+                    .iter()
+                    .map(|entry| {
+                        serde_json::json!({
+                            "name": entry.name,
+                            "kind": entry.kind.as_str(),
+                            "signature": entry.signature,
+                            "documentation": entry.documentation,
+                            "line": entry.span.start.line,
+                        })
+                    })
+                    .collect();
+                serde_json::json!({
+                    "path": file.path,
+                    "module": file.module,
+                    "entries": entries,
+                })
+            })
+            .collect();
+        serde_json::to_string_pretty(&serde_json::json!({ "files": files })).expect("valid json")
+    }
+
+    fn file(
+        &mut self,
+        file: Option<FileId>,
+        path: String,
+        doc: &Doc,
+        source: &str,
+        signatures: &Signatures,
+    ) {
+        let comments = Comments::new(doc, source);
+        let spans = doc.spans();
+        let mut entries = vec![];
+        let mut depth = 0;
+        for (index, (command, span)) in doc.to_commands_iter().zip(&spans).enumerate() {
+            let Ok(command) = command else {
+                continue;
+            };
+            let entry = match &command {
+                Command::Function(_) | Command::Macro(_) => {
+                    depth += 1;
+                    if depth > 1 {
+                        continue;
+                    }
+                    signatures
+                        .signatures()
+                        .iter()
+                        .find(|signature| signature.file == file && signature.command == index)
+                        .map(|signature| {
+                            let kind = match &command {
+                                Command::Function(_) => DocEntryKind::Function,
+                                _ => DocEntryKind::Macro,
+                            };
+                            (signature.name.clone(), kind, call_syntax(signature), None)
+                        })
+                }
+                Command::EndFunction(_) | Command::EndMacro(_) if depth > 0 => {
+                    depth -= 1;
+                    None
+                }
+                Command::Option(option) if depth == 0 => {
+                    let name = option.variable.to_string();
+                    let value = option
+                        .value
+                        .as_ref()
+                        .map_or("OFF".to_string(), ToString::to_string);
+                    let signature = format!("{name}:BOOL={value}");
+                    let help = option.help_text.to_string();
+                    Some((name, DocEntryKind::Option, signature, Some(help)))
+                }
+                Command::Set(set) if depth == 0 => match set.as_ref() {
+                    Set::Cache(cache) => {
+                        let name = cache.variable.to_string();
+                        let value: Vec<_> = cache.value.iter().map(ToString::to_string).collect();
+                        let signature =
+                            format!("{name}:{}={}", cache_type(&cache.cache), value.join(";"));
+                        let help = cache.docstring.to_string();
+                        Some((name, DocEntryKind::Cache, signature, Some(help)))
+                    }
+                    Set::Normal(_) => None,
+                },
+                _ => None,
+            };
+            if let Some((name, kind, signature, help)) = entry {
+                let documentation = comments.above(span.start.line).or(help).unwrap_or_default();
+                entries.push(DocEntry {
+                    name,
+                    kind,
+                    signature,
+                    documentation,
+                    command: index,
+                    span: *span,
+                });
+            }
+        }
+
+        let first_line = spans.first().map_or(usize::MAX, |span| span.start.line);
+        let module = comments.module(first_line);
+        if module.is_some() || !entries.is_empty() {
+            self.files.push(FileDocs {
+                file,
+                path,
+                module,
+                entries,
+            });
+        }
+    }
+}
+
+/// Comments of a file by line.
+struct Comments {
+    /// Line comments alone on their lines.
+    lines: HashMap<usize, String>,
+    /// Bracket comments by their last line, with their first line.
+    brackets: Vec<(usize, usize, String)>,
+}
+
+impl Comments {
+    fn new(doc: &Doc, source: &str) -> Self {
+        let lines = doc
+            .line_comments()
+            .into_iter()
+            .filter(|(span, _)| {
+                let line_start = source[..span.start.offset]
+                    .rfind('\n')
+                    .map_or(0, |newline| newline + 1);
+                source[line_start..span.start.offset].trim().is_empty()
+            })
+            .map(|(span, comment)| {
+                let comment = comment.strip_prefix(' ').unwrap_or(&comment);
+                (span.start.line, comment.trim_end().to_string())
+            })
+            .collect();
+        let brackets = doc
+            .bracket_comments()
+            .into_iter()
+            .map(|(span, comment)| (span.start.line, span.end.line, bracket_text(&comment)))
+            .collect();
+        Self { lines, brackets }
+    }
+
+    /// Bracket comment ending on the line before, or block of line comments
+    /// directly above a line.
+    fn above(&self, line: usize) -> Option<String> {
+        if let Some((_, _, text)) = self.brackets.iter().find(|(_, end, _)| *end + 1 == line) {
+            return Some(text.clone());
+        }
+        let mut block = vec![];
+        let mut previous = line - 1;
+        while let Some(comment) = self.lines.get(&previous) {
+            block.push(comment.as_str());
+            previous -= 1;
+        }
+        block.reverse();
+        (!block.is_empty()).then(|| block.join("\n").trim().to_string())
+    }
+
+    /// Bracket comment before the first command, or block of line comments
+    /// starting on the first line, which does not document the command.
+    fn module(&self, first_command: usize) -> Option<String> {
+        let documents_command = |end: usize| end + 1 == first_command;
+        if let Some((_, _, text)) = self
+            .brackets
+            .iter()
+            .find(|(_, end, _)| *end < first_command && !documents_command(*end))
+        {
+            return Some(text.clone());
+        }
+        let mut end = 1;
+        while self.lines.contains_key(&end) {
+            end += 1;
+        }
+        (end > 1 && !documents_command(end - 1))
+            .then(|| self.above(end))
+            .flatten()
+    }
+}
+
+/// Bracket comment without the `.rst:` marker and the `#` before the
+/// closing bracket of CMake modules, and the surrounding blank lines.
+fn bracket_text(comment: &str) -> String {
+    let comment = comment
+        .strip_prefix(".rst:")
+        .or_else(|| comment.strip_prefix(".rst"))
+        .unwrap_or(comment)
+        .trim_end();
+    let comment = comment.strip_suffix('#').unwrap_or(comment);
+    comment.trim_matches(['\n', '\r']).trim_end().to_string()
+}
+
+/// Call syntax of a function or macro, like
+/// `add_app(<name> [WIN32] [OUTPUT <value>] [SOURCES <value>...])`.
+fn call_syntax(signature: &Signature) -> String {
+    let mut arguments: Vec<_> = signature
+        .positional
+        .iter()
+        .map(|parameter| format!("<{parameter}>"))
+        .collect();
+    if let Some(keywords) = &signature.keywords {
+        arguments.extend(keywords.options.iter().map(|option| format!("[{option}]")));
+        arguments.extend(
+            keywords
+                .one_value
+                .iter()
+                .map(|keyword| format!("[{keyword} <value>]")),
+        );
+        arguments.extend(
+            keywords
+                .multi_value
+                .iter()
+                .map(|keyword| format!("[{keyword} <value>...]")),
+        );
+        if !keywords.complete {
+            arguments.push("...".to_string());
+        }
+    }
+    format!("{}({})", signature.name, arguments.join(" "))
+}
+
+fn cache_type(cache: &Cache) -> &'static str {
+    match cache {
+        Cache::Bool => "BOOL",
+        Cache::FilePath => "FILEPATH",
+        Cache::Path => "PATH",
+        Cache::String => "STRING",
+        Cache::Internal => "INTERNAL",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::{fixture, ProjectLoader};
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn reference_project() {
+        let project = ProjectLoader::new(fixture("reference")).load();
+        let docs = ReferenceDocs::from_project(&project);
+        let helpers = &docs.files()[1];
+        assert_eq!(
+            helpers.module.as_deref(),
+            Some("Helpers\n-------\n\nFunctions to declare demo targets.")
+        );
+        assert_eq!(
+            helpers
+                .entries
+                .iter()
+                .map(|entry| (
+                    entry.kind,
+                    entry.signature.as_str(),
+                    entry.documentation.as_str()
+                ))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    DocEntryKind::Function,
+                    "demo_add_app(<name> [WIN32] [OUTPUT_NAME <value>] [SOURCES <value>...])",
+                    "Add a demo application.\n\nThe sources are compiled with the demo warnings."
+                ),
+                (
+                    DocEntryKind::Macro,
+                    "demo_warnings(<target>)",
+                    "Apply the demo warnings to a target."
+                ),
+                (DocEntryKind::Function, "demo_undocumented()", ""),
+            ]
+        );
+        assert_eq!(
+            docs.to_markdown(),
+            include_str!("../../../fixture/analysis/reference/reference.md")
+        );
+        let json: serde_json::Value = serde_json::from_str(&docs.to_json()).unwrap();
+        assert_eq!(
+            json["files"][0],
+            serde_json::json!({
+                "path": "CMakeLists.txt",
+                "module": "Reference documentation example.\n\nBuilds the demo application.",
+                "entries": [
+                    {
+                        "name": "DEMO_TESTS",
+                        "kind": "option",
+                        "signature": "DEMO_TESTS:BOOL=ON",
+                        "documentation": "Build the unit tests.",
+                        "line": 12,
+                    },
+                    {
+                        "name": "DEMO_DOCS",
+                        "kind": "option",
+                        "signature": "DEMO_DOCS:BOOL=OFF",
+                        "documentation": "Build the documentation",
+                        "line": 13,
+                    },
+                    {
+                        "name": "DEMO_INSTALL_DIR",
+                        "kind": "cache",
+                        "signature": "DEMO_INSTALL_DIR:PATH=share/demo",
+                        "documentation": "Installation directory",
+                        "line": 14,
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn reference_doc() {
+        let source = "#[[Not a module.]]\nfunction(first)\nendfunction()\n# trailing\nset(A 1) # value\noption(B \"help\")\n";
+        let doc = Doc::from(parse_cmakelists(source.as_bytes()).unwrap());
+        let docs = ReferenceDocs::from_doc(&doc, "CMakeLists.txt");
+        let file = &docs.files()[0];
+        assert_eq!(file.module, None);
+        assert_eq!(
+            file.entries
+                .iter()
+                .map(|entry| (entry.name.as_str(), entry.documentation.as_str()))
+                .collect::<Vec<_>>(),
+            [("first", "Not a module."), ("B", "help")]
+        );
+    }
+}
@@ -0,0 +1,565 @@
+use std::collections::HashMap;
+
+use crate::{
+    analysis::{argument_position, text_offset, FileId, Project, ProjectEdits},
+    command::{
+        project::{
+            add_executable::Executable, add_library::Library, export::TargetsExport,
+            target_link_libraries::Library as LinkItems, target_link_libraries::LinkLibrary,
+            target_precompile_headers::TargetPrecompileHeaders, AddCustomCommand, Export, Install,
+            TargetLinkLibraries,
+        },
+        scripting::{get_property, set_property},
+    },
+    lint::Edit,
+    Command, CommandParseError, Doc, Span, Token,
+};
+
+/// Rename of a target in every command referencing it.
+///
+/// Target names are replaced in the commands creating, configuring,
+/// linking, installing and exporting targets, in `if(TARGET <name>)`
+/// conditions and in generator expressions like `$<TARGET_FILE:<name>>`.
+/// Other arguments containing the name, like variable values and messages,
+/// are reported as ambiguous and left unchanged.
+///
+/// ```
+/// use cmake_parser::{analysis::TargetRename, parse_cmakelists, Doc};
+///
+/// let source = "add_library(core core.c)\ntarget_link_libraries(app PRIVATE core)\n";
+/// let doc = Doc::from(parse_cmakelists(source.as_bytes()).unwrap());
+/// let rename = TargetRename::from_doc(&doc, "core", "engine");
+/// assert_eq!(
+///     rename.edits().apply(None, source),
+///     "add_library(engine core.c)\ntarget_link_libraries(app PRIVATE engine)\n"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TargetRename {
+    references: Vec<TargetReference>,
+    issues: Vec<RenameIssue>,
+    edits: ProjectEdits,
+}
+
+/// Renamed reference to the target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetReference {
+    /// File of the command, `None` for [`TargetRename::from_doc`].
+    pub file: Option<FileId>,
+    /// Index of the command in the file.
+    pub command: usize,
+    /// Span of the name in the source.
+    pub span: Span,
+    pub kind: ReferenceKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ReferenceKind {
+    /// Command creating the target.
+    Definition,
+    /// Target argument of a command.
+    Argument,
+    /// `TARGET` test of a condition.
+    Condition,
+    /// Generator expression.
+    Genex,
+}
+
+/// Occurrence of the name which is not renamed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenameIssue {
+    /// File of the command, `None` for [`TargetRename::from_doc`].
+    pub file: Option<FileId>,
+    /// Index of the command in the file.
+    pub command: usize,
+    /// Span of the argument in the source.
+    pub span: Span,
+    pub kind: RenameIssueKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RenameIssueKind {
+    #[error("argument `{0}` may refer to the target")]
+    Ambiguous(String),
+    #[error("reference in `{0}` contains escape sequences and is not renamed")]
+    Escaped(String),
+    #[error("target `{0}` already exists")]
+    Conflict(String),
+}
+
+impl TargetRename {
+    /// Rename in the commands of a single document.
+    pub fn from_doc(doc: &Doc, old: &str, new: &str) -> Self {
+        let mut rename = Self::default();
+        rename.file(None, doc, &doc.source(), old, new);
+        rename
+    }
+
+    /// Rename in all files of a project.
+    pub fn from_project(project: &Project, old: &str, new: &str) -> Self {
+        let mut rename = Self::default();
+        for file in project.files() {
+            if let Ok(doc) = file.doc() {
+                let source = String::from_utf8_lossy(file.source());
+                rename.file(Some(file.id), &doc, &source, old, new);
+            }
+        }
+        rename
+    }
+
+    pub fn references(&self) -> &[TargetReference] {
+        &self.references
+    }
+
+    pub fn issues(&self) -> &[RenameIssue] {
+        &self.issues
+    }
+
+    /// Edits of the files, of the `None` file for [`TargetRename::from_doc`].
+    pub fn edits(&self) -> &ProjectEdits {
+        &self.edits
+    }
+
+    fn file(&mut self, file: Option<FileId>, doc: &Doc, source: &str, old: &str, new: &str) {
+        let commands = doc
+            .to_commands_iter()
+            .zip(doc.arguments())
+            .zip(doc.argument_spans());
+        for (index, ((command, arguments), spans)) in commands.enumerate() {
+            let targets = target_arguments(&command, &arguments, old);
+            for (argument, (token, span)) in arguments.iter().zip(spans).enumerate() {
+                let text = token.to_string();
+                let argument_source = &source[span.range()];
+                let issue = |kind| RenameIssue {
+                    file,
+                    command: index,
+                    span,
+                    kind,
+                };
+                if let Some(&kind) = targets.get(&argument) {
+                    if kind == ReferenceKind::Definition && text == new {
+                        self.issues
+                            .push(issue(RenameIssueKind::Conflict(new.to_string())));
+                        continue;
+                    }
+                    if text == old {
+                        let quoted = argument_source.starts_with(['"', '[']);
+                        let replacement = Token::text_node(new.as_bytes(), quoted).to_source();
+                        self.reference(file, index, span, kind, replacement);
+                        continue;
+                    }
+                }
+
+                let genexes = genex_references(&text, old);
+                let offset = text_offset(source, span, &text);
+                match offset {
+                    Some(offset) => {
+                        for start in &genexes {
+                            let range = offset + start..offset + start + old.len();
+                            let span = inner_span(source, span, range);
+                            self.reference(file, index, span, ReferenceKind::Genex, new.into());
+                        }
+                    }
+                    None if !genexes.is_empty() => self
+                        .issues
+                        .push(issue(RenameIssueKind::Escaped(text.clone()))),
+                    None => (),
+                }
+                if word_matches(&text, old).any(|start| !genexes.contains(&start)) {
+                    self.issues.push(issue(RenameIssueKind::Ambiguous(text)));
+                }
+            }
+        }
+    }
+
+    fn reference(
+        &mut self,
+        file: Option<FileId>,
+        command: usize,
+        span: Span,
+        kind: ReferenceKind,
+        replacement: String,
+    ) {
+        self.edits.push(file, Edit::new(span.range(), replacement));
+        self.references.push(TargetReference {
+            file,
+            command,
+            span,
+            kind,
+        });
+    }
+}
+
+/// Span of a range inside an argument.
+pub(crate) fn inner_span(source: &str, argument: Span, range: std::ops::Range<usize>) -> Span {
+    let position = |offset: usize| {
+        let mut position = argument.start;
+        for ch in source[argument.start.offset..offset].bytes() {
+            if ch == b'\n' {
+                position.line += 1;
+                position.column = 1;
+            } else {
+                position.column += 1;
+            }
+        }
+        position.offset = offset;
+        position
+    };
+    Span {
+        start: position(range.start),
+        end: position(range.end),
+    }
+}
+
+/// Indices of the arguments naming targets with their kinds.
+fn target_arguments(
+    command: &Result<Command, CommandParseError>,
+    arguments: &[Token],
+    old: &str,
+) -> HashMap<usize, ReferenceKind> {
+    let mut targets = HashMap::new();
+    let Ok(command) = command else {
+        return targets;
+    };
+    if let Command::If(_) | Command::ElseIf(_) | Command::While(_) = command {
+        for (index, pair) in arguments.windows(2).enumerate() {
+            if pair[0].as_bytes() == b"TARGET" && pair[1].as_bytes() == old.as_bytes() {
+                targets.insert(index + 1, ReferenceKind::Condition);
+            }
+        }
+        return targets;
+    }
+
+    let (definitions, references) = target_tokens(command);
+    for (tokens, kind) in [
+        (definitions, ReferenceKind::Definition),
+        (references, ReferenceKind::Argument),
+    ] {
+        for token in tokens {
+            if let Some(index) = argument_position(token, arguments) {
+                targets.insert(index, kind);
+            }
+        }
+    }
+    targets
+}
+
+/// Tokens of a command creating targets and referencing targets.
+fn target_tokens<'a, 't>(command: &'a Command<'t>) -> (Vec<&'a Token<'t>>, Vec<&'a Token<'t>>) {
+    let mut definitions = vec![];
+    let mut references: Vec<&Token> = vec![];
+    let link_items = |libraries: &'a [LinkItems<'t>]| {
+        libraries.iter().flat_map(|library| match library {
+            LinkItems::Interface(items) | LinkItems::Public(items) | LinkItems::Private(items) => {
+                items
+            }
+        })
+    };
+    match command {
+        Command::AddExecutable(add_executable) => {
+            definitions.push(&add_executable.name);
+            if let Executable::Alias(alias) = &add_executable.executable {
+                references.push(&alias.target);
+            }
+        }
+        Command::AddLibrary(add_library) => {
+            definitions.push(&add_library.name);
+            if let Library::Alias(alias) = &add_library.library {
+                references.push(&alias.target);
+            }
+        }
+        Command::AddCustomTarget(add_custom_target) => {
+            definitions.push(&add_custom_target.name);
+            references.extend(add_custom_target.depends.iter().flatten());
+        }
+        Command::AddCustomCommand(add_custom_command) => {
+            if let AddCustomCommand::Target(target) = add_custom_command.as_ref() {
+                references.push(&target.target);
+            }
+        }
+        Command::AddDependencies(add_dependencies) => {
+            references.push(&add_dependencies.target);
+            references.extend(add_dependencies.target_dependencies.iter().flatten());
+        }
+        Command::Export(export) => {
+            if let Export::Targets(targets) = export.as_ref() {
+                references.extend(match targets {
+                    TargetsExport::File(file) => &file.targets,
+                    TargetsExport::AndroidMk(android_mk) => &android_mk.targets,
+                });
+            }
+        }
+        Command::GetProperty(get_property) => {
+            if let get_property::Scope::Target(target) = &get_property.scope {
+                references.push(target);
+            }
+        }
+        Command::GetTargetProperty(get_target_property) => {
+            references.push(&get_target_property.target);
+        }
+        Command::Install(install) => {
+            if let Install::Targets(targets) = install.as_ref() {
+                references.extend(&targets.targets);
+            }
+        }
+        Command::SetProperty(set_property) => match &set_property.scope {
+            set_property::Scope::Target(targets) => references.extend(targets.iter().flatten()),
+            set_property::Scope::Source(source) => {
+                references.extend(source.target_directory.iter().flatten())
+            }
+            _ => (),
+        },
+        Command::SetTargetProperties(set_target_properties) => {
+            references.extend(&set_target_properties.targets);
+        }
+        Command::TargetCompileDefinitions(command) => references.push(&command.target),
+        Command::TargetCompileFeatures(command) => references.push(&command.target),
+        Command::TargetCompileOptions(command) => references.push(&command.target),
+        Command::TargetIncludeDirectories(command) => references.push(&command.target),
+        Command::TargetLinkDirectories(command) => references.push(&command.target),
+        Command::TargetLinkOptions(command) => references.push(&command.target),
+        Command::TargetSources(command) => references.push(&command.target),
+        Command::TargetPrecompileHeaders(command) => match command.as_ref() {
+            TargetPrecompileHeaders::Main(main) => references.push(&main.target),
+            TargetPrecompileHeaders::Reuse(reuse) => {
+                references.push(&reuse.target);
+                references.push(&reuse.reuse_from);
+            }
+        },
+        Command::TargetLinkLibraries(target_link_libraries) => match target_link_libraries.as_ref()
+        {
+            TargetLinkLibraries::TargetAndOrDependents(tll) => {
+                references.push(&tll.target);
+                references.extend(link_items(&tll.libraries));
+            }
+            TargetLinkLibraries::TargetAndOrDependentsLegacy(tll) => {
+                references.push(&tll.target);
+                references.extend(tll.libraries.iter().flat_map(|library| match library {
+                    LinkLibrary::LinkPublic(items) | LinkLibrary::LinkPrivate(items) => items,
+                }));
+            }
+            TargetLinkLibraries::DependentsOnlyLegacy(tll) => {
+                references.push(&tll.target);
+                references.extend(&tll.link_interface_libraries);
+            }
+            TargetLinkLibraries::TargetAndDependents(tll) => {
+                references.push(&tll.target);
+                references.extend(&tll.libraries);
+            }
+        },
+        _ => (),
+    }
+    (definitions, references)
+}
+
+/// Offsets of the name as the target of generator expressions like
+/// `$<TARGET_FILE:name>` and `$<TARGET_PROPERTY:name,prop>`.
+fn genex_references(text: &str, name: &str) -> Vec<usize> {
+    let mut offsets = vec![];
+    let mut rest = 0;
+    while let Some(start) = text[rest..].find("$<") {
+        let start = rest + start + 2;
+        rest = start;
+        let Some(colon) = text[start..].find(':') else {
+            break;
+        };
+        let genex = &text[start..start + colon];
+        let offset = start + colon + 1;
+        let after = text[offset..].strip_prefix(name);
+        let terminated = match genex {
+            "TARGET_POLICY" => false,
+            "TARGET_PROPERTY" => after.is_some_and(|after| after.starts_with(',')),
+            "LINK_ONLY" | "COMPILE_ONLY" => {
+                after.is_some_and(|after| after.starts_with(['>', ',']))
+            }
+            genex if genex.starts_with("TARGET_") => {
+                after.is_some_and(|after| after.starts_with(['>', ',']))
+            }
+            _ => false,
+        };
+        if terminated {
+            offsets.push(offset);
+        }
+    }
+    offsets
+}
+
+/// Offsets of the name delimited by characters which cannot be part of a
+/// target name. A single `:`, like the one ending the condition of a
+/// generator expression, delimits the name, `::` does not.
+fn word_matches<'a>(text: &'a str, name: &'a str) -> impl Iterator<Item = usize> + 'a {
+    let is_name_char = |ch: char| ch.is_ascii_alphanumeric() || "_.+-:".contains(ch);
+    text.match_indices(name).filter_map(move |(start, _)| {
+        let before = text[..start]
+            .chars()
+            .next_back()
+            .filter(|&ch| ch != ':' || text[..start].ends_with("::"));
+        let after = text[start + name.len()..].chars().next();
+        (!before.is_some_and(is_name_char) && !after.is_some_and(is_name_char)).then_some(start)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::{fixture, ProjectLoader};
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn rename_doc() {
+        let source = r#"add_library(core OBJECT core.c)
+add_executable(app main.c $<TARGET_OBJECTS:core>)
+target_link_libraries(app PRIVATE core "core")
+add_dependencies(app core)
+set_property(TARGET core app PROPERTY FOLDER core)
+get_target_property(type core TYPE)
+export(TARGETS core FILE core.cmake)
+target_compile_definitions(app PRIVATE "CORE=$<TARGET_PROPERTY:core,NAME>")
+target_precompile_headers(app REUSE_FROM core)
+set(libs core)
+message("linking core\$<TARGET_FILE:core>")
+add_library(engine INTERFACE)
+"#;
+        let doc = Doc::from(parse_cmakelists(source.as_bytes()).unwrap());
+        let rename = TargetRename::from_doc(&doc, "core", "engine");
+        assert_eq!(
+            rename
+                .references()
+                .iter()
+                .map(|reference| (reference.command, reference.kind))
+                .collect::<Vec<_>>(),
+            [
+                (0, ReferenceKind::Definition),
+                (1, ReferenceKind::Genex),
+                (2, ReferenceKind::Argument),
+                (2, ReferenceKind::Argument),
+                (3, ReferenceKind::Argument),
+                (4, ReferenceKind::Argument),
+                (5, ReferenceKind::Argument),
+                (6, ReferenceKind::Argument),
+                (7, ReferenceKind::Genex),
+                (8, ReferenceKind::Argument),
+            ]
+        );
+        assert_eq!(
+            rename
+                .issues()
+                .iter()
+                .map(|issue| (issue.command, issue.kind.to_string()))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    4,
+                    "argument `core` may refer to the target".to_string()
+                ),
+                (
+                    9,
+                    "argument `core` may refer to the target".to_string()
+                ),
+                (
+                    10,
+                    "reference in `linking core$<TARGET_FILE:core>` contains escape sequences and is not renamed".to_string()
+                ),
+                (
+                    10,
+                    "argument `linking core$<TARGET_FILE:core>` may refer to the target".to_string()
+                ),
+                (11, "target `engine` already exists".to_string()),
+            ]
+        );
+        assert_eq!(
+            rename.edits().apply(None, source),
+            r#"add_library(engine OBJECT core.c)
+add_executable(app main.c $<TARGET_OBJECTS:engine>)
+target_link_libraries(app PRIVATE engine "engine")
+add_dependencies(app engine)
+set_property(TARGET engine app PROPERTY FOLDER core)
+get_target_property(type engine TYPE)
+export(TARGETS engine FILE core.cmake)
+target_compile_definitions(app PRIVATE "CORE=$<TARGET_PROPERTY:engine,NAME>")
+target_precompile_headers(app REUSE_FROM engine)
+set(libs core)
+message("linking core\$<TARGET_FILE:core>")
+add_library(engine INTERFACE)
+"#
+        );
+    }
+
+    #[test]
+    fn rename_link_genex() {
+        let source =
+            "target_link_libraries(app PRIVATE $<LINK_ONLY:core> $<$<CONFIG:Debug>:core>)\n";
+        let doc = Doc::from(parse_cmakelists(source.as_bytes()).unwrap());
+        let rename = TargetRename::from_doc(&doc, "core", "engine");
+        assert_eq!(
+            rename
+                .references()
+                .iter()
+                .map(|reference| (reference.command, reference.kind))
+                .collect::<Vec<_>>(),
+            [(0, ReferenceKind::Genex)]
+        );
+        assert_eq!(
+            rename
+                .issues()
+                .iter()
+                .map(|issue| (issue.command, issue.kind.to_string()))
+                .collect::<Vec<_>>(),
+            [(
+                0,
+                "argument `$<$<CONFIG:Debug>:core>` may refer to the target".to_string()
+            )]
+        );
+        assert_eq!(
+            rename.edits().apply(None, source),
+            "target_link_libraries(app PRIVATE $<LINK_ONLY:engine> $<$<CONFIG:Debug>:core>)\n"
+        );
+    }
+
+    #[test]
+    fn rename_project() {
+        let project = ProjectLoader::new(fixture("rename")).load();
+        let rename = TargetRename::from_project(&project, "core", "engine");
+        assert_eq!(
+            rename
+                .issues()
+                .iter()
+                .map(|issue| (issue.command, issue.kind.clone()))
+                .collect::<Vec<_>>(),
+            [(4, RenameIssueKind::Ambiguous("core".to_string()))]
+        );
+        assert_eq!(
+            rename.edits().project_diff(&project),
+            r#"--- a/CMakeLists.txt
++++ b/CMakeLists.txt
+@@ -1,7 +1,7 @@
+ cmake_minimum_required(VERSION 3.20)
+ project(rename C)
+-add_library(core STATIC core.c)
+-add_library(rename::core ALIAS core)
+-set_target_properties(core PROPERTIES OUTPUT_NAME "core")
+-install(TARGETS core EXPORT rename DESTINATION lib)
++add_library(engine STATIC core.c)
++add_library(rename::core ALIAS engine)
++set_target_properties(engine PROPERTIES OUTPUT_NAME "core")
++install(TARGETS engine EXPORT rename DESTINATION lib)
+ add_subdirectory(app)
+--- a/app/CMakeLists.txt
++++ b/app/CMakeLists.txt
+@@ -1,8 +1,8 @@
+ add_executable(app main.c)
+ target_link_libraries(app PRIVATE
+-  core # the library
++  engine # the library
+ )
+-if(TARGET core)
++if(TARGET engine)
+   add_custom_command(TARGET app POST_BUILD
+-    COMMAND ${CMAKE_COMMAND} -E copy "$<TARGET_FILE:core>" $<TARGET_FILE_DIR:app>)
++    COMMAND ${CMAKE_COMMAND} -E copy "$<TARGET_FILE:engine>" $<TARGET_FILE_DIR:app>)
+ endif()
+"#
+        );
+    }
+}
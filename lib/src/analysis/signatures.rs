@@ -0,0 +1,550 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    analysis::{FileId, Project},
+    command::scripting::{CMakeParseArguments, Set},
+    Command, Doc, Span, Token,
+};
+
+/// Signatures of the functions and macros defined in a document or a
+/// project, with the problems of their calls.
+///
+/// The keywords of a function are inferred from the first
+/// `cmake_parse_arguments()` of its body. Keyword lists given as references
+/// like `"${options}"` are resolved from the literal values of `set()`
+/// commands preceding it in the file.
+///
+/// ```
+/// use cmake_parser::{analysis::Signatures, parse_cmakelists, Doc};
+///
+/// let source = r#"function(add_app name)
+///   cmake_parse_arguments(PARSE_ARGV 1 ARG "WIN32" "OUTPUT" "SOURCES")
+/// endfunction()
+/// add_app(app OUTPT app.exe SOURCES main.c)
+/// "#;
+/// let doc = Doc::from(parse_cmakelists(source.as_bytes()).unwrap());
+/// let signatures = Signatures::from_doc(&doc);
+/// let add_app = signatures.get("add_app").unwrap();
+/// assert_eq!(add_app.positional, ["name"]);
+/// assert_eq!(
+///     signatures.issues()[0].kind.to_string(),
+///     "unknown keyword `OUTPT` in call to `add_app`"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Signatures {
+    signatures: Vec<Signature>,
+    issues: Vec<CallIssue>,
+}
+
+/// Signature of a function or macro.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    pub name: String,
+    pub kind: SignatureKind,
+    /// File of the definition, `None` for [`Signatures::from_doc`].
+    pub file: Option<FileId>,
+    /// Index of the `function()` or `macro()` command in the file.
+    pub command: usize,
+    /// Named parameters.
+    pub positional: Vec<String>,
+    /// Keywords parsed by `cmake_parse_arguments()`, if called.
+    pub keywords: Option<Keywords>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SignatureKind {
+    Function,
+    Macro,
+}
+
+/// Keywords of a `cmake_parse_arguments()` call.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Keywords {
+    /// Index of the `cmake_parse_arguments()` command in the file.
+    pub command: usize,
+    pub prefix: String,
+    pub options: Vec<String>,
+    pub one_value: Vec<String>,
+    pub multi_value: Vec<String>,
+    /// Index of the first argument parsed for keywords.
+    pub start: usize,
+    /// Whether all keyword lists could be resolved.
+    pub complete: bool,
+}
+
+/// Problem of a call to a function or macro.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallIssue {
+    /// File of the call, `None` for [`Signatures::from_doc`].
+    pub file: Option<FileId>,
+    /// Index of the call in the file.
+    pub command: usize,
+    /// Span of the argument, or of the call for missing arguments.
+    pub span: Span,
+    pub kind: CallIssueKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum CallIssueKind {
+    #[error("unknown keyword `{keyword}` in call to `{function}`")]
+    UnknownKeyword { function: String, keyword: String },
+    #[error("missing argument `{argument}` in call to `{function}`")]
+    MissingArgument { function: String, argument: String },
+    #[error("keyword `{keyword}` repeated in call to `{function}`")]
+    RepeatedKeyword { function: String, keyword: String },
+}
+
+impl Keywords {
+    fn contains(&self, keyword: &str) -> bool {
+        [&self.options, &self.one_value, &self.multi_value]
+            .iter()
+            .any(|keywords| keywords.iter().any(|other| other == keyword))
+    }
+}
+
+impl Signatures {
+    /// Signatures and calls of a single document.
+    pub fn from_doc(doc: &Doc) -> Self {
+        let mut signatures = Self::default();
+        signatures.definitions(None, doc);
+        signatures.calls(None, doc);
+        signatures
+    }
+
+    /// Signatures and calls of all files of a project.
+    pub fn from_project(project: &Project) -> Self {
+        let mut signatures = Self::default();
+        let docs: Vec<_> = project
+            .files()
+            .iter()
+            .filter_map(|file| Some((file.id, file.doc().ok()?)))
+            .collect();
+        for (file, doc) in &docs {
+            signatures.definitions(Some(*file), doc);
+        }
+        for (file, doc) in &docs {
+            signatures.calls(Some(*file), doc);
+        }
+        signatures
+    }
+
+    /// Signatures in the order of their definitions.
+    pub fn signatures(&self) -> &[Signature] {
+        &self.signatures
+    }
+
+    /// Last definition of a function or macro, names are case-insensitive.
+    pub fn get(&self, name: &str) -> Option<&Signature> {
+        self.signatures
+            .iter()
+            .rev()
+            .find(|signature| signature.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn issues(&self) -> &[CallIssue] {
+        &self.issues
+    }
+
+    fn definitions(&mut self, file: Option<FileId>, doc: &Doc) {
+        let mut globals = HashMap::new();
+        let mut locals = HashMap::new();
+        let mut current: Option<Signature> = None;
+        let mut depth = 0;
+        for (index, command) in doc.to_commands_iter().enumerate() {
+            let Ok(command) = command else {
+                continue;
+            };
+            let definition = match &command {
+                Command::Function(function) => {
+                    Some((SignatureKind::Function, &function.name, &function.args))
+                }
+                Command::Macro(r#macro) => {
+                    Some((SignatureKind::Macro, &r#macro.name, &r#macro.args))
+                }
+                _ => None,
+            };
+            if let Some((kind, name, args)) = definition {
+                depth += 1;
+                if depth == 1 {
+                    locals.clear();
+                    current = Some(Signature {
+                        name: name.to_string(),
+                        kind,
+                        file,
+                        command: index,
+                        positional: args.iter().flatten().map(Token::to_string).collect(),
+                        keywords: None,
+                    });
+                }
+                continue;
+            }
+            match &command {
+                Command::EndFunction(_) | Command::EndMacro(_) if depth > 0 => {
+                    depth -= 1;
+                    if depth == 0 {
+                        self.signatures.extend(current.take());
+                    }
+                }
+                Command::Set(set) => {
+                    if let Set::Normal(set) = set.as_ref() {
+                        let values = literal_values(&set.value);
+                        let variables = if depth > 0 { &mut locals } else { &mut globals };
+                        match values {
+                            Some(values) => variables.insert(set.variable.to_string(), values),
+                            None => variables.remove(&set.variable.to_string()),
+                        };
+                    }
+                }
+                Command::CMakeParseArguments(cmake_parse_arguments) if depth == 1 => {
+                    let Some(signature) = current
+                        .as_mut()
+                        .filter(|signature| signature.keywords.is_none())
+                    else {
+                        continue;
+                    };
+                    let resolve = |name: &str| locals.get(name).or_else(|| globals.get(name));
+                    signature.keywords = Some(keywords(
+                        index,
+                        cmake_parse_arguments,
+                        signature.positional.len(),
+                        resolve,
+                    ));
+                }
+                _ => (),
+            }
+        }
+    }
+
+    fn calls(&mut self, file: Option<FileId>, doc: &Doc) {
+        let commands = doc
+            .names()
+            .zip(doc.arguments())
+            .zip(doc.argument_spans())
+            .zip(doc.spans());
+        for (index, (((name, arguments), spans), span)) in commands.enumerate() {
+            let Some(signature) = self.get(&name) else {
+                continue;
+            };
+            let issues = call_issues(signature, &arguments);
+            self.issues
+                .extend(issues.into_iter().map(|(argument, kind)| CallIssue {
+                    file,
+                    command: index,
+                    span: argument.map_or(span, |argument| spans[argument]),
+                    kind,
+                }));
+        }
+    }
+}
+
+/// Values of a `set()` without references, split on semicolons.
+fn literal_values(values: &[Token]) -> Option<Vec<String>> {
+    let mut literals = vec![];
+    for value in values {
+        let value = value.to_string();
+        if value.contains('$') {
+            return None;
+        }
+        literals.extend(
+            value
+                .split(';')
+                .filter(|item| !item.is_empty())
+                .map(str::to_string),
+        );
+    }
+    Some(literals)
+}
+
+/// Keywords of a `cmake_parse_arguments()` call in a function with a number
+/// of named parameters.
+fn keywords<'a>(
+    command: usize,
+    cmake_parse_arguments: &CMakeParseArguments,
+    parameters: usize,
+    resolve: impl Fn(&str) -> Option<&'a Vec<String>>,
+) -> Keywords {
+    let (prefix, lists, start) = match cmake_parse_arguments {
+        CMakeParseArguments::Function(function) => (
+            &function.prefix,
+            [
+                &function.options,
+                &function.one_value_keywords,
+                &function.multi_value_keywords,
+            ],
+            function.n.to_string().parse().unwrap_or(parameters),
+        ),
+        CMakeParseArguments::Regular(regular) => {
+            let args: Vec<_> = regular
+                .args
+                .iter()
+                .flatten()
+                .map(Token::to_string)
+                .collect();
+            let start = if args.iter().any(|arg| arg == "${ARGV}") {
+                0
+            } else {
+                parameters
+            };
+            (
+                &regular.prefix,
+                [
+                    &regular.options,
+                    &regular.one_value_keywords,
+                    &regular.multi_value_keywords,
+                ],
+                start,
+            )
+        }
+    };
+    let mut complete = true;
+    let [options, one_value, multi_value] = lists.map(|list| {
+        let mut keywords = vec![];
+        for item in list.to_string().split(';').filter(|item| !item.is_empty()) {
+            let reference = item
+                .strip_prefix("${")
+                .and_then(|item| item.strip_suffix('}'));
+            match reference.map(&resolve) {
+                Some(Some(values)) => keywords.extend(values.iter().cloned()),
+                _ if item.contains('$') => complete = false,
+                _ => keywords.push(item.to_string()),
+            }
+        }
+        keywords
+    });
+    Keywords {
+        command,
+        prefix: prefix.to_string(),
+        options,
+        one_value,
+        multi_value,
+        start,
+        complete,
+    }
+}
+
+/// Problems of a call with the index of the argument, following the
+/// parsing of `cmake_parse_arguments()`.
+///
+/// Unquoted upper case words which are left unparsed are reported as
+/// unknown keywords. Arguments with references may expand to any number
+/// of arguments, so calls having them are not checked for missing
+/// arguments.
+fn call_issues(signature: &Signature, arguments: &[Token]) -> Vec<(Option<usize>, CallIssueKind)> {
+    #[derive(Clone, Copy)]
+    enum State {
+        Unparsed,
+        OneValue(bool),
+        MultiValue,
+    }
+
+    let function = signature.name.clone();
+    let texts: Vec<_> = arguments.iter().map(Token::to_string).collect();
+    let expands = arguments
+        .iter()
+        .zip(&texts)
+        .any(|(argument, text)| !argument.is_quoted() && text.contains("${"));
+    let mut issues = vec![];
+    let keywords = signature.keywords.as_ref();
+    let is_keyword = |text: &str| keywords.is_some_and(|keywords| keywords.contains(text));
+
+    if !expands {
+        let missing = signature
+            .positional
+            .iter()
+            .enumerate()
+            .find(|(index, _)| texts.get(*index).is_none_or(|text| is_keyword(text)));
+        if let Some((index, argument)) = missing {
+            let position = (index < arguments.len()).then_some(index);
+            issues.push((
+                position,
+                CallIssueKind::MissingArgument {
+                    function: function.clone(),
+                    argument: argument.clone(),
+                },
+            ));
+        }
+    }
+
+    let Some(keywords) = keywords else {
+        return issues;
+    };
+    let mut state = State::Unparsed;
+    let mut seen = HashSet::new();
+    for (index, (argument, text)) in arguments
+        .iter()
+        .zip(&texts)
+        .enumerate()
+        .skip(keywords.start)
+    {
+        let is_option = keywords.options.contains(text);
+        let is_one_value = keywords.one_value.contains(text);
+        if is_option || is_one_value || keywords.multi_value.contains(text) {
+            if (is_option || is_one_value) && !seen.insert(text.as_str()) {
+                issues.push((
+                    Some(index),
+                    CallIssueKind::RepeatedKeyword {
+                        function: function.clone(),
+                        keyword: text.clone(),
+                    },
+                ));
+            }
+            state = if is_option {
+                State::Unparsed
+            } else if is_one_value {
+                State::OneValue(false)
+            } else {
+                State::MultiValue
+            };
+            continue;
+        }
+        match state {
+            State::OneValue(false) => state = State::OneValue(true),
+            State::MultiValue => (),
+            State::Unparsed | State::OneValue(true) => {
+                let upper_case = text.len() > 1
+                    && text.starts_with(|ch: char| ch.is_ascii_uppercase())
+                    && text
+                        .bytes()
+                        .all(|ch| ch.is_ascii_uppercase() || ch.is_ascii_digit() || ch == b'_');
+                if keywords.complete && !argument.is_quoted() && upper_case {
+                    issues.push((
+                        Some(index),
+                        CallIssueKind::UnknownKeyword {
+                            function: function.clone(),
+                            keyword: text.clone(),
+                        },
+                    ));
+                }
+            }
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::{fixture, ProjectLoader};
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn signatures_doc() {
+        let source = r#"set(options WIN32 "MACOSX_BUNDLE")
+function(add_app name)
+  set(one OUTPUT DESTINATION)
+  cmake_parse_arguments(PARSE_ARGV 1 ARG "${options}" "${one}" "SOURCES;LIBS")
+endfunction()
+macro(add_lib name type)
+  cmake_parse_arguments(LIB "" "" "SOURCES" ${ARGN})
+endmacro()
+function(configure_app name)
+  cmake_parse_arguments(ARG "${unknown}" "" "" ${ARGN})
+endfunction()
+add_app(app SOURCES main.c LIBS PUBLIC core OUTPUT app.exe OUTPUT app)
+ADD_APP(SOURCES main.c)
+add_app(app WIN32 OUTPT app.exe "QUOTED" DESTINATION bin OPTIONAL)
+add_lib(core)
+add_lib(core STATIC SOURCES core.c ${EXTRA})
+add_lib(${ARGS})
+configure_app(app TYPO)
+"#;
+        let doc = Doc::from(parse_cmakelists(source.as_bytes()).unwrap());
+        let signatures = Signatures::from_doc(&doc);
+        let add_app = signatures.get("add_app").unwrap();
+        assert_eq!(
+            add_app.keywords,
+            Some(Keywords {
+                command: 3,
+                prefix: "ARG".to_string(),
+                options: vec!["WIN32".to_string(), "MACOSX_BUNDLE".to_string()],
+                one_value: vec!["OUTPUT".to_string(), "DESTINATION".to_string()],
+                multi_value: vec!["SOURCES".to_string(), "LIBS".to_string()],
+                start: 1,
+                complete: true,
+            })
+        );
+        let add_lib = signatures.get("add_lib").unwrap();
+        assert_eq!(
+            (add_lib.kind, add_lib.positional.clone()),
+            (
+                SignatureKind::Macro,
+                vec!["name".to_string(), "type".to_string()]
+            )
+        );
+        assert_eq!(add_lib.keywords.as_ref().unwrap().start, 2);
+        assert!(
+            !signatures
+                .get("configure_app")
+                .unwrap()
+                .keywords
+                .as_ref()
+                .unwrap()
+                .complete
+        );
+        assert_eq!(
+            signatures
+                .issues()
+                .iter()
+                .map(|issue| (
+                    issue.command,
+                    &source[issue.span.range()],
+                    issue.kind.to_string()
+                ))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    11,
+                    "OUTPUT",
+                    "keyword `OUTPUT` repeated in call to `add_app`".to_string()
+                ),
+                (
+                    12,
+                    "SOURCES",
+                    "missing argument `name` in call to `add_app`".to_string()
+                ),
+                (
+                    13,
+                    "OUTPT",
+                    "unknown keyword `OUTPT` in call to `add_app`".to_string()
+                ),
+                (
+                    13,
+                    "OPTIONAL",
+                    "unknown keyword `OPTIONAL` in call to `add_app`".to_string()
+                ),
+                (
+                    14,
+                    "add_lib(core)",
+                    "missing argument `type` in call to `add_lib`".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn signatures_project() {
+        let project = ProjectLoader::new(fixture("signatures")).load();
+        let signatures = Signatures::from_project(&project);
+        let helpers = project.file_id(&fixture("signatures").join("cmake/helpers.cmake"));
+        let app = project.file_id(&fixture("signatures").join("app/CMakeLists.txt"));
+        let signature = signatures.get("project_add_app").unwrap();
+        assert_eq!((signature.file, signature.command), (helpers, 0));
+        assert_eq!(
+            signatures
+                .issues()
+                .iter()
+                .map(|issue| (issue.file, issue.command, issue.kind.clone()))
+                .collect::<Vec<_>>(),
+            [(
+                app,
+                1,
+                CallIssueKind::UnknownKeyword {
+                    function: "project_add_app".to_string(),
+                    keyword: "SOURCE".to_string(),
+                }
+            )]
+        );
+    }
+}
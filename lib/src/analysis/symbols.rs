@@ -0,0 +1,1160 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    analysis::{
+        argument_position, rename::inner_span, text_offset, variables::reference_ranges, FileId,
+        Project, VariableInfo,
+    },
+    command::{
+        project::{TryCompile, TryRun},
+        scripting::{
+            cmake_host_system_information::Query,
+            cmake_language::{CMakeLanguageDefer, DeferCallOption},
+            cmake_path::{
+                CMakePathGeneration, CMakePathModification, CMakePathNativeConversion,
+                CMakePathQuery, PathComponent,
+            },
+            file::{
+                DownloadOption, FileArchiving, FileFilesystem, FileLocking, FilePathConversion,
+                FileReading, FileTransfer, UploadOption,
+            },
+            get_directory_property,
+            list::{ListModification, ListOrdering, ListReading, ListSearch},
+            string::{
+                self, StringComparison, StringGeneration, StringManipulation, StringRegex,
+                StringSearchAndReplace,
+            },
+            CMakeLanguage, CMakeParseArguments, CMakePath, CMakePolicy, File, FindFile,
+            FindLibrary, FindPackage, FindPath, FindProgram, ForEach, List, Set,
+        },
+    },
+    doc::bool_constant,
+    Command, Doc, Span, Token,
+};
+
+/// Index of the variables set and read by the commands of a document or
+/// a project.
+///
+/// Writes are recorded for the commands setting variables, like `set()`,
+/// `option()` and the output variables of `list()`, `string()` or
+/// `execute_process()`, for `foreach()` loop variables, function
+/// parameters, `find_package()` results and `cmake_parse_arguments()`
+/// prefixes. Reads are recorded for `${...}` references, variable names of
+/// conditions and variable names passed to commands like `list(LENGTH)`.
+///
+/// ```
+/// use cmake_parser::{analysis::VariableIndex, parse_cmakelists, Doc};
+///
+/// let source = "set(SOURCES main.c)\nadd_executable(app ${SOURCES} ${EXTRA})\n";
+/// let doc = Doc::from(parse_cmakelists(source.as_bytes()).unwrap());
+/// let index = VariableIndex::from_doc(&doc);
+/// assert_eq!(index.definitions_of("SOURCES").count(), 1);
+/// assert_eq!(index.uses_of("SOURCES").count(), 1);
+/// assert_eq!(
+///     index.issues()[0].kind.to_string(),
+///     "variable `EXTRA` is never set"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VariableIndex {
+    definitions: Vec<VariableDefinition>,
+    uses: Vec<VariableUse>,
+    /// Prefixes of the variables set by find modules and by
+    /// `cmake_parse_arguments()` with computed keywords.
+    prefixes: Vec<String>,
+}
+
+/// Command setting a variable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VariableDefinition {
+    pub name: String,
+    /// File of the command, `None` for [`VariableIndex::from_doc`].
+    pub file: Option<FileId>,
+    /// Index of the command in the file.
+    pub command: usize,
+    /// Span of the argument naming the variable.
+    pub span: Span,
+    pub scope: VariableScope,
+    pub kind: DefinitionKind,
+}
+
+/// Command reading a variable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VariableUse {
+    pub name: String,
+    /// File of the command, `None` for [`VariableIndex::from_doc`].
+    pub file: Option<FileId>,
+    /// Index of the command in the file.
+    pub command: usize,
+    /// Span of the name, or of the whole argument when it contains escape
+    /// sequences.
+    pub span: Span,
+    pub scope: VariableScope,
+    pub kind: UseKind,
+}
+
+/// Innermost construct enclosing a command.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VariableScope {
+    Directory,
+    Function(String),
+    Macro(String),
+    Block,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DefinitionKind {
+    /// `set()` of a normal variable.
+    Set,
+    /// `set(CACHE)` entry.
+    Cache,
+    Unset,
+    Option,
+    /// Output variable of a command.
+    Output,
+    /// Command modifying the value in place, like `list(APPEND)`.
+    Update,
+    /// `foreach()` loop variable.
+    ForEach,
+    /// Function or macro parameter.
+    Parameter,
+    /// Result variable of `find_package()`.
+    Package,
+    /// Variable set by `cmake_parse_arguments()`.
+    ParseArguments,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UseKind {
+    /// `${...}` or `$CACHE{...}` reference.
+    Reference,
+    /// Variable name evaluated by a condition.
+    Condition,
+    /// `DEFINED` test of a condition, which does not need the variable to
+    /// be set.
+    Defined,
+    /// Variable name passed to a command, like the list of `list(LENGTH)`.
+    Name,
+}
+
+/// Variable which is set but never read, or read but never set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolIssue {
+    /// File of the command, `None` for [`VariableIndex::from_doc`].
+    pub file: Option<FileId>,
+    /// Index of the command in the file.
+    pub command: usize,
+    pub span: Span,
+    pub kind: SymbolIssueKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SymbolIssueKind {
+    #[error("variable `{0}` is never used")]
+    NeverUsed(String),
+    #[error("variable `{0}` is never set")]
+    NeverSet(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Access {
+    Definition(DefinitionKind),
+    Use(UseKind),
+}
+
+const OUTPUT: Access = Access::Definition(DefinitionKind::Output);
+const UPDATE: Access = Access::Definition(DefinitionKind::Update);
+const NAME: Access = Access::Use(UseKind::Name);
+
+impl VariableIndex {
+    /// Index of the commands of a single document.
+    pub fn from_doc(doc: &Doc) -> Self {
+        let mut index = Self::default();
+        index.file(None, doc, &doc.source());
+        index
+    }
+
+    /// Index of all files of a project.
+    pub fn from_project(project: &Project) -> Self {
+        let mut index = Self::default();
+        for file in project.files() {
+            if let Ok(doc) = file.doc() {
+                let source = String::from_utf8_lossy(file.source());
+                index.file(Some(file.id), &doc, &source);
+            }
+        }
+        index
+    }
+
+    /// Definitions in the order of the files and commands.
+    pub fn definitions(&self) -> &[VariableDefinition] {
+        &self.definitions
+    }
+
+    /// Uses in the order of the files and commands.
+    pub fn uses(&self) -> &[VariableUse] {
+        &self.uses
+    }
+
+    pub fn definitions_of<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a VariableDefinition> + 'a {
+        self.definitions
+            .iter()
+            .filter(move |definition| definition.name == name)
+    }
+
+    pub fn uses_of<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a VariableUse> + 'a {
+        self.uses.iter().filter(move |usage| usage.name == name)
+    }
+
+    /// Name of the variable defined or used at an offset of a file.
+    pub fn name_at(&self, file: Option<FileId>, offset: usize) -> Option<&str> {
+        let definitions = self
+            .definitions
+            .iter()
+            .map(|definition| (&definition.name, definition.file, definition.span));
+        let uses = self
+            .uses
+            .iter()
+            .map(|usage| (&usage.name, usage.file, usage.span));
+        definitions
+            .chain(uses)
+            .find(|(_, other, span)| *other == file && span.range().contains(&offset))
+            .map(|(name, _, _)| name.as_str())
+    }
+
+    /// Variables never read and variables read but never set, reported at
+    /// their first occurrence.
+    ///
+    /// Variables documented by CMake, cache entries, options, package
+    /// results and the arguments of functions and macros are not reported.
+    pub fn issues(&self) -> Vec<SymbolIssue> {
+        let defined: HashSet<&str> = self
+            .definitions
+            .iter()
+            .map(|definition| definition.name.as_str())
+            .collect();
+        let used: HashSet<&str> = self.uses.iter().map(|usage| usage.name.as_str()).collect();
+        let known = |name: &str| VariableInfo::find(name).is_some();
+
+        let mut issues = vec![];
+        let mut reported = HashSet::new();
+        for definition in &self.definitions {
+            let name = definition.name.as_str();
+            if matches!(
+                definition.kind,
+                DefinitionKind::Cache
+                    | DefinitionKind::Unset
+                    | DefinitionKind::Package
+                    | DefinitionKind::ParseArguments
+            ) || used.contains(name)
+                || known(name)
+                || !reported.insert(name)
+            {
+                continue;
+            }
+            issues.push(SymbolIssue {
+                file: definition.file,
+                command: definition.command,
+                span: definition.span,
+                kind: SymbolIssueKind::NeverUsed(definition.name.clone()),
+            });
+        }
+        for usage in &self.uses {
+            let name = usage.name.as_str();
+            let argument = matches!(
+                usage.scope,
+                VariableScope::Function(_) | VariableScope::Macro(_)
+            ) && (["ARGC", "ARGN", "ARGV"].contains(&name)
+                || name
+                    .strip_prefix("ARGV")
+                    .is_some_and(|rest| rest.bytes().all(|ch| ch.is_ascii_digit())));
+            if usage.kind == UseKind::Defined
+                || defined.contains(name)
+                || known(name)
+                || argument
+                || self.prefixes.iter().any(|prefix| name.starts_with(prefix))
+                || !reported.insert(name)
+            {
+                continue;
+            }
+            issues.push(SymbolIssue {
+                file: usage.file,
+                command: usage.command,
+                span: usage.span,
+                kind: SymbolIssueKind::NeverSet(usage.name.clone()),
+            });
+        }
+        issues
+    }
+
+    fn file(&mut self, file: Option<FileId>, doc: &Doc, source: &str) {
+        let mut scopes = vec![];
+        let commands = doc
+            .to_commands_iter()
+            .zip(doc.arguments())
+            .zip(doc.argument_spans());
+        for (index, ((command, arguments), spans)) in commands.enumerate() {
+            let command = command.ok();
+            let opened = match &command {
+                Some(Command::Function(function)) => {
+                    Some(VariableScope::Function(function.name.to_string()))
+                }
+                Some(Command::Macro(r#macro)) => {
+                    Some(VariableScope::Macro(r#macro.name.to_string()))
+                }
+                Some(Command::Block(_)) => Some(VariableScope::Block),
+                _ => None,
+            };
+            if let Some(opened) = opened {
+                scopes.push(opened);
+            }
+            let scope = scopes.last().cloned().unwrap_or(VariableScope::Directory);
+            if let Some(Command::EndFunction(_) | Command::EndMacro(_) | Command::EndBlock(_)) =
+                &command
+            {
+                scopes.pop();
+            }
+
+            let mut accesses = match &command {
+                Some(command) => argument_accesses(command, &arguments),
+                None => HashMap::new(),
+            };
+            if let Some(Command::If(_) | Command::ElseIf(_) | Command::While(_)) = &command {
+                accesses.extend(condition_accesses(&arguments, &spans, source));
+            }
+            for (argument, (token, &span)) in arguments.iter().zip(&spans).enumerate() {
+                let text = token.to_string();
+                let argument_source = &source[span.range()];
+                if let Some(&access) = accesses.get(&argument) {
+                    if !text.contains('$') {
+                        self.access(file, index, &text, span, &scope, access);
+                    }
+                }
+                if argument_source.starts_with('[') {
+                    continue;
+                }
+                let offset = text_offset(source, span, &text);
+                for range in reference_ranges(&text) {
+                    let name_span = match offset {
+                        Some(offset) => {
+                            inner_span(source, span, offset + range.start..offset + range.end)
+                        }
+                        None => span,
+                    };
+                    let access = Access::Use(UseKind::Reference);
+                    self.access(file, index, &text[range], name_span, &scope, access);
+                }
+            }
+
+            let derived = match &command {
+                Some(Command::FindPackage(find_package)) => {
+                    let name = match find_package.as_ref() {
+                        FindPackage::Full(full) => &full.package_name,
+                        FindPackage::Basic(basic) => &basic.package_name,
+                    };
+                    self.package(name)
+                }
+                Some(Command::CMakeParseArguments(cmake_parse_arguments)) => {
+                    self.parse_arguments(cmake_parse_arguments)
+                }
+                _ => vec![],
+            };
+            for (token, names, kind) in derived {
+                let Some(span) = argument_position(token, &arguments).map(|index| spans[index])
+                else {
+                    continue;
+                };
+                for name in names {
+                    let access = Access::Definition(kind);
+                    self.access(file, index, &name, span, &scope, access);
+                }
+            }
+        }
+    }
+
+    fn access(
+        &mut self,
+        file: Option<FileId>,
+        command: usize,
+        name: &str,
+        span: Span,
+        scope: &VariableScope,
+        access: Access,
+    ) {
+        match access {
+            Access::Definition(kind) => self.definitions.push(VariableDefinition {
+                name: name.to_string(),
+                file,
+                command,
+                span,
+                scope: scope.clone(),
+                kind,
+            }),
+            Access::Use(kind) => self.uses.push(VariableUse {
+                name: name.to_string(),
+                file,
+                command,
+                span,
+                scope: scope.clone(),
+                kind,
+            }),
+        }
+    }
+
+    /// Result variables of `find_package(<name>)`, other variables set by
+    /// find modules are recognized by their prefix.
+    fn package<'a, 't>(&mut self, token: &'a Token<'t>) -> Vec<Derived<'a, 't>> {
+        let name = token.to_string();
+        if name.contains('$') {
+            return vec![];
+        }
+        for prefix in [format!("{name}_"), format!("{}_", name.to_uppercase())] {
+            if !self.prefixes.contains(&prefix) {
+                self.prefixes.push(prefix);
+            }
+        }
+        let names = ["FOUND", "VERSION"]
+            .iter()
+            .map(|suffix| format!("{name}_{suffix}"))
+            .collect();
+        vec![(token, names, DefinitionKind::Package)]
+    }
+
+    /// Variables `<prefix>_<keyword>` set by `cmake_parse_arguments()`.
+    fn parse_arguments<'a, 't>(
+        &mut self,
+        command: &'a CMakeParseArguments<'t>,
+    ) -> Vec<Derived<'a, 't>> {
+        let (prefix, keywords) = match command {
+            CMakeParseArguments::Regular(regular) => (
+                &regular.prefix,
+                [
+                    &regular.options,
+                    &regular.one_value_keywords,
+                    &regular.multi_value_keywords,
+                ],
+            ),
+            CMakeParseArguments::Function(function) => (
+                &function.prefix,
+                [
+                    &function.options,
+                    &function.one_value_keywords,
+                    &function.multi_value_keywords,
+                ],
+            ),
+        };
+        let prefix_name = prefix.to_string();
+        if prefix_name.contains('$') {
+            return vec![];
+        }
+        let mut names: Vec<_> = ["UNPARSED_ARGUMENTS", "KEYWORDS_MISSING_VALUES"]
+            .iter()
+            .map(|keyword| format!("{prefix_name}_{keyword}"))
+            .collect();
+        for keywords in keywords {
+            let keywords = keywords.to_string();
+            if keywords.contains('$') {
+                let computed = format!("{prefix_name}_");
+                if !self.prefixes.contains(&computed) {
+                    self.prefixes.push(computed);
+                }
+                continue;
+            }
+            names.extend(
+                keywords
+                    .split(';')
+                    .filter(|keyword| !keyword.is_empty())
+                    .map(|keyword| format!("{prefix_name}_{keyword}")),
+            );
+        }
+        vec![(prefix, names, DefinitionKind::ParseArguments)]
+    }
+}
+
+/// Token naming the variables derived from it.
+type Derived<'a, 't> = (&'a Token<'t>, Vec<String>, DefinitionKind);
+
+/// Indices of the arguments naming variables with their accesses.
+fn argument_accesses(command: &Command, arguments: &[Token]) -> HashMap<usize, Access> {
+    variable_tokens(command)
+        .into_iter()
+        .filter_map(|(token, access)| Some((argument_position(token, arguments)?, access)))
+        .collect()
+}
+
+/// Indices of the arguments of a condition evaluated as variable names.
+///
+/// Operands of unary tests on paths, targets, commands, policies and tests
+/// are not variables, nor are the right operands of comparisons which are
+/// most often literals.
+fn condition_accesses(arguments: &[Token], spans: &[Span], source: &str) -> Vec<(usize, Access)> {
+    const OPERATORS: &[&str] = &[
+        "NOT",
+        "AND",
+        "OR",
+        "(",
+        ")",
+        "DEFINED",
+        "EQUAL",
+        "LESS",
+        "LESS_EQUAL",
+        "GREATER",
+        "GREATER_EQUAL",
+        "STREQUAL",
+        "STRLESS",
+        "STRLESS_EQUAL",
+        "STRGREATER",
+        "STRGREATER_EQUAL",
+        "VERSION_EQUAL",
+        "VERSION_LESS",
+        "VERSION_LESS_EQUAL",
+        "VERSION_GREATER",
+        "VERSION_GREATER_EQUAL",
+        "PATH_EQUAL",
+        "MATCHES",
+        "IN_LIST",
+        "IS_NEWER_THAN",
+    ];
+    const UNARY: &[&str] = &[
+        "EXISTS",
+        "COMMAND",
+        "POLICY",
+        "TARGET",
+        "TEST",
+        "IS_DIRECTORY",
+        "IS_SYMLINK",
+        "IS_ABSOLUTE",
+        "IS_READABLE",
+        "IS_WRITABLE",
+        "IS_EXECUTABLE",
+    ];
+    let is_name = |text: &str| {
+        !text.is_empty()
+            && text
+                .bytes()
+                .all(|ch| ch.is_ascii_alphanumeric() || b"/_.+-".contains(&ch))
+    };
+    let unquoted: Vec<Option<String>> = arguments
+        .iter()
+        .zip(spans)
+        .map(|(token, span)| {
+            (!source[span.range()].starts_with(['"', '['])).then(|| token.to_string())
+        })
+        .collect();
+
+    let mut accesses = vec![];
+    for (index, text) in unquoted.iter().enumerate() {
+        let Some(text) = text else {
+            continue;
+        };
+        let previous = index
+            .checked_sub(1)
+            .and_then(|previous| unquoted[previous].as_deref());
+        let next = unquoted.get(index + 1).and_then(Option::as_deref);
+        if OPERATORS.contains(&text.as_str()) || UNARY.contains(&text.as_str()) {
+            continue;
+        }
+        let access = match previous {
+            Some("DEFINED") => Access::Use(UseKind::Defined),
+            Some("IN_LIST") => Access::Use(UseKind::Condition),
+            Some(previous) if UNARY.contains(&previous) => continue,
+            Some(previous)
+                if OPERATORS.contains(&previous)
+                    && !["NOT", "AND", "OR", "("].contains(&previous) =>
+            {
+                continue
+            }
+            _ if next == Some("IS_NEWER_THAN") => continue,
+            _ => Access::Use(UseKind::Condition),
+        };
+        if is_name(text) && bool_constant(text).is_none() {
+            accesses.push((index, access));
+        }
+    }
+    accesses
+}
+
+/// Typed tokens of a command naming variables it sets or reads.
+fn variable_tokens<'a, 't>(command: &'a Command<'t>) -> Vec<(&'a Token<'t>, Access)> {
+    let mut tokens = vec![];
+    let mut push = |variables: &mut dyn Iterator<Item = &'a Token<'t>>, access: Access| {
+        tokens.extend(variables.map(|token| (token, access)))
+    };
+    macro_rules! push {
+        ($variables:expr, $access:expr) => {
+            push(&mut $variables.into_iter(), $access)
+        };
+    }
+    match command {
+        Command::Set(set) => match set.as_ref() {
+            Set::Normal(set) => push!([&set.variable], Access::Definition(DefinitionKind::Set)),
+            Set::Cache(set) => push!([&set.variable], Access::Definition(DefinitionKind::Cache)),
+        },
+        Command::Unset(unset) => {
+            push!([&unset.variable], Access::Definition(DefinitionKind::Unset))
+        }
+        Command::Option(option) => {
+            push!(
+                [&option.variable],
+                Access::Definition(DefinitionKind::Option)
+            )
+        }
+        Command::Function(function) => push!(
+            function.args.iter().flatten(),
+            Access::Definition(DefinitionKind::Parameter)
+        ),
+        Command::Macro(r#macro) => push!(
+            r#macro.args.iter().flatten(),
+            Access::Definition(DefinitionKind::Parameter)
+        ),
+        Command::ForEach(foreach) => {
+            let loop_var = Access::Definition(DefinitionKind::ForEach);
+            match foreach.as_ref() {
+                ForEach::RangeStop(range) => push!([&range.loop_var], loop_var),
+                ForEach::RangeStartStop(range) => push!([&range.loop_var], loop_var),
+                ForEach::In(r#in) => {
+                    push!([&r#in.loop_var], loop_var);
+                    push!(r#in.lists.iter().flatten(), NAME);
+                }
+                ForEach::InZipLists(zip) => {
+                    push!(&zip.loop_var, loop_var);
+                    push!(&zip.zip_lists.lists, NAME);
+                }
+            }
+        }
+        Command::List(list) => match list.as_ref() {
+            List::Reading(reading) => match reading {
+                ListReading::Length(length) => {
+                    push!([&length.list], NAME);
+                    push!([&length.out_var], OUTPUT);
+                }
+                ListReading::Get(get) => {
+                    push!([&get.list], NAME);
+                    push!([&get.out_var], OUTPUT);
+                }
+                ListReading::Join(join) => {
+                    push!([&join.list], NAME);
+                    push!([&join.out_var], OUTPUT);
+                }
+            },
+            List::Search(ListSearch::Find(find)) => {
+                push!([&find.list], NAME);
+                push!([&find.out_var], OUTPUT);
+            }
+            List::Modification(modification) => match modification {
+                ListModification::Append(append) => push!([&append.list], UPDATE),
+                ListModification::Filter(filter) => push!([&filter.list], UPDATE),
+                ListModification::Insert(insert) => push!([&insert.list], UPDATE),
+                ListModification::PopBack(pop) => {
+                    push!([&pop.list], UPDATE);
+                    push!(pop.out_var.iter().flatten(), OUTPUT);
+                }
+                ListModification::PopFront(pop) => {
+                    push!([&pop.list], UPDATE);
+                    push!(pop.out_var.iter().flatten(), OUTPUT);
+                }
+                ListModification::Prepend(prepend) => push!([&prepend.list], UPDATE),
+                ListModification::RemoveItem(remove) => push!([&remove.list], UPDATE),
+                ListModification::RemoveAt(remove) => push!([&remove.list], UPDATE),
+                ListModification::RemoveDuplicates(remove) => push!([&remove.list], UPDATE),
+                ListModification::Transform(transform) => match &transform.output_variable {
+                    Some(output_variable) => {
+                        push!([&transform.list], NAME);
+                        push!([output_variable], OUTPUT);
+                    }
+                    None => push!([&transform.list], UPDATE),
+                },
+            },
+            List::Ordering(ordering) => match ordering {
+                ListOrdering::Reverse(reverse) => push!([&reverse.list], UPDATE),
+                ListOrdering::Sort(sort) => push!([&sort.list], UPDATE),
+            },
+        },
+        Command::String(command) => match command.as_ref() {
+            string::String::SearchAndReplace(search) => match search {
+                StringSearchAndReplace::Find(find) => push!([&find.output_variable], OUTPUT),
+                StringSearchAndReplace::Replace(replace) => {
+                    push!([&replace.output_variable], OUTPUT)
+                }
+                StringSearchAndReplace::Regex(regex) => match regex {
+                    StringRegex::Match(regex) => push!([&regex.output_variable], OUTPUT),
+                    StringRegex::MatchAll(regex) => push!([&regex.output_variable], OUTPUT),
+                    StringRegex::Replace(regex) => push!([&regex.output_variable], OUTPUT),
+                },
+            },
+            string::String::Manipulation(manipulation) => match manipulation {
+                StringManipulation::Append(append) => push!([&append.string_variable], UPDATE),
+                StringManipulation::Prepend(prepend) => {
+                    push!([&prepend.string_variable], UPDATE)
+                }
+                StringManipulation::Concat(concat) => push!([&concat.output_variable], OUTPUT),
+                StringManipulation::Join(join) => push!([&join.output_variable], OUTPUT),
+                StringManipulation::ToLower(lower) => push!([&lower.output_variable], OUTPUT),
+                StringManipulation::ToUpper(upper) => push!([&upper.output_variable], OUTPUT),
+                StringManipulation::Length(length) => push!([&length.output_variable], OUTPUT),
+                StringManipulation::Substring(substring) => {
+                    push!([&substring.output_variable], OUTPUT)
+                }
+                StringManipulation::Strip(strip) => push!([&strip.output_variable], OUTPUT),
+                StringManipulation::GenexStrip(strip) => push!([&strip.output_variable], OUTPUT),
+                StringManipulation::Repeat(repeat) => push!([&repeat.output_variable], OUTPUT),
+            },
+            string::String::Comparison(comparison) => match comparison {
+                StringComparison::Less(compare) => push!([&compare.output_variable], OUTPUT),
+                StringComparison::Greater(compare) => push!([&compare.output_variable], OUTPUT),
+                StringComparison::Equal(compare) => push!([&compare.output_variable], OUTPUT),
+                StringComparison::NotEqual(compare) => push!([&compare.output_variable], OUTPUT),
+                StringComparison::LessEqual(compare) => {
+                    push!([&compare.output_variable], OUTPUT)
+                }
+                StringComparison::GreaterEqual(compare) => {
+                    push!([&compare.output_variable], OUTPUT)
+                }
+            },
+            string::String::Hash(hash) => push!([&hash.output_variable], OUTPUT),
+            string::String::Generation(generation) => match generation {
+                StringGeneration::Ascii(ascii) => push!([&ascii.output_variable], OUTPUT),
+                StringGeneration::Hex(hex) => push!([&hex.output_variable], OUTPUT),
+                StringGeneration::Configure(configure) => {
+                    push!([&configure.output_variable], OUTPUT)
+                }
+                StringGeneration::MakeCIdentifier(identifier) => {
+                    push!([&identifier.output_variable], OUTPUT)
+                }
+                StringGeneration::Random(random) => push!([&random.output_variable], OUTPUT),
+                StringGeneration::Timestamp(timestamp) => {
+                    push!([&timestamp.output_variable], OUTPUT)
+                }
+                StringGeneration::Uuid(uuid) => push!([&uuid.output_variable], OUTPUT),
+            },
+            string::String::Json(json) => {
+                push!([&json.output_variable], OUTPUT);
+                push!(&json.error_variable, OUTPUT);
+            }
+        },
+        Command::Math(math) => push!([&math.variable], OUTPUT),
+        Command::CMakePath(cmake_path) => match cmake_path.as_ref() {
+            CMakePath::Decomposition(decomposition) => {
+                push!([&decomposition.path_var], NAME);
+                match &decomposition.component {
+                    PathComponent::RootName(out_var)
+                    | PathComponent::RootDirectory(out_var)
+                    | PathComponent::RootPath(out_var)
+                    | PathComponent::Filename(out_var)
+                    | PathComponent::RelativePart(out_var)
+                    | PathComponent::ParentPath(out_var) => push!([out_var], OUTPUT),
+                    PathComponent::Extension(extension) => push!([&extension.out_var], OUTPUT),
+                    PathComponent::Stem(stem) => push!([&stem.out_var], OUTPUT),
+                }
+            }
+            CMakePath::Query(query) => match query {
+                CMakePathQuery::HasRootName(query)
+                | CMakePathQuery::HasRootDirectory(query)
+                | CMakePathQuery::HasRootPath(query)
+                | CMakePathQuery::HasFilename(query)
+                | CMakePathQuery::HasExtension(query)
+                | CMakePathQuery::HasStem(query)
+                | CMakePathQuery::HasRelativePart(query)
+                | CMakePathQuery::HasParentPath(query)
+                | CMakePathQuery::IsAbsolute(query)
+                | CMakePathQuery::IsRelative(query) => {
+                    push!([&query.path_var], NAME);
+                    push!([&query.out_var], OUTPUT);
+                }
+                CMakePathQuery::IsPrefix(query) => {
+                    push!([&query.path_var], NAME);
+                    push!([&query.out_var], OUTPUT);
+                }
+                CMakePathQuery::Compare(query) => push!([&query.out_var], OUTPUT),
+            },
+            CMakePath::Modification(modification) => {
+                let (path_var, out_var) = match modification {
+                    CMakePathModification::Set(set) => (&set.path_var, &None),
+                    CMakePathModification::Append(append) => (&append.path_var, &append.out_var),
+                    CMakePathModification::AppendString(append) => {
+                        (&append.path_var, &append.out_var)
+                    }
+                    CMakePathModification::RemoveFilename(remove) => {
+                        (&remove.path_var, &remove.out_var)
+                    }
+                    CMakePathModification::ReplaceFilename(replace) => {
+                        (&replace.path_var, &replace.out_var)
+                    }
+                    CMakePathModification::RemoveExtension(remove) => {
+                        (&remove.path_var, &remove.out_var)
+                    }
+                    CMakePathModification::ReplaceExtension(replace) => {
+                        (&replace.path_var, &replace.out_var)
+                    }
+                };
+                path_or_output(&mut push, path_var, out_var);
+            }
+            CMakePath::Generation(generation) => {
+                let (path_var, out_var) = match generation {
+                    CMakePathGeneration::Normal(normal) => (&normal.path_var, &normal.out_var),
+                    CMakePathGeneration::Relative(relative) => {
+                        (&relative.path_var, &relative.out_var)
+                    }
+                    CMakePathGeneration::Absolute(absolute) => {
+                        (&absolute.path_var, &absolute.out_var)
+                    }
+                };
+                path_or_output(&mut push, path_var, out_var);
+            }
+            CMakePath::NativeConversion(conversion) => match conversion {
+                CMakePathNativeConversion::NativePath(native) => {
+                    path_or_output(&mut push, &native.path_var, &native.out_var)
+                }
+                CMakePathNativeConversion::Convert(convert) => {
+                    push!([&convert.out_var], OUTPUT)
+                }
+            },
+            CMakePath::Hashing(hashing) => {
+                push!([&hashing.path_var], NAME);
+                push!([&hashing.out_var], OUTPUT);
+            }
+        },
+        Command::File(file) => match file.as_ref() {
+            File::Reading(reading) => match reading {
+                FileReading::Read(read) => push!([&read.variable], OUTPUT),
+                FileReading::Strings(strings) => push!([&strings.variable], OUTPUT),
+                FileReading::Hash(hash) => push!([&hash.variable], OUTPUT),
+                FileReading::Timestamp(timestamp) => push!([&timestamp.variable], OUTPUT),
+                FileReading::GetRuntimeDependencies(dependencies) => {
+                    push!(&dependencies.resolved_dependencies_var, OUTPUT);
+                    push!(&dependencies.unresolved_dependencies_var, OUTPUT);
+                }
+            },
+            File::Filesystem(filesystem) => match filesystem {
+                FileFilesystem::Glob(glob) => push!([&glob.variable], OUTPUT),
+                FileFilesystem::GlobRecurse(glob) => push!([&glob.variable], OUTPUT),
+                FileFilesystem::Rename(rename) => push!(&rename.result, OUTPUT),
+                FileFilesystem::CopyFile(copy) => push!(&copy.result, OUTPUT),
+                FileFilesystem::Size(size) => push!([&size.variable], OUTPUT),
+                FileFilesystem::ReadSymlink(read) => push!([&read.variable], OUTPUT),
+                FileFilesystem::CreateLink(link) => push!(&link.result, OUTPUT),
+                _ => (),
+            },
+            File::PathConversion(conversion) => match conversion {
+                FilePathConversion::RealPath(real_path) => push!([&real_path.out_var], OUTPUT),
+                FilePathConversion::RelativePath(relative) => {
+                    push!([&relative.variable], OUTPUT)
+                }
+                FilePathConversion::ToCMakePath(path) => push!([&path.variable], OUTPUT),
+                FilePathConversion::ToNativePath(path) => push!([&path.variable], OUTPUT),
+            },
+            File::Transfer(transfer) => match transfer {
+                FileTransfer::Download(download) => push!(
+                    download
+                        .options
+                        .iter()
+                        .flatten()
+                        .filter_map(|option| match option {
+                            DownloadOption::Log(variable) | DownloadOption::Status(variable) => {
+                                Some(variable)
+                            }
+                            _ => None,
+                        }),
+                    OUTPUT
+                ),
+                FileTransfer::Upload(upload) => push!(
+                    upload
+                        .options
+                        .iter()
+                        .flatten()
+                        .filter_map(|option| match option {
+                            UploadOption::Log(variable) | UploadOption::Status(variable) => {
+                                Some(variable)
+                            }
+                            _ => None,
+                        }),
+                    OUTPUT
+                ),
+            },
+            File::Locking(FileLocking::Lock(lock)) => push!(&lock.result_variable, OUTPUT),
+            File::Writing(_)
+            | File::Archiving(FileArchiving::ArchiveCreate(_))
+            | File::Archiving(FileArchiving::ArchiveExtract(_)) => (),
+        },
+        Command::ExecuteProcess(execute_process) => {
+            push!(&execute_process.result_variable, OUTPUT);
+            push!(&execute_process.results_variable, OUTPUT);
+            push!(&execute_process.output_variable, OUTPUT);
+            push!(&execute_process.error_variable, OUTPUT);
+        }
+        Command::FindFile(find) => match find.as_ref() {
+            FindFile::General(general) => push!([&general.variable], OUTPUT),
+            FindFile::Short(short) => push!([&short.variable], OUTPUT),
+        },
+        Command::FindLibrary(find) => match find.as_ref() {
+            FindLibrary::General(general) => push!([&general.variable], OUTPUT),
+            FindLibrary::Short(short) => push!([&short.variable], OUTPUT),
+        },
+        Command::FindPath(find) => match find.as_ref() {
+            FindPath::General(general) => push!([&general.variable], OUTPUT),
+            FindPath::Short(short) => push!([&short.variable], OUTPUT),
+        },
+        Command::FindProgram(find) => match find.as_ref() {
+            FindProgram::General(general) => push!([&general.variable], OUTPUT),
+            FindProgram::Short(short) => push!([&short.variable], OUTPUT),
+        },
+        Command::GetCMakeProperty(property) => push!([&property.var], OUTPUT),
+        Command::GetDirectoryProperty(property) => {
+            push!([&property.name], OUTPUT);
+            if let get_directory_property::Scope::Definition(definition) = &property.scope {
+                push!([definition], NAME);
+            }
+        }
+        Command::GetFilenameComponent(component) => push!([&component.variable], OUTPUT),
+        Command::GetProperty(property) => push!([&property.variable], OUTPUT),
+        Command::GetSourceFileProperty(property) => push!([&property.variable], OUTPUT),
+        Command::GetTargetProperty(property) => push!([&property.var], OUTPUT),
+        Command::GetTestProperty(property) => push!([&property.var], OUTPUT),
+        Command::AuxSourceDirectory(aux) => push!([&aux.variable], OUTPUT),
+        Command::SeparateArguments(separate) => push!([&separate.variable], UPDATE),
+        Command::SiteName(site_name) => push!([&site_name.variable], OUTPUT),
+        Command::Include(include) => push!(&include.result_variable, OUTPUT),
+        Command::CMakeHostSystemInformation(information) => {
+            push!([&information.result], OUTPUT);
+            if let Query::WindowsRegistry(query) = &information.query {
+                push!(&query.error_variable, OUTPUT);
+            }
+        }
+        Command::CMakePolicy(policy) => {
+            if let CMakePolicy::Get(get) = policy.as_ref() {
+                push!([&get.variable], OUTPUT);
+            }
+        }
+        Command::CMakeLanguage(language) => match language.as_ref() {
+            CMakeLanguage::Defer(defer) => match defer {
+                CMakeLanguageDefer::Call(call) => push!(
+                    call.options
+                        .iter()
+                        .flatten()
+                        .filter_map(|option| match option {
+                            DeferCallOption::IdVar(variable) => Some(variable),
+                            _ => None,
+                        }),
+                    OUTPUT
+                ),
+                CMakeLanguageDefer::GetCallIds(get) => push!([&get.var], OUTPUT),
+                CMakeLanguageDefer::GetCall(get) => push!([&get.var], OUTPUT),
+                CMakeLanguageDefer::CancelCall(_) => (),
+            },
+            CMakeLanguage::GetMessageLogLevel(get) => push!([&get.out_var], OUTPUT),
+            _ => (),
+        },
+        Command::TryCompile(try_compile) => {
+            let (result, output) = match try_compile.as_ref() {
+                TryCompile::Sources(sources) => {
+                    (&sources.compile_result_var, &sources.output_variable)
+                }
+                TryCompile::SourcesAlt(sources) => {
+                    (&sources.compile_result_var, &sources.output_variable)
+                }
+                TryCompile::Project(project) => {
+                    (&project.compile_result_var, &project.output_variable)
+                }
+                TryCompile::ProjectAlt(project) => {
+                    (&project.compile_result_var, &project.output_variable)
+                }
+            };
+            push!([result], OUTPUT);
+            push!(output, OUTPUT);
+        }
+        Command::TryRun(try_run) => match try_run.as_ref() {
+            TryRun::Regular(regular) => {
+                push!(
+                    [&regular.run_result_var, &regular.compile_result_var],
+                    OUTPUT
+                );
+                push!(&regular.compile_output_variable, OUTPUT);
+                push!(&regular.run_output_variable, OUTPUT);
+                push!(&regular.run_output_stdout_variable, OUTPUT);
+                push!(&regular.run_output_stderr_variable, OUTPUT);
+            }
+            TryRun::Alt(alt) => {
+                push!([&alt.run_result_var, &alt.compile_result_var], OUTPUT);
+                push!(&alt.compile_output_variable, OUTPUT);
+                push!(&alt.run_output_variable, OUTPUT);
+                push!(&alt.output_variable, OUTPUT);
+            }
+        },
+        Command::MarkAsAdvanced(mark_as_advanced) => push!(&mark_as_advanced.vars, NAME),
+        Command::VariableWatch(variable_watch) => push!([&variable_watch.variable], NAME),
+        Command::Return(r#return) => push!(r#return.propagate.iter().flatten(), NAME),
+        _ => (),
+    }
+    tokens
+}
+
+/// Accesses of `cmake_path()` commands reading the path variable into an
+/// output variable, or updating it.
+fn path_or_output<'a, 't>(
+    push: &mut impl FnMut(&mut dyn Iterator<Item = &'a Token<'t>>, Access),
+    path_var: &'a Token<'t>,
+    out_var: &'a Option<Token<'t>>,
+) {
+    match out_var {
+        Some(out_var) => {
+            push(&mut [path_var].into_iter(), NAME);
+            push(&mut [out_var].into_iter(), OUTPUT);
+        }
+        None => push(&mut [path_var].into_iter(), UPDATE),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::{fixture, ProjectLoader};
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn index_doc() {
+        let source = r#"option(WITH_TESTS "Build tests" ON)
+set(SOURCES main.c)
+list(APPEND SOURCES util.c)
+list(LENGTH SOURCES count)
+function(add_app name)
+  cmake_parse_arguments(ARG "STATIC" "OUTPUT" "LIBS" ${ARGN})
+  add_executable(${name} ${SOURCES} ${ARG_LIBS})
+  message("${ARG_UNKNOWN} ${ARGV0}")
+endfunction()
+foreach(item IN LISTS SOURCES)
+  message(STATUS "${item}")
+endforeach()
+if(WITH_TESTS AND DEFINED CUSTOM AND CMAKE_BUILD_TYPE STREQUAL Debug)
+  find_package(GTest)
+  message(${GTest_FOUND} ${GTEST_LIBRARIES})
+endif()
+execute_process(COMMAND git describe OUTPUT_VARIABLE version)
+set(unused 1)
+"#;
+        let doc = Doc::from(parse_cmakelists(source.as_bytes()).unwrap());
+        let index = VariableIndex::from_doc(&doc);
+        assert_eq!(
+            index
+                .definitions()
+                .iter()
+                .map(|definition| (
+                    definition.name.as_str(),
+                    definition.command,
+                    definition.kind
+                ))
+                .collect::<Vec<_>>(),
+            [
+                ("WITH_TESTS", 0, DefinitionKind::Option),
+                ("SOURCES", 1, DefinitionKind::Set),
+                ("SOURCES", 2, DefinitionKind::Update),
+                ("count", 3, DefinitionKind::Output),
+                ("name", 4, DefinitionKind::Parameter),
+                ("ARG_UNPARSED_ARGUMENTS", 5, DefinitionKind::ParseArguments),
+                (
+                    "ARG_KEYWORDS_MISSING_VALUES",
+                    5,
+                    DefinitionKind::ParseArguments
+                ),
+                ("ARG_STATIC", 5, DefinitionKind::ParseArguments),
+                ("ARG_OUTPUT", 5, DefinitionKind::ParseArguments),
+                ("ARG_LIBS", 5, DefinitionKind::ParseArguments),
+                ("item", 9, DefinitionKind::ForEach),
+                ("GTest_FOUND", 13, DefinitionKind::Package),
+                ("GTest_VERSION", 13, DefinitionKind::Package),
+                ("version", 16, DefinitionKind::Output),
+                ("unused", 17, DefinitionKind::Set),
+            ]
+        );
+        assert_eq!(
+            index
+                .uses()
+                .iter()
+                .map(|usage| (usage.name.as_str(), usage.command, usage.kind))
+                .collect::<Vec<_>>(),
+            [
+                ("SOURCES", 3, UseKind::Name),
+                ("ARGN", 5, UseKind::Reference),
+                ("name", 6, UseKind::Reference),
+                ("SOURCES", 6, UseKind::Reference),
+                ("ARG_LIBS", 6, UseKind::Reference),
+                ("ARG_UNKNOWN", 7, UseKind::Reference),
+                ("ARGV0", 7, UseKind::Reference),
+                ("SOURCES", 9, UseKind::Name),
+                ("item", 10, UseKind::Reference),
+                ("WITH_TESTS", 12, UseKind::Condition),
+                ("CUSTOM", 12, UseKind::Defined),
+                ("CMAKE_BUILD_TYPE", 12, UseKind::Condition),
+                ("GTest_FOUND", 14, UseKind::Reference),
+                ("GTEST_LIBRARIES", 14, UseKind::Reference),
+            ]
+        );
+        for usage in index.uses() {
+            assert_eq!(&source[usage.span.range()], usage.name);
+        }
+        assert_eq!(
+            index.uses_of("ARG_LIBS").next().unwrap().scope,
+            VariableScope::Function("add_app".to_string())
+        );
+        let offset = source.find("${item}").unwrap() + 2;
+        assert_eq!(index.name_at(None, offset), Some("item"));
+        assert_eq!(
+            index
+                .issues()
+                .iter()
+                .map(|issue| (issue.command, issue.kind.to_string()))
+                .collect::<Vec<_>>(),
+            [
+                (3, "variable `count` is never used".to_string()),
+                (16, "variable `version` is never used".to_string()),
+                (17, "variable `unused` is never used".to_string()),
+                (7, "variable `ARG_UNKNOWN` is never set".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn index_project() {
+        let project = ProjectLoader::new(fixture("symbols")).load();
+        let index = VariableIndex::from_project(&project);
+        let root = project.file_id(&fixture("symbols").join("CMakeLists.txt"));
+        let app = project.file_id(&fixture("symbols").join("app/CMakeLists.txt"));
+        let helpers = project.file_id(&fixture("symbols").join("cmake/helpers.cmake"));
+        assert_eq!(
+            index
+                .definitions_of("COMMON_SOURCES")
+                .map(|definition| (definition.file, definition.command))
+                .collect::<Vec<_>>(),
+            [(root, 3)]
+        );
+        assert_eq!(
+            index
+                .uses_of("COMMON_SOURCES")
+                .map(|usage| (usage.file, usage.command, usage.scope.clone()))
+                .collect::<Vec<_>>(),
+            [(
+                helpers,
+                1,
+                VariableScope::Function("symbols_add_app".to_string())
+            )]
+        );
+        assert_eq!(
+            index
+                .issues()
+                .iter()
+                .map(|issue| (issue.file, issue.command, issue.kind.clone()))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    root,
+                    5,
+                    SymbolIssueKind::NeverUsed("LEGACY_FLAGS".to_string())
+                ),
+                (app, 1, SymbolIssueKind::NeverSet("APP_STATIC".to_string())),
+            ]
+        );
+    }
+}
@@ -0,0 +1,677 @@
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+
+use crate::{
+    command::project::{
+        add_executable::Executable,
+        add_library::{ImportedLibraryType, Library, NormalLibraryType},
+        target_link_libraries::{self, LinkLibrary},
+        TargetLinkLibraries,
+    },
+    Command, Token,
+};
+
+/// Index of a node in a [`TargetGraph`].
+pub type NodeId = usize;
+
+/// Dependency graph between targets.
+///
+/// Nodes are the targets created by `add_executable()`, `add_library()` and
+/// `add_custom_target()`. Names linked with `target_link_libraries()` or added
+/// with `add_dependencies()` that are not declared as targets are represented
+/// by [`TargetKind::External`] nodes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TargetGraph {
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+    index: HashMap<String, NodeId>,
+}
+
+/// Target in a [`TargetGraph`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Node {
+    pub name: String,
+    pub kind: TargetKind,
+    /// The target was declared with `IMPORTED`.
+    pub imported: bool,
+}
+
+/// Kind of a target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TargetKind {
+    Executable,
+    StaticLibrary,
+    SharedLibrary,
+    ModuleLibrary,
+    ObjectLibrary,
+    InterfaceLibrary,
+    /// Library without an explicit type, `STATIC` or `SHARED` depending on `BUILD_SHARED_LIBS`.
+    Library,
+    /// Imported library of `UNKNOWN` type.
+    UnknownLibrary,
+    Custom,
+    Alias,
+    /// Library name or imported target which is not declared in the analyzed commands.
+    External,
+}
+
+/// Directed edge between two targets: `from` depends on `to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Edge {
+    pub from: NodeId,
+    pub to: NodeId,
+    pub visibility: Visibility,
+}
+
+/// Label of a dependency edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Visibility {
+    Public,
+    Private,
+    Interface,
+    LinkPublic,
+    LinkPrivate,
+    LinkInterfaceLibraries,
+    /// `target_link_libraries()` without a visibility keyword.
+    Plain,
+    /// `add_dependencies()`.
+    Dependency,
+    /// Alias target referring to the aliased one.
+    Alias,
+}
+
+impl TargetGraph {
+    /// Build the graph from the commands of a single listfile or of a whole project.
+    pub fn from_commands(commands: &[Command]) -> Self {
+        let mut graph = Self::default();
+
+        for command in commands {
+            match command {
+                Command::AddExecutable(add_executable) => {
+                    let (kind, imported) = match &add_executable.executable {
+                        Executable::Alias(_) => (TargetKind::Alias, false),
+                        Executable::Imported(_) => (TargetKind::Executable, true),
+                        Executable::Normal(_) => (TargetKind::Executable, false),
+                    };
+                    graph.declare(&add_executable.name, kind, imported);
+                }
+                Command::AddLibrary(add_library) => {
+                    let (kind, imported) = match &add_library.library {
+                        Library::Object(_) => (TargetKind::ObjectLibrary, false),
+                        Library::Interface(_) => (TargetKind::InterfaceLibrary, false),
+                        Library::Imported(imported) => (
+                            match imported.library_type {
+                                ImportedLibraryType::Static => TargetKind::StaticLibrary,
+                                ImportedLibraryType::Shared => TargetKind::SharedLibrary,
+                                ImportedLibraryType::Module => TargetKind::ModuleLibrary,
+                                ImportedLibraryType::Unknown => TargetKind::UnknownLibrary,
+                            },
+                            true,
+                        ),
+                        Library::Alias(_) => (TargetKind::Alias, false),
+                        Library::Normal(normal) => (
+                            match normal.library_type {
+                                Some(NormalLibraryType::Static) => TargetKind::StaticLibrary,
+                                Some(NormalLibraryType::Shared) => TargetKind::SharedLibrary,
+                                Some(NormalLibraryType::Module) => TargetKind::ModuleLibrary,
+                                None => TargetKind::Library,
+                            },
+                            false,
+                        ),
+                    };
+                    graph.declare(&add_library.name, kind, imported);
+                }
+                Command::AddCustomTarget(add_custom_target) => {
+                    graph.declare(&add_custom_target.name, TargetKind::Custom, false);
+                }
+                _ => (),
+            }
+        }
+
+        for command in commands {
+            match command {
+                Command::AddExecutable(add_executable) => {
+                    if let Executable::Alias(alias) = &add_executable.executable {
+                        graph.link(&add_executable.name, &alias.target, Visibility::Alias);
+                    }
+                }
+                Command::AddLibrary(add_library) => {
+                    if let Library::Alias(alias) = &add_library.library {
+                        graph.link(&add_library.name, &alias.target, Visibility::Alias);
+                    }
+                }
+                Command::AddDependencies(add_dependencies) => {
+                    for dependency in add_dependencies.target_dependencies.iter().flatten() {
+                        graph.link(&add_dependencies.target, dependency, Visibility::Dependency);
+                    }
+                }
+                Command::TargetLinkLibraries(target_link_libraries) => {
+                    graph.target_link_libraries(target_link_libraries)
+                }
+                _ => (),
+            }
+        }
+
+        graph
+    }
+
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    pub fn node(&self, id: NodeId) -> Option<&Node> {
+        self.nodes.get(id)
+    }
+
+    pub fn node_id(&self, name: &str) -> Option<NodeId> {
+        self.index.get(name).copied()
+    }
+
+    /// Edges going out of the given node.
+    pub fn dependencies(&self, id: NodeId) -> impl Iterator<Item = &Edge> {
+        self.edges.iter().filter(move |edge| edge.from == id)
+    }
+
+    /// Edges coming into the given node.
+    pub fn dependents(&self, id: NodeId) -> impl Iterator<Item = &Edge> {
+        self.edges.iter().filter(move |edge| edge.to == id)
+    }
+
+    /// Strongly connected components forming dependency cycles.
+    ///
+    /// Every cycle is returned once, starting with its smallest node id.
+    pub fn cycles(&self) -> Vec<Vec<NodeId>> {
+        let mut tarjan = Tarjan::new(self);
+        for id in 0..self.nodes.len() {
+            if tarjan.indices[id].is_none() {
+                tarjan.connect(id);
+            }
+        }
+
+        let Tarjan {
+            successors,
+            components,
+            ..
+        } = tarjan;
+        let mut cycles: Vec<_> = components
+            .into_iter()
+            .filter(|component| {
+                component.len() > 1 || successors[component[0]].contains(&component[0])
+            })
+            .map(|mut component| {
+                component.sort_unstable();
+                component
+            })
+            .collect();
+        cycles.sort();
+        cycles
+    }
+
+    /// Graphviz representation of the graph.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph \"targets\" {\n");
+        for (id, node) in self.nodes.iter().enumerate() {
+            writeln!(
+                dot,
+                "    \"node{id}\" [ label = \"{}\", shape = {} ];",
+                escape_dot(&node.name),
+                node.kind.dot_shape()
+            )
+            .unwrap();
+        }
+        for edge in &self.edges {
+            write!(dot, "    \"node{}\" -> \"node{}\"", edge.from, edge.to).unwrap();
+            let label = edge.visibility.as_str();
+            match edge.visibility.dot_style() {
+                Some(style) => writeln!(dot, " [ label = \"{label}\", style = {style} ];").unwrap(),
+                None => writeln!(dot, " [ label = \"{label}\" ];").unwrap(),
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// JSON representation of the graph with `nodes` and `edges` arrays.
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> String {
+        let nodes: Vec<_> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(id, node)| {
+                serde_json::json!({
+                    "id": id,
+                    "name": node.name,
+                    "kind": node.kind.as_str(),
+                    "imported": node.imported,
+                })
+            })
+            .collect();
+        let edges: Vec<_> = self
+            .edges
+            .iter()
+            .map(|edge| {
+                serde_json::json!({
+                    "from": edge.from,
+                    "to": edge.to,
+                    "visibility": edge.visibility.as_str(),
+                })
+            })
+            .collect();
+        serde_json::to_string_pretty(&serde_json::json!({
+            "nodes": nodes,
+            "edges": edges,
+        }))
+        .expect("valid json")
+    }
+
+    fn declare(&mut self, name: &Token, kind: TargetKind, imported: bool) {
+        let name = name.to_string();
+        match self.index.get(&name) {
+            Some(&id) => {
+                let node = &mut self.nodes[id];
+                node.kind = kind;
+                node.imported = imported;
+            }
+            None => {
+                self.index.insert(name.clone(), self.nodes.len());
+                self.nodes.push(Node {
+                    name,
+                    kind,
+                    imported,
+                });
+            }
+        }
+    }
+
+    fn node_or_external(&mut self, name: &Token) -> NodeId {
+        let name = name.to_string();
+        if let Some(&id) = self.index.get(&name) {
+            return id;
+        }
+        let id = self.nodes.len();
+        self.index.insert(name.clone(), id);
+        self.nodes.push(Node {
+            name,
+            kind: TargetKind::External,
+            imported: false,
+        });
+        id
+    }
+
+    fn link(&mut self, from: &Token, to: &Token, visibility: Visibility) {
+        let from = self.node_or_external(from);
+        let to = self.node_or_external(to);
+        let edge = Edge {
+            from,
+            to,
+            visibility,
+        };
+        if !self.edges.contains(&edge) {
+            self.edges.push(edge);
+        }
+    }
+
+    fn link_items(&mut self, target: &Token, items: &[Token], visibility: Visibility) {
+        for item in items.iter().filter(|item| is_link_item(item)) {
+            self.link(target, item, visibility);
+        }
+    }
+
+    fn target_link_libraries(&mut self, target_link_libraries: &TargetLinkLibraries) {
+        match target_link_libraries {
+            TargetLinkLibraries::TargetAndOrDependents(tll) => {
+                for library in &tll.libraries {
+                    let (items, visibility) = match library {
+                        target_link_libraries::Library::Interface(items) => {
+                            (items, Visibility::Interface)
+                        }
+                        target_link_libraries::Library::Public(items) => {
+                            (items, Visibility::Public)
+                        }
+                        target_link_libraries::Library::Private(items) => {
+                            (items, Visibility::Private)
+                        }
+                    };
+                    self.link_items(&tll.target, items, visibility);
+                }
+            }
+            TargetLinkLibraries::TargetAndOrDependentsLegacy(tll) => {
+                for library in &tll.libraries {
+                    let (items, visibility) = match library {
+                        LinkLibrary::LinkPublic(items) => (items, Visibility::LinkPublic),
+                        LinkLibrary::LinkPrivate(items) => (items, Visibility::LinkPrivate),
+                    };
+                    self.link_items(&tll.target, items, visibility);
+                }
+            }
+            TargetLinkLibraries::DependentsOnlyLegacy(tll) => self.link_items(
+                &tll.target,
+                &tll.link_interface_libraries,
+                Visibility::LinkInterfaceLibraries,
+            ),
+            TargetLinkLibraries::TargetAndDependents(tll) => {
+                self.link_items(&tll.target, &tll.libraries, Visibility::Plain)
+            }
+        }
+    }
+}
+
+impl TargetKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TargetKind::Executable => "executable",
+            TargetKind::StaticLibrary => "static_library",
+            TargetKind::SharedLibrary => "shared_library",
+            TargetKind::ModuleLibrary => "module_library",
+            TargetKind::ObjectLibrary => "object_library",
+            TargetKind::InterfaceLibrary => "interface_library",
+            TargetKind::Library => "library",
+            TargetKind::UnknownLibrary => "unknown_library",
+            TargetKind::Custom => "custom",
+            TargetKind::Alias => "alias",
+            TargetKind::External => "external",
+        }
+    }
+
+    fn dot_shape(&self) -> &'static str {
+        match self {
+            TargetKind::Executable => "egg",
+            TargetKind::StaticLibrary => "octagon",
+            TargetKind::SharedLibrary => "doubleoctagon",
+            TargetKind::ModuleLibrary => "tripleoctagon",
+            TargetKind::ObjectLibrary => "hexagon",
+            TargetKind::InterfaceLibrary => "pentagon",
+            TargetKind::Library => "octagon",
+            TargetKind::UnknownLibrary => "septagon",
+            TargetKind::Custom => "box",
+            TargetKind::Alias => "cds",
+            TargetKind::External => "ellipse",
+        }
+    }
+}
+
+impl fmt::Display for TargetKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Visibility {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Visibility::Public => "PUBLIC",
+            Visibility::Private => "PRIVATE",
+            Visibility::Interface => "INTERFACE",
+            Visibility::LinkPublic => "LINK_PUBLIC",
+            Visibility::LinkPrivate => "LINK_PRIVATE",
+            Visibility::LinkInterfaceLibraries => "LINK_INTERFACE_LIBRARIES",
+            Visibility::Plain => "",
+            Visibility::Dependency => "DEPENDS",
+            Visibility::Alias => "ALIAS",
+        }
+    }
+
+    fn dot_style(&self) -> Option<&'static str> {
+        match self {
+            Visibility::Private | Visibility::LinkPrivate => Some("dashed"),
+            Visibility::Interface | Visibility::LinkInterfaceLibraries => Some("dotted"),
+            Visibility::Dependency | Visibility::Alias => Some("bold"),
+            Visibility::Public | Visibility::LinkPublic | Visibility::Plain => None,
+        }
+    }
+}
+
+impl fmt::Display for Visibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Linker flags, generator expressions and `debug`/`optimized`/`general` keywords are not targets.
+fn is_link_item(item: &Token) -> bool {
+    let bytes = item.as_bytes();
+    !(bytes.is_empty()
+        || bytes.starts_with(b"-")
+        || bytes.starts_with(b"$<")
+        || matches!(bytes, b"debug" | b"optimized" | b"general"))
+}
+
+fn escape_dot(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+struct Tarjan {
+    successors: Vec<Vec<NodeId>>,
+    index: usize,
+    indices: Vec<Option<usize>>,
+    low_links: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<NodeId>,
+    components: Vec<Vec<NodeId>>,
+}
+
+impl Tarjan {
+    fn new(graph: &TargetGraph) -> Self {
+        let len = graph.nodes.len();
+        let mut successors = vec![vec![]; len];
+        for edge in &graph.edges {
+            successors[edge.from].push(edge.to);
+        }
+        Self {
+            successors,
+            index: 0,
+            indices: vec![None; len],
+            low_links: vec![0; len],
+            on_stack: vec![false; len],
+            stack: vec![],
+            components: vec![],
+        }
+    }
+
+    /// Visits the nodes reachable from `id` with an explicit call stack of
+    /// nodes and their next successor, so long dependency chains do not
+    /// overflow the stack.
+    fn connect(&mut self, id: NodeId) {
+        self.visit(id);
+        let mut calls = vec![(id, 0)];
+        while let Some((node, next)) = calls.pop() {
+            if let Some(&successor) = self.successors[node].get(next) {
+                calls.push((node, next + 1));
+                match self.indices[successor] {
+                    None => {
+                        self.visit(successor);
+                        calls.push((successor, 0));
+                    }
+                    Some(index) if self.on_stack[successor] => {
+                        self.low_links[node] = self.low_links[node].min(index);
+                    }
+                    Some(_) => (),
+                }
+                continue;
+            }
+
+            if Some(self.low_links[node]) == self.indices[node] {
+                let mut component = vec![];
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                self.components.push(component);
+            }
+            if let Some(&(caller, _)) = calls.last() {
+                self.low_links[caller] = self.low_links[caller].min(self.low_links[node]);
+            }
+        }
+    }
+
+    fn visit(&mut self, id: NodeId) {
+        self.indices[id] = Some(self.index);
+        self.low_links[id] = self.index;
+        self.index += 1;
+        self.stack.push(id);
+        self.on_stack[id] = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    fn graph(src: &[u8]) -> TargetGraph {
+        let cmakelists = parse_cmakelists(src).unwrap();
+        let doc = Doc::from(cmakelists);
+        TargetGraph::from_commands(&doc.commands().unwrap())
+    }
+
+    #[test]
+    fn target_graph() {
+        let graph = graph(include_bytes!("../../../fixture/analysis/target_graph"));
+
+        let names: Vec<_> = graph
+            .nodes()
+            .iter()
+            .map(|node| (node.name.as_str(), node.kind, node.imported))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("core", TargetKind::StaticLibrary, false),
+                ("core::core", TargetKind::Alias, false),
+                ("util", TargetKind::SharedLibrary, false),
+                ("headers", TargetKind::InterfaceLibrary, false),
+                ("ZLIB::ZLIB", TargetKind::UnknownLibrary, true),
+                ("app", TargetKind::Executable, false),
+                ("docs", TargetKind::Custom, false),
+                ("Threads::Threads", TargetKind::External, false),
+                ("m", TargetKind::External, false),
+                ("legacy", TargetKind::External, false),
+            ]
+        );
+
+        let edges: Vec<_> = graph
+            .edges()
+            .iter()
+            .map(|edge| {
+                (
+                    graph.nodes()[edge.from].name.as_str(),
+                    graph.nodes()[edge.to].name.as_str(),
+                    edge.visibility,
+                )
+            })
+            .collect();
+        assert_eq!(
+            edges,
+            vec![
+                ("core::core", "core", Visibility::Alias),
+                ("core", "util", Visibility::Public),
+                ("core", "ZLIB::ZLIB", Visibility::Private),
+                ("core", "headers", Visibility::Interface),
+                ("util", "Threads::Threads", Visibility::Plain),
+                ("util", "m", Visibility::Plain),
+                ("app", "core::core", Visibility::Private),
+                ("app", "docs", Visibility::Dependency),
+                ("legacy", "util", Visibility::LinkPublic),
+            ]
+        );
+
+        assert!(graph.cycles().is_empty());
+    }
+
+    #[test]
+    fn target_graph_cycles() {
+        let graph = graph(
+            br#"
+add_library(a a.c)
+add_library(b b.c)
+add_library(c c.c)
+add_library(d d.c)
+target_link_libraries(a PUBLIC b)
+target_link_libraries(b PRIVATE c)
+target_link_libraries(c INTERFACE a)
+target_link_libraries(d d)
+"#,
+        );
+        let cycles: Vec<Vec<_>> = graph
+            .cycles()
+            .into_iter()
+            .map(|cycle| {
+                cycle
+                    .into_iter()
+                    .map(|id| graph.node(id).unwrap().name.as_str())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(cycles, vec![vec!["a", "b", "c"], vec!["d"]]);
+    }
+
+    #[test]
+    fn target_graph_long_chain() {
+        let len = 200_000;
+        let graph = TargetGraph {
+            nodes: (0..len)
+                .map(|id| Node {
+                    name: format!("t{id}"),
+                    kind: TargetKind::Library,
+                    imported: false,
+                })
+                .collect(),
+            edges: (1..len)
+                .map(|id| Edge {
+                    from: id - 1,
+                    to: id,
+                    visibility: Visibility::Public,
+                })
+                .chain(std::iter::once(Edge {
+                    from: len - 1,
+                    to: len - 2,
+                    visibility: Visibility::Public,
+                }))
+                .collect(),
+            index: HashMap::new(),
+        };
+        assert_eq!(graph.cycles(), vec![vec![len - 2, len - 1]]);
+    }
+
+    #[test]
+    fn target_graph_export() {
+        let graph = graph(
+            br#"
+add_executable(app main.c)
+target_link_libraries(app PRIVATE "lib\"q")
+"#,
+        );
+        assert_eq!(
+            graph.to_dot(),
+            r#"digraph "targets" {
+    "node0" [ label = "app", shape = egg ];
+    "node1" [ label = "lib\"q", shape = ellipse ];
+    "node0" -> "node1" [ label = "PRIVATE", style = dashed ];
+}
+"#
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&graph.to_json()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "nodes": [
+                    { "id": 0, "name": "app", "kind": "executable", "imported": false },
+                    { "id": 1, "name": "lib\"q", "kind": "external", "imported": false },
+                ],
+                "edges": [
+                    { "from": 0, "to": 1, "visibility": "PRIVATE" },
+                ],
+            })
+        );
+    }
+}
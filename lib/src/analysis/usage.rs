@@ -0,0 +1,523 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    analysis::{Evaluation, Genex, GenexContext, GenexError, Visibility},
+    command::project::{
+        add_executable::Executable,
+        add_library::Library,
+        target_compile_definitions::Definition,
+        target_compile_features::Feature,
+        target_compile_options, target_include_directories,
+        target_include_directories::Mode,
+        target_link_directories,
+        target_link_libraries::{self, LinkLibrary},
+        target_link_options, TargetLinkLibraries,
+    },
+    Command, Token,
+};
+
+/// Usage requirements of targets with their transitive propagation.
+///
+/// Values set with `PRIVATE` apply to the target itself, values set with
+/// `INTERFACE` apply to the targets linking it, and `PUBLIC` values apply to
+/// both. Interface values are propagated through `target_link_libraries()`
+/// the same way CMake does it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UsageRequirements {
+    targets: Vec<TargetUsage>,
+    index: HashMap<String, usize>,
+    aliases: HashMap<String, String>,
+}
+
+/// Values of a target set directly on it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TargetUsage {
+    pub name: String,
+    /// Values used to build the target itself.
+    pub own: Requirements,
+    /// Values used by the targets linking this target.
+    pub interface: Requirements,
+}
+
+/// Build requirements of a target.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Requirements {
+    pub include_directories: Vec<Value>,
+    pub compile_definitions: Vec<Value>,
+    pub compile_options: Vec<Value>,
+    pub compile_features: Vec<Value>,
+    pub link_libraries: Vec<Value>,
+    pub link_options: Vec<Value>,
+    pub link_directories: Vec<Value>,
+}
+
+/// Requirement value with the command it was contributed by.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Value {
+    pub value: String,
+    pub origin: Origin,
+}
+
+/// Command contributing a [`Value`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Origin {
+    /// Index of the command in the analyzed commands.
+    pub command: usize,
+    /// Target the command was applied to.
+    pub target: String,
+    pub visibility: Visibility,
+}
+
+impl Value {
+    /// Evaluates generator expressions of the value.
+    pub fn evaluate(&self, context: &GenexContext) -> Result<Evaluation, GenexError> {
+        Genex::parse(&self.value)?.evaluate(context)
+    }
+}
+
+impl UsageRequirements {
+    pub fn from_commands(commands: &[Command]) -> Self {
+        let mut usage = Self::default();
+
+        for command in commands {
+            match command {
+                Command::AddExecutable(add_executable) => match &add_executable.executable {
+                    Executable::Alias(alias) => usage.alias(&add_executable.name, &alias.target),
+                    _ => {
+                        usage.target(&add_executable.name);
+                    }
+                },
+                Command::AddLibrary(add_library) => match &add_library.library {
+                    Library::Alias(alias) => usage.alias(&add_library.name, &alias.target),
+                    _ => {
+                        usage.target(&add_library.name);
+                    }
+                },
+                Command::AddCustomTarget(add_custom_target) => {
+                    usage.target(&add_custom_target.name);
+                }
+                _ => (),
+            }
+        }
+
+        for (index, command) in commands.iter().enumerate() {
+            match command {
+                Command::TargetIncludeDirectories(tid) => {
+                    let groups: Vec<_> = tid
+                        .directories
+                        .iter()
+                        .map(|directory| match directory {
+                            target_include_directories::Directory::Interface(values) => {
+                                (Visibility::Interface, values)
+                            }
+                            target_include_directories::Directory::Public(values) => {
+                                (Visibility::Public, values)
+                            }
+                            target_include_directories::Directory::Private(values) => {
+                                (Visibility::Private, values)
+                            }
+                        })
+                        .collect();
+                    let before = matches!(tid.mode, Some(Mode::Before));
+                    usage.add(index, &tid.target, &groups, before, |r| {
+                        &mut r.include_directories
+                    });
+                }
+                Command::TargetCompileDefinitions(tcd) => {
+                    let groups: Vec<_> = tcd
+                        .definitions
+                        .iter()
+                        .map(|definition| match definition {
+                            Definition::Interface(values) => (Visibility::Interface, values),
+                            Definition::Public(values) => (Visibility::Public, values),
+                            Definition::Private(values) => (Visibility::Private, values),
+                        })
+                        .collect();
+                    usage.add(index, &tcd.target, &groups, false, |r| {
+                        &mut r.compile_definitions
+                    });
+                }
+                Command::TargetCompileOptions(tco) => {
+                    let groups: Vec<_> = tco
+                        .options
+                        .iter()
+                        .map(|option| match option {
+                            target_compile_options::Option::Interface(values) => {
+                                (Visibility::Interface, values)
+                            }
+                            target_compile_options::Option::Public(values) => {
+                                (Visibility::Public, values)
+                            }
+                            target_compile_options::Option::Private(values) => {
+                                (Visibility::Private, values)
+                            }
+                        })
+                        .collect();
+                    usage.add(index, &tco.target, &groups, tco.before, |r| {
+                        &mut r.compile_options
+                    });
+                }
+                Command::TargetCompileFeatures(tcf) => {
+                    let groups: Vec<_> = tcf
+                        .features
+                        .iter()
+                        .map(|feature| match feature {
+                            Feature::Interface(values) => (Visibility::Interface, values),
+                            Feature::Public(values) => (Visibility::Public, values),
+                            Feature::Private(values) => (Visibility::Private, values),
+                        })
+                        .collect();
+                    usage.add(index, &tcf.target, &groups, false, |r| {
+                        &mut r.compile_features
+                    });
+                }
+                Command::TargetLinkOptions(tlo) => {
+                    let groups: Vec<_> = tlo
+                        .options
+                        .iter()
+                        .map(|option| match option {
+                            target_link_options::Option::Interface(values) => {
+                                (Visibility::Interface, values)
+                            }
+                            target_link_options::Option::Public(values) => {
+                                (Visibility::Public, values)
+                            }
+                            target_link_options::Option::Private(values) => {
+                                (Visibility::Private, values)
+                            }
+                        })
+                        .collect();
+                    usage.add(index, &tlo.target, &groups, tlo.before, |r| {
+                        &mut r.link_options
+                    });
+                }
+                Command::TargetLinkDirectories(tld) => {
+                    let groups: Vec<_> = tld
+                        .directories
+                        .iter()
+                        .map(|directory| match directory {
+                            target_link_directories::Directory::Interface(values) => {
+                                (Visibility::Interface, values)
+                            }
+                            target_link_directories::Directory::Public(values) => {
+                                (Visibility::Public, values)
+                            }
+                            target_link_directories::Directory::Private(values) => {
+                                (Visibility::Private, values)
+                            }
+                        })
+                        .collect();
+                    usage.add(index, &tld.target, &groups, tld.before, |r| {
+                        &mut r.link_directories
+                    });
+                }
+                Command::TargetLinkLibraries(tll) => {
+                    let (target, groups): (_, Vec<_>) = match tll.as_ref() {
+                        TargetLinkLibraries::TargetAndOrDependents(tll) => (
+                            &tll.target,
+                            tll.libraries
+                                .iter()
+                                .map(|library| match library {
+                                    target_link_libraries::Library::Interface(values) => {
+                                        (Visibility::Interface, values)
+                                    }
+                                    target_link_libraries::Library::Public(values) => {
+                                        (Visibility::Public, values)
+                                    }
+                                    target_link_libraries::Library::Private(values) => {
+                                        (Visibility::Private, values)
+                                    }
+                                })
+                                .collect(),
+                        ),
+                        TargetLinkLibraries::TargetAndOrDependentsLegacy(tll) => (
+                            &tll.target,
+                            tll.libraries
+                                .iter()
+                                .map(|library| match library {
+                                    LinkLibrary::LinkPublic(values) => {
+                                        (Visibility::LinkPublic, values)
+                                    }
+                                    LinkLibrary::LinkPrivate(values) => {
+                                        (Visibility::LinkPrivate, values)
+                                    }
+                                })
+                                .collect(),
+                        ),
+                        TargetLinkLibraries::DependentsOnlyLegacy(tll) => (
+                            &tll.target,
+                            vec![(
+                                Visibility::LinkInterfaceLibraries,
+                                &tll.link_interface_libraries,
+                            )],
+                        ),
+                        TargetLinkLibraries::TargetAndDependents(tll) => {
+                            (&tll.target, vec![(Visibility::Plain, &tll.libraries)])
+                        }
+                    };
+                    usage.add(index, target, &groups, false, |r| &mut r.link_libraries);
+                }
+                _ => (),
+            }
+        }
+
+        // `-D` prefixes are removed from compile definitions
+        for target in &mut usage.targets {
+            for requirements in [&mut target.own, &mut target.interface] {
+                for definition in &mut requirements.compile_definitions {
+                    if let Some(stripped) = definition.value.strip_prefix("-D") {
+                        definition.value = stripped.to_string();
+                    }
+                }
+            }
+        }
+
+        usage
+    }
+
+    /// Targets in order of their appearance.
+    pub fn targets(&self) -> &[TargetUsage] {
+        &self.targets
+    }
+
+    /// Values set directly on the target or on the target it is an alias of.
+    pub fn target_usage(&self, name: &str) -> Option<&TargetUsage> {
+        self.index
+            .get(self.resolve(name))
+            .map(|&index| &self.targets[index])
+    }
+
+    /// Effective requirements used to build the target including the ones
+    /// propagated from the linked targets.
+    ///
+    /// Values are ordered as CMake orders them: own values first, then the
+    /// interface values of the linked targets in link order. Duplicated values
+    /// are kept only once.
+    pub fn effective(&self, name: &str) -> Option<Requirements> {
+        let target = self.target_usage(name)?;
+        let mut effective = target.own.clone();
+        let mut visited = HashSet::from([target.name.as_str()]);
+        let mut queue: Vec<_> = target
+            .own
+            .link_libraries
+            .iter()
+            .map(|value| value.value.as_str())
+            .collect();
+        let mut link_libraries = vec![];
+
+        while !queue.is_empty() {
+            let mut next = vec![];
+            for library in queue {
+                let Some(dependency) = self.target_usage(library) else {
+                    continue;
+                };
+                if !visited.insert(dependency.name.as_str()) {
+                    continue;
+                }
+                effective.extend(&dependency.interface);
+                link_libraries.extend(dependency.interface.link_libraries.iter().cloned());
+                next.extend(
+                    dependency
+                        .interface
+                        .link_libraries
+                        .iter()
+                        .map(|value| value.value.as_str()),
+                );
+            }
+            queue = next;
+        }
+        effective.link_libraries.extend(link_libraries);
+        effective.dedup();
+
+        Some(effective)
+    }
+
+    fn resolve<'a>(&'a self, name: &'a str) -> &'a str {
+        self.aliases.get(name).map(String::as_str).unwrap_or(name)
+    }
+
+    fn target(&mut self, name: &Token) -> usize {
+        let name = name.to_string();
+        let name = self.resolve(&name).to_string();
+        if let Some(&index) = self.index.get(&name) {
+            return index;
+        }
+        let index = self.targets.len();
+        self.index.insert(name.clone(), index);
+        self.targets.push(TargetUsage {
+            name,
+            ..Default::default()
+        });
+        index
+    }
+
+    fn alias(&mut self, name: &Token, target: &Token) {
+        self.aliases.insert(name.to_string(), target.to_string());
+    }
+
+    fn add(
+        &mut self,
+        command: usize,
+        target: &Token,
+        groups: &[(Visibility, &Vec<Token>)],
+        before: bool,
+        field: fn(&mut Requirements) -> &mut Vec<Value>,
+    ) {
+        let index = self.target(target);
+        let mut own = vec![];
+        let mut interface = vec![];
+        for (visibility, values) in groups {
+            for value in values.iter() {
+                let bytes = value.as_bytes();
+                if bytes.is_empty() || matches!(bytes, b"debug" | b"optimized" | b"general") {
+                    continue;
+                }
+                let value = Value {
+                    value: value.to_string(),
+                    origin: Origin {
+                        command,
+                        target: target.to_string(),
+                        visibility: *visibility,
+                    },
+                };
+                match visibility {
+                    Visibility::Private | Visibility::LinkPrivate => own.push(value),
+                    Visibility::Interface | Visibility::LinkInterfaceLibraries => {
+                        interface.push(value)
+                    }
+                    _ => {
+                        own.push(value.clone());
+                        interface.push(value);
+                    }
+                }
+            }
+        }
+
+        let usage = &mut self.targets[index];
+        for (requirements, values) in [(&mut usage.own, own), (&mut usage.interface, interface)] {
+            let field = field(requirements);
+            if before {
+                field.splice(0..0, values);
+            } else {
+                field.extend(values);
+            }
+        }
+    }
+}
+
+impl Requirements {
+    fn extend(&mut self, other: &Requirements) {
+        self.include_directories
+            .extend(other.include_directories.iter().cloned());
+        self.compile_definitions
+            .extend(other.compile_definitions.iter().cloned());
+        self.compile_options
+            .extend(other.compile_options.iter().cloned());
+        self.compile_features
+            .extend(other.compile_features.iter().cloned());
+        self.link_options.extend(other.link_options.iter().cloned());
+        self.link_directories
+            .extend(other.link_directories.iter().cloned());
+    }
+
+    fn dedup(&mut self) {
+        for values in [
+            &mut self.include_directories,
+            &mut self.compile_definitions,
+            &mut self.compile_options,
+            &mut self.compile_features,
+            &mut self.link_libraries,
+            &mut self.link_options,
+            &mut self.link_directories,
+        ] {
+            let mut seen = HashSet::new();
+            values.retain(|value| seen.insert(value.value.clone()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    fn usage(src: &[u8]) -> UsageRequirements {
+        let cmakelists = parse_cmakelists(src).unwrap();
+        let doc = Doc::from(cmakelists);
+        UsageRequirements::from_commands(&doc.commands().unwrap())
+    }
+
+    fn values(values: &[Value]) -> Vec<&str> {
+        values.iter().map(|value| value.value.as_str()).collect()
+    }
+
+    #[test]
+    fn usage_requirements() {
+        let usage = usage(include_bytes!("../../../fixture/analysis/usage"));
+
+        let names: Vec<_> = usage
+            .targets()
+            .iter()
+            .map(|target| target.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["core", "util", "headers", "app"]);
+
+        let core = usage.target_usage("core::core").unwrap();
+        assert_eq!(core.name, "core");
+        assert_eq!(
+            values(&core.own.include_directories),
+            vec!["core/src", "core/include"]
+        );
+        assert_eq!(
+            values(&core.interface.include_directories),
+            vec!["core/include"]
+        );
+        assert_eq!(values(&core.own.compile_options), Vec::<&str>::new());
+        assert_eq!(values(&core.interface.compile_options), vec!["-Wall"]);
+        assert_eq!(values(&core.interface.link_libraries), vec!["util"]);
+
+        let util = usage.target_usage("util").unwrap();
+        assert_eq!(
+            values(&util.own.compile_definitions),
+            vec!["UTIL=1", "UTIL_BUILD"]
+        );
+        assert_eq!(values(&util.interface.compile_definitions), vec!["UTIL=1"]);
+    }
+
+    #[test]
+    fn effective_requirements() {
+        let usage = usage(include_bytes!("../../../fixture/analysis/usage"));
+
+        let core = usage.effective("core").unwrap();
+        assert_eq!(
+            values(&core.include_directories),
+            vec!["core/src", "core/include", "util/include", "include"]
+        );
+        assert_eq!(values(&core.compile_definitions), vec!["UTIL=1"]);
+        assert_eq!(values(&core.compile_options), Vec::<&str>::new());
+
+        let app = usage.effective("app").unwrap();
+        assert_eq!(
+            values(&app.include_directories),
+            vec!["util/include", "core/include"]
+        );
+        assert_eq!(values(&app.compile_definitions), vec!["UTIL=1"]);
+        assert_eq!(values(&app.compile_options), vec!["-Wall"]);
+        assert_eq!(values(&app.compile_features), vec!["cxx_std_17"]);
+        assert_eq!(values(&app.link_libraries), vec!["core::core", "m", "util"]);
+
+        let origins: Vec<_> = app
+            .include_directories
+            .iter()
+            .map(|value| (value.origin.command, value.origin.target.as_str()))
+            .collect();
+        assert_eq!(origins, vec![(13, "app"), (10, "core")]);
+        assert_eq!(
+            app.compile_definitions[0].origin.visibility,
+            Visibility::Public
+        );
+
+        assert_eq!(usage.effective("unknown"), None);
+    }
+}
@@ -0,0 +1,2924 @@
+use crate::analysis::{VariableCategory, VariableInfo};
+
+const INFORMATION: VariableCategory = VariableCategory::Information;
+const BEHAVIOR: VariableCategory = VariableCategory::Behavior;
+const SYSTEM: VariableCategory = VariableCategory::System;
+const BUILD: VariableCategory = VariableCategory::Build;
+const LANGUAGE: VariableCategory = VariableCategory::Language;
+const CTEST: VariableCategory = VariableCategory::CTest;
+const CPACK: VariableCategory = VariableCategory::CPack;
+
+const fn var(
+    category: VariableCategory,
+    name: &'static str,
+    description: &'static str,
+) -> VariableInfo {
+    VariableInfo {
+        name,
+        category,
+        description,
+        read_only: false,
+    }
+}
+
+const fn read_only(
+    category: VariableCategory,
+    name: &'static str,
+    description: &'static str,
+) -> VariableInfo {
+    VariableInfo {
+        name,
+        category,
+        description,
+        read_only: true,
+    }
+}
+
+/// Variables documented by CMake 3.26.
+pub(crate) const VARIABLES: &[VariableInfo] = &[
+    // Variables that provide information
+    var(
+        INFORMATION,
+        "CMAKE_AR",
+        "Name of the archiving tool for static libraries.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_ARGC",
+        "Number of command line arguments passed to CMake in script mode.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_ARGV0",
+        "Command line argument passed to CMake in script mode.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_ARGV<n>",
+        "Command line argument passed to CMake in script mode.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_BINARY_DIR",
+        "Path to the top level of the build tree.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_BUILD_TOOL",
+        "Deprecated, use CMAKE_MAKE_PROGRAM.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_CACHE_MAJOR_VERSION",
+        "Major version of CMake used to create the cache file.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_CACHE_MINOR_VERSION",
+        "Minor version of CMake used to create the cache file.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_CACHE_PATCH_VERSION",
+        "Patch version of CMake used to create the cache file.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_CACHEFILE_DIR",
+        "Directory of the CMakeCache.txt file.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_CFG_INTDIR",
+        "Build-time reference to the per-configuration output subdirectory.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_COMMAND",
+        "Full path to the cmake executable.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_CPACK_COMMAND",
+        "Full path to the cpack executable.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_CROSSCOMPILING",
+        "Whether CMake is cross compiling.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_CROSSCOMPILING_EMULATOR",
+        "Emulator used to run cross compiled executables.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_CTEST_COMMAND",
+        "Full path to the ctest executable.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_CURRENT_BINARY_DIR",
+        "Path to the build directory being processed.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_CURRENT_FUNCTION",
+        "Name of the function being executed.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_CURRENT_FUNCTION_LIST_DIR",
+        "Directory of the file defining the function being executed.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_CURRENT_FUNCTION_LIST_FILE",
+        "Full path to the file defining the function being executed.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_CURRENT_FUNCTION_LIST_LINE",
+        "Line of the definition of the function being executed.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_CURRENT_LIST_DIR",
+        "Directory of the listfile being processed.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_CURRENT_LIST_FILE",
+        "Full path to the listfile being processed.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_CURRENT_LIST_LINE",
+        "Line of the listfile being processed.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_CURRENT_SOURCE_DIR",
+        "Path to the source directory being processed.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_DEBUG_TARGET_PROPERTIES",
+        "Target properties to trace the origin of.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_DIRECTORY_LABELS",
+        "Labels of the tests of the current directory.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_DL_LIBS",
+        "Name of the library containing dlopen and dlclose.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_DOTNET_SDK",
+        "SDK of generated .NET projects.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_EDIT_COMMAND",
+        "Full path to cmake-gui or ccmake.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_EXECUTABLE_SUFFIX",
+        "Suffix of executables on this platform.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_EXECUTABLE_SUFFIX_<LANG>",
+        "Suffix of executables linked with a language.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_EXTRA_GENERATOR",
+        "Extra generator used to build the project.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_EXTRA_SHARED_LIBRARY_SUFFIXES",
+        "Additional suffixes of shared libraries.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_FIND_DEBUG_MODE",
+        "Whether find commands print their search details.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_FIND_PACKAGE_NAME",
+        "Name of the package being searched by find_package().",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_FIND_PACKAGE_SORT_DIRECTION",
+        "Sort direction of find_package() candidates.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_FIND_PACKAGE_SORT_ORDER",
+        "Sort order of find_package() candidates.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_GENERATOR",
+        "Generator used to build the project.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_GENERATOR_INSTANCE",
+        "Generator-specific instance specification.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_GENERATOR_PLATFORM",
+        "Generator-specific target platform.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_GENERATOR_TOOLSET",
+        "Native build system toolset of the generator.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_IMPORT_LIBRARY_PREFIX",
+        "Prefix of import libraries.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_IMPORT_LIBRARY_SUFFIX",
+        "Suffix of import libraries.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_JOB_POOL_COMPILE",
+        "Default JOB_POOL_COMPILE of targets.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_JOB_POOL_LINK",
+        "Default JOB_POOL_LINK of targets.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_JOB_POOL_PRECOMPILE_HEADER",
+        "Default JOB_POOL_PRECOMPILE_HEADER of targets.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_JOB_POOLS",
+        "Default JOB_POOLS global property.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_<LANG>_COMPILER_AR",
+        "Wrapper of ar adding the compiler plugin.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_<LANG>_COMPILER_FRONTEND_VARIANT",
+        "Command line style of the compiler frontend.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_<LANG>_COMPILER_LINKER",
+        "Full path to the linker of the language.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_<LANG>_COMPILER_RANLIB",
+        "Wrapper of ranlib adding the compiler plugin.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_<LANG>_LINK_LIBRARY_SUFFIX",
+        "Suffix of libraries linked with the language.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_LINK_LIBRARY_SUFFIX",
+        "Suffix of linked libraries.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_LINK_SEARCH_END_STATIC",
+        "Default LINK_SEARCH_END_STATIC of targets.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_LINK_SEARCH_START_STATIC",
+        "Default LINK_SEARCH_START_STATIC of targets.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_MAJOR_VERSION",
+        "Major version of the running CMake.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_MAKE_PROGRAM",
+        "Tool launching the native build system.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_MATCH_COUNT",
+        "Number of groups matched by the last regular expression.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_MATCH_<n>",
+        "Group matched by the last regular expression.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_MINIMUM_REQUIRED_VERSION",
+        "Version set by cmake_minimum_required().",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_MINOR_VERSION",
+        "Minor version of the running CMake.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_NETRC",
+        "Whether file(DOWNLOAD) and file(UPLOAD) use a netrc file.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_NETRC_FILE",
+        "Netrc file of file(DOWNLOAD) and file(UPLOAD).",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_PARENT_LIST_FILE",
+        "Full path to the listfile including the current one.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_PATCH_VERSION",
+        "Patch version of the running CMake.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_PROJECT_DESCRIPTION",
+        "Description of the top level project.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_PROJECT_HOMEPAGE_URL",
+        "Homepage URL of the top level project.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_PROJECT_NAME",
+        "Name of the top level project.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_PROJECT_VERSION",
+        "Version of the top level project.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_PROJECT_VERSION_MAJOR",
+        "Major version of the top level project.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_PROJECT_VERSION_MINOR",
+        "Minor version of the top level project.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_PROJECT_VERSION_PATCH",
+        "Patch version of the top level project.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_PROJECT_VERSION_TWEAK",
+        "Tweak version of the top level project.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_RANLIB",
+        "Name of the randomizing tool for static libraries.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_ROOT",
+        "Installation directory of CMake.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_RULE_MESSAGES",
+        "Whether Makefiles print progress messages.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_SCRIPT_MODE_FILE",
+        "Full path to the script run with cmake -P.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_SHARED_LIBRARY_PREFIX",
+        "Prefix of shared libraries.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_SHARED_LIBRARY_SUFFIX",
+        "Suffix of shared libraries.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_SHARED_MODULE_PREFIX",
+        "Prefix of loadable modules.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_SHARED_MODULE_SUFFIX",
+        "Suffix of loadable modules.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_SIZEOF_VOID_P",
+        "Size of a pointer to void.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_SKIP_INSTALL_RULES",
+        "Whether to skip generating install rules.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_SKIP_RPATH",
+        "Whether to skip adding run time paths.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_SOURCE_DIR",
+        "Path to the top level of the source tree.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_STATIC_LIBRARY_PREFIX",
+        "Prefix of static libraries.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_STATIC_LIBRARY_SUFFIX",
+        "Suffix of static libraries.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_Swift_MODULE_DIRECTORY",
+        "Default Swift_MODULE_DIRECTORY of targets.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_Swift_NUM_THREADS",
+        "Number of threads of the Swift compiler.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_TOOLCHAIN_FILE",
+        "Path to the toolchain file.",
+    ),
+    read_only(INFORMATION, "CMAKE_TWEAK_VERSION", "Deprecated, always 0."),
+    var(
+        INFORMATION,
+        "CMAKE_VERBOSE_MAKEFILE",
+        "Whether Makefiles print the full commands.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_VERSION",
+        "Full version of the running CMake.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_VS_DEVENV_COMMAND",
+        "Path to devenv.com of Visual Studio.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_VS_MSBUILD_COMMAND",
+        "Path to MSBuild.exe of Visual Studio.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_VS_NsightTegra_VERSION",
+        "Version of Nvidia Nsight Tegra Visual Studio Edition.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_VS_PLATFORM_NAME",
+        "Visual Studio target platform name.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_VS_PLATFORM_NAME_DEFAULT",
+        "Default Visual Studio target platform name.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_VS_PLATFORM_TOOLSET",
+        "Visual Studio platform toolset name.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_VS_PLATFORM_TOOLSET_VERSION",
+        "Visual Studio platform toolset version.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_VS_VERSION_BUILD_NUMBER",
+        "Full version of Visual Studio.",
+    ),
+    read_only(
+        INFORMATION,
+        "CMAKE_VS_WINDOWS_TARGET_PLATFORM_VERSION",
+        "Windows SDK version selected by Visual Studio.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_XCODE_BUILD_SYSTEM",
+        "Xcode build system selected by the generator.",
+    ),
+    var(
+        INFORMATION,
+        "CMAKE_XCODE_PLATFORM_TOOLSET",
+        "Xcode compiler selection.",
+    ),
+    read_only(
+        INFORMATION,
+        "<PROJECT-NAME>_BINARY_DIR",
+        "Top level binary directory of the named project.",
+    ),
+    read_only(
+        INFORMATION,
+        "<PROJECT-NAME>_DESCRIPTION",
+        "Description of the named project.",
+    ),
+    read_only(
+        INFORMATION,
+        "<PROJECT-NAME>_HOMEPAGE_URL",
+        "Homepage URL of the named project.",
+    ),
+    read_only(
+        INFORMATION,
+        "<PROJECT-NAME>_IS_TOP_LEVEL",
+        "Whether the named project is the top level project.",
+    ),
+    read_only(
+        INFORMATION,
+        "<PROJECT-NAME>_SOURCE_DIR",
+        "Top level source directory of the named project.",
+    ),
+    read_only(
+        INFORMATION,
+        "<PROJECT-NAME>_VERSION",
+        "Version of the named project.",
+    ),
+    read_only(
+        INFORMATION,
+        "<PROJECT-NAME>_VERSION_MAJOR",
+        "Major version of the named project.",
+    ),
+    read_only(
+        INFORMATION,
+        "<PROJECT-NAME>_VERSION_MINOR",
+        "Minor version of the named project.",
+    ),
+    read_only(
+        INFORMATION,
+        "<PROJECT-NAME>_VERSION_PATCH",
+        "Patch version of the named project.",
+    ),
+    read_only(
+        INFORMATION,
+        "<PROJECT-NAME>_VERSION_TWEAK",
+        "Tweak version of the named project.",
+    ),
+    read_only(
+        INFORMATION,
+        "PROJECT_BINARY_DIR",
+        "Top level binary directory of the current project.",
+    ),
+    read_only(
+        INFORMATION,
+        "PROJECT_DESCRIPTION",
+        "Description of the current project.",
+    ),
+    read_only(
+        INFORMATION,
+        "PROJECT_HOMEPAGE_URL",
+        "Homepage URL of the current project.",
+    ),
+    read_only(
+        INFORMATION,
+        "PROJECT_IS_TOP_LEVEL",
+        "Whether the current project is the top level project.",
+    ),
+    read_only(INFORMATION, "PROJECT_NAME", "Name of the current project."),
+    read_only(
+        INFORMATION,
+        "PROJECT_SOURCE_DIR",
+        "Top level source directory of the current project.",
+    ),
+    read_only(
+        INFORMATION,
+        "PROJECT_VERSION",
+        "Version of the current project.",
+    ),
+    read_only(
+        INFORMATION,
+        "PROJECT_VERSION_MAJOR",
+        "Major version of the current project.",
+    ),
+    read_only(
+        INFORMATION,
+        "PROJECT_VERSION_MINOR",
+        "Minor version of the current project.",
+    ),
+    read_only(
+        INFORMATION,
+        "PROJECT_VERSION_PATCH",
+        "Patch version of the current project.",
+    ),
+    read_only(
+        INFORMATION,
+        "PROJECT_VERSION_TWEAK",
+        "Tweak version of the current project.",
+    ),
+    // Variables that change behavior
+    var(
+        BEHAVIOR,
+        "BUILD_SHARED_LIBS",
+        "Whether add_library() builds shared libraries by default.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_ABSOLUTE_DESTINATION_FILES",
+        "Files installed with absolute destinations.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_APPBUNDLE_PATH",
+        "Search paths of macOS application bundles.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_BUILD_TYPE",
+        "Build type of single-configuration generators.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_CLANG_VFS_OVERLAY",
+        "Clang virtual file system overlay for cross compiling.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_CODEBLOCKS_COMPILER_ID",
+        "Compiler id of generated CodeBlocks projects.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_CODEBLOCKS_EXCLUDE_EXTERNAL_FILES",
+        "Whether CodeBlocks projects exclude external files.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_CODELITE_USE_TARGETS",
+        "Whether CodeLite projects are target oriented.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_COLOR_DIAGNOSTICS",
+        "Whether to color diagnostics of build tools.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_COLOR_MAKEFILE",
+        "Whether Makefiles print colored messages.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_CONFIGURATION_TYPES",
+        "Configurations of multi-configuration generators.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_DEPENDS_IN_PROJECT_ONLY",
+        "Whether dependency scanning is limited to the project.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_DISABLE_FIND_PACKAGE_<PackageName>",
+        "Whether to disable find_package() of a package.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_ECLIPSE_GENERATE_LINKED_RESOURCES",
+        "Whether Eclipse projects have linked resources.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_ECLIPSE_GENERATE_SOURCE_PROJECT",
+        "Whether to generate an Eclipse source project.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_ECLIPSE_MAKE_ARGUMENTS",
+        "Arguments of make in Eclipse projects.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_ECLIPSE_RESOURCE_ENCODING",
+        "Resource encoding of Eclipse projects.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_ECLIPSE_VERSION",
+        "Version of Eclipse the projects are generated for.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_ERROR_DEPRECATED",
+        "Whether deprecated functionality is an error.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_EXECUTE_PROCESS_COMMAND_ECHO",
+        "Whether execute_process() echoes its commands.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_EXPORT_COMPILE_COMMANDS",
+        "Whether to generate compile_commands.json.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_EXPORT_PACKAGE_REGISTRY",
+        "Whether export(PACKAGE) populates the package registry.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_EXPORT_NO_PACKAGE_REGISTRY",
+        "Whether export(PACKAGE) is disabled.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_APPBUNDLE",
+        "How find commands consider macOS application bundles.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_FRAMEWORK",
+        "How find commands consider macOS frameworks.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_LIBRARY_CUSTOM_LIB_SUFFIX",
+        "Custom suffix of lib directories searched by find_library().",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_LIBRARY_PREFIXES",
+        "Prefixes of library names searched by find_library().",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_LIBRARY_SUFFIXES",
+        "Suffixes of library names searched by find_library().",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_NO_INSTALL_PREFIX",
+        "Whether find commands skip the install prefix.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_PACKAGE_NO_PACKAGE_REGISTRY",
+        "Whether find_package() skips the user package registry.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_PACKAGE_NO_SYSTEM_PACKAGE_REGISTRY",
+        "Whether find_package() skips the system package registry.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_PACKAGE_PREFER_CONFIG",
+        "Whether find_package() tries config mode first.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_PACKAGE_RESOLVE_SYMLINKS",
+        "Whether find_package() resolves symbolic links.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_PACKAGE_TARGETS_GLOBAL",
+        "Whether imported targets of find_package() are global.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_PACKAGE_WARN_NO_MODULE",
+        "Whether find_package() warns without a find module.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_ROOT_PATH",
+        "Root directories prepended to search paths.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_ROOT_PATH_MODE_INCLUDE",
+        "Whether find_file() and find_path() use CMAKE_FIND_ROOT_PATH.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_ROOT_PATH_MODE_LIBRARY",
+        "Whether find_library() uses CMAKE_FIND_ROOT_PATH.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_ROOT_PATH_MODE_PACKAGE",
+        "Whether find_package() uses CMAKE_FIND_ROOT_PATH.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_ROOT_PATH_MODE_PROGRAM",
+        "Whether find_program() uses CMAKE_FIND_ROOT_PATH.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_USE_CMAKE_ENVIRONMENT_PATH",
+        "Whether find commands search the CMake environment paths.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_USE_CMAKE_PATH",
+        "Whether find commands search the CMake cache paths.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_USE_CMAKE_SYSTEM_PATH",
+        "Whether find commands search the CMake system paths.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_USE_INSTALL_PREFIX",
+        "Whether find commands search the install prefix.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_USE_PACKAGE_REGISTRY",
+        "Whether find_package() searches the user package registry.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_USE_PACKAGE_ROOT_PATH",
+        "Whether find commands search <PackageName>_ROOT.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_USE_SYSTEM_ENVIRONMENT_PATH",
+        "Whether find commands search the PATH environment variable.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FIND_USE_SYSTEM_PACKAGE_REGISTRY",
+        "Whether find_package() searches the system package registry.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_FRAMEWORK_PATH",
+        "Search paths of macOS frameworks.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_IGNORE_PATH",
+        "Directories ignored by find commands.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_IGNORE_PREFIX_PATH",
+        "Prefixes ignored by find commands.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_INCLUDE_DIRECTORIES_BEFORE",
+        "Whether include_directories() prepends by default.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_INCLUDE_DIRECTORIES_PROJECT_BEFORE",
+        "Whether project include directories come first.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_INCLUDE_PATH",
+        "Search paths of find_file() and find_path().",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_INSTALL_DEFAULT_COMPONENT_NAME",
+        "Default component of install() rules.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_INSTALL_DEFAULT_DIRECTORY_PERMISSIONS",
+        "Default permissions of installed directories.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_INSTALL_<dir>",
+        "Installation directory set by the GNUInstallDirs module.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_INSTALL_FULL_<dir>",
+        "Absolute installation directory set by the GNUInstallDirs module.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_INSTALL_MESSAGE",
+        "Which messages installation prints.",
+    ),
+    var(BEHAVIOR, "CMAKE_INSTALL_PREFIX", "Installation prefix."),
+    var(
+        BEHAVIOR,
+        "CMAKE_INSTALL_PREFIX_INITIALIZED_TO_DEFAULT",
+        "Whether CMAKE_INSTALL_PREFIX has its default value.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_LIBRARY_PATH",
+        "Search paths of find_library().",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_LINK_DIRECTORIES_BEFORE",
+        "Whether link_directories() prepends by default.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_LINK_LIBRARIES_ONLY_TARGETS",
+        "Default LINK_LIBRARIES_ONLY_TARGETS of targets.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_MAXIMUM_RECURSION_DEPTH",
+        "Maximum depth of nested calls.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_MESSAGE_CONTEXT",
+        "Context prepended to message() output.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_MESSAGE_CONTEXT_SHOW",
+        "Whether message() shows its context.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_MESSAGE_INDENT",
+        "Indentation of message() output.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_MESSAGE_LOG_LEVEL",
+        "Log level of message() output.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_MODULE_PATH",
+        "Search paths of include() and find modules.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_POLICY_DEFAULT_CMP<NNNN>",
+        "Default setting of an unset policy.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_POLICY_WARNING_CMP<NNNN>",
+        "Whether to warn about an unset policy.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_PREFIX_PATH",
+        "Installation prefixes searched by find commands.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_PROGRAM_PATH",
+        "Search paths of find_program().",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_PROJECT_INCLUDE",
+        "Files included at the end of every project() call.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_PROJECT_INCLUDE_BEFORE",
+        "Files included at the start of every project() call.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_PROJECT_<PROJECT-NAME>_INCLUDE",
+        "Files included at the end of the named project() call.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_PROJECT_<PROJECT-NAME>_INCLUDE_BEFORE",
+        "Files included at the start of the named project() call.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_PROJECT_TOP_LEVEL_INCLUDES",
+        "Files included by the first project() call.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_REQUIRE_FIND_PACKAGE_<PackageName>",
+        "Whether find_package() of a package is required.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_SKIP_INSTALL_ALL_DEPENDENCY",
+        "Whether the install target skips building all.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_STAGING_PREFIX",
+        "Installation prefix of cross compiled projects on the host.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_SUBLIME_TEXT_2_ENV_SETTINGS",
+        "Environment settings of Sublime Text 2 projects.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_SUBLIME_TEXT_2_EXCLUDE_BUILD_TREE",
+        "Whether Sublime Text 2 projects exclude the build tree.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_SUPPRESS_REGENERATION",
+        "Whether to skip the regeneration check.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_SYSROOT",
+        "Root directory of the target system.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_SYSROOT_COMPILE",
+        "Root directory of the target system when compiling.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_SYSROOT_LINK",
+        "Root directory of the target system when linking.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_SYSTEM_APPBUNDLE_PATH",
+        "System search paths of macOS application bundles.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_SYSTEM_FRAMEWORK_PATH",
+        "System search paths of macOS frameworks.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_SYSTEM_IGNORE_PATH",
+        "System directories ignored by find commands.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_SYSTEM_IGNORE_PREFIX_PATH",
+        "System prefixes ignored by find commands.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_SYSTEM_INCLUDE_PATH",
+        "System search paths of find_file() and find_path().",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_SYSTEM_LIBRARY_PATH",
+        "System search paths of find_library().",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_SYSTEM_PREFIX_PATH",
+        "System installation prefixes searched by find commands.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_SYSTEM_PROGRAM_PATH",
+        "System search paths of find_program().",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_TLS_CAINFO",
+        "Default certificate authority file of file(DOWNLOAD) and file(UPLOAD).",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_TLS_VERIFY",
+        "Whether file(DOWNLOAD) and file(UPLOAD) verify certificates.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_USER_MAKE_RULES_OVERRIDE",
+        "File overriding platform information.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_WARN_DEPRECATED",
+        "Whether to warn about deprecated functionality.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_WARN_ON_ABSOLUTE_INSTALL_DESTINATION",
+        "Whether to warn about absolute install destinations.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_XCODE_GENERATE_SCHEME",
+        "Whether the Xcode generator writes schemes.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_XCODE_GENERATE_TOP_LEVEL_PROJECT_ONLY",
+        "Whether Xcode generates only the top level project.",
+    ),
+    var(
+        BEHAVIOR,
+        "CMAKE_XCODE_LINK_BUILD_PHASE_MODE",
+        "Default XCODE_LINK_BUILD_PHASE_MODE of targets.",
+    ),
+    var(
+        BEHAVIOR,
+        "<PackageName>_ROOT",
+        "Prefixes searched by find_package() of the package.",
+    ),
+    // Variables that describe the system
+    read_only(SYSTEM, "ANDROID", "Whether the target system is Android."),
+    read_only(
+        SYSTEM,
+        "APPLE",
+        "Whether the target system is an Apple platform.",
+    ),
+    read_only(SYSTEM, "BORLAND", "Whether the Borland compiler is used."),
+    read_only(SYSTEM, "BSD", "BSD variant of the target system."),
+    read_only(
+        SYSTEM,
+        "CMAKE_ANDROID_NDK_VERSION",
+        "Version of the Android NDK.",
+    ),
+    read_only(
+        SYSTEM,
+        "CMAKE_CL_64",
+        "Deprecated, use CMAKE_SIZEOF_VOID_P.",
+    ),
+    read_only(
+        SYSTEM,
+        "CMAKE_COMPILER_2005",
+        "Whether the Visual Studio 2005 compiler is used.",
+    ),
+    read_only(
+        SYSTEM,
+        "CMAKE_HOST_APPLE",
+        "Whether the host system is an Apple platform.",
+    ),
+    read_only(SYSTEM, "CMAKE_HOST_BSD", "BSD variant of the host system."),
+    read_only(
+        SYSTEM,
+        "CMAKE_HOST_LINUX",
+        "Whether the host system is Linux.",
+    ),
+    read_only(
+        SYSTEM,
+        "CMAKE_HOST_SOLARIS",
+        "Whether the host system is Solaris.",
+    ),
+    read_only(
+        SYSTEM,
+        "CMAKE_HOST_SYSTEM",
+        "Composite name of the host system.",
+    ),
+    read_only(
+        SYSTEM,
+        "CMAKE_HOST_SYSTEM_NAME",
+        "Name of the host operating system.",
+    ),
+    read_only(
+        SYSTEM,
+        "CMAKE_HOST_SYSTEM_PROCESSOR",
+        "Name of the host CPU.",
+    ),
+    read_only(
+        SYSTEM,
+        "CMAKE_HOST_SYSTEM_VERSION",
+        "Version of the host operating system.",
+    ),
+    read_only(
+        SYSTEM,
+        "CMAKE_HOST_UNIX",
+        "Whether the host system is UNIX-like.",
+    ),
+    read_only(
+        SYSTEM,
+        "CMAKE_HOST_WIN32",
+        "Whether the host system is Windows.",
+    ),
+    var(
+        SYSTEM,
+        "CMAKE_LIBRARY_ARCHITECTURE",
+        "Target architecture library directory name.",
+    ),
+    var(
+        SYSTEM,
+        "CMAKE_LIBRARY_ARCHITECTURE_REGEX",
+        "Regular expression of architecture library directory names.",
+    ),
+    var(
+        SYSTEM,
+        "CMAKE_OBJECT_PATH_MAX",
+        "Maximum length of object file paths.",
+    ),
+    var(
+        SYSTEM,
+        "CMAKE_SYSTEM",
+        "Composite name of the target system.",
+    ),
+    var(
+        SYSTEM,
+        "CMAKE_SYSTEM_NAME",
+        "Name of the target operating system.",
+    ),
+    var(SYSTEM, "CMAKE_SYSTEM_PROCESSOR", "Name of the target CPU."),
+    var(
+        SYSTEM,
+        "CMAKE_SYSTEM_VERSION",
+        "Version of the target operating system.",
+    ),
+    read_only(SYSTEM, "CYGWIN", "Whether the target system is Cygwin."),
+    read_only(
+        SYSTEM,
+        "GHSMULTI",
+        "Whether the Green Hills MULTI generator is used.",
+    ),
+    read_only(SYSTEM, "IOS", "Whether the target system is iOS."),
+    read_only(SYSTEM, "LINUX", "Whether the target system is Linux."),
+    read_only(SYSTEM, "MINGW", "Whether the MinGW compiler is used."),
+    read_only(
+        SYSTEM,
+        "MSVC",
+        "Whether a Microsoft Visual C++ compatible compiler is used.",
+    ),
+    read_only(
+        SYSTEM,
+        "MSVC_IDE",
+        "Whether a Visual Studio generator is used.",
+    ),
+    read_only(
+        SYSTEM,
+        "MSVC_TOOLSET_VERSION",
+        "Toolset version of Microsoft Visual C++.",
+    ),
+    read_only(SYSTEM, "MSVC_VERSION", "Version of Microsoft Visual C++."),
+    read_only(
+        SYSTEM,
+        "MSYS",
+        "Whether the MSYS Makefiles generator is used.",
+    ),
+    read_only(SYSTEM, "UNIX", "Whether the target system is UNIX-like."),
+    read_only(SYSTEM, "WIN32", "Whether the target system is Windows."),
+    read_only(SYSTEM, "WINCE", "Whether the target system is Windows CE."),
+    read_only(
+        SYSTEM,
+        "WINDOWS_PHONE",
+        "Whether the target system is Windows Phone.",
+    ),
+    read_only(
+        SYSTEM,
+        "WINDOWS_STORE",
+        "Whether the target system is Windows Store.",
+    ),
+    read_only(SYSTEM, "XCODE", "Whether the Xcode generator is used."),
+    read_only(SYSTEM, "XCODE_VERSION", "Version of Xcode."),
+    // Variables that control the build
+    var(
+        BUILD,
+        "CMAKE_ADSP_ROOT",
+        "Root directory of the ADSP toolchain.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_AIX_EXPORT_ALL_SYMBOLS",
+        "Default AIX_EXPORT_ALL_SYMBOLS of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_ANDROID_ABI",
+        "Default ANDROID_ABI of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_ANDROID_API",
+        "Default ANDROID_API of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_ANDROID_ARCH_ABI",
+        "Android ABI of cross compiled projects.",
+    ),
+    var(BUILD, "CMAKE_ANDROID_NDK", "Path to the Android NDK."),
+    var(
+        BUILD,
+        "CMAKE_ANDROID_STL_TYPE",
+        "Android C++ standard library.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_APPLE_SILICON_PROCESSOR",
+        "Host architecture on Apple silicon.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_ARCHIVE_OUTPUT_DIRECTORY",
+        "Default ARCHIVE_OUTPUT_DIRECTORY of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_ARCHIVE_OUTPUT_DIRECTORY_<CONFIG>",
+        "Default ARCHIVE_OUTPUT_DIRECTORY_<CONFIG> of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_AUTOGEN_ORIGIN_DEPENDS",
+        "Default AUTOGEN_ORIGIN_DEPENDS of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_AUTOGEN_PARALLEL",
+        "Default AUTOGEN_PARALLEL of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_AUTOGEN_VERBOSE",
+        "Whether AUTOMOC, AUTOUIC and AUTORCC are verbose.",
+    ),
+    var(BUILD, "CMAKE_AUTOMOC", "Default AUTOMOC of targets."),
+    var(
+        BUILD,
+        "CMAKE_AUTOMOC_COMPILER_PREDEFINES",
+        "Default AUTOMOC_COMPILER_PREDEFINES of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_AUTOMOC_DEPEND_FILTERS",
+        "Default AUTOMOC_DEPEND_FILTERS of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_AUTOMOC_MACRO_NAMES",
+        "Default AUTOMOC_MACRO_NAMES of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_AUTOMOC_MOC_OPTIONS",
+        "Default AUTOMOC_MOC_OPTIONS of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_AUTOMOC_PATH_PREFIX",
+        "Default AUTOMOC_PATH_PREFIX of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_AUTOMOC_EXECUTABLE",
+        "Default AUTOMOC_EXECUTABLE of targets.",
+    ),
+    var(BUILD, "CMAKE_AUTORCC", "Default AUTORCC of targets."),
+    var(
+        BUILD,
+        "CMAKE_AUTORCC_OPTIONS",
+        "Default AUTORCC_OPTIONS of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_AUTORCC_EXECUTABLE",
+        "Default AUTORCC_EXECUTABLE of targets.",
+    ),
+    var(BUILD, "CMAKE_AUTOUIC", "Default AUTOUIC of targets."),
+    var(
+        BUILD,
+        "CMAKE_AUTOUIC_OPTIONS",
+        "Default AUTOUIC_OPTIONS of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_AUTOUIC_SEARCH_PATHS",
+        "Default AUTOUIC_SEARCH_PATHS of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_AUTOUIC_EXECUTABLE",
+        "Default AUTOUIC_EXECUTABLE of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_BUILD_RPATH",
+        "Default BUILD_RPATH of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_BUILD_RPATH_USE_ORIGIN",
+        "Default BUILD_RPATH_USE_ORIGIN of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_BUILD_WITH_INSTALL_NAME_DIR",
+        "Default BUILD_WITH_INSTALL_NAME_DIR of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_BUILD_WITH_INSTALL_RPATH",
+        "Default BUILD_WITH_INSTALL_RPATH of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_COMPILE_PDB_OUTPUT_DIRECTORY",
+        "Default COMPILE_PDB_OUTPUT_DIRECTORY of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_COMPILE_PDB_OUTPUT_DIRECTORY_<CONFIG>",
+        "Default COMPILE_PDB_OUTPUT_DIRECTORY_<CONFIG> of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_COMPILE_WARNING_AS_ERROR",
+        "Default COMPILE_WARNING_AS_ERROR of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_<CONFIG>_POSTFIX",
+        "Default <CONFIG>_POSTFIX of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_CROSS_CONFIGS",
+        "Configurations available to cross-config builds of Ninja Multi-Config.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_CTEST_ARGUMENTS",
+        "Arguments of ctest run by the test target.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_CUDA_RESOLVE_DEVICE_SYMBOLS",
+        "Default CUDA_RESOLVE_DEVICE_SYMBOLS of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_CUDA_RUNTIME_LIBRARY",
+        "Default CUDA_RUNTIME_LIBRARY of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_CUDA_SEPARABLE_COMPILATION",
+        "Default CUDA_SEPARABLE_COMPILATION of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_DEBUG_POSTFIX",
+        "Default DEBUG_POSTFIX of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_DEFAULT_BUILD_TYPE",
+        "Default configuration of Ninja Multi-Config.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_DEFAULT_CONFIGS",
+        "Default configurations built by Ninja Multi-Config.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_DEPENDS_USE_COMPILER",
+        "Whether Makefiles use compiler generated dependencies.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_DISABLE_PRECOMPILE_HEADERS",
+        "Default DISABLE_PRECOMPILE_HEADERS of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_ENABLE_EXPORTS",
+        "Default ENABLE_EXPORTS of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_EXE_LINKER_FLAGS",
+        "Linker flags of executables.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_EXE_LINKER_FLAGS_<CONFIG>",
+        "Linker flags of executables in a configuration.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_EXE_LINKER_FLAGS_<CONFIG>_INIT",
+        "Initial linker flags of executables in a configuration.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_EXE_LINKER_FLAGS_INIT",
+        "Initial linker flags of executables.",
+    ),
+    var(BUILD, "CMAKE_FOLDER", "Default FOLDER of targets."),
+    var(BUILD, "CMAKE_FRAMEWORK", "Default FRAMEWORK of targets."),
+    var(
+        BUILD,
+        "CMAKE_FRAMEWORK_MULTI_CONFIG_POSTFIX_<CONFIG>",
+        "Default FRAMEWORK_MULTI_CONFIG_POSTFIX_<CONFIG> of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_Fortran_FORMAT",
+        "Default Fortran_FORMAT of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_Fortran_MODULE_DIRECTORY",
+        "Default Fortran_MODULE_DIRECTORY of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_Fortran_PREPROCESS",
+        "Default Fortran_PREPROCESS of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_GHS_NO_SOURCE_GROUP_FILE",
+        "Whether Green Hills MULTI projects skip source group files.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_GLOBAL_AUTOGEN_TARGET",
+        "Whether to generate a global autogen target.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_GLOBAL_AUTOGEN_TARGET_NAME",
+        "Name of the global autogen target.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_GLOBAL_AUTORCC_TARGET",
+        "Whether to generate a global autorcc target.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_GLOBAL_AUTORCC_TARGET_NAME",
+        "Name of the global autorcc target.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_INSTALL_NAME_DIR",
+        "Default INSTALL_NAME_DIR of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_INSTALL_REMOVE_ENVIRONMENT_RPATH",
+        "Default INSTALL_REMOVE_ENVIRONMENT_RPATH of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_INSTALL_RPATH",
+        "Default INSTALL_RPATH of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_INSTALL_RPATH_USE_LINK_PATH",
+        "Default INSTALL_RPATH_USE_LINK_PATH of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_INTERPROCEDURAL_OPTIMIZATION",
+        "Default INTERPROCEDURAL_OPTIMIZATION of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_INTERPROCEDURAL_OPTIMIZATION_<CONFIG>",
+        "Default INTERPROCEDURAL_OPTIMIZATION_<CONFIG> of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_IOS_INSTALL_COMBINED",
+        "Default IOS_INSTALL_COMBINED of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_<LANG>_CLANG_TIDY",
+        "Default <LANG>_CLANG_TIDY of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_<LANG>_CLANG_TIDY_EXPORT_FIXES_DIR",
+        "Default <LANG>_CLANG_TIDY_EXPORT_FIXES_DIR of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_<LANG>_COMPILER_LAUNCHER",
+        "Default <LANG>_COMPILER_LAUNCHER of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_<LANG>_CPPCHECK",
+        "Default <LANG>_CPPCHECK of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_<LANG>_CPPLINT",
+        "Default <LANG>_CPPLINT of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_<LANG>_INCLUDE_WHAT_YOU_USE",
+        "Default <LANG>_INCLUDE_WHAT_YOU_USE of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_<LANG>_LINK_GROUP_USING_<FEATURE>",
+        "Link group feature of the language.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_<LANG>_LINK_LIBRARY_FILE_FLAG",
+        "Flag linking a library by file path.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_<LANG>_LINK_LIBRARY_FLAG",
+        "Flag linking a library by name.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_<LANG>_LINK_LIBRARY_USING_<FEATURE>",
+        "Link library feature of the language.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_<LANG>_LINK_WHAT_YOU_USE_FLAG",
+        "Linker flag of LINK_WHAT_YOU_USE.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_<LANG>_LINKER_LAUNCHER",
+        "Default <LANG>_LINKER_LAUNCHER of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_<LANG>_VISIBILITY_PRESET",
+        "Default <LANG>_VISIBILITY_PRESET of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_LIBRARY_OUTPUT_DIRECTORY",
+        "Default LIBRARY_OUTPUT_DIRECTORY of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_LIBRARY_OUTPUT_DIRECTORY_<CONFIG>",
+        "Default LIBRARY_OUTPUT_DIRECTORY_<CONFIG> of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_LIBRARY_PATH_FLAG",
+        "Flag adding a library search path.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_LINK_DEF_FILE_FLAG",
+        "Flag linking a module definition file.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_LINK_DEPENDS_NO_SHARED",
+        "Default LINK_DEPENDS_NO_SHARED of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_LINK_GROUP_USING_<FEATURE>",
+        "Link group feature.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_LINK_INTERFACE_LIBRARIES",
+        "Default LINK_INTERFACE_LIBRARIES of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_LINK_LIBRARY_FILE_FLAG",
+        "Flag linking a library by file path.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_LINK_LIBRARY_FLAG",
+        "Flag linking a library by name.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_LINK_LIBRARY_USING_<FEATURE>",
+        "Link library feature.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_LINK_WHAT_YOU_USE",
+        "Default LINK_WHAT_YOU_USE of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_LINK_WHAT_YOU_USE_CHECK",
+        "Command checking LINK_WHAT_YOU_USE.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_MACOSX_BUNDLE",
+        "Default MACOSX_BUNDLE of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_MACOSX_RPATH",
+        "Default MACOSX_RPATH of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_MAP_IMPORTED_CONFIG_<CONFIG>",
+        "Default MAP_IMPORTED_CONFIG_<CONFIG> of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_MODULE_LINKER_FLAGS",
+        "Linker flags of modules.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_MODULE_LINKER_FLAGS_<CONFIG>",
+        "Linker flags of modules in a configuration.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_MODULE_LINKER_FLAGS_<CONFIG>_INIT",
+        "Initial linker flags of modules in a configuration.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_MODULE_LINKER_FLAGS_INIT",
+        "Initial linker flags of modules.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_MSVC_DEBUG_INFORMATION_FORMAT",
+        "Default MSVC_DEBUG_INFORMATION_FORMAT of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_MSVC_RUNTIME_LIBRARY",
+        "Default MSVC_RUNTIME_LIBRARY of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_MSVCIDE_RUN_PATH",
+        "Extra PATH of custom commands in Visual Studio.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_NINJA_OUTPUT_PATH_PREFIX",
+        "Prefix of output paths of Ninja.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_NO_BUILTIN_CHRPATH",
+        "Whether to use relinking instead of the builtin chrpath.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_NO_SYSTEM_FROM_IMPORTED",
+        "Default NO_SYSTEM_FROM_IMPORTED of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_OPTIMIZE_DEPENDENCIES",
+        "Default OPTIMIZE_DEPENDENCIES of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_OSX_ARCHITECTURES",
+        "Target architectures of macOS and iOS.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_OSX_DEPLOYMENT_TARGET",
+        "Minimum version of macOS to deploy to.",
+    ),
+    var(BUILD, "CMAKE_OSX_SYSROOT", "Location of the macOS SDK."),
+    var(
+        BUILD,
+        "CMAKE_PCH_INSTANTIATE_TEMPLATES",
+        "Default PCH_INSTANTIATE_TEMPLATES of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_PCH_WARN_INVALID",
+        "Default PCH_WARN_INVALID of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_PDB_OUTPUT_DIRECTORY",
+        "Default PDB_OUTPUT_DIRECTORY of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_PDB_OUTPUT_DIRECTORY_<CONFIG>",
+        "Default PDB_OUTPUT_DIRECTORY_<CONFIG> of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_PLATFORM_NO_VERSIONED_SONAME",
+        "Whether shared libraries have no versioned soname.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_POSITION_INDEPENDENT_CODE",
+        "Default POSITION_INDEPENDENT_CODE of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_RUNTIME_OUTPUT_DIRECTORY",
+        "Default RUNTIME_OUTPUT_DIRECTORY of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_RUNTIME_OUTPUT_DIRECTORY_<CONFIG>",
+        "Default RUNTIME_OUTPUT_DIRECTORY_<CONFIG> of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_SHARED_LINKER_FLAGS",
+        "Linker flags of shared libraries.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_SHARED_LINKER_FLAGS_<CONFIG>",
+        "Linker flags of shared libraries in a configuration.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_SHARED_LINKER_FLAGS_<CONFIG>_INIT",
+        "Initial linker flags of shared libraries in a configuration.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_SHARED_LINKER_FLAGS_INIT",
+        "Initial linker flags of shared libraries.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_SKIP_BUILD_RPATH",
+        "Whether to skip run time paths in the build tree.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_SKIP_INSTALL_RPATH",
+        "Whether to skip run time paths in the install tree.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_STATIC_LINKER_FLAGS",
+        "Archiver flags of static libraries.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_STATIC_LINKER_FLAGS_<CONFIG>",
+        "Archiver flags of static libraries in a configuration.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_STATIC_LINKER_FLAGS_<CONFIG>_INIT",
+        "Initial archiver flags of static libraries in a configuration.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_STATIC_LINKER_FLAGS_INIT",
+        "Initial archiver flags of static libraries.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_TRY_COMPILE_CONFIGURATION",
+        "Configuration of try_compile() and try_run() projects.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_TRY_COMPILE_NO_PLATFORM_VARIABLES",
+        "Whether try_compile() skips platform variables.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_TRY_COMPILE_PLATFORM_VARIABLES",
+        "Variables propagated to try_compile() projects.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_TRY_COMPILE_TARGET_TYPE",
+        "Target type of try_compile() projects.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_UNITY_BUILD",
+        "Default UNITY_BUILD of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_UNITY_BUILD_BATCH_SIZE",
+        "Default UNITY_BUILD_BATCH_SIZE of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_UNITY_BUILD_UNIQUE_ID",
+        "Default UNITY_BUILD_UNIQUE_ID of targets.",
+    ),
+    var(BUILD, "CMAKE_USE_RELATIVE_PATHS", "Removed, has no effect."),
+    var(
+        BUILD,
+        "CMAKE_VERIFY_INTERFACE_HEADER_SETS",
+        "Whether to verify interface header sets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_VISIBILITY_INLINES_HIDDEN",
+        "Default VISIBILITY_INLINES_HIDDEN of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_VS_GLOBALS",
+        "Default VS_GLOBAL_<variable> of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_VS_INCLUDE_INSTALL_TO_DEFAULT_BUILD",
+        "Whether Visual Studio builds INSTALL by default.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_VS_INCLUDE_PACKAGE_TO_DEFAULT_BUILD",
+        "Whether Visual Studio builds PACKAGE by default.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_VS_JUST_MY_CODE_DEBUGGING",
+        "Default VS_JUST_MY_CODE_DEBUGGING of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_VS_SDK_EXCLUDE_DIRECTORIES",
+        "Visual Studio SDK exclude directories.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_VS_SDK_EXECUTABLE_DIRECTORIES",
+        "Visual Studio SDK executable directories.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_VS_SDK_INCLUDE_DIRECTORIES",
+        "Visual Studio SDK include directories.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_VS_SDK_LIBRARY_DIRECTORIES",
+        "Visual Studio SDK library directories.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_VS_SDK_REFERENCE_DIRECTORIES",
+        "Visual Studio SDK reference directories.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_VS_SDK_SOURCE_DIRECTORIES",
+        "Visual Studio SDK source directories.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_VS_WINRT_BY_DEFAULT",
+        "Whether Visual Studio targets WinRT by default.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_WATCOM_RUNTIME_LIBRARY",
+        "Default WATCOM_RUNTIME_LIBRARY of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_WIN32_EXECUTABLE",
+        "Default WIN32_EXECUTABLE of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_WINDOWS_EXPORT_ALL_SYMBOLS",
+        "Default WINDOWS_EXPORT_ALL_SYMBOLS of targets.",
+    ),
+    var(
+        BUILD,
+        "CMAKE_XCODE_ATTRIBUTE_<an-attribute>",
+        "Xcode attribute of the generated project.",
+    ),
+    var(
+        BUILD,
+        "EXECUTABLE_OUTPUT_PATH",
+        "Old output directory of executables.",
+    ),
+    var(
+        BUILD,
+        "LIBRARY_OUTPUT_PATH",
+        "Old output directory of libraries.",
+    ),
+    // Variables for languages
+    read_only(
+        LANGUAGE,
+        "CMAKE_C_COMPILE_FEATURES",
+        "C features known to the compiler.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_C_EXTENSIONS",
+        "Default C_EXTENSIONS of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_C_STANDARD",
+        "Default C_STANDARD of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_C_STANDARD_REQUIRED",
+        "Default C_STANDARD_REQUIRED of targets.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_COMPILER_IS_GNUCC",
+        "Whether the C compiler is GNU.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_COMPILER_IS_GNUCXX",
+        "Whether the C++ compiler is GNU.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_COMPILER_IS_GNUG77",
+        "Whether the Fortran compiler is GNU.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_CUDA_ARCHITECTURES",
+        "Default CUDA_ARCHITECTURES of targets.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_CUDA_COMPILE_FEATURES",
+        "CUDA features known to the compiler.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_CUDA_EXTENSIONS",
+        "Default CUDA_EXTENSIONS of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_CUDA_HOST_COMPILER",
+        "Host compiler of CUDA.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_CUDA_STANDARD",
+        "Default CUDA_STANDARD of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_CUDA_STANDARD_REQUIRED",
+        "Default CUDA_STANDARD_REQUIRED of targets.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_CUDA_TOOLKIT_INCLUDE_DIRECTORIES",
+        "Include directories of the CUDA toolkit.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_CXX_COMPILE_FEATURES",
+        "C++ features known to the compiler.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_CXX_EXTENSIONS",
+        "Default CXX_EXTENSIONS of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_CXX_SCAN_FOR_MODULES",
+        "Default CXX_SCAN_FOR_MODULES of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_CXX_STANDARD",
+        "Default CXX_STANDARD of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_CXX_STANDARD_REQUIRED",
+        "Default CXX_STANDARD_REQUIRED of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_Fortran_MODDIR_DEFAULT",
+        "Default Fortran module output directory.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_Fortran_MODDIR_FLAG",
+        "Flag setting the Fortran module output directory.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_Fortran_MODOUT_FLAG",
+        "Flag enabling Fortran module output.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_HIP_ARCHITECTURES",
+        "Default HIP_ARCHITECTURES of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_HIP_EXTENSIONS",
+        "Default HIP_EXTENSIONS of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_HIP_STANDARD",
+        "Default HIP_STANDARD of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_HIP_STANDARD_REQUIRED",
+        "Default HIP_STANDARD_REQUIRED of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_ISPC_HEADER_DIRECTORY",
+        "Default ISPC_HEADER_DIRECTORY of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_ISPC_HEADER_SUFFIX",
+        "Default ISPC_HEADER_SUFFIX of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_ISPC_INSTRUCTION_SETS",
+        "Default ISPC_INSTRUCTION_SETS of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_ANDROID_TOOLCHAIN_MACHINE",
+        "Android toolchain machine of the language.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_ANDROID_TOOLCHAIN_PREFIX",
+        "Android toolchain prefix of the language.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_ANDROID_TOOLCHAIN_SUFFIX",
+        "Android toolchain suffix of the language.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_ARCHIVE_APPEND",
+        "Rule appending to a static archive.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_ARCHIVE_CREATE",
+        "Rule creating a static archive.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_ARCHIVE_FINISH",
+        "Rule finishing a static archive.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_BYTE_ORDER",
+        "Byte order of the target architecture.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_COMPILE_OBJECT",
+        "Rule compiling an object file.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_COMPILER",
+        "Full path to the compiler of the language.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_<LANG>_COMPILER_ABI",
+        "ABI of the compiler.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_<LANG>_COMPILER_ARCHITECTURE_ID",
+        "Target architecture of the compiler.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_COMPILER_EXTERNAL_TOOLCHAIN",
+        "External toolchain of cross compilers.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_<LANG>_COMPILER_ID",
+        "Identification of the compiler vendor.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_<LANG>_COMPILER_LOADED",
+        "Whether the language is enabled.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_<LANG>_COMPILER_PREDEFINES_COMMAND",
+        "Command printing the predefined macros of the compiler.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_COMPILER_TARGET",
+        "Target triple of cross compilers.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_<LANG>_COMPILER_VERSION",
+        "Version of the compiler.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_CREATE_SHARED_LIBRARY",
+        "Rule creating a shared library.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_CREATE_SHARED_MODULE",
+        "Rule creating a shared module.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_CREATE_STATIC_LIBRARY",
+        "Rule creating a static library.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_EXTENSIONS",
+        "Default <LANG>_EXTENSIONS of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_EXTENSIONS_DEFAULT",
+        "Default of compiler extensions.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_FLAGS",
+        "Compiler flags of the language.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_FLAGS_<CONFIG>",
+        "Compiler flags of the language in a configuration.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_FLAGS_<CONFIG>_INIT",
+        "Initial compiler flags of the language in a configuration.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_FLAGS_DEBUG",
+        "Compiler flags of the Debug configuration.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_FLAGS_DEBUG_INIT",
+        "Initial compiler flags of the Debug configuration.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_FLAGS_INIT",
+        "Initial compiler flags of the language.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_FLAGS_MINSIZEREL",
+        "Compiler flags of the MinSizeRel configuration.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_FLAGS_MINSIZEREL_INIT",
+        "Initial compiler flags of the MinSizeRel configuration.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_FLAGS_RELEASE",
+        "Compiler flags of the Release configuration.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_FLAGS_RELEASE_INIT",
+        "Initial compiler flags of the Release configuration.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_FLAGS_RELWITHDEBINFO",
+        "Compiler flags of the RelWithDebInfo configuration.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_FLAGS_RELWITHDEBINFO_INIT",
+        "Initial compiler flags of the RelWithDebInfo configuration.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_IGNORE_EXTENSIONS",
+        "File extensions ignored by the language.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_<LANG>_IMPLICIT_INCLUDE_DIRECTORIES",
+        "Include directories searched by the compiler.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_<LANG>_IMPLICIT_LINK_DIRECTORIES",
+        "Link directories searched by the compiler.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_<LANG>_IMPLICIT_LINK_FRAMEWORK_DIRECTORIES",
+        "Framework directories searched by the compiler.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_<LANG>_IMPLICIT_LINK_LIBRARIES",
+        "Libraries linked by the compiler.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_<LANG>_LIBRARY_ARCHITECTURE",
+        "Target architecture library directory of the language.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_LINK_EXECUTABLE",
+        "Rule linking an executable.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_LINKER_PREFERENCE",
+        "Preference of the language as linker language.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_LINKER_PREFERENCE_PROPAGATES",
+        "Whether the linker preference propagates to dependents.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_LINKER_WRAPPER_FLAG",
+        "Flag passing options to the linker through the compiler.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_LINKER_WRAPPER_FLAG_SEP",
+        "Separator of options passed to the linker.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_OUTPUT_EXTENSION",
+        "Extension of object files of the language.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_<LANG>_SIMULATE_ID",
+        "Identification of the compiler the compiler simulates.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_<LANG>_SIMULATE_VERSION",
+        "Version of the compiler the compiler simulates.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_<LANG>_SIZEOF_DATA_PTR",
+        "Size of a data pointer of the language.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_SOURCE_FILE_EXTENSIONS",
+        "Source file extensions of the language.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_STANDARD",
+        "Default <LANG>_STANDARD of targets.",
+    ),
+    read_only(
+        LANGUAGE,
+        "CMAKE_<LANG>_STANDARD_DEFAULT",
+        "Default standard of the compiler.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_STANDARD_INCLUDE_DIRECTORIES",
+        "Include directories of every compilation of the language.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_STANDARD_LIBRARIES",
+        "Libraries linked to every target of the language.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_<LANG>_STANDARD_REQUIRED",
+        "Default <LANG>_STANDARD_REQUIRED of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_OBJC_EXTENSIONS",
+        "Default OBJC_EXTENSIONS of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_OBJC_STANDARD",
+        "Default OBJC_STANDARD of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_OBJC_STANDARD_REQUIRED",
+        "Default OBJC_STANDARD_REQUIRED of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_OBJCXX_EXTENSIONS",
+        "Default OBJCXX_EXTENSIONS of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_OBJCXX_STANDARD",
+        "Default OBJCXX_STANDARD of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_OBJCXX_STANDARD_REQUIRED",
+        "Default OBJCXX_STANDARD_REQUIRED of targets.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_Swift_LANGUAGE_VERSION",
+        "Default Swift language version.",
+    ),
+    var(
+        LANGUAGE,
+        "CMAKE_USER_MAKE_RULES_OVERRIDE_<LANG>",
+        "File overriding platform information of the language.",
+    ),
+    // Variables for CTest
+    var(
+        CTEST,
+        "CTEST_BINARY_DIRECTORY",
+        "Build directory of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_BUILD_COMMAND",
+        "Build command of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_BUILD_NAME",
+        "Build name of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_BZR_COMMAND",
+        "Bazaar command of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_BZR_UPDATE_OPTIONS",
+        "Bazaar update options of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CHANGE_ID",
+        "Change id of the dashboard submission.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CHECKOUT_COMMAND",
+        "Checkout command of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CONFIGURATION_TYPE",
+        "Configuration of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CONFIGURE_COMMAND",
+        "Configure command of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_COVERAGE_COMMAND",
+        "Coverage command of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_COVERAGE_EXTRA_FLAGS",
+        "Extra flags of the coverage command.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CUSTOM_COVERAGE_EXCLUDE",
+        "Files excluded from coverage.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CUSTOM_ERROR_EXCEPTION",
+        "Regular expressions of lines that are not errors.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CUSTOM_ERROR_MATCH",
+        "Regular expressions of error lines.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CUSTOM_ERROR_POST_CONTEXT",
+        "Lines after an error included in reports.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CUSTOM_ERROR_PRE_CONTEXT",
+        "Lines before an error included in reports.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CUSTOM_MAXIMUM_FAILED_TEST_OUTPUT_SIZE",
+        "Maximum output size of failed tests.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CUSTOM_MAXIMUM_NUMBER_OF_ERRORS",
+        "Maximum number of reported errors.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CUSTOM_MAXIMUM_NUMBER_OF_WARNINGS",
+        "Maximum number of reported warnings.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CUSTOM_MAXIMUM_PASSED_TEST_OUTPUT_SIZE",
+        "Maximum output size of passed tests.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CUSTOM_MEMCHECK_IGNORE",
+        "Tests excluded from memory checks.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CUSTOM_POST_MEMCHECK",
+        "Commands run after memory checks.",
+    ),
+    var(CTEST, "CTEST_CUSTOM_POST_TEST", "Commands run after tests."),
+    var(
+        CTEST,
+        "CTEST_CUSTOM_PRE_MEMCHECK",
+        "Commands run before memory checks.",
+    ),
+    var(CTEST, "CTEST_CUSTOM_PRE_TEST", "Commands run before tests."),
+    var(
+        CTEST,
+        "CTEST_CUSTOM_TESTS_IGNORE",
+        "Tests that are not run.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CUSTOM_WARNING_EXCEPTION",
+        "Regular expressions of lines that are not warnings.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CUSTOM_WARNING_MATCH",
+        "Regular expressions of warning lines.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CVS_CHECKOUT",
+        "Deprecated, use CTEST_CHECKOUT_COMMAND.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CVS_COMMAND",
+        "CVS command of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_CVS_UPDATE_OPTIONS",
+        "CVS update options of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_DROP_LOCATION",
+        "Submission location of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_DROP_METHOD",
+        "Submission method of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_DROP_SITE",
+        "Submission site of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_DROP_SITE_CDASH",
+        "Whether the submission site is CDash.",
+    ),
+    var(
+        CTEST,
+        "CTEST_DROP_SITE_PASSWORD",
+        "Submission site password of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_DROP_SITE_USER",
+        "Submission site user of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_EXTRA_COVERAGE_GLOB",
+        "Extra files considered for coverage.",
+    ),
+    var(
+        CTEST,
+        "CTEST_GIT_COMMAND",
+        "Git command of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_GIT_INIT_SUBMODULES",
+        "Whether to initialize Git submodules.",
+    ),
+    var(
+        CTEST,
+        "CTEST_GIT_UPDATE_CUSTOM",
+        "Custom Git update command.",
+    ),
+    var(
+        CTEST,
+        "CTEST_GIT_UPDATE_OPTIONS",
+        "Git update options of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_HG_COMMAND",
+        "Mercurial command of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_HG_UPDATE_OPTIONS",
+        "Mercurial update options of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_LABELS_FOR_SUBPROJECTS",
+        "Labels treated as subprojects.",
+    ),
+    var(
+        CTEST,
+        "CTEST_MEMORYCHECK_COMMAND",
+        "Memory check command of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_MEMORYCHECK_COMMAND_OPTIONS",
+        "Options of the memory check command.",
+    ),
+    var(
+        CTEST,
+        "CTEST_MEMORYCHECK_SANITIZER_OPTIONS",
+        "Options of sanitizer memory checks.",
+    ),
+    var(
+        CTEST,
+        "CTEST_MEMORYCHECK_SUPPRESSIONS_FILE",
+        "Suppressions file of the memory check command.",
+    ),
+    var(CTEST, "CTEST_MEMORYCHECK_TYPE", "Type of memory checks."),
+    var(
+        CTEST,
+        "CTEST_NIGHTLY_START_TIME",
+        "Start time of nightly dashboards.",
+    ),
+    var(
+        CTEST,
+        "CTEST_P4_CLIENT",
+        "Perforce client of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_P4_COMMAND",
+        "Perforce command of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_P4_OPTIONS",
+        "Perforce options of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_P4_UPDATE_OPTIONS",
+        "Perforce update options of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_RESOURCE_SPEC_FILE",
+        "Resource specification file of tests.",
+    ),
+    var(
+        CTEST,
+        "CTEST_RUN_CURRENT_SCRIPT",
+        "Whether to run the current script.",
+    ),
+    var(CTEST, "CTEST_SCP_COMMAND", "Deprecated, has no effect."),
+    var(
+        CTEST,
+        "CTEST_SCRIPT_DIRECTORY",
+        "Directory of the CTest script.",
+    ),
+    var(CTEST, "CTEST_SITE", "Site name of the dashboard client."),
+    var(
+        CTEST,
+        "CTEST_SOURCE_DIRECTORY",
+        "Source directory of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_SUBMIT_INACTIVITY_TIMEOUT",
+        "Inactivity timeout of submissions.",
+    ),
+    var(
+        CTEST,
+        "CTEST_SUBMIT_URL",
+        "Submission URL of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_SVN_COMMAND",
+        "Subversion command of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_SVN_OPTIONS",
+        "Subversion options of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_SVN_UPDATE_OPTIONS",
+        "Subversion update options of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_TEST_LOAD",
+        "CPU load limit of parallel tests.",
+    ),
+    var(CTEST, "CTEST_TEST_TIMEOUT", "Default timeout of tests."),
+    var(CTEST, "CTEST_TRIGGER_SITE", "Deprecated, has no effect."),
+    var(
+        CTEST,
+        "CTEST_UPDATE_COMMAND",
+        "Update command of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_UPDATE_OPTIONS",
+        "Update options of the dashboard client.",
+    ),
+    var(
+        CTEST,
+        "CTEST_UPDATE_VERSION_ONLY",
+        "Whether to only record the source version.",
+    ),
+    var(
+        CTEST,
+        "CTEST_UPDATE_VERSION_OVERRIDE",
+        "Source version recorded instead of updating.",
+    ),
+    var(
+        CTEST,
+        "CTEST_USE_LAUNCHERS",
+        "Whether to use launchers for build output.",
+    ),
+    // Variables for CPack
+    var(
+        CPACK,
+        "CPACK_ABSOLUTE_DESTINATION_FILES",
+        "Files installed with absolute destinations.",
+    ),
+    var(
+        CPACK,
+        "CPACK_COMPONENT_INCLUDE_TOPLEVEL_DIRECTORY",
+        "Whether component packages include the top level directory.",
+    ),
+    var(
+        CPACK,
+        "CPACK_CUSTOM_INSTALL_VARIABLES",
+        "Variables set for custom install scripts.",
+    ),
+    var(
+        CPACK,
+        "CPACK_ERROR_ON_ABSOLUTE_INSTALL_DESTINATION",
+        "Whether absolute install destinations are an error.",
+    ),
+    var(
+        CPACK,
+        "CPACK_INCLUDE_TOPLEVEL_DIRECTORY",
+        "Whether packages include the top level directory.",
+    ),
+    var(
+        CPACK,
+        "CPACK_INSTALL_DEFAULT_DIRECTORY_PERMISSIONS",
+        "Default permissions of installed directories.",
+    ),
+    var(
+        CPACK,
+        "CPACK_PACKAGING_INSTALL_PREFIX",
+        "Installation prefix inside packages.",
+    ),
+    var(
+        CPACK,
+        "CPACK_SET_DESTDIR",
+        "Whether to install with DESTDIR.",
+    ),
+    var(
+        CPACK,
+        "CPACK_WARN_ON_ABSOLUTE_INSTALL_DESTINATION",
+        "Whether to warn about absolute install destinations.",
+    ),
+];
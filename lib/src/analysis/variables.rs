@@ -0,0 +1,354 @@
+use std::{collections::HashSet, fmt, ops::Range};
+
+use crate::{
+    analysis::{
+        properties::{edit_distance, pattern_match},
+        property_catalog::LANGUAGES,
+        variable_catalog::VARIABLES,
+    },
+    command::scripting::Set,
+    Command, Doc,
+};
+
+/// Kind of variables, following the sections of cmake-variables(7).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VariableCategory {
+    Information,
+    Behavior,
+    System,
+    Build,
+    Language,
+    CTest,
+    CPack,
+}
+
+/// Variable documented by CMake 3.26.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VariableInfo {
+    /// Name, families of variables have placeholders like `<LANG>` or
+    /// `<PackageName>` as in `CMAKE_<LANG>_FLAGS`.
+    pub name: &'static str,
+    pub category: VariableCategory,
+    /// One sentence summary of the documentation.
+    pub description: &'static str,
+    /// Whether the variable is provided by CMake and should not be set.
+    pub read_only: bool,
+}
+
+/// Problem with a variable set or referenced by a command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VariableIssue {
+    /// Index of the command in the analyzed document.
+    pub command: usize,
+    pub variable: String,
+    pub kind: VariableIssueKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum VariableIssueKind {
+    #[error("read-only variable provided by CMake")]
+    ReadOnly,
+    #[error(
+        "unknown CMake variable{}",
+        .suggestion.as_ref().map(|name| format!(", did you mean {name}?")).unwrap_or_default()
+    )]
+    Unknown { suggestion: Option<String> },
+}
+
+impl fmt::Display for VariableCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Information => "Variables that Provide Information",
+            Self::Behavior => "Variables that Change Behavior",
+            Self::System => "Variables that Describe the System",
+            Self::Build => "Variables that Control the Build",
+            Self::Language => "Variables for Languages",
+            Self::CTest => "Variables for CTest",
+            Self::CPack => "Variables for CPack",
+        })
+    }
+}
+
+impl VariableInfo {
+    /// All variables of the catalog.
+    pub fn all() -> &'static [VariableInfo] {
+        VARIABLES
+    }
+
+    /// Finds the variable a name refers to. Exact names take precedence
+    /// over families of variables, and families with fewer placeholders
+    /// over the others.
+    ///
+    /// ```
+    /// use cmake_parser::analysis::VariableInfo;
+    ///
+    /// let info = VariableInfo::find("CMAKE_CXX_FLAGS_RELEASE").unwrap();
+    /// assert_eq!(info.name, "CMAKE_<LANG>_FLAGS_RELEASE");
+    /// assert!(!info.read_only);
+    /// ```
+    pub fn find(name: &str) -> Option<&'static VariableInfo> {
+        VARIABLES.iter().find(|info| info.name == name).or_else(|| {
+            VARIABLES
+                .iter()
+                .filter(|info| info.matches(name))
+                .min_by_key(|info| info.name.matches('<').count())
+        })
+    }
+
+    /// Whether a variable name is this variable or belongs to its family.
+    pub fn matches(&self, name: &str) -> bool {
+        pattern_match(self.name, name)
+    }
+
+    /// Whether the name has a placeholder for names chosen by projects,
+    /// like `<PROJECT-NAME>_SOURCE_DIR` or `<PackageName>_ROOT`.
+    fn has_project_placeholder(&self) -> bool {
+        ["<PROJECT-NAME>", "<PackageName>"]
+            .iter()
+            .any(|placeholder| self.name.contains(placeholder))
+    }
+}
+
+/// Checks the variables set by `set()` and `unset()` and the variables
+/// referenced with `${...}` against the catalog.
+///
+/// Setting a read-only variable is reported. Variables of project-named
+/// families like `<PROJECT-NAME>_SOURCE_DIR` are only read-only for
+/// projects declared with `project()`. Unknown `CMAKE_` variables are
+/// reported with the most similar known name when set, and when referenced
+/// without being set in the document.
+///
+/// ```
+/// use cmake_parser::{analysis, parse_cmakelists, Doc};
+///
+/// let cmakelists = parse_cmakelists(b"message(${CMAKE_SORUCE_DIR})\n").unwrap();
+/// let issues = analysis::check_variables(&Doc::from(cmakelists));
+/// assert_eq!(
+///     issues[0].kind.to_string(),
+///     "unknown CMake variable, did you mean CMAKE_SOURCE_DIR?"
+/// );
+/// ```
+pub fn check_variables(doc: &Doc) -> Vec<VariableIssue> {
+    let writes: Vec<Vec<String>> = doc
+        .to_commands_iter()
+        .map(|command| match command {
+            Ok(Command::Set(set)) => match set.as_ref() {
+                Set::Normal(set) => vec![set.variable.to_string()],
+                Set::Cache(set) => vec![set.variable.to_string()],
+            },
+            Ok(Command::Unset(unset)) => vec![unset.variable.to_string()],
+            _ => vec![],
+        })
+        .collect();
+    let projects: Vec<String> = doc
+        .to_commands_iter()
+        .filter_map(|command| match command {
+            Ok(Command::Project(project)) => Some(project.project_name.to_string()),
+            _ => None,
+        })
+        .collect();
+    let written: HashSet<&String> = writes.iter().flatten().collect();
+
+    let mut issues = vec![];
+    for (index, (writes, arguments)) in writes.iter().zip(doc.arguments()).enumerate() {
+        let mut issue = |variable: &str, kind| {
+            issues.push(VariableIssue {
+                command: index,
+                variable: variable.to_string(),
+                kind,
+            })
+        };
+        for variable in writes {
+            match VariableInfo::find(variable) {
+                Some(info) if info.read_only => {
+                    let declared = !info.has_project_placeholder()
+                        || projects.iter().any(|project| {
+                            variable
+                                .strip_prefix(project.as_str())
+                                .is_some_and(|rest| rest.starts_with('_'))
+                        });
+                    if declared {
+                        issue(variable, VariableIssueKind::ReadOnly);
+                    }
+                }
+                Some(_) => (),
+                None if variable.starts_with("CMAKE_") => issue(
+                    variable,
+                    VariableIssueKind::Unknown {
+                        suggestion: suggestion(variable),
+                    },
+                ),
+                None => (),
+            }
+        }
+
+        let mut seen = HashSet::new();
+        for argument in &arguments {
+            for variable in references(&argument.to_string()) {
+                if !variable.starts_with("CMAKE_")
+                    || written.contains(&variable)
+                    || VariableInfo::find(&variable).is_some()
+                    || !seen.insert(variable.clone())
+                {
+                    continue;
+                }
+                issue(
+                    &variable,
+                    VariableIssueKind::Unknown {
+                        suggestion: suggestion(&variable),
+                    },
+                );
+            }
+        }
+    }
+    issues
+}
+
+/// Names of the variables referenced with `${...}` or `$CACHE{...}` whose
+/// names are not themselves computed from references.
+fn references(text: &str) -> Vec<String> {
+    reference_ranges(text)
+        .into_iter()
+        .map(|range| text[range].to_string())
+        .collect()
+}
+
+/// Ranges of the names of [`references`] in the text.
+pub(crate) fn reference_ranges(text: &str) -> Vec<Range<usize>> {
+    let is_name = |ch: u8| ch.is_ascii_alphanumeric() || b"/_.+-".contains(&ch);
+    let bytes = text.as_bytes();
+    let mut names = vec![];
+    for (start, _) in text.match_indices('$') {
+        let rest = &text[start + 1..];
+        let Some(open) = ["{", "CACHE{"]
+            .iter()
+            .find_map(|prefix| rest.strip_prefix(prefix))
+            .map(|name| bytes.len() - name.len())
+        else {
+            continue;
+        };
+        let end = bytes[open..]
+            .iter()
+            .position(|ch| !is_name(*ch))
+            .map_or(bytes.len(), |end| open + end);
+        if end > open && bytes.get(end) == Some(&b'}') {
+            names.push(open..end);
+        }
+    }
+    names
+}
+
+/// Most similar known variable within two edits.
+fn suggestion(name: &str) -> Option<String> {
+    VARIABLES
+        .iter()
+        .flat_map(|info| match info.name.split_once("<LANG>") {
+            None if !info.name.contains('<') => vec![info.name.to_string()],
+            Some((prefix, suffix)) if !prefix.contains('<') && !suffix.contains('<') => LANGUAGES
+                .iter()
+                .map(|language| format!("{prefix}{language}{suffix}"))
+                .collect(),
+            _ => vec![],
+        })
+        .map(|candidate| (edit_distance(name, &candidate), candidate))
+        .filter(|(distance, _)| *distance <= 2)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn variable_catalog() {
+        let find = |name| VariableInfo::find(name).map(|info| info.name);
+        assert_eq!(find("CMAKE_SOURCE_DIR"), Some("CMAKE_SOURCE_DIR"));
+        assert_eq!(
+            find("CMAKE_CXX_COMPILER_ID"),
+            Some("CMAKE_<LANG>_COMPILER_ID")
+        );
+        assert_eq!(
+            find("CMAKE_C_FLAGS_DEBUG"),
+            Some("CMAKE_<LANG>_FLAGS_DEBUG")
+        );
+        assert_eq!(
+            find("CMAKE_C_FLAGS_PROFILE"),
+            Some("CMAKE_<LANG>_FLAGS_<CONFIG>")
+        );
+        assert_eq!(find("Boost_ROOT"), Some("<PackageName>_ROOT"));
+        assert_eq!(find("CMAKE_MATCH_1"), Some("CMAKE_MATCH_<n>"));
+        assert_eq!(find("CMAKE_RUST_FLAGS"), None);
+
+        let info = VariableInfo::find("CMAKE_CURRENT_LIST_DIR").unwrap();
+        assert_eq!(info.category, VariableCategory::Information);
+        assert!(info.read_only);
+        assert_eq!(
+            info.category.to_string(),
+            "Variables that Provide Information"
+        );
+        assert!(!VariableInfo::find("CMAKE_BUILD_TYPE").unwrap().read_only);
+
+        let mut names = HashSet::new();
+        for info in VariableInfo::all() {
+            assert!(names.insert(info.name), "duplicate {}", info.name);
+            assert!(!info.description.is_empty(), "{}", info.name);
+        }
+    }
+
+    #[test]
+    fn variable_references() {
+        assert_eq!(
+            references("${A}/${B_${C}}/$ENV{D}/$CACHE{E}/${}/$"),
+            vec!["A".to_string(), "C".to_string(), "E".to_string()]
+        );
+    }
+
+    #[test]
+    fn check() {
+        let src = include_bytes!("../../../fixture/analysis/variables/check");
+        let cmakelists = parse_cmakelists(src).unwrap();
+        let doc = Doc::from(cmakelists);
+        let issues: Vec<_> = check_variables(&doc)
+            .into_iter()
+            .map(|issue| (issue.command, issue.variable, issue.kind.to_string()))
+            .collect();
+        assert_eq!(
+            issues,
+            vec![
+                (
+                    2,
+                    "CMAKE_SOURCE_DIR".to_string(),
+                    "read-only variable provided by CMake".to_string()
+                ),
+                (
+                    3,
+                    "CMAKE_CXX_STANDART".to_string(),
+                    "unknown CMake variable, did you mean CMAKE_CXX_STANDARD?".to_string()
+                ),
+                (
+                    4,
+                    "demo_SOURCE_DIR".to_string(),
+                    "read-only variable provided by CMake".to_string()
+                ),
+                (
+                    7,
+                    "CMAKE_CURRENT_SOURCE_DIRECTORY".to_string(),
+                    "unknown CMake variable".to_string()
+                ),
+                (
+                    7,
+                    "CMAKE_BINRY_DIR".to_string(),
+                    "unknown CMake variable, did you mean CMAKE_BINARY_DIR?".to_string()
+                ),
+                (
+                    9,
+                    "PROJECT_NAME".to_string(),
+                    "read-only variable provided by CMake".to_string()
+                ),
+            ]
+        );
+    }
+}
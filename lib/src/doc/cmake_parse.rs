@@ -85,6 +85,43 @@ pub trait CMakeParse<'t>: 't + Sized {
     fn reset_mode() -> bool {
         false
     }
+
+    /// Adds the keywords accepted by the parser, including those of nested
+    /// parsers.
+    fn keywords(#[allow(unused_variables)] keywords: &mut KeywordSet) {}
+}
+
+/// Keywords collected by [`CMakeParse::keywords`] in the order they were
+/// added, without duplicates.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct KeywordSet {
+    keywords: Vec<&'static str>,
+    visited: Vec<&'static str>,
+}
+
+impl KeywordSet {
+    /// Keywords of `T`.
+    pub fn of<'t, T: CMakeParse<'t>>() -> Vec<&'static str> {
+        let mut keywords = Self::default();
+        keywords.visit::<T>();
+        keywords.keywords
+    }
+
+    pub fn insert(&mut self, keyword: &'static str) {
+        if !self.keywords.contains(&keyword) {
+            self.keywords.push(keyword);
+        }
+    }
+
+    /// Adds the keywords of `T` unless they were already added, which stops
+    /// the recursion of nested parsers.
+    pub fn visit<'t, T: CMakeParse<'t>>(&mut self) {
+        let name = std::any::type_name::<T>();
+        if !self.visited.contains(&name) {
+            self.visited.push(name);
+            T::keywords(self);
+        }
+    }
 }
 
 impl<'t> CMakeParse<'t> for Token<'t> {
@@ -151,6 +188,10 @@ where
     fn reset_mode() -> bool {
         T::reset_mode()
     }
+
+    fn keywords(keywords: &mut KeywordSet) {
+        keywords.visit::<T>()
+    }
 }
 
 impl<'t> CMakeParse<'t> for bool {
@@ -220,6 +261,10 @@ where
     fn rest<'tv>(tokens: &'tv [Token<'t>]) -> &'tv [Token<'t>] {
         T::rest(tokens)
     }
+
+    fn keywords(keywords: &mut KeywordSet) {
+        keywords.visit::<T>()
+    }
 }
 
 impl<'t, T> CMakeParse<'t> for Box<T>
@@ -253,6 +298,10 @@ where
     fn reset_mode() -> bool {
         T::reset_mode()
     }
+
+    fn keywords(keywords: &mut KeywordSet) {
+        keywords.visit::<T>()
+    }
 }
 
 impl<'t, T1, T2> CMakeParse<'t> for (T1, T2)
@@ -264,6 +313,11 @@ where
         T1::parse(tokens)
             .and_then(|(t1, tokens)| T2::parse(tokens).map(|(t2, tokens)| ((t1, t2), tokens)))
     }
+
+    fn keywords(keywords: &mut KeywordSet) {
+        keywords.visit::<T1>();
+        keywords.visit::<T2>();
+    }
 }
 
 #[cfg(test)]
@@ -432,10 +486,7 @@ pub(crate) mod tests {
 
         let mut current_mode = default_mode;
 
-        loop {
-            let Some((first, rest)) = tokens.split_first() else {
-                break;
-            };
+        while let Some((first, rest)) = tokens.split_first() {
             tokens = rest;
             let keyword = first.as_bytes();
             if field.matches(field_keyword, keyword, tokens) {
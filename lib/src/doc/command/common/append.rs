@@ -1,7 +1,7 @@
 use cmake_parser_derive::CMake;
 
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "append")]
 pub enum Append {
     After,
     Before,
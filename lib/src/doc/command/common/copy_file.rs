@@ -3,7 +3,7 @@ use cmake_parser_derive::CMake;
 use crate::Token;
 
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "copy_file")]
 pub struct CopyFile<'t> {
     #[cmake(positional)]
     pub file_name: Token<'t>,
@@ -3,7 +3,7 @@ use cmake_parser_derive::CMake;
 use crate::command::common::{FileMatchKind, Permission};
 
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", match_fields)]
+#[cmake(pkg = "crate", match_fields, visit = "file_match")]
 pub struct FileMatch<'t> {
     pub kind: Option<FileMatchKind<'t>>,
     pub exclude: bool,
@@ -3,7 +3,7 @@ use cmake_parser_derive::CMake;
 use crate::Token;
 
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", transparent)]
+#[cmake(pkg = "crate", transparent, visit = "file_match_kind")]
 pub enum FileMatchKind<'t> {
     Pattern(Token<'t>),
     #[cmake(rename = "REGEX")]
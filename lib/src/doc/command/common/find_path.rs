@@ -3,7 +3,7 @@ use cmake_parser_derive::CMake;
 use crate::Token;
 
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", untagged)]
+#[cmake(pkg = "crate", untagged, visit = "common_find_path")]
 pub enum FindPath<'t> {
     #[cmake(transparent)]
     Env(Token<'t>),
@@ -3,7 +3,7 @@ use cmake_parser_derive::CMake;
 use crate::Token;
 
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "lang_extensions")]
 pub enum LangExtensions<'t> {
     #[cmake(rename = "C_EXTENSIONS")]
     C(Token<'t>),
@@ -3,7 +3,7 @@ use cmake_parser_derive::CMake;
 use crate::Token;
 
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "lang_standard_required")]
 pub enum LangStandardRequired<'t> {
     #[cmake(rename = "C_STANDARD_REQUIRED")]
     C(Token<'t>),
@@ -3,7 +3,7 @@ use cmake_parser_derive::CMake;
 use crate::Token;
 
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", untagged, complete)]
+#[cmake(pkg = "crate", untagged, complete, visit = "names")]
 pub enum Names<'t> {
     #[cmake(rename = "NAMES", transparent)]
     Multi(Vec<Token<'t>>),
@@ -1,7 +1,7 @@
 use cmake_parser_derive::CMake;
 
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", list)]
+#[cmake(pkg = "crate", list, visit = "newline_style")]
 pub enum NewlineStyle {
     Unix,
     Dos,
@@ -3,7 +3,7 @@ use cmake_parser_derive::CMake;
 use crate::Token;
 
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", positional)]
+#[cmake(pkg = "crate", positional, visit = "property")]
 pub struct Property<'t> {
     pub prop: Token<'t>,
     pub value: Token<'t>,
@@ -3,7 +3,7 @@ use cmake_parser_derive::CMake;
 use crate::Token;
 
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", untagged)]
+#[cmake(pkg = "crate", untagged, visit = "source_alt")]
 pub enum SourceAlt<'t> {
     #[cmake(transparent)]
     Sources(Vec<Token<'t>>),
@@ -1,7 +1,7 @@
 use cmake_parser_derive::CMake;
 
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", list)]
+#[cmake(pkg = "crate", list, visit = "windows_registry_view")]
 pub enum WindowsRegistryView {
     #[cmake(rename = "64")]
     Bits64,
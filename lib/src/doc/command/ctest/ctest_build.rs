@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/ctest_build.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", allow_empty)]
+#[cmake(pkg = "crate", allow_empty, visit = "ctest_build")]
 pub struct CTestBuild<'t> {
     #[cmake(rename = "BUILD")]
     pub build_dir: Option<Token<'t>>,
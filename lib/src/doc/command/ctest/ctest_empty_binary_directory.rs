@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/ctest_empty_binary_directory.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", positional)]
+#[cmake(pkg = "crate", positional, visit = "ctest_empty_binary_directory")]
 pub struct CTestEmptyBinaryDirectory<'t> {
     pub directory: Token<'t>,
 }
@@ -10,7 +10,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/ctest_memcheck.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", allow_empty)]
+#[cmake(pkg = "crate", allow_empty, visit = "ctest_memcheck")]
 pub struct CTestMemCheck<'t> {
     #[cmake(rename = "BUILD")]
     pub build_dir: Option<Token<'t>>,
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/ctest_read_custom_files.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", positional)]
+#[cmake(pkg = "crate", positional, visit = "ctest_read_custom_files")]
 pub struct CTestReadCustomFiles<'t> {
     pub directories: Vec<Token<'t>>,
 }
@@ -9,7 +9,12 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/ctest_run_script.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", allow_empty, default = "scripts")]
+#[cmake(
+    pkg = "crate",
+    allow_empty,
+    default = "scripts",
+    visit = "ctest_run_script"
+)]
 pub struct CTestRunScript<'t> {
     pub new_process: bool,
     #[cmake(rename = "")]
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/ctest_sleep.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", untagged)]
+#[cmake(pkg = "crate", untagged, visit = "ctest_sleep")]
 pub enum CTestSleep<'t> {
     Time(CTestSleepTime<'t>),
     Seconds(CTestSleepSeconds<'t>),
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/ctest_start.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", default = "model")]
+#[cmake(pkg = "crate", default = "model", visit = "ctest_start")]
 pub struct CTestStart<'t> {
     #[cmake(rename = "")]
     pub model: Option<Model<'t>>,
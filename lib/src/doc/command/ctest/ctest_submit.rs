@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/ctest_submit.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", untagged)]
+#[cmake(pkg = "crate", untagged, visit = "ctest_submit")]
 pub enum CTestSubmit<'t> {
     #[cmake(rename = "CDASH_UPLOAD", transparent)]
     CDashUpload(CDashUpload<'t>),
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/ctest_upload.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "ctest_upload")]
 pub struct CTestUpload<'t> {
     pub files: Vec<Token<'t>>,
     pub capture_cmake_error: Option<Token<'t>>,
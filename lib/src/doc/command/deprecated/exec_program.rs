@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/exec_program.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", default = "dir")]
+#[cmake(pkg = "crate", default = "dir", visit = "exec_program")]
 pub struct ExecProgram<'t> {
     #[cmake(positional)]
     pub executable: Token<'t>,
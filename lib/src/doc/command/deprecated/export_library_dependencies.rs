@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/export_library_dependencies.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "export_library_dependencies")]
 pub struct ExportLibraryDependencies<'t> {
     #[cmake(positional)]
     pub file: Token<'t>,
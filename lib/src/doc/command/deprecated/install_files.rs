@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/install_files.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", untagged)]
+#[cmake(pkg = "crate", untagged, visit = "install_files")]
 pub enum InstallFiles<'t> {
     Files(InstallFilesAll<'t>),
     RegEx(InstallFilesRegEx<'t>),
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/install_programs.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", untagged)]
+#[cmake(pkg = "crate", untagged, visit = "install_programs")]
 pub enum InstallPrograms<'t> {
     RegEx(InstallProgramsRegEx<'t>),
     Files(InstallProgramsFiles<'t>),
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/install_targets.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", default = "targets")]
+#[cmake(pkg = "crate", default = "targets", visit = "install_targets")]
 pub struct InstallTargets<'t> {
     #[cmake(positional)]
     pub dir: Token<'t>,
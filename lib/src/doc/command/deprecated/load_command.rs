@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/load_command.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", positional)]
+#[cmake(pkg = "crate", positional, visit = "load_command")]
 pub struct LoadCommand<'t> {
     pub command_name: Token<'t>,
     pub locations: Vec<Token<'t>>,
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/output_required_files.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", positional)]
+#[cmake(pkg = "crate", positional, visit = "output_required_files")]
 pub struct OutputRequiredFiles<'t> {
     pub src_file: Token<'t>,
     pub output_file: Token<'t>,
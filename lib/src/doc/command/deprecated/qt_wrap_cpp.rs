@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/qt_wrap_cpp.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", positional)]
+#[cmake(pkg = "crate", positional, visit = "qt_wrap_cpp")]
 pub struct QtWrapCpp<'t> {
     pub lib: Token<'t>,
     pub dest: Token<'t>,
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/qt_wrap_ui.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", positional)]
+#[cmake(pkg = "crate", positional, visit = "qt_wrap_ui")]
 pub struct QtWrapUi<'t> {
     pub lib: Token<'t>,
     pub headers_dest: Token<'t>,
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/subdir_depends.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", positional)]
+#[cmake(pkg = "crate", positional, visit = "subdir_depends")]
 pub struct SubdirDepends<'t> {
     pub subdir: Token<'t>,
     pub dependencies: Vec<Token<'t>>,
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/subdirs.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", default = "dirs")]
+#[cmake(pkg = "crate", default = "dirs", visit = "subdirs")]
 pub struct Subdirs<'t> {
     #[cmake(rename = "")]
     pub dirs: Vec<Token<'t>>,
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/use_mangled_mesa.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", positional)]
+#[cmake(pkg = "crate", positional, visit = "use_mangled_mesa")]
 pub struct UseMangledMesa<'t> {
     pub path_to_mesa: Token<'t>,
     pub output_directory: Token<'t>,
@@ -4,6 +4,9 @@ pub mod deprecated;
 pub mod project;
 pub mod scripting;
 
+use crate::doc::command_scope::{CommandScope, ToCommandScope};
+use crate::{CMakeParse, Documented, KeywordSet, Token};
+
 /// CMake command.
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/manual/cmake-commands.7.html>
@@ -265,6 +268,260 @@ pub enum Command<'t> {
     While(Box<scripting::While<'t>>),
 }
 
+/// Calls the macro `$callback` with every command parsed into [`Command`],
+/// as `"name" => Variant(Type)`, or as `"name" => Variant = Type` for the
+/// variants without value where `Type` only documents the command.
+///
+/// The name dispatch, [`command_infos`], [`Command::name`], the
+/// [`ToCommandScope`] implementation of [`Command`] and the visitors are
+/// generated from this list, so a new command is added here and to
+/// [`Command`].
+macro_rules! commands {
+    ($callback:ident) => {
+        $callback! {
+                "add_compile_definitions" => AddCompileDefinitions(project::AddCompileDefinitions<'t>),
+                "add_compile_options" => AddCompileOptions(project::AddCompileOptions<'t>),
+                "add_custom_command" => AddCustomCommand(project::AddCustomCommand<'t>),
+                "add_custom_target" => AddCustomTarget(project::AddCustomTarget<'t>),
+                "add_definitions" => AddDefinitions(project::AddDefinitions<'t>),
+                "add_dependencies" => AddDependencies(project::AddDependencies<'t>),
+                "add_executable" => AddExecutable(project::AddExecutable<'t>),
+                "add_library" => AddLibrary(project::AddLibrary<'t>),
+                "add_link_options" => AddLinkOptions(project::AddLinkOptions<'t>),
+                "add_subdirectory" => AddSubdirectory(project::AddSubdirectory<'t>),
+                "add_test" => AddTest(project::AddTest<'t>),
+                "aux_source_directory" => AuxSourceDirectory(project::AuxSourceDirectory<'t>),
+                "build_command" => BuildCommand(project::BuildCommand<'t>),
+                "create_test_sourcelist" => CreateTestSourceList(project::CreateTestSourceList<'t>),
+                "define_property" => DefineProperty(project::DefineProperty<'t>),
+                "enable_language" => EnableLanguage(project::EnableLanguage<'t>),
+                "enable_testing" => EnableTesting = project::EnableTesting,
+                "export" => Export(project::Export<'t>),
+                "fltk_wrap_ui" => FLTKWrapUI(project::FLTKWrapUI<'t>),
+                "get_source_file_property" => GetSourceFileProperty(project::GetSourceFileProperty<'t>),
+                "get_target_property" => GetTargetProperty(project::GetTargetProperty<'t>),
+                "get_test_property" => GetTestProperty(project::GetTestProperty<'t>),
+                "include_directories" => IncludeDirectories(project::IncludeDirectories<'t>),
+                "include_external_msproject" => IncludeExternalMSProject(project::IncludeExternalMSProject<'t>),
+                "include_regular_expression" => IncludeRegularExpression(project::IncludeRegularExpression<'t>),
+                "install" => Install(project::Install<'t>),
+                "link_directories" => LinkDirectories(project::LinkDirectories<'t>),
+                "link_libraries" => LinkLibraries(project::LinkLibraries<'t>),
+                "load_cache" => LoadCache(project::LoadCache<'t>),
+                "project" => Project(project::Project<'t>),
+                "remove_definitions" => RemoveDefinitions(project::RemoveDefinitions<'t>),
+                "set_source_files_properties" => SetSourceFileProperties(project::SetSourceFileProperties<'t>),
+                "set_target_properties" => SetTargetProperties(project::SetTargetProperties<'t>),
+                "set_tests_properties" => SetTestsProperties(project::SetTestsProperties<'t>),
+                "source_group" => SourceGroup(project::SourceGroup<'t>),
+                "target_compile_definitions" => TargetCompileDefinitions(project::TargetCompileDefinitions<'t>),
+                "target_compile_features" => TargetCompileFeatures(project::TargetCompileFeatures<'t>),
+                "target_compile_options" => TargetCompileOptions(project::TargetCompileOptions<'t>),
+                "target_include_directories" => TargetIncludeDirectories(project::TargetIncludeDirectories<'t>),
+                "target_link_directories" => TargetLinkDirectories(project::TargetLinkDirectories<'t>),
+                "target_link_libraries" => TargetLinkLibraries(project::TargetLinkLibraries<'t>),
+                "target_link_options" => TargetLinkOptions(project::TargetLinkOptions<'t>),
+                "target_precompile_headers" => TargetPrecompileHeaders(project::TargetPrecompileHeaders<'t>),
+                "target_sources" => TargetSources(project::TargetSources<'t>),
+                "try_compile" => TryCompile(project::TryCompile<'t>),
+                "try_run" => TryRun(project::TryRun<'t>),
+                "ctest_build" => CTestBuild(ctest::CTestBuild<'t>),
+                "ctest_configure" => CTestConfigure(ctest::CTestConfigure<'t>),
+                "ctest_coverage" => CTestCoverage(ctest::CTestCoverage<'t>),
+                "ctest_empty_binary_directory" => CTestEmptyBinaryDirectory(ctest::CTestEmptyBinaryDirectory<'t>),
+                "ctest_memcheck" => CTestMemCheck(ctest::CTestMemCheck<'t>),
+                "ctest_read_custom_files" => CTestReadCustomFiles(ctest::CTestReadCustomFiles<'t>),
+                "ctest_run_script" => CTestRunScript(ctest::CTestRunScript<'t>),
+                "ctest_sleep" => CTestSleep(ctest::CTestSleep<'t>),
+                "ctest_start" => CTestStart(ctest::CTestStart<'t>),
+                "ctest_submit" => CTestSubmit(ctest::CTestSubmit<'t>),
+                "ctest_test" => CTestTest(ctest::CTestTest<'t>),
+                "ctest_update" => CTestUpdate(ctest::CTestUpdate<'t>),
+                "ctest_upload" => CTestUpload(ctest::CTestUpload<'t>),
+                "build_name" => BuildName(deprecated::BuildName<'t>),
+                "exec_program" => ExecProgram(deprecated::ExecProgram<'t>),
+                "export_library_dependencies" => ExportLibraryDependencies(deprecated::ExportLibraryDependencies<'t>),
+                "install_files" => InstallFiles(deprecated::InstallFiles<'t>),
+                "install_programs" => InstallPrograms(deprecated::InstallPrograms<'t>),
+                "install_targets" => InstallTargets(deprecated::InstallTargets<'t>),
+                "load_command" => LoadCommand(deprecated::LoadCommand<'t>),
+                "make_directory" => MakeDirectory(deprecated::MakeDirectory<'t>),
+                "output_required_files" => OutputRequiredFiles(deprecated::OutputRequiredFiles<'t>),
+                "qt_wrap_cpp" => QtWrapCpp(deprecated::QtWrapCpp<'t>),
+                "qt_wrap_ui" => QtWrapUi(deprecated::QtWrapUi<'t>),
+                "remove" => Remove(deprecated::Remove<'t>),
+                "subdir_depends" => SubdirDepends(deprecated::SubdirDepends<'t>),
+                "subdirs" => Subdirs(deprecated::Subdirs<'t>),
+                "use_mangled_mesa" => UseMangledMesa(deprecated::UseMangledMesa<'t>),
+                "utility_source" => UtilitySource(deprecated::UtilitySource<'t>),
+                "variable_requires" => VariableRequires(deprecated::VariableRequires<'t>),
+                "write_file" => WriteFile(deprecated::WriteFile<'t>),
+                "block" => Block(scripting::Block<'t>),
+                "break" => Break(scripting::Break),
+                "cmake_host_system_information" => CMakeHostSystemInformation(scripting::CMakeHostSystemInformation<'t>),
+                "cmake_language" => CMakeLanguage(scripting::CMakeLanguage<'t>),
+                "cmake_minimum_required" => CMakeMinimumRequired(scripting::CMakeMinimumRequired<'t>),
+                "cmake_parse_arguments" => CMakeParseArguments(scripting::CMakeParseArguments<'t>),
+                "cmake_path" => CMakePath(scripting::CMakePath<'t>),
+                "cmake_policy" => CMakePolicy(scripting::CMakePolicy<'t>),
+                "configure_file" => ConfigureFile(scripting::ConfigureFile<'t>),
+                "continue" => Continue(scripting::Continue),
+                "else" => Else(scripting::Else<'t>),
+                "elseif" => ElseIf(scripting::ElseIf<'t>),
+                "endblock" => EndBlock(scripting::EndBlock),
+                "endforeach" => EndForEach(scripting::EndForEach<'t>),
+                "endfunction" => EndFunction(scripting::EndFunction<'t>),
+                "endif" => EndIf(scripting::EndIf<'t>),
+                "endmacro" => EndMacro(scripting::EndMacro<'t>),
+                "endwhile" => EndWhile(scripting::EndWhile<'t>),
+                "execute_process" => ExecuteProcess(scripting::ExecuteProcess<'t>),
+                "file" => File(scripting::File<'t>),
+                "find_file" => FindFile(scripting::FindFile<'t>),
+                "find_library" => FindLibrary(scripting::FindLibrary<'t>),
+                "find_package" => FindPackage(scripting::FindPackage<'t>),
+                "find_path" => FindPath(scripting::FindPath<'t>),
+                "find_program" => FindProgram(scripting::FindProgram<'t>),
+                "foreach" => ForEach(scripting::ForEach<'t>),
+                "function" => Function(scripting::Function<'t>),
+                "get_cmake_property" => GetCMakeProperty(scripting::GetCMakeProperty<'t>),
+                "get_directory_property" => GetDirectoryProperty(scripting::GetDirectoryProperty<'t>),
+                "get_filename_component" => GetFilenameComponent(scripting::GetFilenameComponent<'t>),
+                "get_property" => GetProperty(scripting::GetProperty<'t>),
+                "if" => If(scripting::If<'t>),
+                "include" => Include(scripting::Include<'t>),
+                "include_guard" => IncludeGuard(scripting::IncludeGuard),
+                "list" => List(scripting::List<'t>),
+                "macro" => Macro(scripting::Macro<'t>),
+                "mark_as_advanced" => MarkAsAdvanced(scripting::MarkAsAdvanced<'t>),
+                "math" => Math(scripting::Math<'t>),
+                "message" => Message(scripting::Message<'t>),
+                "option" => Option(scripting::Option<'t>),
+                "return" => Return(scripting::Return<'t>),
+                "separate_arguments" => SeparateArguments(scripting::SeparateArguments<'t>),
+                "set" => Set(scripting::Set<'t>),
+                "set_directory_properties" => SetDirectoryProperties(scripting::SetDirectoryProperties<'t>),
+                "set_property" => SetProperty(scripting::SetProperty<'t>),
+                "site_name" => SiteName(scripting::SiteName<'t>),
+                "string" => String(scripting::String<'t>),
+                "unset" => Unset(scripting::Unset<'t>),
+                "variable_watch" => VariableWatch(scripting::VariableWatch<'t>),
+                "while" => While(scripting::While<'t>),
+        }
+    };
+}
+
+pub(crate) use commands;
+
+macro_rules! parse_command {
+    ($($name:literal => $variant:ident $(($ty:ty))? $(= $unit:path)?,)*) => {
+        /// Parses the arguments of the command `identifier`.
+        pub(crate) fn parse_command<'t>(
+            identifier: &[u8],
+            tokens: Vec<Token<'t>>,
+        ) -> Result<Command<'t>, CommandParseError> {
+            match std::str::from_utf8(identifier) {
+                $(Ok($name) => parse_command!(@parse tokens, $variant $(($ty))?),)*
+                _ => Err(CommandParseError::UnknownCommand(
+                    String::from_utf8_lossy(identifier).to_string(),
+                )),
+            }
+        }
+    };
+    (@parse $tokens:ident, $variant:ident ($ty:ty)) => {
+        to_command($tokens, Command::$variant)
+    };
+    (@parse $tokens:ident, $variant:ident) => {
+        Ok(Command::$variant)
+    };
+}
+
+commands!(parse_command);
+
+macro_rules! command_scope {
+    ($($name:literal => $variant:ident $(($ty:ty))? $(= $unit:path)?,)*) => {
+        impl<'t> ToCommandScope for Command<'t> {
+            fn to_command_scope(&self) -> CommandScope {
+                match self {
+                    $(command_scope!(@pattern command, $variant $(($ty))?) => {
+                        command_scope!(@scope command $(($ty))? $(= $unit)?)
+                    })*
+                }
+            }
+        }
+    };
+    (@pattern $command:ident, $variant:ident ($ty:ty)) => {
+        Self::$variant($command)
+    };
+    (@pattern $command:ident, $variant:ident) => {
+        Self::$variant
+    };
+    (@scope $command:ident ($ty:ty)) => {
+        $command.to_command_scope()
+    };
+    (@scope $command:ident = $unit:path) => {
+        $unit.to_command_scope()
+    };
+}
+
+commands!(command_scope);
+
+fn to_command<'t, C, F>(tokens: Vec<Token<'t>>, f: F) -> Result<Command<'t>, CommandParseError>
+where
+    C: CMakeParse<'t>,
+    F: Fn(Box<C>) -> Command<'t>,
+{
+    CMakeParse::complete(&tokens).map(f)
+}
+
+/// Name, documentation and keywords of a command parsed into [`Command`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandInfo {
+    pub name: &'static str,
+    /// Doc comment of the command type, ending with the reference URL.
+    pub documentation: &'static str,
+    /// Keywords accepted by the command parser in declaration order.
+    pub keywords: Vec<&'static str>,
+}
+
+macro_rules! command_infos {
+    ($($name:literal => $variant:ident $(($ty:ty))? $(= $unit:path)?,)*) => {
+        impl<'t> Command<'t> {
+            /// Lowercase name of the command.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant { .. } => $name,)*
+                }
+            }
+
+            fn infos() -> Vec<CommandInfo> {
+                vec![$(info::<$($ty)? $($unit)?>($name),)*]
+            }
+        }
+
+        /// Commands parsed into [`Command`] in the order of its variants.
+        pub fn command_infos() -> Vec<CommandInfo> {
+            Command::infos()
+        }
+    };
+}
+
+commands!(command_infos);
+
+/// Command parsed into [`Command`] with the case-insensitive `name`.
+pub fn command_info(name: &str) -> Option<CommandInfo> {
+    command_infos()
+        .into_iter()
+        .find(|info| info.name.eq_ignore_ascii_case(name))
+}
+
+fn info<'t, T: CMakeParse<'t> + Documented>(name: &'static str) -> CommandInfo {
+    CommandInfo {
+        name,
+        documentation: T::DOCUMENTATION,
+        keywords: KeywordSet::of::<T>(),
+    }
+}
+
 #[derive(Debug, thiserror::Error, PartialEq)]
 pub enum CommandParseError {
     #[error("required token is missing: {0}")]
@@ -284,3 +541,66 @@ pub enum CommandParseError {
     #[error("all arguments must be parsed")]
     Incomplete,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn command_infos_parse() {
+        let infos = command_infos();
+        let source: std::string::String = infos
+            .iter()
+            .map(|info| format!("{}()\n", info.name))
+            .collect();
+        let doc = Doc::from(parse_cmakelists(source.as_bytes()).unwrap());
+        let names: Vec<_> = doc.names().collect();
+        assert_eq!(names.len(), infos.len());
+        for ((info, name), command) in infos.iter().zip(names).zip(doc.to_commands_iter()) {
+            assert_eq!(info.name, name);
+            match command {
+                Ok(command) => assert_eq!(command.name(), info.name),
+                Err(err) => assert_ne!(err, CommandParseError::UnknownCommand(name)),
+            }
+            assert!(info.documentation.ends_with(&format!(
+                "Reference: <https://cmake.org/cmake/help/v3.26/command/{}.html>",
+                info.name
+            )));
+        }
+    }
+
+    #[test]
+    fn command_info_keywords() {
+        let info = command_info("Add_Executable").unwrap();
+        assert_eq!(info.name, "add_executable");
+        assert_eq!(
+            info.keywords,
+            [
+                "ALIAS",
+                "IMPORTED",
+                "GLOBAL",
+                "WIN32",
+                "MACOSX_BUNDLE",
+                "EXCLUDE_FROM_ALL"
+            ]
+        );
+        assert_eq!(
+            command_info("target_link_libraries").unwrap().keywords,
+            [
+                "INTERFACE",
+                "PUBLIC",
+                "PRIVATE",
+                "LINK_PUBLIC",
+                "LINK_PRIVATE",
+                "LINK_INTERFACE_LIBRARIES"
+            ]
+        );
+        assert_eq!(
+            command_info("foreach").unwrap().keywords,
+            ["RANGE", "IN", "ZIP_LISTS", "LISTS", "ITEMS"]
+        );
+        assert_eq!(command_info("unknown"), None);
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/add_compile_definitions.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "add_compile_definitions")]
 pub struct AddCompileDefinitions<'t> {
     #[cmake(positional)]
     pub compile_definitions: Vec<Token<'t>>,
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/add_compile_options.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "add_compile_options")]
 pub struct AddCompileOptions<'t> {
     #[cmake(positional)]
     pub compile_options: Vec<Token<'t>>,
@@ -11,7 +11,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/add_custom_command.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "add_custom_command")]
 pub enum AddCustomCommand<'t> {
     Output(AddCustomCommandOutput<'t>),
     Target(AddCustomCommandTarget<'t>),
@@ -13,7 +13,7 @@ use crate::{command::common::CustomCommand, CommandScope, ToCommandScope, Token}
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/add_custom_target.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", default = "commands")]
+#[cmake(pkg = "crate", default = "commands", visit = "add_custom_target")]
 pub struct AddCustomTarget<'t> {
     #[cmake(positional)]
     pub name: Token<'t>,
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/add_definitions.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "add_definitions")]
 pub struct AddDefinitions<'t> {
     #[cmake(positional)]
     pub definitions: Vec<Token<'t>>,
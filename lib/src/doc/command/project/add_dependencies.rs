@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/add_dependencies.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", positional)]
+#[cmake(pkg = "crate", positional, visit = "add_dependencies")]
 pub struct AddDependencies<'t> {
     pub target: Token<'t>,
     pub target_dependencies: Option<Vec<Token<'t>>>,
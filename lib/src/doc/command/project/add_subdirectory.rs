@@ -6,14 +6,14 @@ use crate::{CommandScope, ToCommandScope, Token};
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/add_subdirectory.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", default = "binary_dir")]
+#[cmake(pkg = "crate", default = "binary_dir", visit = "add_subdirectory")]
 pub struct AddSubdirectory<'t> {
     #[cmake(positional)]
-    source_dir: Token<'t>,
+    pub source_dir: Token<'t>,
     #[cmake(rename = "")]
-    binary_dir: Option<Token<'t>>,
-    exclude_from_all: bool,
-    system: bool,
+    pub binary_dir: Option<Token<'t>>,
+    pub exclude_from_all: bool,
+    pub system: bool,
 }
 
 impl<'t> ToCommandScope for AddSubdirectory<'t> {
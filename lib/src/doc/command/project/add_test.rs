@@ -6,7 +6,7 @@ use crate::{command::common::CustomCommand, CommandScope, ToCommandScope, Token}
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/add_test.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "add_test")]
 pub struct AddTest<'t> {
     name: Token<'t>,
     #[cmake(rename = "COMMAND")]
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/aux_source_directory.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", positional)]
+#[cmake(pkg = "crate", positional, visit = "aux_source_directory")]
 pub struct AuxSourceDirectory<'t> {
     pub dir: Token<'t>,
     pub variable: Token<'t>,
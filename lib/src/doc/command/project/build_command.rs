@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/build_command.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", untagged)]
+#[cmake(pkg = "crate", untagged, visit = "build_command")]
 pub enum BuildCommand<'t> {
     Variable(VariableBuildCommand<'t>),
     CacheVariable(CacheVariableBuildCommand<'t>),
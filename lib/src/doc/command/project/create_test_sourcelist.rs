@@ -6,7 +6,7 @@ use crate::{CommandScope, ToCommandScope, Token};
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/create_test_sourcelist.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", default = "tests")]
+#[cmake(pkg = "crate", default = "tests", visit = "create_test_sourcelist")]
 pub struct CreateTestSourceList<'t> {
     #[cmake(positional)]
     pub source_list_name: Token<'t>,
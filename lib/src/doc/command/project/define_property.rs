@@ -6,7 +6,7 @@ use crate::{CommandScope, ToCommandScope, Token};
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/define_property.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "define_property")]
 pub struct DefineProperty<'t> {
     #[cmake(positional)]
     pub property_scope: PropertyScope,
@@ -6,7 +6,7 @@ use crate::{CommandScope, ToCommandScope, Token};
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/enable_language.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", default = "langs")]
+#[cmake(pkg = "crate", default = "langs", visit = "enable_language")]
 pub struct EnableLanguage<'t> {
     #[cmake(rename = "")]
     pub langs: Vec<Token<'t>>,
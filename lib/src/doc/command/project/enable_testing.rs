@@ -1,3 +1,23 @@
+use cmake_parser_derive::CMake;
+
+use crate::{CommandScope, ToCommandScope};
+
+/// Enable testing for current directory and below.
+///
+/// Parsed as [`Command::EnableTesting`](crate::Command::EnableTesting),
+/// which has no value.
+///
+/// Reference: <https://cmake.org/cmake/help/v3.26/command/enable_testing.html>
+#[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cmake(pkg = "crate")]
+pub struct EnableTesting;
+
+impl ToCommandScope for EnableTesting {
+    fn to_command_scope(&self) -> CommandScope {
+        CommandScope::Project
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -6,7 +6,7 @@ use crate::{CommandScope, ToCommandScope, Token};
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/export.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "export")]
 pub enum Export<'t> {
     Targets(TargetsExport<'t>),
     Export(ExportExport<'t>),
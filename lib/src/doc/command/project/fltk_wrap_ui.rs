@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/fltk_wrap_ui.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", positional)]
+#[cmake(pkg = "crate", positional, visit = "fltk_wrap_ui")]
 pub struct FLTKWrapUI<'t> {
     pub resulting_library_name: Token<'t>,
     pub sources: Vec<Token<'t>>,
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/get_source_file_property.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", positional)]
+#[cmake(pkg = "crate", positional, visit = "get_source_file_property")]
 pub struct GetSourceFileProperty<'t> {
     pub variable: Token<'t>,
     pub file: Token<'t>,
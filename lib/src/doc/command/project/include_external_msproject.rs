@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/include_external_msproject.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", default = "deps")]
+#[cmake(pkg = "crate", default = "deps", visit = "include_external_msproject")]
 pub struct IncludeExternalMSProject<'t> {
     #[cmake(positional)]
     pub project_name: Token<'t>,
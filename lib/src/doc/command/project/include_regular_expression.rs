@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/include_regular_expression.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", positional)]
+#[cmake(pkg = "crate", positional, visit = "include_regular_expression")]
 pub struct IncludeRegularExpression<'t> {
     pub regex_match: Token<'t>,
     pub regex_complain: Option<Token<'t>>,
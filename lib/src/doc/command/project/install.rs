@@ -10,7 +10,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/install.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "install")]
 pub enum Install<'t> {
     Targets(TargetsInstall<'t>),
     ImportedRuntimeArtifacts(ImportedRuntimeArtifactsInstall<'t>),
@@ -288,6 +288,21 @@ impl<'t> crate::CMakeParse<'t> for IncludesDestination<'t> {
     ) -> Result<(Self, &'tv [Token<'t>]), crate::CommandParseError> {
         crate::CMakeParse::parse(tokens).map(|(dirs, tokens)| (Self { dirs }, tokens))
     }
+
+    fn keywords(keywords: &mut crate::KeywordSet) {
+        keywords.insert("INCLUDES");
+        keywords.insert("DESTINATION");
+    }
+}
+
+impl<'t> crate::Visitable<'t> for IncludesDestination<'t> {
+    fn walk<V: crate::Visit<'t> + ?Sized>(&self, visitor: &mut V) {
+        self.dirs.accept(visitor);
+    }
+
+    fn walk_mut<V: crate::VisitMut<'t> + ?Sized>(&mut self, visitor: &mut V) {
+        self.dirs.accept_mut(visitor);
+    }
 }
 
 #[cfg(test)]
@@ -10,7 +10,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/link_directories.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", positional)]
+#[cmake(pkg = "crate", positional, visit = "link_directories")]
 pub struct LinkDirectories<'t> {
     pub append: Option<Append>,
     pub dirs: Vec<Token<'t>>,
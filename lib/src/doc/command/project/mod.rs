@@ -1,50 +1,50 @@
-mod add_compile_definitions;
-mod add_compile_options;
-mod add_custom_command;
-mod add_custom_target;
-mod add_definitions;
-mod add_dependencies;
-mod add_executable;
-mod add_library;
-mod add_link_options;
-mod add_subdirectory;
-mod add_test;
-mod aux_source_directory;
-mod build_command;
-mod create_test_sourcelist;
-mod define_property;
-mod enable_language;
-mod enable_testing;
-mod export;
-mod fltk_wrap_ui;
-mod get_source_file_property;
-mod get_target_property;
-mod get_test_property;
-mod include_directories;
-mod include_external_msproject;
-mod include_regular_expression;
-mod install;
-mod link_directories;
-mod link_libraries;
-mod load_cache;
+pub mod add_compile_definitions;
+pub mod add_compile_options;
+pub mod add_custom_command;
+pub mod add_custom_target;
+pub mod add_definitions;
+pub mod add_dependencies;
+pub mod add_executable;
+pub mod add_library;
+pub mod add_link_options;
+pub mod add_subdirectory;
+pub mod add_test;
+pub mod aux_source_directory;
+pub mod build_command;
+pub mod create_test_sourcelist;
+pub mod define_property;
+pub mod enable_language;
+pub mod enable_testing;
+pub mod export;
+pub mod fltk_wrap_ui;
+pub mod get_source_file_property;
+pub mod get_target_property;
+pub mod get_test_property;
+pub mod include_directories;
+pub mod include_external_msproject;
+pub mod include_regular_expression;
+pub mod install;
+pub mod link_directories;
+pub mod link_libraries;
+pub mod load_cache;
 #[allow(clippy::module_inception)]
-mod project;
-mod remove_definitions;
-mod set_source_files_properties;
-mod set_target_properties;
-mod set_tests_properties;
-mod source_group;
-mod target_compile_definitions;
-mod target_compile_features;
-mod target_compile_options;
-mod target_include_directories;
-mod target_link_directories;
-mod target_link_libraries;
-mod target_link_options;
-mod target_precompile_headers;
-mod target_sources;
-mod try_compile;
-mod try_run;
+pub mod project;
+pub mod remove_definitions;
+pub mod set_source_files_properties;
+pub mod set_target_properties;
+pub mod set_tests_properties;
+pub mod source_group;
+pub mod target_compile_definitions;
+pub mod target_compile_features;
+pub mod target_compile_options;
+pub mod target_include_directories;
+pub mod target_link_directories;
+pub mod target_link_libraries;
+pub mod target_link_options;
+pub mod target_precompile_headers;
+pub mod target_sources;
+pub mod try_compile;
+pub mod try_run;
 
 pub use add_compile_definitions::AddCompileDefinitions;
 pub use add_compile_options::AddCompileOptions;
@@ -62,6 +62,7 @@ pub use build_command::BuildCommand;
 pub use create_test_sourcelist::CreateTestSourceList;
 pub use define_property::DefineProperty;
 pub use enable_language::EnableLanguage;
+pub use enable_testing::EnableTesting;
 pub use export::Export;
 pub use fltk_wrap_ui::FLTKWrapUI;
 pub use get_source_file_property::GetSourceFileProperty;
@@ -2,19 +2,33 @@ use cmake_parser_derive::CMake;
 
 use crate::{
     doc::command_scope::{CommandScope, ToCommandScope},
-    Token,
+    eval::CMakeVersion,
+    Token, TokenValueError,
 };
 
 /// Set the name of the project.
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/project.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", positional)]
+#[cmake(pkg = "crate", positional, visit = "project")]
 pub struct Project<'t> {
     pub project_name: Token<'t>,
     pub details: Option<ProjectDetails<'t>>,
 }
 
+impl<'t> Project<'t> {
+    /// Version given with the `VERSION` keyword.
+    pub fn version(&self) -> Result<Option<CMakeVersion>, TokenValueError> {
+        match &self.details {
+            Some(ProjectDetails::General(GeneralProjectDetails {
+                version: Some(version),
+                ..
+            })) => version.to_version().map(Some),
+            _ => Ok(None),
+        }
+    }
+}
+
 impl<'t> ToCommandScope for Project<'t> {
     fn to_command_scope(&self) -> CommandScope {
         CommandScope::Project
@@ -72,4 +86,24 @@ mod tests {
             ])
         )
     }
+
+    #[test]
+    fn project_version() {
+        let src = include_bytes!("../../../../../fixture/commands/project/project");
+        let cmakelists = parse_cmakelists(src).unwrap();
+        let doc = Doc::from(cmakelists);
+        let versions: Vec<_> = doc
+            .commands()
+            .unwrap()
+            .into_iter()
+            .map(|command| match command {
+                Command::Project(project) => project.version(),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            versions,
+            [Ok(None), Ok(None), Ok(Some("1".parse().unwrap()))]
+        );
+    }
 }
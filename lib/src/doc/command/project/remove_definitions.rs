@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/remove_definitions.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "remove_definitions")]
 pub struct RemoveDefinitions<'t> {
     #[cmake(positional)]
     pub definitions: Vec<Token<'t>>,
@@ -10,7 +10,11 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/set_source_files_properties.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", default = "files")]
+#[cmake(
+    pkg = "crate",
+    default = "files",
+    visit = "set_source_files_properties"
+)]
 pub struct SetSourceFileProperties<'t> {
     #[cmake(rename = "")]
     pub files: Vec<Token<'t>>,
@@ -10,7 +10,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/set_target_properties.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", default = "targets")]
+#[cmake(pkg = "crate", default = "targets", visit = "set_target_properties")]
 pub struct SetTargetProperties<'t> {
     #[cmake(rename = "")]
     pub targets: Vec<Token<'t>>,
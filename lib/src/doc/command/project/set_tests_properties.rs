@@ -10,7 +10,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/set_tests_properties.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", default = "tests")]
+#[cmake(pkg = "crate", default = "tests", visit = "set_tests_properties")]
 pub struct SetTestsProperties<'t> {
     #[cmake(rename = "")]
     pub tests: Vec<Token<'t>>,
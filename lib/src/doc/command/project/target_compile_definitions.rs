@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/target_compile_definitions.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "target_compile_definitions")]
 pub struct TargetCompileDefinitions<'t> {
     #[cmake(positional)]
     pub target: Token<'t>,
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/target_link_libraries.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", untagged)]
+#[cmake(pkg = "crate", untagged, visit = "target_link_libraries")]
 pub enum TargetLinkLibraries<'t> {
     TargetAndOrDependents(TargetAndOrDependents<'t>),
     TargetAndOrDependentsLegacy(TargetAndOrDependentsLegacy<'t>),
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/target_precompile_headers.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", untagged)]
+#[cmake(pkg = "crate", untagged, visit = "target_precompile_headers")]
 pub enum TargetPrecompileHeaders<'t> {
     Main(MainTargetPrecompileHeaders<'t>),
     Reuse(ReuseTargetPrecompileHeaders<'t>),
@@ -12,7 +12,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/try_compile.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", untagged)]
+#[cmake(pkg = "crate", untagged, visit = "try_compile")]
 pub enum TryCompile<'t> {
     Sources(TryCompileProjectSources<'t>),
     SourcesAlt(TryCompileProjectSourcesAlt<'t>),
@@ -12,7 +12,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/try_run.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", untagged)]
+#[cmake(pkg = "crate", untagged, visit = "try_run")]
 pub enum TryRun<'t> {
     Regular(TryRunRegular<'t>),
     Alt(TryRunAlt<'t>),
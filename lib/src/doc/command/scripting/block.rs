@@ -9,10 +9,10 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/block.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", allow_empty)]
+#[cmake(pkg = "crate", allow_empty, visit = "block")]
 pub struct Block<'t> {
-    scope_for: Option<ScopeFor>,
-    propagate: Option<Vec<Token<'t>>>,
+    pub scope_for: Option<ScopeFor>,
+    pub propagate: Option<Vec<Token<'t>>>,
 }
 
 impl<'t> ToCommandScope for Block<'t> {
@@ -24,8 +24,8 @@ impl<'t> ToCommandScope for Block<'t> {
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cmake(pkg = "crate")]
 pub struct ScopeFor {
-    policies: bool,
-    variables: bool,
+    pub policies: bool,
+    pub variables: bool,
 }
 
 #[cfg(test)]
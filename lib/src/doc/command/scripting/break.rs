@@ -6,7 +6,7 @@ use crate::doc::command_scope::{CommandScope, ToCommandScope};
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/break.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "break")]
 pub struct Break;
 
 impl ToCommandScope for Break {
@@ -10,7 +10,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/cmake_host_system_information.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "cmake_host_system_information")]
 pub struct CMakeHostSystemInformation<'t> {
     pub result: Token<'t>,
     pub query: Query<'t>,
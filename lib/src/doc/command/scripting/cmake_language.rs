@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/cmake_language.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", transparent)]
+#[cmake(pkg = "crate", transparent, visit = "cmake_language")]
 pub enum CMakeLanguage<'t> {
     Call(CMakeLanguageCall<'t>),
     Eval(CMakeLanguageEval<'t>),
@@ -2,19 +2,27 @@ use cmake_parser_derive::CMake;
 
 use crate::{
     doc::command_scope::{CommandScope, ToCommandScope},
-    Token,
+    eval::VersionRange,
+    Token, TokenValueError,
 };
 
 /// Require a minimum version of cmake.
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/cmake_minimum_required.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "cmake_minimum_required")]
 pub struct CMakeMinimumRequired<'t> {
     pub version: Token<'t>,
     pub fatal_error: bool,
 }
 
+impl<'t> CMakeMinimumRequired<'t> {
+    /// Minimum version with the optional policy maximum, `min[...max]`.
+    pub fn version_range(&self) -> Result<VersionRange, TokenValueError> {
+        self.version.to_version_range()
+    }
+}
+
 impl<'t> ToCommandScope for CMakeMinimumRequired<'t> {
     fn to_command_scope(&self) -> CommandScope {
         CommandScope::Scripting
@@ -48,4 +56,18 @@ mod tests {
             ])
         )
     }
+
+    #[test]
+    fn cmake_minimum_required_version_range() {
+        let cmakelists =
+            parse_cmakelists(b"cmake_minimum_required(VERSION 3.15...3.26)\n").unwrap();
+        let doc = Doc::from(cmakelists);
+        let commands = doc.commands().unwrap();
+        let [Command::CMakeMinimumRequired(command)] = commands.as_slice() else {
+            unreachable!()
+        };
+        let range = command.version_range().unwrap();
+        assert_eq!(range.to_string(), "3.15...3.26");
+        assert!(range.contains(&"3.20".parse().unwrap()));
+    }
 }
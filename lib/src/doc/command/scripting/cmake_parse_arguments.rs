@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/cmake_parse_arguments.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", untagged)]
+#[cmake(pkg = "crate", untagged, visit = "cmake_parse_arguments")]
 pub enum CMakeParseArguments<'t> {
     #[cmake(rename = "PARSE_ARGV", transparent)]
     Function(CMakeParseArgumentsFunction<'t>),
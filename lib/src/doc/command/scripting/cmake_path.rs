@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/cmake_path.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", untagged)]
+#[cmake(pkg = "crate", untagged, visit = "cmake_path")]
 pub enum CMakePath<'t> {
     Decomposition(CMakePathDecomposition<'t>),
     Query(CMakePathQuery<'t>),
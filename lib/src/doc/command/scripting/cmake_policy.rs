@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/cmake_policy.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", transparent)]
+#[cmake(pkg = "crate", transparent, visit = "cmake_policy")]
 pub enum CMakePolicy<'t> {
     Version(PolicyVersion<'t>),
     Set(PolicySet<'t>),
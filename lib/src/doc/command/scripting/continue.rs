@@ -6,7 +6,7 @@ use crate::doc::command_scope::{CommandScope, ToCommandScope};
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/continue.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "continue")]
 pub struct Continue;
 
 impl ToCommandScope for Continue {
@@ -6,7 +6,7 @@ use crate::doc::command_scope::{CommandScope, ToCommandScope};
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/endblock.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "endblock")]
 pub struct EndBlock;
 
 impl ToCommandScope for EndBlock {
@@ -10,7 +10,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/execute_process.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-#[cmake(pkg = "crate")]
+#[cmake(pkg = "crate", visit = "execute_process")]
 pub struct ExecuteProcess<'t> {
     #[cmake(rename = "COMMAND")]
     pub commands: Vec<CustomCommand<'t>>,
@@ -3,19 +3,34 @@ use cmake_parser_derive::CMake;
 use crate::{
     command::common::{FindRoot, WindowsRegistryView},
     doc::command_scope::{CommandScope, ToCommandScope},
-    Token,
+    eval::VersionRequirement,
+    Token, TokenValueError,
 };
 
 /// Find a package
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/find_package.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", untagged)]
+#[cmake(pkg = "crate", untagged, visit = "find_package")]
 pub enum FindPackage<'t> {
     Full(FindPackageFull<'t>),
     Basic(FindPackageBasic<'t>),
 }
 
+impl<'t> FindPackage<'t> {
+    /// Version the package is required to have, if any.
+    pub fn version_requirement(&self) -> Result<Option<VersionRequirement>, TokenValueError> {
+        let (version, exact) = match self {
+            FindPackage::Full(full) => (&full.version, full.exact),
+            FindPackage::Basic(basic) => (&basic.version, basic.exact),
+        };
+        version
+            .as_ref()
+            .map(|version| version.to_version_requirement(exact))
+            .transpose()
+    }
+}
+
 impl<'t> ToCommandScope for FindPackage<'t> {
     fn to_command_scope(&self) -> CommandScope {
         CommandScope::Scripting
@@ -100,6 +115,39 @@ mod tests {
     use crate::*;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn find_package_version_requirement() {
+        let src = b"find_package(Boost 1.70...<1.85 REQUIRED)\nfind_package(ZLIB 1.2 EXACT CONFIG)\nfind_package(Threads)\nfind_package(Foo 1.0...2.0 EXACT)\n";
+        let cmakelists = parse_cmakelists(src).unwrap();
+        let doc = Doc::from(cmakelists);
+        let requirements: Vec<_> = doc
+            .commands()
+            .unwrap()
+            .into_iter()
+            .map(|command| match command {
+                Command::FindPackage(find_package) => find_package.version_requirement(),
+                _ => unreachable!(),
+            })
+            .collect();
+        let version = |s: &str| s.parse().unwrap();
+        let Ok(Some(boost)) = &requirements[0] else {
+            panic!("{:?}", requirements[0]);
+        };
+        assert!(boost.accepts(&version("1.84.0")));
+        assert!(!boost.accepts(&version("1.85")));
+        assert_eq!(
+            requirements[1],
+            Ok(Some(eval::VersionRequirement::Exact(version("1.2"))))
+        );
+        assert_eq!(requirements[2], Ok(None));
+        assert_eq!(
+            requirements[3],
+            Err(TokenValueError::Version(eval::VersionError::ExactRange(
+                "1.0...2.0".to_string()
+            )))
+        );
+    }
+
     #[test]
     fn package_components() {
         #[derive(CMake, Debug, PartialEq, Eq)]
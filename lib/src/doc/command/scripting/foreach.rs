@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/foreach.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", untagged)]
+#[cmake(pkg = "crate", untagged, visit = "foreach")]
 pub enum ForEach<'t> {
     RangeStop(RangeStop<'t>),
     RangeStartStop(RangeStartStop<'t>),
@@ -81,6 +81,21 @@ impl<'t> crate::CMakeParse<'t> for ZipLists<'t> {
     ) -> Result<(Self, &'tv [Token<'t>]), crate::CommandParseError> {
         crate::CMakeParse::parse(tokens).map(|(lists, tokens)| (Self { lists }, tokens))
     }
+
+    fn keywords(keywords: &mut crate::KeywordSet) {
+        keywords.insert("IN");
+        keywords.insert("ZIP_LISTS");
+    }
+}
+
+impl<'t> crate::Visitable<'t> for ZipLists<'t> {
+    fn walk<V: crate::Visit<'t> + ?Sized>(&self, visitor: &mut V) {
+        self.lists.accept(visitor);
+    }
+
+    fn walk_mut<V: crate::VisitMut<'t> + ?Sized>(&mut self, visitor: &mut V) {
+        self.lists.accept_mut(visitor);
+    }
 }
 
 #[cfg(test)]
@@ -6,7 +6,7 @@ use crate::doc::command_scope::{CommandScope, ToCommandScope};
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/include_guard.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", positional)]
+#[cmake(pkg = "crate", positional, visit = "include_guard")]
 pub struct IncludeGuard {
     pub scope: Option<Scope>,
 }
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/mark_as_advanced.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", default = "vars")]
+#[cmake(pkg = "crate", default = "vars", visit = "mark_as_advanced")]
 pub struct MarkAsAdvanced<'t> {
     #[cmake(positional)]
     pub mode: Option<Mode>,
@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/set_directory_properties.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", positional)]
+#[cmake(pkg = "crate", positional, visit = "set_directory_properties")]
 pub struct SetDirectoryProperties<'t> {
     #[cmake(transparent)]
     pub properties: Vec<Property<'t>>,
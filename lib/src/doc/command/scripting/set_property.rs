@@ -9,7 +9,7 @@ use crate::{
 ///
 /// Reference: <https://cmake.org/cmake/help/v3.26/command/set_property.html>
 #[derive(CMake, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cmake(pkg = "crate", default = "values")]
+#[cmake(pkg = "crate", default = "values", visit = "set_property")]
 pub struct SetProperty<'t> {
     pub scope: Scope<'t>,
     pub append: bool,
@@ -0,0 +1,5 @@
+/// Documentation of a type deriving [`CMake`](crate::CMake), taken from its
+/// doc comment.
+pub trait Documented {
+    const DOCUMENTATION: &'static str;
+}
@@ -1,5 +1,9 @@
 use std::fmt::{self, Display};
 
+use crate::eval::{
+    CMakeList, CMakeVersion, GenericPath, PathStyle, VersionError, VersionRange, VersionRequirement,
+};
+
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Token<'b> {
     bytes: &'b [u8],
@@ -18,6 +22,139 @@ impl<'tn> Token<'tn> {
     pub fn is_quoted(&self) -> bool {
         self.quoted
     }
+
+    pub fn as_str(&self) -> Result<&str, TokenValueError> {
+        std::str::from_utf8(self.bytes).map_err(|_| TokenValueError::Utf8(self.to_string()))
+    }
+
+    /// Source of the token as a command argument. Quoted tokens and tokens
+    /// that would not parse as a single unquoted argument are quoted with
+    /// `"`, `\` and line endings escaped. The value is the one decoded by
+    /// the parser, so escape sequences like `\;` or `\$` of the original
+    /// source are not restored.
+    pub fn to_source(&self) -> String {
+        let text = String::from_utf8_lossy(self.bytes);
+        let unquoted = !self.quoted
+            && !text.is_empty()
+            && !text
+                .chars()
+                .any(|ch| ch.is_whitespace() || "()#\"".contains(ch));
+        if unquoted {
+            return text.into_owned();
+        }
+        let mut source = String::from("\"");
+        for ch in text.chars() {
+            match ch {
+                '"' | '\\' => {
+                    source.push('\\');
+                    source.push(ch);
+                }
+                '\n' => source.push_str("\\n"),
+                '\r' => source.push_str("\\r"),
+                '\t' => source.push_str("\\t"),
+                _ => source.push(ch),
+            }
+        }
+        source.push('"');
+        source
+    }
+
+    /// Token without a leading prefix.
+    pub(crate) fn strip_prefix(&self, prefix: &[u8]) -> Option<Token<'tn>> {
+        let bytes = self.bytes.strip_prefix(prefix)?;
+        Some(Token::text_node(bytes, self.quoted))
+    }
+
+    /// Unquoted tokens of the whitespace separated words of the token.
+    pub(crate) fn split_whitespace(&self) -> Vec<Token<'tn>> {
+        self.bytes
+            .split(u8::is_ascii_whitespace)
+            .filter(|word| !word.is_empty())
+            .map(|word| Token::text_node(word, false))
+            .collect()
+    }
+
+    /// Interprets the token as a boolean constant of `if()`.
+    ///
+    /// `1`, `ON`, `YES`, `TRUE`, `Y` and non-zero numbers are true. `0`,
+    /// `OFF`, `NO`, `FALSE`, `N`, `IGNORE`, `NOTFOUND`, names ending in
+    /// `-NOTFOUND` and the empty string are false. Constants are case
+    /// insensitive.
+    pub fn to_bool(&self) -> Result<bool, TokenValueError> {
+        let value = self.as_str()?;
+        bool_constant(value).ok_or_else(|| TokenValueError::Bool(value.to_string()))
+    }
+
+    /// Interprets the token as a version like `3.20.1`.
+    pub fn to_version(&self) -> Result<CMakeVersion, TokenValueError> {
+        Ok(self.as_str()?.parse()?)
+    }
+
+    /// Interprets the token as a version range like `3.15...<3.27`.
+    pub fn to_version_range(&self) -> Result<VersionRange, TokenValueError> {
+        Ok(self.as_str()?.parse()?)
+    }
+
+    /// Interprets the token as a `find_package()` version with the `EXACT`
+    /// option.
+    pub fn to_version_requirement(
+        &self,
+        exact: bool,
+    ) -> Result<VersionRequirement, TokenValueError> {
+        Ok(VersionRequirement::new(self.as_str()?, exact)?)
+    }
+
+    /// Splits the token into a `;` separated list.
+    pub fn to_list(&self) -> Result<CMakeList, TokenValueError> {
+        Ok(CMakeList::parse(self.as_str()?))
+    }
+
+    /// Interprets the token as a non-empty path.
+    pub fn to_path(&self, style: PathStyle) -> Result<GenericPath, TokenValueError> {
+        match self.as_str()? {
+            "" => Err(TokenValueError::EmptyPath),
+            path => Ok(GenericPath::new(path, style)),
+        }
+    }
+}
+
+/// Value of a boolean constant, see [`Token::to_bool`].
+pub(crate) fn bool_constant(value: &str) -> Option<bool> {
+    if let Some(constant) = named_bool_constant(value) {
+        return Some(constant);
+    }
+    let numeric = value
+        .bytes()
+        .all(|b| b.is_ascii_digit() || matches!(b, b'.' | b'+' | b'-' | b'e' | b'E'));
+    match value.parse::<f64>() {
+        Ok(number) if numeric => Some(number != 0.0),
+        _ => None,
+    }
+}
+
+/// Value of a boolean constant which is not a number. `$<BOOL:...>` and
+/// `#cmakedefine` treat only these as false.
+pub(crate) fn named_bool_constant(value: &str) -> Option<bool> {
+    let upper = value.to_ascii_uppercase();
+    match upper.as_str() {
+        "1" | "ON" | "YES" | "TRUE" | "Y" => Some(true),
+        "" | "0" | "OFF" | "NO" | "FALSE" | "N" | "IGNORE" | "NOTFOUND" => Some(false),
+        _ if upper.ends_with("-NOTFOUND") => Some(false),
+        _ => None,
+    }
+}
+
+/// Failure to interpret a [`Token`] as a typed value.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TokenValueError {
+    #[error("{0} is not valid UTF-8")]
+    Utf8(String),
+    #[error("{0} is not a boolean constant")]
+    Bool(String),
+    #[error(transparent)]
+    Version(#[from] VersionError),
+    #[error("path is empty")]
+    EmptyPath,
 }
 
 impl<'b> Display for Token<'b> {
@@ -132,7 +269,8 @@ impl<'kv, 'k, 'tnv, 'tn> Iterator for TokenDeclarations<'kv, 'k, 'tnv, 'tn> {
 #[cfg(test)]
 mod tests {
 
-    use super::{declarations_by_keywords, TextNodeDeclaration, Token};
+    use super::{declarations_by_keywords, TextNodeDeclaration, Token, TokenValueError};
+    use crate::eval::{PathStyle, VersionError};
 
     fn to_text_nodes<'tn>(tns: &[&'tn [u8]]) -> Vec<Token<'tn>> {
         tns.iter().map(|&x| Token::text_node(x, false)).collect()
@@ -161,4 +299,66 @@ mod tests {
         );
         assert_eq!(None, iter.next());
     }
+
+    #[test]
+    fn check_typed_values() {
+        let token = |bytes: &'static [u8]| Token::text_node(bytes, false);
+        for (bytes, expected) in [
+            (b"ON".as_slice(), true),
+            (b"yes", true),
+            (b"2.5", true),
+            (b"0.0", false),
+            (b"Off", false),
+            (b"", false),
+            (b"ZLIB_LIBRARY-NOTFOUND", false),
+        ] {
+            assert_eq!(token(bytes).to_bool(), Ok(expected));
+        }
+        assert_eq!(
+            token(b"maybe").to_bool(),
+            Err(TokenValueError::Bool("maybe".to_string()))
+        );
+        assert_eq!(
+            token(b"\xff").to_bool(),
+            Err(TokenValueError::Utf8("\u{fffd}".to_string()))
+        );
+        assert_eq!(
+            token(b"3.20.1").to_version().map(|v| v.to_string()),
+            Ok("3.20.1".to_string())
+        );
+        assert_eq!(
+            token(b"3.x").to_version(),
+            Err(TokenValueError::Version(VersionError::Invalid(
+                "3.x".to_string()
+            )))
+        );
+        assert_eq!(token(b"a;b\\;c").to_list().unwrap().items(), ["a", "b;c"]);
+        assert_eq!(
+            token(b"C:\\src")
+                .to_path(PathStyle::Windows)
+                .unwrap()
+                .as_str(),
+            "C:/src"
+        );
+        assert_eq!(
+            token(b"").to_path(PathStyle::Posix),
+            Err(TokenValueError::EmptyPath)
+        );
+    }
+
+    #[test]
+    fn check_to_source() {
+        assert_eq!(Token::text_node(b"a;b\\;c", false).to_source(), "a;b\\;c");
+        assert_eq!(Token::text_node(b"a", true).to_source(), "\"a\"");
+        assert_eq!(Token::text_node(b"", false).to_source(), "\"\"");
+        assert_eq!(
+            Token::text_node(b"say \"hi\"\n", true).to_source(),
+            "\"say \\\"hi\\\"\\n\""
+        );
+        assert_eq!(Token::text_node(b"a b", false).to_source(), "\"a b\"");
+        assert_eq!(
+            Token::text_node(b" -E  echo ", true).split_whitespace(),
+            to_text_nodes(&[b"-E", b"echo"])
+        );
+    }
 }
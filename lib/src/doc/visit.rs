@@ -0,0 +1,321 @@
+use crate::{
+    command::{commands, common, ctest, deprecated, project, scripting},
+    Command, Token,
+};
+
+/// Value which can be traversed by a [`Visit`] or [`VisitMut`].
+///
+/// Implemented for every type with the [`CMake`](crate::CMake) derive. The
+/// `visit` attribute names the visitor method called for the type, like
+/// `#[cmake(visit = "add_executable")]` for `visit_add_executable()`.
+pub trait Visitable<'t> {
+    /// Calls the visitor method for the value, which walks into it by
+    /// default.
+    fn accept<V: Visit<'t> + ?Sized>(&self, visitor: &mut V) {
+        self.walk(visitor)
+    }
+
+    /// Calls the mutable visitor method for the value, which walks into it
+    /// by default.
+    fn accept_mut<V: VisitMut<'t> + ?Sized>(&mut self, visitor: &mut V) {
+        self.walk_mut(visitor)
+    }
+
+    /// Accepts the visitor for each nested value.
+    fn walk<V: Visit<'t> + ?Sized>(&self, visitor: &mut V);
+
+    /// Accepts the mutable visitor for each nested value.
+    fn walk_mut<V: VisitMut<'t> + ?Sized>(&mut self, visitor: &mut V);
+}
+
+/// Generates [`Visit`], [`VisitMut`] and the walk of [`Command`] from the
+/// `commands!` table, with a `visit_<name>` method for every command and
+/// for the [`common`] types listed here.
+macro_rules! visitors {
+    ($($name:literal => $variant:ident $(($ty:ty))? $(= $unit:path)?,)*) => {
+        visitors! {
+            @traits
+            $($($name: $ty;)?)*
+            "append": common::Append;
+            "condition": common::Condition<'t>;
+            "copy_file": common::CopyFile<'t>;
+            "custom_command": common::CustomCommand<'t>;
+            "file_match": common::FileMatch<'t>;
+            "file_match_kind": common::FileMatchKind<'t>;
+            "common_find_path": common::FindPath<'t>;
+            "find_root": common::FindRoot;
+            "hash_algorithm": common::HashAlgorithm;
+            "lang_extensions": common::LangExtensions<'t>;
+            "lang_standard": common::LangStandard<'t>;
+            "lang_standard_required": common::LangStandardRequired<'t>;
+            "names": common::Names<'t>;
+            "newline_style": common::NewlineStyle;
+            "permission": common::Permission;
+            "permissions": common::Permissions<'t>;
+            "property": common::Property<'t>;
+            "schedule_random": common::ScheduleRandom;
+            "source": common::Source<'t>;
+            "source_alt": common::SourceAlt<'t>;
+            "windows_registry_view": common::WindowsRegistryView;
+        }
+
+        impl<'t> Visitable<'t> for Command<'t> {
+            fn accept<V: Visit<'t> + ?Sized>(&self, visitor: &mut V) {
+                visitor.visit_command(self)
+            }
+
+            fn accept_mut<V: VisitMut<'t> + ?Sized>(&mut self, visitor: &mut V) {
+                visitor.visit_command_mut(self)
+            }
+
+            fn walk<V: Visit<'t> + ?Sized>(&self, visitor: &mut V) {
+                match self {
+                    $(visitors!(@pattern command, $variant $(($ty))?) => {
+                        visitors!(@accept command $(($ty))?, accept, visitor)
+                    })*
+                }
+            }
+
+            fn walk_mut<V: VisitMut<'t> + ?Sized>(&mut self, visitor: &mut V) {
+                match self {
+                    $(visitors!(@pattern command, $variant $(($ty))?) => {
+                        visitors!(@accept command $(($ty))?, accept_mut, visitor)
+                    })*
+                }
+            }
+        }
+    };
+    (@traits $($name:literal: $ty:ty;)*) => {
+        /// Visitor of parsed commands.
+        ///
+        /// There is a method for tokens, for [`Command`], for every command
+        /// struct and for the [`common`] types. Their default
+        /// implementations walk into the nested values, so a visitor only
+        /// overrides the methods it is interested in.
+        ///
+        /// ```
+        /// use cmake_parser::{command::project::AddExecutable, parse_cmakelists, Doc, Visit, Visitable};
+        ///
+        /// #[derive(Default)]
+        /// struct Executables(Vec<String>);
+        ///
+        /// impl<'t> Visit<'t> for Executables {
+        ///     fn visit_add_executable(&mut self, node: &AddExecutable<'t>) {
+        ///         self.0.push(node.name.to_string());
+        ///     }
+        /// }
+        ///
+        /// let cmakelists = parse_cmakelists(b"add_executable(app main.c)\nif(WIN32)\nendif()\n").unwrap();
+        /// let mut executables = Executables::default();
+        /// for command in Doc::from(cmakelists).commands().unwrap() {
+        ///     command.accept(&mut executables);
+        /// }
+        /// assert_eq!(executables.0, ["app"]);
+        /// ```
+        pub trait Visit<'t> {
+            fn visit_token(&mut self, #[allow(unused_variables)] token: &Token<'t>) {}
+
+            fn visit_command(&mut self, command: &Command<'t>) {
+                command.walk(self)
+            }
+
+            $(cmake_parser_derive::visit_method!($name: $ty);)*
+        }
+
+        /// Visitor of parsed commands which can modify them, with the
+        /// methods of [`Visit`].
+        pub trait VisitMut<'t> {
+            fn visit_token_mut(&mut self, #[allow(unused_variables)] token: &mut Token<'t>) {}
+
+            fn visit_command_mut(&mut self, command: &mut Command<'t>) {
+                command.walk_mut(self)
+            }
+
+            $(cmake_parser_derive::visit_method!(mut $name: $ty);)*
+        }
+    };
+    (@pattern $command:ident, $variant:ident ($ty:ty)) => {
+        Self::$variant($command)
+    };
+    (@pattern $command:ident, $variant:ident) => {
+        Self::$variant
+    };
+    (@accept $command:ident ($ty:ty), $accept:ident, $visitor:ident) => {
+        $command.$accept($visitor)
+    };
+    (@accept $command:ident, $accept:ident, $visitor:ident) => {
+        ()
+    };
+}
+
+commands!(visitors);
+
+impl<'t> Visitable<'t> for Token<'t> {
+    fn accept<V: Visit<'t> + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_token(self)
+    }
+
+    fn accept_mut<V: VisitMut<'t> + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_token_mut(self)
+    }
+
+    fn walk<V: Visit<'t> + ?Sized>(&self, _: &mut V) {}
+
+    fn walk_mut<V: VisitMut<'t> + ?Sized>(&mut self, _: &mut V) {}
+}
+
+impl<'t, T: Visitable<'t>> Visitable<'t> for Option<T> {
+    fn walk<V: Visit<'t> + ?Sized>(&self, visitor: &mut V) {
+        if let Some(value) = self {
+            value.accept(visitor);
+        }
+    }
+
+    fn walk_mut<V: VisitMut<'t> + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(value) = self {
+            value.accept_mut(visitor);
+        }
+    }
+}
+
+impl<'t, T: Visitable<'t>> Visitable<'t> for Vec<T> {
+    fn walk<V: Visit<'t> + ?Sized>(&self, visitor: &mut V) {
+        for value in self {
+            value.accept(visitor);
+        }
+    }
+
+    fn walk_mut<V: VisitMut<'t> + ?Sized>(&mut self, visitor: &mut V) {
+        for value in self {
+            value.accept_mut(visitor);
+        }
+    }
+}
+
+impl<'t, T: Visitable<'t>> Visitable<'t> for Box<T> {
+    fn accept<V: Visit<'t> + ?Sized>(&self, visitor: &mut V) {
+        self.as_ref().accept(visitor)
+    }
+
+    fn accept_mut<V: VisitMut<'t> + ?Sized>(&mut self, visitor: &mut V) {
+        self.as_mut().accept_mut(visitor)
+    }
+
+    fn walk<V: Visit<'t> + ?Sized>(&self, visitor: &mut V) {
+        self.as_ref().walk(visitor)
+    }
+
+    fn walk_mut<V: VisitMut<'t> + ?Sized>(&mut self, visitor: &mut V) {
+        self.as_mut().walk_mut(visitor)
+    }
+}
+
+impl<'t, T1: Visitable<'t>, T2: Visitable<'t>> Visitable<'t> for (T1, T2) {
+    fn walk<V: Visit<'t> + ?Sized>(&self, visitor: &mut V) {
+        self.0.accept(visitor);
+        self.1.accept(visitor);
+    }
+
+    fn walk_mut<V: VisitMut<'t> + ?Sized>(&mut self, visitor: &mut V) {
+        self.0.accept_mut(visitor);
+        self.1.accept_mut(visitor);
+    }
+}
+
+impl<'t> Visitable<'t> for bool {
+    fn walk<V: Visit<'t> + ?Sized>(&self, _: &mut V) {}
+
+    fn walk_mut<V: VisitMut<'t> + ?Sized>(&mut self, _: &mut V) {}
+}
+
+impl<'t> Visitable<'t> for () {
+    fn walk<V: Visit<'t> + ?Sized>(&self, _: &mut V) {}
+
+    fn walk_mut<V: VisitMut<'t> + ?Sized>(&mut self, _: &mut V) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    #[derive(Default)]
+    struct Paths(Vec<String>);
+
+    impl<'t> Visit<'t> for Paths {
+        fn visit_token(&mut self, token: &Token<'t>) {
+            let token = token.to_string();
+            if token.contains('/') || token.contains('.') {
+                self.0.push(token);
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct Tokens(Vec<String>);
+
+    impl<'t> Visit<'t> for Tokens {
+        fn visit_token(&mut self, token: &Token<'t>) {
+            self.0.push(token.to_string());
+        }
+    }
+
+    struct RenameTarget(&'static str, &'static str);
+
+    impl<'t> VisitMut<'t> for RenameTarget {
+        fn visit_token_mut(&mut self, token: &mut Token<'t>) {
+            if token.as_bytes() == self.0.as_bytes() {
+                *token = Token::text_node(self.1.as_bytes(), token.is_quoted());
+            }
+        }
+
+        // Message texts are not target names.
+        fn visit_message_mut(&mut self, _: &mut scripting::Message<'t>) {}
+    }
+
+    #[test]
+    fn visit() {
+        let cmakelists = parse_cmakelists(
+            b"add_executable(foo main.c src/util.c)
+if(WIN32)
+  target_sources(foo PRIVATE win/main.rc)
+endif()
+install(TARGETS foo PERMISSIONS OWNER_READ DESTINATION bin/)
+message(foo)
+",
+        )
+        .unwrap();
+        let doc = Doc::from(cmakelists);
+        let mut commands = doc.commands().unwrap();
+
+        let mut paths = Paths::default();
+        for command in &commands {
+            command.accept(&mut paths);
+        }
+        assert_eq!(paths.0, ["main.c", "src/util.c", "win/main.rc", "bin/"]);
+
+        let mut rename = RenameTarget("foo", "bar");
+        for command in &mut commands {
+            command.accept_mut(&mut rename);
+        }
+        let mut tokens = Tokens::default();
+        for command in &commands {
+            command.accept(&mut tokens);
+        }
+        assert_eq!(
+            tokens.0,
+            [
+                "bar",
+                "main.c",
+                "src/util.c",
+                "WIN32",
+                "bar",
+                "win/main.rc",
+                "bar",
+                "bin/",
+                "foo"
+            ]
+        );
+    }
+}
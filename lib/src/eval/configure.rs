@@ -0,0 +1,326 @@
+use std::borrow::Cow;
+
+use crate::{
+    command::{
+        common::NewlineStyle,
+        scripting::{file::FileConfigure, ConfigureFile},
+    },
+    doc::named_bool_constant,
+    eval::{Regex, Variables},
+};
+
+/// Source of the variable values of configured templates.
+pub trait Lookup {
+    fn lookup(&self, name: &str) -> Option<Cow<'_, str>>;
+}
+
+impl Lookup for Variables {
+    fn lookup(&self, name: &str) -> Option<Cow<'_, str>> {
+        self.get(name).map(Cow::Borrowed)
+    }
+}
+
+impl<F: Fn(&str) -> Option<String>> Lookup for F {
+    fn lookup(&self, name: &str) -> Option<Cow<'_, str>> {
+        self(name).map(Cow::Owned)
+    }
+}
+
+/// Options of `configure_file()`, `file(CONFIGURE)` and `string(CONFIGURE)`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigureOptions {
+    /// Replace only `@VAR@` references, `@ONLY`.
+    pub only: bool,
+    /// Escape quotes in substituted values, `ESCAPE_QUOTES`.
+    pub escape_quotes: bool,
+    /// Copy the input unchanged, `COPYONLY`.
+    pub copy_only: bool,
+    /// Line endings of generated files, `NEWLINE_STYLE`.
+    pub newline_style: Option<NewlineStyle>,
+}
+
+impl From<&ConfigureFile<'_>> for ConfigureOptions {
+    fn from(command: &ConfigureFile<'_>) -> Self {
+        Self {
+            only: command.only,
+            escape_quotes: command.escape_quotes,
+            copy_only: command.copy_only,
+            newline_style: command.newline_style.clone(),
+        }
+    }
+}
+
+impl From<&FileConfigure<'_>> for ConfigureOptions {
+    fn from(command: &FileConfigure<'_>) -> Self {
+        Self {
+            only: command.only,
+            escape_quotes: command.escape_quotes,
+            copy_only: false,
+            newline_style: command.newline_style.clone(),
+        }
+    }
+}
+
+/// Generates the content of a file from a template as `configure_file()`
+/// does.
+///
+/// Lines are configured one by one and written with the line endings of
+/// the newline style, `\n` by default.
+///
+/// ```
+/// use cmake_parser::eval::{configure_file, ConfigureOptions};
+///
+/// let template = "#cmakedefine HAVE_ZLIB\n#cmakedefine01 HAVE_BZIP2\n#define VERSION \"@VERSION@\"\n";
+/// let lookup = |name: &str| match name {
+///     "HAVE_ZLIB" => Some("ON".to_string()),
+///     "VERSION" => Some(env!("CARGO_PKG_VERSION").to_string()),
+///     _ => None,
+/// };
+/// let options = ConfigureOptions {
+///     only: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     configure_file(template, &lookup, &options),
+///     format!(
+///         "#define HAVE_ZLIB\n#define HAVE_BZIP2 0\n#define VERSION \"{}\"\n",
+///         env!("CARGO_PKG_VERSION")
+///     )
+/// );
+/// ```
+pub fn configure_file<L: Lookup + ?Sized>(
+    input: &str,
+    lookup: &L,
+    options: &ConfigureOptions,
+) -> String {
+    if options.copy_only {
+        return input.to_string();
+    }
+    let newline = match options.newline_style {
+        Some(NewlineStyle::Dos | NewlineStyle::Win32 | NewlineStyle::CrLf) => "\r\n",
+        Some(NewlineStyle::Unix | NewlineStyle::Lf) | None => "\n",
+    };
+    let mut output = String::new();
+    let mut lines = input.split('\n').peekable();
+    while let Some(line) = lines.next() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        output.push_str(&configure(line, lookup, options));
+        if lines.peek().is_some() {
+            output.push_str(newline);
+        }
+    }
+    output
+}
+
+/// Configures a string as `string(CONFIGURE)` does.
+///
+/// Lines with `#cmakedefine VAR` become `#define VAR` or `/* #undef VAR */`,
+/// `#cmakedefine01 VAR` lines get a `1` or `0` value. Then `@VAR@` and
+/// `${VAR}` references are replaced, undefined variables with empty
+/// strings.
+pub fn configure<L: Lookup + ?Sized>(
+    input: &str,
+    lookup: &L,
+    options: &ConfigureOptions,
+) -> String {
+    let defined = defines(input, lookup);
+    let mut result = String::new();
+    let mut rest = defined.as_str();
+    while let Some(ch) = rest.chars().next() {
+        let reference = match ch {
+            '$' if !options.only => variable_reference(rest, lookup),
+            '@' => at_reference(rest, lookup),
+            _ => None,
+        };
+        match reference {
+            Some((value, length)) => {
+                if options.escape_quotes {
+                    result.push_str(&value.replace('"', "\\\""));
+                } else {
+                    result.push_str(&value);
+                }
+                rest = &rest[length..];
+            }
+            None => {
+                result.push(ch);
+                rest = &rest[ch.len_utf8()..];
+            }
+        }
+    }
+    result
+}
+
+/// Replaces `#cmakedefine` and `#cmakedefine01` lines.
+fn defines<L: Lookup + ?Sized>(input: &str, lookup: &L) -> String {
+    let define = Regex::new("#([ \t]*)cmakedefine[ \t]+([A-Za-z_0-9]*)").expect("valid regex");
+    let define01 = Regex::new("#([ \t]*)cmakedefine01[ \t]+([A-Za-z_0-9]*)").expect("valid regex");
+    let is_off = |name: &str| {
+        lookup
+            .lookup(name)
+            .is_none_or(|value| named_bool_constant(&value) == Some(false))
+    };
+    let lines: Vec<String> = input
+        .split('\n')
+        .map(|line| {
+            if let Some(captures) = define.find(line) {
+                let (indentation, name) = (captures.get(1).unwrap(), captures.get(2).unwrap());
+                if is_off(name) {
+                    format!("/* #undef {name} */")
+                } else {
+                    line.replace(
+                        &format!("#{indentation}cmakedefine"),
+                        &format!("#{indentation}define"),
+                    )
+                }
+            } else if let Some(captures) = define01.find(line) {
+                let (indentation, name) = (captures.get(1).unwrap(), captures.get(2).unwrap());
+                let value = if is_off(name) { 0 } else { 1 };
+                let line = line.replace(
+                    &format!("#{indentation}cmakedefine01"),
+                    &format!("#{indentation}define"),
+                );
+                format!("{line} {value}")
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+    lines.join("\n")
+}
+
+fn is_name_char(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || matches!(ch, '_' | '/' | '.' | '+' | '-')
+}
+
+/// `@VAR@` reference at the start of the input with its length.
+fn at_reference<L: Lookup + ?Sized>(input: &str, lookup: &L) -> Option<(String, usize)> {
+    let end = input[1..].find(|ch| !is_name_char(ch))? + 1;
+    if end == 1 || !input[end..].starts_with('@') {
+        return None;
+    }
+    let value = lookup.lookup(&input[1..end]).unwrap_or_default();
+    Some((value.into_owned(), end + 1))
+}
+
+/// `${VAR}` reference at the start of the input, possibly with nested
+/// references in the name, with its length.
+fn variable_reference<L: Lookup + ?Sized>(input: &str, lookup: &L) -> Option<(String, usize)> {
+    if !input.starts_with("${") {
+        return None;
+    }
+    let mut name = String::new();
+    let mut position = 2;
+    loop {
+        let ch = input[position..].chars().next()?;
+        match ch {
+            '}' => break,
+            '$' => {
+                let (value, length) = variable_reference(&input[position..], lookup)?;
+                name.push_str(&value);
+                position += length;
+            }
+            _ if is_name_char(ch) => {
+                name.push(ch);
+                position += 1;
+            }
+            _ => return None,
+        }
+    }
+    let value = lookup.lookup(&name).unwrap_or_default();
+    Some((value.into_owned(), position + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn configure_string() {
+        let variables = Variables::from_iter([
+            ("NAME", "app"),
+            ("VERSION", "1.2"),
+            ("KIND", "NAME"),
+            ("QUOTED", "say \"hi\""),
+        ]);
+        let configure = |input, only, escape_quotes| {
+            configure(
+                input,
+                &variables,
+                &ConfigureOptions {
+                    only,
+                    escape_quotes,
+                    ..Default::default()
+                },
+            )
+        };
+
+        assert_eq!(
+            configure("@NAME@ ${VERSION} ${${KIND}} ${MISSING}.", false, false),
+            "app 1.2 app ."
+        );
+        assert_eq!(
+            configure("@NAME@ ${VERSION} a@b $x ${", true, false),
+            "app ${VERSION} a@b $x ${"
+        );
+        assert_eq!(
+            configure("#define MSG \"@QUOTED@\"", false, true),
+            "#define MSG \"say \\\"hi\\\"\""
+        );
+        assert_eq!(configure("mail@example.com@", false, false), "mail");
+    }
+
+    #[test]
+    fn configure_defines() {
+        let variables = Variables::from_iter([
+            ("HAVE_ZLIB", "ON"),
+            ("HAVE_LZMA", "LZMA-NOTFOUND"),
+            ("HAVE_BZIP2", "1"),
+            ("SIZE", "8"),
+        ]);
+        let options = ConfigureOptions::default();
+
+        assert_eq!(
+            configure_file(
+                include_str!("../../../fixture/eval/configure/config.h.in"),
+                &variables,
+                &options
+            ),
+            "#define HAVE_ZLIB 1\n#  define SIZE 8\n/* #undef HAVE_LZMA */\n/* #undef HAVE_ZSTD */\n#define HAVE_BZIP2 1\n# define HAVE_LZMA 0\n"
+        );
+    }
+
+    #[test]
+    fn configure_file_options() {
+        let lookup = |name: &str| (name == "NAME").then(|| "app".to_string());
+
+        assert_eq!(
+            configure_file("@NAME@\r\n${NAME}", &lookup, &ConfigureOptions::default()),
+            "app\napp"
+        );
+        assert_eq!(
+            configure_file(
+                "@NAME@\n${NAME}\n",
+                &lookup,
+                &ConfigureOptions {
+                    only: true,
+                    newline_style: Some(NewlineStyle::CrLf),
+                    ..Default::default()
+                }
+            ),
+            "app\r\n${NAME}\r\n"
+        );
+        assert_eq!(
+            configure_file(
+                "@NAME@\r\n",
+                &lookup,
+                &ConfigureOptions {
+                    copy_only: true,
+                    newline_style: Some(NewlineStyle::Unix),
+                    ..Default::default()
+                }
+            ),
+            "@NAME@\r\n"
+        );
+    }
+}
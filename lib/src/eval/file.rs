@@ -0,0 +1,1171 @@
+use std::{collections::HashSet, io, time::UNIX_EPOCH};
+
+use crate::{
+    command::{
+        common::{FileMatch, FileMatchKind},
+        scripting::{
+            file::{
+                FileCopyFile, FileFilesystem, FileGlob, FileGlobRecurse, FileHash,
+                FileMakeDirectory, FilePathConversion, FileRead, FileReading, FileRealPath,
+                FileRelativePath, FileRemove, FileRename, FileSize, FileStrings, FileTimestamp,
+                FileToCMakePath, FileToNativePath, FileTouch, FileWrite, FileWriting,
+                ListDirectories, StringsEncoding, StringsOption,
+            },
+            ConfigureFile, File,
+        },
+    },
+    eval::{
+        configure_file,
+        filesystem::parent,
+        format_timestamp,
+        hash::{hash, hex},
+        path::path_list_separator,
+        CMakeList, ConfigureOptions, EntryKind, FileSystem, GenericPath, PathStyle, Regex,
+        RegexError, Variables,
+    },
+    Token,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FileError {
+    #[error("{path}: {kind}")]
+    Io { path: String, kind: io::ErrorKind },
+    #[error("file({0}) can not be evaluated")]
+    Unsupported(&'static str),
+    #[error("{0} is not a valid number")]
+    InvalidNumber(String),
+    #[error(transparent)]
+    Regex(#[from] RegexError),
+}
+
+type Result<T> = std::result::Result<T, FileError>;
+
+/// Kind of a filesystem access of an evaluated script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AccessKind {
+    Read,
+    List,
+    Write,
+    CreateDirectory,
+    Remove,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FileAccess {
+    pub kind: AccessKind,
+    pub path: String,
+}
+
+/// Evaluates `file()` commands against a [`FileSystem`] recording the
+/// files they access.
+///
+/// Relative paths are resolved against `CMAKE_CURRENT_SOURCE_DIR`, or
+/// `CMAKE_CURRENT_BINARY_DIR` for the outputs of `CONFIGURE` and the
+/// destination of `COPY` and `INSTALL`. Network, locking, archiving, link
+/// and permission subcommands are not evaluated.
+///
+/// ```
+/// use cmake_parser::{eval, parse_cmakelists, Command, Doc};
+///
+/// let cmakelists = parse_cmakelists(b"file(WRITE out.txt hello)\n").unwrap();
+/// let doc = Doc::from(cmakelists);
+/// let Ok(Command::File(file)) = doc.to_commands_iter().next().unwrap() else {
+///     unreachable!()
+/// };
+/// let mut variables = eval::Variables::from_iter([("CMAKE_CURRENT_SOURCE_DIR", "/src")]);
+/// let mut evaluator = eval::FileEvaluator::new(eval::MemoryFileSystem::new());
+/// evaluator.file(&file, &mut variables).unwrap();
+/// assert_eq!(
+///     evaluator.file_system().files().collect::<Vec<_>>(),
+///     vec![("/src/out.txt", b"hello".as_slice())]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileEvaluator<F> {
+    fs: F,
+    style: PathStyle,
+    accesses: Vec<FileAccess>,
+}
+
+impl<F: FileSystem> FileEvaluator<F> {
+    pub fn new(fs: F) -> Self {
+        Self {
+            fs,
+            style: PathStyle::default(),
+            accesses: vec![],
+        }
+    }
+
+    pub fn style(mut self, style: PathStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn file_system(&self) -> &F {
+        &self.fs
+    }
+
+    pub fn into_file_system(self) -> F {
+        self.fs
+    }
+
+    /// Accesses in the order the evaluated commands made them.
+    pub fn accesses(&self) -> &[FileAccess] {
+        &self.accesses
+    }
+
+    /// Runs a `file()` command updating the variables.
+    pub fn file(&mut self, command: &File, variables: &mut Variables) -> Result<()> {
+        match command {
+            File::Reading(reading) => self.reading(reading, variables),
+            File::Writing(writing) => self.writing(writing, variables),
+            File::Filesystem(filesystem) => self.filesystem(filesystem, variables),
+            File::PathConversion(conversion) => self.path_conversion(conversion, variables),
+            File::Transfer(_) => Err(FileError::Unsupported("DOWNLOAD/UPLOAD")),
+            File::Locking(_) => Err(FileError::Unsupported("LOCK")),
+            File::Archiving(_) => Err(FileError::Unsupported("ARCHIVE")),
+        }
+    }
+
+    /// Runs a `configure_file()` command.
+    ///
+    /// The input is relative to `CMAKE_CURRENT_SOURCE_DIR`, the output to
+    /// `CMAKE_CURRENT_BINARY_DIR`. An output directory gets a file with the
+    /// name of the input.
+    pub fn configure_file(&mut self, command: &ConfigureFile, variables: &Variables) -> Result<()> {
+        let input = self.source_path(&command.input, variables);
+        let mut output = self.binary_path(&command.output, variables);
+        if self.fs.kind(&output) == Some(EntryKind::Directory) {
+            let name = input.rsplit('/').next().unwrap_or(&input);
+            output = join(&output, name);
+        }
+        let content = self.read(&input)?;
+        let configured = configure_file(
+            &String::from_utf8_lossy(&content),
+            variables,
+            &ConfigureOptions::from(command),
+        );
+        self.write(&output, configured.as_bytes())
+    }
+
+    fn reading(&mut self, reading: &FileReading, variables: &mut Variables) -> Result<()> {
+        match reading {
+            FileReading::Read(FileRead {
+                filename,
+                variable,
+                offset,
+                limit,
+                hex: as_hex,
+            }) => {
+                let content = self.read(&self.source_path(filename, variables))?;
+                let offset = offset.as_ref().map(number).transpose()?.unwrap_or(0);
+                let content = &content[offset.min(content.len())..];
+                let limit = limit.as_ref().map(number).transpose()?;
+                let content = &content[..limit.unwrap_or(usize::MAX).min(content.len())];
+                let value = if *as_hex {
+                    hex(content)
+                } else {
+                    String::from_utf8_lossy(content).into_owned()
+                };
+                variables.set(variable.to_string(), value);
+            }
+            FileReading::Strings(FileStrings {
+                filename,
+                variable,
+                options,
+            }) => {
+                let content = self.read(&self.source_path(filename, variables))?;
+                let strings = strings(&content, options.as_deref().unwrap_or_default())?;
+                variables.set(variable.to_string(), strings.join(";"));
+            }
+            FileReading::Hash(FileHash {
+                hash_algorithm,
+                filename,
+                variable,
+            }) => {
+                let content = self.read(&self.source_path(filename, variables))?;
+                variables.set(variable.to_string(), hash(hash_algorithm, &content));
+            }
+            FileReading::Timestamp(FileTimestamp {
+                filename,
+                variable,
+                format,
+                utc,
+            }) => {
+                let path = self.source_path(filename, variables);
+                let value = self
+                    .fs
+                    .modified(&path)
+                    .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+                    .map(|since_epoch| {
+                        format_timestamp(
+                            since_epoch.as_secs() as i64,
+                            since_epoch.subsec_micros(),
+                            format.as_ref().map(Token::to_string).as_deref(),
+                            *utc,
+                        )
+                    })
+                    .unwrap_or_default();
+                variables.set(variable.to_string(), value);
+            }
+            FileReading::GetRuntimeDependencies(_) => {
+                return Err(FileError::Unsupported("GET_RUNTIME_DEPENDENCIES"))
+            }
+        }
+        Ok(())
+    }
+
+    fn writing(&mut self, writing: &FileWriting, variables: &mut Variables) -> Result<()> {
+        match writing {
+            FileWriting::Write(FileWrite { filename, content }) => {
+                let path = self.source_path(filename, variables);
+                self.write(&path, concat(content).as_bytes())?;
+            }
+            FileWriting::Append(FileWrite { filename, content }) => {
+                let path = self.source_path(filename, variables);
+                let mut existing = if self.fs.kind(&path) == Some(EntryKind::File) {
+                    self.read(&path)?
+                } else {
+                    vec![]
+                };
+                existing.extend_from_slice(concat(content).as_bytes());
+                self.write(&path, &existing)?;
+            }
+            FileWriting::Touch(FileTouch { files }) => {
+                for file in files.iter().flatten() {
+                    let path = self.source_path(file, variables);
+                    if self.fs.kind(&path).is_none() {
+                        self.write(&path, &[])?;
+                    }
+                }
+            }
+            FileWriting::TouchNoCreate(_) => {}
+            FileWriting::Generate(_) => return Err(FileError::Unsupported("GENERATE")),
+            FileWriting::Configure(configure) => {
+                let path = self.binary_path(&configure.output, variables);
+                let configured = configure_file(
+                    &configure.content.to_string(),
+                    variables,
+                    &ConfigureOptions::from(configure),
+                );
+                self.write(&path, configured.as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn filesystem(&mut self, filesystem: &FileFilesystem, variables: &mut Variables) -> Result<()> {
+        match filesystem {
+            FileFilesystem::Glob(FileGlob {
+                variable,
+                list_directories,
+                relative,
+                globbing_expressions,
+                ..
+            }) => {
+                let list_directories = list_directories.as_ref() != Some(&ListDirectories::False);
+                let files = self.glob(
+                    globbing_expressions.as_deref().unwrap_or_default(),
+                    relative.as_ref(),
+                    false,
+                    false,
+                    list_directories,
+                    variables,
+                );
+                variables.set(variable.to_string(), files.join(";"));
+            }
+            FileFilesystem::GlobRecurse(FileGlobRecurse {
+                variable,
+                follow_symlinks,
+                list_directories,
+                relative,
+                globbing_expressions,
+                ..
+            }) => {
+                let list_directories = list_directories.as_ref() == Some(&ListDirectories::True);
+                let files = self.glob(
+                    globbing_expressions.as_deref().unwrap_or_default(),
+                    relative.as_ref(),
+                    true,
+                    *follow_symlinks,
+                    list_directories,
+                    variables,
+                );
+                variables.set(variable.to_string(), files.join(";"));
+            }
+            FileFilesystem::MakeDirectory(FileMakeDirectory { directories }) => {
+                for directory in directories.iter().flatten() {
+                    let path = self.source_path(directory, variables);
+                    self.create_dir_all(&path)?;
+                }
+            }
+            FileFilesystem::Remove(FileRemove { files }) => {
+                for file in files
+                    .iter()
+                    .flatten()
+                    .filter(|file| !file.as_bytes().is_empty())
+                {
+                    let path = self.source_path(file, variables);
+                    if self.fs.kind(&path) == Some(EntryKind::File) {
+                        self.remove(&path, false)?;
+                    }
+                }
+            }
+            FileFilesystem::RemoveRecurse(FileRemove { files }) => {
+                for file in files
+                    .iter()
+                    .flatten()
+                    .filter(|file| !file.as_bytes().is_empty())
+                {
+                    let path = self.source_path(file, variables);
+                    if self.fs.kind(&path).is_some() {
+                        self.remove(&path, true)?;
+                    }
+                }
+            }
+            FileFilesystem::Rename(FileRename {
+                oldname,
+                newname,
+                result,
+                no_replace,
+            }) => {
+                let from = self.source_path(oldname, variables);
+                let to = self.source_path(newname, variables);
+                let renamed = if *no_replace && self.fs.kind(&to).is_some() {
+                    Err(FileError::Io {
+                        path: to,
+                        kind: io::ErrorKind::AlreadyExists,
+                    })
+                } else {
+                    self.rename(&from, &to)
+                };
+                report(renamed, result.as_ref(), variables)?;
+            }
+            FileFilesystem::CopyFile(FileCopyFile {
+                oldname,
+                newname,
+                result,
+                only_if_different,
+                ..
+            }) => {
+                let from = self.source_path(oldname, variables);
+                let to = self.source_path(newname, variables);
+                let copied = self.read(&from).and_then(|content| {
+                    if *only_if_different
+                        && self.fs.kind(&to) == Some(EntryKind::File)
+                        && self.fs.read(&to).ok().as_ref() == Some(&content)
+                    {
+                        return Ok(());
+                    }
+                    self.write(&to, &content)
+                });
+                report(copied, result.as_ref(), variables)?;
+            }
+            FileFilesystem::Copy(copy) => {
+                self.copy(
+                    &copy.files,
+                    &copy.destination,
+                    copy.files_matching,
+                    copy.file_matches.as_deref().unwrap_or_default(),
+                    variables,
+                )?;
+            }
+            FileFilesystem::Install(install) => {
+                self.copy(
+                    &install.files,
+                    &install.destination,
+                    install.files_matching,
+                    install.file_matches.as_deref().unwrap_or_default(),
+                    variables,
+                )?;
+            }
+            FileFilesystem::Size(FileSize { filename, variable }) => {
+                let content = self.read(&self.source_path(filename, variables))?;
+                variables.set(variable.to_string(), content.len().to_string());
+            }
+            FileFilesystem::ReadSymlink(_) => return Err(FileError::Unsupported("READ_SYMLINK")),
+            FileFilesystem::CreateLink(_) => return Err(FileError::Unsupported("CREATE_LINK")),
+            FileFilesystem::Chmod(_) | FileFilesystem::ChmodRecurse(_) => {
+                return Err(FileError::Unsupported("CHMOD"))
+            }
+        }
+        Ok(())
+    }
+
+    fn path_conversion(
+        &mut self,
+        conversion: &FilePathConversion,
+        variables: &mut Variables,
+    ) -> Result<()> {
+        let (variable, value) = match conversion {
+            FilePathConversion::RealPath(FileRealPath {
+                path,
+                out_var,
+                base_directory,
+                ..
+            }) => {
+                let base = match base_directory {
+                    Some(base) => GenericPath::new(base.to_string(), self.style),
+                    None => self.base(variables, "CMAKE_CURRENT_SOURCE_DIR"),
+                };
+                let path = GenericPath::new(path.to_string(), self.style).absolute(&base);
+                (out_var, trim_separator(path.normal()))
+            }
+            FilePathConversion::RelativePath(FileRelativePath {
+                variable,
+                directory,
+                file,
+            }) => {
+                let directory = GenericPath::new(directory.to_string(), self.style).normal();
+                let file = GenericPath::new(file.to_string(), self.style).normal();
+                let relative = trim_separator(file.relative_to(&directory));
+                (
+                    variable,
+                    if relative == "." {
+                        String::new()
+                    } else {
+                        relative
+                    },
+                )
+            }
+            FilePathConversion::ToCMakePath(FileToCMakePath { path, variable }) => {
+                let paths: Vec<String> = path
+                    .to_string()
+                    .split(path_list_separator(self.style))
+                    .map(|path| GenericPath::new(path, self.style).to_string())
+                    .collect();
+                (variable, paths.join(";"))
+            }
+            FilePathConversion::ToNativePath(FileToNativePath { path, variable }) => {
+                let paths: Vec<String> = CMakeList::parse_non_empty(&path.to_string())
+                    .iter()
+                    .map(|path| GenericPath::new(path.as_str(), self.style).native())
+                    .collect();
+                (variable, paths.join(";"))
+            }
+        };
+        variables.set(variable.to_string(), value);
+        Ok(())
+    }
+
+    /// Expands globbing expressions to sorted absolute paths, or paths
+    /// relative to `relative`.
+    fn glob(
+        &mut self,
+        expressions: &[Token],
+        relative: Option<&Token>,
+        recurse: bool,
+        follow_symlinks: bool,
+        list_directories: bool,
+        variables: &Variables,
+    ) -> Vec<String> {
+        let mut files = vec![];
+        for expression in expressions {
+            let pattern = GenericPath::new(self.source_path(expression, variables), self.style);
+            let names: Vec<&str> = pattern
+                .relative_part()
+                .split('/')
+                .filter(|name| !name.is_empty())
+                .collect();
+            let Some((last, directories)) = names.split_last() else {
+                continue;
+            };
+            let mut found = vec![];
+            if recurse {
+                for directory in self.expand(pattern.root_path(), directories) {
+                    let mut walk = Walk {
+                        pattern: last,
+                        follow_symlinks,
+                        list_directories,
+                        visited: HashSet::new(),
+                        found: &mut found,
+                    };
+                    if let Ok(canonical) = self.fs.canonicalize(&directory) {
+                        walk.visited.insert(canonical);
+                    }
+                    self.walk(&directory, &mut walk);
+                }
+            } else {
+                for directory in self.expand(pattern.root_path(), directories) {
+                    for name in self.list(&directory) {
+                        if !glob_match(last.as_bytes(), name.as_bytes()) {
+                            continue;
+                        }
+                        let path = join(&directory, &name);
+                        if list_directories || self.fs.kind(&path) != Some(EntryKind::Directory) {
+                            found.push(path);
+                        }
+                    }
+                }
+            }
+            found.sort();
+            files.extend(found);
+        }
+        if let Some(relative) = relative {
+            let base = GenericPath::new(relative.to_string(), self.style).normal();
+            for file in &mut files {
+                *file =
+                    trim_separator(GenericPath::new(file.as_str(), self.style).relative_to(&base));
+            }
+        }
+        files
+    }
+
+    /// Directories matching the patterns of the directory part of a globbing
+    /// expression.
+    fn expand(&mut self, root: &str, patterns: &[&str]) -> Vec<String> {
+        let mut directories = vec![root.to_string()];
+        for pattern in patterns {
+            let mut matched = vec![];
+            for directory in directories {
+                if !has_wildcard(pattern) {
+                    let path = join(&directory, pattern);
+                    if self.fs.kind(&path) == Some(EntryKind::Directory) {
+                        matched.push(path);
+                    }
+                    continue;
+                }
+                for name in self.list(&directory) {
+                    let path = join(&directory, &name);
+                    if glob_match(pattern.as_bytes(), name.as_bytes())
+                        && self.fs.kind(&path) == Some(EntryKind::Directory)
+                    {
+                        matched.push(path);
+                    }
+                }
+            }
+            directories = matched;
+        }
+        directories
+    }
+
+    /// Collects the entries below a directory. Symbolic links to
+    /// directories are matched like files unless they are followed, and
+    /// directories already visited through another link are skipped.
+    fn walk(&mut self, directory: &str, walk: &mut Walk) {
+        for name in self.list(directory) {
+            let path = join(directory, &name);
+            let is_directory = self.fs.kind(&path) == Some(EntryKind::Directory);
+            if is_directory && (walk.follow_symlinks || !self.fs.is_symlink(&path)) {
+                if walk.follow_symlinks {
+                    let canonical = self.fs.canonicalize(&path).unwrap_or_else(|_| path.clone());
+                    if !walk.visited.insert(canonical) {
+                        continue;
+                    }
+                }
+                if walk.list_directories {
+                    walk.found.push(path.clone());
+                }
+                self.walk(&path, walk);
+            } else if glob_match(walk.pattern.as_bytes(), name.as_bytes()) {
+                walk.found.push(path);
+            }
+        }
+    }
+
+    /// Copies files and directories into a destination directory, keeping
+    /// only the files selected by the `PATTERN` and `REGEX` options.
+    fn copy(
+        &mut self,
+        files: &[Token],
+        destination: &Token,
+        files_matching: bool,
+        matches: &[FileMatch],
+        variables: &Variables,
+    ) -> Result<()> {
+        let destination = self.binary_path(destination, variables);
+        let rules = matches
+            .iter()
+            .filter_map(|rule| {
+                let matcher = match rule.kind.as_ref()? {
+                    FileMatchKind::Pattern(pattern) => Matcher::Pattern(pattern.to_string()),
+                    FileMatchKind::RegEx(regex) => match Regex::new(&regex.to_string()) {
+                        Ok(regex) => Matcher::Regex(regex),
+                        Err(e) => return Some(Err(e)),
+                    },
+                };
+                Some(Ok((matcher, rule.exclude)))
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        self.create_dir_all(&destination)?;
+        for file in files {
+            let source = self.source_path(file, variables);
+            let name = GenericPath::new(source.as_str(), self.style)
+                .filename()
+                .to_string();
+            self.copy_entry(&source, &join(&destination, &name), files_matching, &rules)?;
+        }
+        Ok(())
+    }
+
+    fn copy_entry(
+        &mut self,
+        source: &str,
+        destination: &str,
+        files_matching: bool,
+        rules: &[(Matcher, bool)],
+    ) -> Result<()> {
+        let matching: Vec<bool> = rules
+            .iter()
+            .filter(|(matcher, _)| matcher.is_match(source))
+            .map(|(_, exclude)| *exclude)
+            .collect();
+        if matching.contains(&true) {
+            return Ok(());
+        }
+        match self.fs.kind(source) {
+            Some(EntryKind::Directory) => {
+                self.create_dir_all(destination)?;
+                for name in self.list(source) {
+                    self.copy_entry(
+                        &join(source, &name),
+                        &join(destination, &name),
+                        files_matching,
+                        rules,
+                    )?;
+                }
+            }
+            _ if files_matching && matching.is_empty() => {}
+            _ => {
+                let content = self.read(source)?;
+                self.write(destination, &content)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn base(&self, variables: &Variables, name: &str) -> GenericPath {
+        GenericPath::new(variables.get(name).unwrap_or_default(), self.style)
+    }
+
+    fn resolve(&self, path: &Token, variables: &Variables, base: &str) -> String {
+        let path = GenericPath::new(path.to_string(), self.style);
+        trim_separator(path.absolute(&self.base(variables, base)).normal())
+    }
+
+    fn source_path(&self, path: &Token, variables: &Variables) -> String {
+        self.resolve(path, variables, "CMAKE_CURRENT_SOURCE_DIR")
+    }
+
+    fn binary_path(&self, path: &Token, variables: &Variables) -> String {
+        self.resolve(path, variables, "CMAKE_CURRENT_BINARY_DIR")
+    }
+
+    fn record(&mut self, kind: AccessKind, path: &str) {
+        self.accesses.push(FileAccess {
+            kind,
+            path: path.to_string(),
+        });
+    }
+
+    fn read(&mut self, path: &str) -> Result<Vec<u8>> {
+        self.record(AccessKind::Read, path);
+        self.fs.read(path).map_err(io_error(path))
+    }
+
+    fn list(&mut self, directory: &str) -> Vec<String> {
+        self.record(AccessKind::List, directory);
+        self.fs.read_dir(directory).unwrap_or_default()
+    }
+
+    /// Writes a file creating its parent directories.
+    fn write(&mut self, path: &str, content: &[u8]) -> Result<()> {
+        if let Some(parent) = parent(path) {
+            self.create_dir_all(parent)?;
+        }
+        self.record(AccessKind::Write, path);
+        self.fs.write(path, content).map_err(io_error(path))
+    }
+
+    fn create_dir_all(&mut self, path: &str) -> Result<()> {
+        if self.fs.kind(path) == Some(EntryKind::Directory) {
+            return Ok(());
+        }
+        self.record(AccessKind::CreateDirectory, path);
+        self.fs.create_dir_all(path).map_err(io_error(path))
+    }
+
+    fn remove(&mut self, path: &str, recurse: bool) -> Result<()> {
+        self.record(AccessKind::Remove, path);
+        let removed = if recurse {
+            self.fs.remove_all(path)
+        } else {
+            self.fs.remove(path)
+        };
+        removed.map_err(io_error(path))
+    }
+
+    fn rename(&mut self, from: &str, to: &str) -> Result<()> {
+        self.record(AccessKind::Remove, from);
+        self.record(AccessKind::Write, to);
+        self.fs.rename(from, to).map_err(io_error(from))
+    }
+}
+
+/// State of a `GLOB_RECURSE` search.
+struct Walk<'w> {
+    pattern: &'w str,
+    follow_symlinks: bool,
+    list_directories: bool,
+    /// Canonical paths of the directories entered.
+    visited: HashSet<String>,
+    found: &'w mut Vec<String>,
+}
+
+enum Matcher {
+    Pattern(String),
+    Regex(Regex),
+}
+
+impl Matcher {
+    /// Patterns match the file name, regular expressions the full path.
+    fn is_match(&self, path: &str) -> bool {
+        match self {
+            Self::Pattern(pattern) => {
+                let name = path.rsplit('/').next().unwrap_or(path);
+                glob_match(pattern.as_bytes(), name.as_bytes())
+            }
+            Self::Regex(regex) => regex.is_match(path),
+        }
+    }
+}
+
+fn io_error(path: &str) -> impl FnOnce(io::Error) -> FileError + '_ {
+    move |e| FileError::Io {
+        path: path.to_string(),
+        kind: e.kind(),
+    }
+}
+
+/// Sets the `RESULT` variable to `0` or the error, the error is returned
+/// without one.
+fn report(result: Result<()>, variable: Option<&Token>, variables: &mut Variables) -> Result<()> {
+    match (result, variable) {
+        (Ok(()), Some(variable)) => variables.set(variable.to_string(), "0"),
+        (Err(e), Some(variable)) => variables.set(variable.to_string(), e.to_string()),
+        (result, None) => return result,
+    }
+    Ok(())
+}
+
+fn number(token: &Token) -> Result<usize> {
+    token
+        .to_string()
+        .parse()
+        .map_err(|_| FileError::InvalidNumber(token.to_string()))
+}
+
+fn concat(tokens: &Option<Vec<Token>>) -> String {
+    tokens.iter().flatten().map(Token::to_string).collect()
+}
+
+fn join(directory: &str, name: &str) -> String {
+    if directory.is_empty() || directory.ends_with('/') {
+        format!("{directory}{name}")
+    } else {
+        format!("{directory}/{name}")
+    }
+}
+
+/// Drops a trailing directory separator which is not the root directory.
+fn trim_separator(path: GenericPath) -> String {
+    let root = path.root_path().len();
+    let trimmed = path.as_str().trim_end_matches('/');
+    path.as_str()[..trimmed.len().max(root)].to_string()
+}
+
+/// Extracts the printable strings of a file as `file(STRINGS)` does.
+fn strings(content: &[u8], options: &[StringsOption]) -> Result<Vec<String>> {
+    let mut length_minimum = 0;
+    let mut length_maximum = 0;
+    let mut limit_count = usize::MAX;
+    let mut limit_input = usize::MAX;
+    let mut limit_output = usize::MAX;
+    let mut newline_consume = false;
+    let mut regex = None;
+    let mut utf8 = false;
+    for option in options {
+        match option {
+            StringsOption::LengthMaximum(value) => length_maximum = number(value)?,
+            StringsOption::LengthMinimum(value) => length_minimum = number(value)?,
+            StringsOption::LimitCount(value) => limit_count = number(value)?,
+            StringsOption::LimitInput(value) => limit_input = number(value)?,
+            StringsOption::LimitOutput(value) => limit_output = number(value)?,
+            StringsOption::NewlineConsume => newline_consume = true,
+            StringsOption::NoHexConversion => {}
+            StringsOption::Regex(value) => regex = Some(Regex::new(&value.to_string())?),
+            StringsOption::Encoding(StringsEncoding::Utf8) => utf8 = true,
+            StringsOption::Encoding(_) => return Err(FileError::Unsupported("STRINGS ENCODING")),
+        }
+    }
+
+    let mut strings = vec![];
+    let mut output = 0;
+    let mut current: Vec<u8> = vec![];
+    let mut flush = |current: &mut Vec<u8>, strings: &mut Vec<String>| {
+        let string = String::from_utf8_lossy(current).into_owned();
+        current.clear();
+        if string.is_empty()
+            || string.len() < length_minimum
+            || regex.as_ref().is_some_and(|regex| !regex.is_match(&string))
+            || strings.len() >= limit_count
+            || output + string.len() > limit_output
+        {
+            return;
+        }
+        output += string.len();
+        strings.push(string);
+    };
+    for &byte in content.iter().take(limit_input) {
+        match byte {
+            b'\r' => {}
+            b'\n' if newline_consume => current.push(byte),
+            b'\t' | 0x20..=0x7e => current.push(byte),
+            0x80.. if utf8 => current.push(byte),
+            _ => flush(&mut current, &mut strings),
+        }
+        if length_maximum > 0 && current.len() >= length_maximum {
+            flush(&mut current, &mut strings);
+        }
+    }
+    flush(&mut current, &mut strings);
+    Ok(strings)
+}
+
+fn has_wildcard(pattern: &str) -> bool {
+    pattern.contains(['*', '?', '['])
+}
+
+/// Matches a file name against a globbing pattern with `*`, `?` and
+/// `[...]` character classes.
+fn glob_match(pattern: &[u8], name: &[u8]) -> bool {
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                backtrack = Some((p, n));
+                p += 1;
+                continue;
+            }
+            Some(b'?') => {
+                p += 1;
+                n += 1;
+                continue;
+            }
+            Some(b'[') => {
+                if let Some((matched, length)) = class_match(&pattern[p..], name[n]) {
+                    if matched {
+                        p += length;
+                        n += 1;
+                        continue;
+                    }
+                } else if name[n] == b'[' {
+                    p += 1;
+                    n += 1;
+                    continue;
+                }
+            }
+            Some(&ch) if ch == name[n] => {
+                p += 1;
+                n += 1;
+                continue;
+            }
+            _ => {}
+        }
+        match backtrack {
+            Some((star, matched)) => {
+                p = star + 1;
+                n = matched + 1;
+                backtrack = Some((star, matched + 1));
+            }
+            None => return false,
+        }
+    }
+    pattern[p..].iter().all(|&ch| ch == b'*')
+}
+
+/// Matches a character class at the start of the pattern returning whether
+/// it matched and its length, `None` for an unterminated class.
+fn class_match(pattern: &[u8], ch: u8) -> Option<(bool, usize)> {
+    let mut position = 1;
+    let negated = matches!(pattern.get(position), Some(b'!' | b'^'));
+    if negated {
+        position += 1;
+    }
+    let mut matched = false;
+    let mut first = true;
+    loop {
+        let &start = pattern.get(position)?;
+        if start == b']' && !first {
+            return Some((matched != negated, position + 1));
+        }
+        first = false;
+        if pattern.get(position + 1) == Some(&b'-')
+            && pattern.get(position + 2).is_some_and(|&end| end != b']')
+        {
+            let end = pattern[position + 2];
+            matched |= (start..=end).contains(&ch);
+            position += 3;
+        } else {
+            matched |= start == ch;
+            position += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::{DiskFileSystem, MemoryFileSystem};
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    fn project() -> MemoryFileSystem {
+        let mut fs = MemoryFileSystem::new();
+        fs.insert_file("/src/CMakeLists.txt", "project(app)\n");
+        fs.insert_file("/src/VERSION", "1.2.3\n");
+        fs.insert_file("/src/main.c", "int main() {}\n");
+        fs.insert_file("/src/util/util.c", "");
+        fs.insert_file("/src/util/util.h", "");
+        fs.insert_file("/src/util/detail/impl.c", "");
+        fs.insert_file("/src/include/app.h", "");
+        fs.insert_file("/src/include/app.txt", "");
+        fs.insert_file("/src/data.bin", b"\x01abc\x00defg\nhi\r\nxyz\n".as_slice());
+        fs
+    }
+
+    fn run<F: FileSystem>(evaluator: &mut FileEvaluator<F>, src: &[u8]) -> Variables {
+        let cmakelists = parse_cmakelists(src).unwrap();
+        let doc = Doc::from(cmakelists);
+        let mut variables = Variables::from_iter([
+            ("CMAKE_CURRENT_SOURCE_DIR", "/src"),
+            ("CMAKE_CURRENT_BINARY_DIR", "/build"),
+        ]);
+        for command in doc.commands().unwrap() {
+            match command {
+                Command::File(command) => evaluator.file(&command, &mut variables).unwrap(),
+                Command::ConfigureFile(command) => {
+                    evaluator.configure_file(&command, &variables).unwrap()
+                }
+                _ => unreachable!("only file() and configure_file() commands are expected"),
+            }
+        }
+        variables
+    }
+
+    fn values<'v>(variables: &'v Variables, names: &[&str]) -> Vec<Option<&'v str>> {
+        names.iter().map(|name| variables.get(name)).collect()
+    }
+
+    #[test]
+    fn file_reading() {
+        let mut evaluator = FileEvaluator::new(project());
+        let variables = run(
+            &mut evaluator,
+            include_bytes!("../../../fixture/eval/file/reading"),
+        );
+        assert_eq!(
+            values(
+                &variables,
+                &["version", "offset", "hex", "strings", "long", "limited", "hash", "size"]
+            ),
+            [
+                Some("1.2.3\n"),
+                Some("2."),
+                Some("312e"),
+                Some("abc;defg;hi;xyz"),
+                Some("defg;xyz"),
+                Some("abc"),
+                Some("d82f34ae9aa41bc4a0cb529a1ac0898fed09d6b479fb1cc44cb66c34f15ee84d"),
+                Some("18"),
+            ]
+            .to_vec()
+        );
+        assert!(evaluator
+            .accesses()
+            .iter()
+            .all(|access| access.kind == AccessKind::Read));
+    }
+
+    #[test]
+    fn file_glob() {
+        let mut evaluator = FileEvaluator::new(project());
+        let variables = run(
+            &mut evaluator,
+            include_bytes!("../../../fixture/eval/file/glob"),
+        );
+        assert_eq!(
+            values(
+                &variables,
+                &[
+                    "sources",
+                    "relative",
+                    "recurse",
+                    "directories",
+                    "class",
+                    "nested"
+                ]
+            ),
+            [
+                Some("/src/main.c"),
+                Some("include/app.h;main.c;util"),
+                Some("util/detail/impl.c;util/util.c"),
+                Some("util/detail;util/detail/impl.c;util/util.c;util/util.h"),
+                Some("/src/include/app.h;/src/include/app.txt"),
+                Some("/src/util/detail/impl.c"),
+            ]
+            .to_vec()
+        );
+    }
+
+    #[test]
+    fn file_writing() {
+        let mut fs = project();
+        fs.insert_file(
+            "/src/version.h.in",
+            "#cmakedefine01 HAVE_VERSION\r\n#define VERSION \"${VERSION}\"\r\n",
+        );
+        let mut evaluator = FileEvaluator::new(fs);
+        let variables = run(
+            &mut evaluator,
+            include_bytes!("../../../fixture/eval/file/writing"),
+        );
+        let fs = evaluator.file_system();
+        assert_eq!(
+            fs.files()
+                .filter(|(path, _)| !path.starts_with("/src/") || path.starts_with("/src/gen"))
+                .map(|(path, content)| (path, String::from_utf8_lossy(content).into_owned()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "/build/config.h",
+                    "#define BINARY_DIR \"/build\"\r\n#define VERSION ${VERSION}\r\n".to_string()
+                ),
+                ("/build/copy/include/app.h", String::new()),
+                ("/build/copy/util/detail/impl.c", String::new()),
+                ("/build/copy/util/util.c", String::new()),
+                (
+                    "/build/copy/version.h.in",
+                    "#cmakedefine01 HAVE_VERSION\r\n#define VERSION \"${VERSION}\"\r\n".to_string()
+                ),
+                ("/build/install/app.h", String::new()),
+                (
+                    "/build/version.h",
+                    "#define HAVE_VERSION 0\n#define VERSION \"\"\n".to_string()
+                ),
+                ("/src/gen/copied.txt", "ab-c\n".to_string()),
+                ("/src/gen/log.txt", "ab-c\n".to_string()),
+                ("/src/gen/other.txt", String::new()),
+                ("/src/gen/renamed.txt", String::new()),
+            ]
+        );
+        assert_eq!(fs.kind("/src/util/util.h"), None);
+        assert_eq!(fs.kind("/src/include"), Some(EntryKind::Directory));
+        assert_eq!(fs.kind("/build/empty/dir"), Some(EntryKind::Directory));
+        assert_eq!(
+            values(
+                &variables,
+                &[
+                    "rename_result",
+                    "real",
+                    "relative",
+                    "same",
+                    "cmake",
+                    "native"
+                ]
+            ),
+            [
+                Some("/src/gen/renamed.txt: entity already exists"),
+                Some("/src/include"),
+                Some("../include/app.h"),
+                Some(""),
+                Some("/a;/b"),
+                Some("/a;/b"),
+            ]
+            .to_vec()
+        );
+    }
+
+    #[test]
+    fn file_dry_run() {
+        let mut evaluator = FileEvaluator::new(DiskFileSystem::new());
+        let variables = run(
+            &mut evaluator,
+            b"file(WRITE /nonexistent/out.txt content)
+file(READ /nonexistent/out.txt written)
+file(GLOB listed /nonexistent/*)
+file(RENAME /nonexistent/out.txt /nonexistent/renamed.txt)
+file(GLOB renamed /nonexistent/*)
+file(REMOVE_RECURSE /nonexistent)
+file(GLOB removed /nonexistent/*)
+",
+        );
+        assert_eq!(
+            values(&variables, &["written", "listed", "renamed", "removed"]),
+            [
+                Some("content"),
+                Some("/nonexistent/out.txt"),
+                Some("/nonexistent/renamed.txt"),
+                Some(""),
+            ]
+        );
+        assert_eq!(
+            evaluator.accesses()[..2],
+            [
+                FileAccess {
+                    kind: AccessKind::CreateDirectory,
+                    path: "/nonexistent".to_string()
+                },
+                FileAccess {
+                    kind: AccessKind::Write,
+                    path: "/nonexistent/out.txt".to_string()
+                },
+            ]
+        );
+        let fs = evaluator.into_file_system();
+        assert_eq!(fs.kind("/nonexistent"), None);
+        assert_eq!(fs.changes().files().count(), 0);
+        assert!(!std::path::Path::new("/nonexistent").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn file_glob_symlinks() {
+        let root = std::env::temp_dir().join(format!("cmake-parser-glob-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("src/sub")).unwrap();
+        std::fs::write(root.join("src/sub/a.c"), "").unwrap();
+        std::os::unix::fs::symlink(root.join("src"), root.join("src/sub/loop")).unwrap();
+        let root_path = root.to_string_lossy().into_owned();
+
+        let mut evaluator = FileEvaluator::new(DiskFileSystem::new());
+        let variables = run(
+            &mut evaluator,
+            format!(
+                "file(GLOB_RECURSE plain RELATIVE {root_path}/src {root_path}/src/*)
+file(GLOB_RECURSE followed FOLLOW_SYMLINKS RELATIVE {root_path}/src {root_path}/src/*)
+"
+            )
+            .as_bytes(),
+        );
+        std::fs::remove_dir_all(&root).unwrap();
+        assert_eq!(
+            values(&variables, &["plain", "followed"]),
+            [Some("sub/a.c;sub/loop"), Some("sub/a.c")]
+        );
+    }
+
+    #[test]
+    fn glob_pattern() {
+        let matches = |pattern: &str, name: &str| glob_match(pattern.as_bytes(), name.as_bytes());
+        assert!(matches("*.c", "main.c"));
+        assert!(matches("*", ".hidden"));
+        assert!(matches("a*b*c", "aXbYbc"));
+        assert!(!matches("*.c", "main.cpp"));
+        assert!(matches("?ain.[ch]", "main.h"));
+        assert!(matches("[!a-c]*", "data"));
+        assert!(!matches("[!a-d]*", "data"));
+        assert!(matches("[]]", "]"));
+        assert!(matches("[", "["));
+    }
+}
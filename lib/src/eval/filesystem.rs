@@ -0,0 +1,452 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs, io,
+    path::Path,
+    time::SystemTime,
+};
+
+/// Kind of a filesystem entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EntryKind {
+    File,
+    Directory,
+}
+
+/// Filesystem `file()` commands are evaluated against.
+///
+/// Paths are absolute paths with `/` separators as the evaluator resolves
+/// them.
+pub trait FileSystem {
+    /// Kind of the entry, symbolic links are followed.
+    fn kind(&self, path: &str) -> Option<EntryKind>;
+    /// The entry itself is a symbolic link.
+    fn is_symlink(&self, _path: &str) -> bool {
+        false
+    }
+    /// Path with the symbolic links resolved.
+    fn canonicalize(&self, path: &str) -> io::Result<String> {
+        Ok(path.to_string())
+    }
+    fn read(&self, path: &str) -> io::Result<Vec<u8>>;
+    /// Names of the entries of a directory.
+    fn read_dir(&self, path: &str) -> io::Result<Vec<String>>;
+    fn modified(&self, _path: &str) -> Option<SystemTime> {
+        None
+    }
+    /// Creates or truncates a file, the parent directory has to exist.
+    fn write(&mut self, path: &str, content: &[u8]) -> io::Result<()>;
+    fn create_dir_all(&mut self, path: &str) -> io::Result<()>;
+    /// Removes a file or an empty directory.
+    fn remove(&mut self, path: &str) -> io::Result<()>;
+    /// Removes a file or a directory with its content.
+    fn remove_all(&mut self, path: &str) -> io::Result<()>;
+    fn rename(&mut self, from: &str, to: &str) -> io::Result<()>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum MemoryEntry {
+    File(Vec<u8>),
+    Directory,
+}
+
+/// Filesystem kept in memory.
+///
+/// ```
+/// use cmake_parser::eval::{EntryKind, FileSystem, MemoryFileSystem};
+///
+/// let mut fs = MemoryFileSystem::new();
+/// fs.insert_file("/src/main.c", "int main() {}");
+/// assert_eq!(fs.kind("/src"), Some(EntryKind::Directory));
+/// assert_eq!(fs.read_dir("/src").unwrap(), vec!["main.c"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryFileSystem {
+    entries: BTreeMap<String, MemoryEntry>,
+}
+
+impl MemoryFileSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a file creating its parent directories.
+    pub fn insert_file(&mut self, path: &str, content: impl Into<Vec<u8>>) {
+        if let Some(parent) = parent(path) {
+            self.insert_directories(parent);
+        }
+        self.entries
+            .insert(path.to_string(), MemoryEntry::File(content.into()));
+    }
+
+    /// Files with their content sorted by path.
+    pub fn files(&self) -> impl Iterator<Item = (&str, &[u8])> {
+        self.entries.iter().filter_map(|(path, entry)| match entry {
+            MemoryEntry::File(content) => Some((path.as_str(), content.as_slice())),
+            MemoryEntry::Directory => None,
+        })
+    }
+
+    /// Directories sorted by path.
+    pub fn directories(&self) -> impl Iterator<Item = &str> {
+        self.entries
+            .iter()
+            .filter(|(_, entry)| **entry == MemoryEntry::Directory)
+            .map(|(path, _)| path.as_str())
+    }
+
+    fn insert_directories(&mut self, path: &str) {
+        let mut path = Some(path);
+        while let Some(directory) = path.filter(|path| !path.is_empty()) {
+            self.entries
+                .entry(directory.to_string())
+                .or_insert(MemoryEntry::Directory);
+            path = parent(directory);
+        }
+    }
+
+    /// Paths of all entries below a directory.
+    fn children(&self, path: &str) -> Vec<String> {
+        let prefix = if path.ends_with('/') {
+            path.to_string()
+        } else {
+            format!("{path}/")
+        };
+        self.entries
+            .range(prefix.clone()..)
+            .map(|(child, _)| child)
+            .take_while(|child| child.starts_with(&prefix))
+            .cloned()
+            .collect()
+    }
+
+    fn not_found() -> io::Error {
+        io::Error::from(io::ErrorKind::NotFound)
+    }
+}
+
+impl FileSystem for MemoryFileSystem {
+    fn kind(&self, path: &str) -> Option<EntryKind> {
+        match self.entries.get(path) {
+            Some(MemoryEntry::File(_)) => Some(EntryKind::File),
+            Some(MemoryEntry::Directory) => Some(EntryKind::Directory),
+            None if path == "/" => Some(EntryKind::Directory),
+            None => None,
+        }
+    }
+
+    fn read(&self, path: &str) -> io::Result<Vec<u8>> {
+        match self.entries.get(path) {
+            Some(MemoryEntry::File(content)) => Ok(content.clone()),
+            Some(MemoryEntry::Directory) => Err(io::ErrorKind::IsADirectory.into()),
+            None => Err(Self::not_found()),
+        }
+    }
+
+    fn read_dir(&self, path: &str) -> io::Result<Vec<String>> {
+        if self.kind(path) != Some(EntryKind::Directory) {
+            return Err(Self::not_found());
+        }
+        Ok(self
+            .children(path)
+            .into_iter()
+            .filter(|child| parent(child) == Some(path))
+            .map(|child| child.rsplit('/').next().unwrap_or(&child).to_string())
+            .collect())
+    }
+
+    fn write(&mut self, path: &str, content: &[u8]) -> io::Result<()> {
+        match parent(path).map(|parent| self.kind(parent)) {
+            Some(Some(EntryKind::Directory)) | None => {}
+            Some(_) => return Err(Self::not_found()),
+        }
+        if self.kind(path) == Some(EntryKind::Directory) {
+            return Err(io::ErrorKind::IsADirectory.into());
+        }
+        self.entries
+            .insert(path.to_string(), MemoryEntry::File(content.to_vec()));
+        Ok(())
+    }
+
+    fn create_dir_all(&mut self, path: &str) -> io::Result<()> {
+        if self.kind(path) == Some(EntryKind::File) {
+            return Err(io::ErrorKind::AlreadyExists.into());
+        }
+        self.insert_directories(path);
+        Ok(())
+    }
+
+    fn remove(&mut self, path: &str) -> io::Result<()> {
+        if !self.children(path).is_empty() {
+            return Err(io::ErrorKind::DirectoryNotEmpty.into());
+        }
+        self.entries
+            .remove(path)
+            .map(|_| ())
+            .ok_or_else(Self::not_found)
+    }
+
+    fn remove_all(&mut self, path: &str) -> io::Result<()> {
+        for child in self.children(path) {
+            self.entries.remove(&child);
+        }
+        self.remove(path)
+    }
+
+    fn rename(&mut self, from: &str, to: &str) -> io::Result<()> {
+        if self.kind(from).is_none() {
+            return Err(Self::not_found());
+        }
+        if parent(to).is_some_and(|parent| self.kind(parent) != Some(EntryKind::Directory)) {
+            return Err(Self::not_found());
+        }
+        let mut moved = self.children(from);
+        moved.insert(0, from.to_string());
+        if self.kind(to).is_some() {
+            self.remove_all(to)?;
+        }
+        for path in moved {
+            let entry = self.entries.remove(&path).expect("existing entry");
+            self.entries
+                .insert(format!("{to}{}", &path[from.len()..]), entry);
+        }
+        Ok(())
+    }
+}
+
+/// Real filesystem which is only read, changes are kept in memory.
+///
+/// Evaluating a script against it is a dry run: files the script writes,
+/// removes or renames are visible to its later commands, but the disk is not
+/// changed.
+///
+/// ```
+/// use cmake_parser::eval::{DiskFileSystem, EntryKind, FileSystem};
+///
+/// let mut fs = DiskFileSystem::new();
+/// fs.create_dir_all("/nonexistent/dir").unwrap();
+/// fs.write("/nonexistent/dir/out.txt", b"content").unwrap();
+/// assert_eq!(fs.read("/nonexistent/dir/out.txt").unwrap(), b"content");
+/// assert_eq!(fs.changes().kind("/nonexistent"), Some(EntryKind::Directory));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiskFileSystem {
+    /// Files and directories written by the evaluated commands.
+    changes: MemoryFileSystem,
+    /// Paths removed by the evaluated commands, hiding the disk entries
+    /// below them which were not written again.
+    removed: BTreeSet<String>,
+}
+
+impl DiskFileSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Files and directories written by the evaluated commands.
+    pub fn changes(&self) -> &MemoryFileSystem {
+        &self.changes
+    }
+
+    /// Paths removed by the evaluated commands.
+    pub fn removed(&self) -> impl Iterator<Item = &str> {
+        self.removed.iter().map(String::as_str)
+    }
+
+    /// The disk entry is hidden by a removal of it or of one of its parents.
+    fn is_removed(&self, path: &str) -> bool {
+        let mut path = Some(path);
+        while let Some(current) = path {
+            if self.removed.contains(current) {
+                return true;
+            }
+            path = parent(current);
+        }
+        false
+    }
+
+    fn on_disk(&self, path: &str) -> bool {
+        !self.changes.entries.contains_key(path) && !self.is_removed(path)
+    }
+
+    /// Paths of the files and directories below a directory, parents first.
+    fn tree(&self, path: &str) -> io::Result<Vec<(String, Option<Vec<u8>>)>> {
+        let mut tree = vec![];
+        let mut directories = vec![path.to_string()];
+        while let Some(directory) = directories.pop() {
+            for name in self.read_dir(&directory)? {
+                let child = format!("{}/{name}", directory.trim_end_matches('/'));
+                if self.kind(&child) == Some(EntryKind::Directory) {
+                    tree.push((child.clone(), None));
+                    directories.push(child);
+                } else {
+                    let content = self.read(&child)?;
+                    tree.push((child, Some(content)));
+                }
+            }
+        }
+        Ok(tree)
+    }
+}
+
+impl FileSystem for DiskFileSystem {
+    fn kind(&self, path: &str) -> Option<EntryKind> {
+        if self.changes.entries.contains_key(path) {
+            return self.changes.kind(path);
+        }
+        if self.is_removed(path) {
+            return None;
+        }
+        let metadata = fs::metadata(path).ok()?;
+        Some(if metadata.is_dir() {
+            EntryKind::Directory
+        } else {
+            EntryKind::File
+        })
+    }
+
+    fn is_symlink(&self, path: &str) -> bool {
+        self.on_disk(path)
+            && fs::symlink_metadata(path).is_ok_and(|metadata| metadata.file_type().is_symlink())
+    }
+
+    fn canonicalize(&self, path: &str) -> io::Result<String> {
+        if !self.on_disk(path) {
+            return Ok(path.to_string());
+        }
+        Ok(fs::canonicalize(path)?.to_string_lossy().into_owned())
+    }
+
+    fn read(&self, path: &str) -> io::Result<Vec<u8>> {
+        if self.changes.entries.contains_key(path) {
+            return self.changes.read(path);
+        }
+        if self.is_removed(path) {
+            return Err(MemoryFileSystem::not_found());
+        }
+        fs::read(path)
+    }
+
+    fn read_dir(&self, path: &str) -> io::Result<Vec<String>> {
+        if self.kind(path) != Some(EntryKind::Directory) {
+            return Err(MemoryFileSystem::not_found());
+        }
+        let mut names = BTreeSet::new();
+        let written = self.changes.kind(path) == Some(EntryKind::Directory);
+        if written {
+            names.extend(self.changes.read_dir(path)?);
+        }
+        if !self.is_removed(path) {
+            let entries = match fs::read_dir(path) {
+                Ok(entries) => entries,
+                Err(_) if written => return Ok(names.into_iter().collect()),
+                Err(e) => return Err(e),
+            };
+            for entry in entries {
+                let name = entry?.file_name().to_string_lossy().into_owned();
+                let child = format!("{}/{name}", path.trim_end_matches('/'));
+                if !self.is_removed(&child) {
+                    names.insert(name);
+                }
+            }
+        }
+        Ok(names.into_iter().collect())
+    }
+
+    fn modified(&self, path: &str) -> Option<SystemTime> {
+        if !self.on_disk(path) {
+            return None;
+        }
+        fs::metadata(Path::new(path)).ok()?.modified().ok()
+    }
+
+    fn write(&mut self, path: &str, content: &[u8]) -> io::Result<()> {
+        if parent(path).is_some_and(|parent| self.kind(parent) != Some(EntryKind::Directory)) {
+            return Err(MemoryFileSystem::not_found());
+        }
+        if self.kind(path) == Some(EntryKind::Directory) {
+            return Err(io::ErrorKind::IsADirectory.into());
+        }
+        self.changes.insert_file(path, content);
+        Ok(())
+    }
+
+    fn create_dir_all(&mut self, path: &str) -> io::Result<()> {
+        let mut current = Some(path);
+        while let Some(directory) = current {
+            if self.kind(directory) == Some(EntryKind::File) {
+                return Err(io::ErrorKind::AlreadyExists.into());
+            }
+            current = parent(directory);
+        }
+        self.changes.insert_directories(path);
+        Ok(())
+    }
+
+    fn remove(&mut self, path: &str) -> io::Result<()> {
+        match self.kind(path) {
+            None => return Err(MemoryFileSystem::not_found()),
+            Some(EntryKind::Directory) if !self.read_dir(path)?.is_empty() => {
+                return Err(io::ErrorKind::DirectoryNotEmpty.into())
+            }
+            Some(_) => {}
+        }
+        self.remove_all(path)
+    }
+
+    fn remove_all(&mut self, path: &str) -> io::Result<()> {
+        if self.kind(path).is_none() {
+            return Err(MemoryFileSystem::not_found());
+        }
+        if self.changes.entries.contains_key(path) {
+            self.changes.remove_all(path)?;
+        }
+        if self.kind(path).is_some() {
+            self.removed.insert(path.to_string());
+        }
+        Ok(())
+    }
+
+    fn rename(&mut self, from: &str, to: &str) -> io::Result<()> {
+        let Some(kind) = self.kind(from) else {
+            return Err(MemoryFileSystem::not_found());
+        };
+        if parent(to).is_some_and(|parent| self.kind(parent) != Some(EntryKind::Directory)) {
+            return Err(MemoryFileSystem::not_found());
+        }
+        let moved = match kind {
+            EntryKind::Directory => self.tree(from)?,
+            EntryKind::File => vec![],
+        };
+        let content = match kind {
+            EntryKind::Directory => None,
+            EntryKind::File => Some(self.read(from)?),
+        };
+        if self.kind(to).is_some() {
+            self.remove_all(to)?;
+        }
+        self.remove_all(from)?;
+        match content {
+            Some(content) => self.changes.insert_file(to, content),
+            None => self.changes.insert_directories(to),
+        }
+        for (path, content) in moved {
+            let path = format!("{to}{}", &path[from.len()..]);
+            match content {
+                Some(content) => self.changes.insert_file(&path, content),
+                None => self.changes.insert_directories(&path),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parent directory of an absolute path, `None` for the root and relative
+/// names.
+pub(crate) fn parent(path: &str) -> Option<&str> {
+    let position = path.trim_end_matches('/').rfind('/')?;
+    Some(if position == 0 {
+        "/"
+    } else {
+        &path[..position]
+    })
+}
@@ -0,0 +1,27 @@
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+use sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512};
+
+use crate::command::common::HashAlgorithm;
+
+/// Lowercase hexadecimal digest of the data.
+pub(crate) fn hash(algorithm: &HashAlgorithm, data: &[u8]) -> String {
+    let digest = match algorithm {
+        HashAlgorithm::MD5 => Md5::digest(data).to_vec(),
+        HashAlgorithm::SHA1 => Sha1::digest(data).to_vec(),
+        HashAlgorithm::SHA224 => Sha224::digest(data).to_vec(),
+        HashAlgorithm::SHA256 => Sha256::digest(data).to_vec(),
+        HashAlgorithm::SHA384 => Sha384::digest(data).to_vec(),
+        HashAlgorithm::SHA512 => Sha512::digest(data).to_vec(),
+        HashAlgorithm::SHA3_224 => Sha3_224::digest(data).to_vec(),
+        HashAlgorithm::SHA3_256 => Sha3_256::digest(data).to_vec(),
+        HashAlgorithm::SHA3_384 => Sha3_384::digest(data).to_vec(),
+        HashAlgorithm::SHA3_512 => Sha3_512::digest(data).to_vec(),
+    };
+    hex(&digest)
+}
+
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
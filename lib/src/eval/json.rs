@@ -0,0 +1,358 @@
+use serde_json::Value;
+
+use crate::{
+    command::scripting::string::{
+        JsonCommand, StringJsonEqual, StringJsonGet, StringJsonLength, StringJsonMember,
+        StringJsonRemove, StringJsonSet, StringJsonType,
+    },
+    Token,
+};
+
+/// Error of a `string(JSON)` command with the path it occurred at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct JsonError {
+    pub path: Vec<String>,
+    pub message: String,
+}
+
+type Result<T> = std::result::Result<T, JsonError>;
+
+/// Runs a `string(JSON)` subcommand returning the value of the output
+/// variable.
+pub(crate) fn json(command: &JsonCommand) -> Result<String> {
+    match command {
+        JsonCommand::Get(StringJsonGet {
+            json_string,
+            member_index,
+        }) => {
+            let document = parse(json_string)?;
+            let value = lookup(&document, member_index)?;
+            Ok(match value {
+                Value::Array(_) | Value::Object(_) => write(value),
+                Value::Bool(value) => if *value { "ON" } else { "OFF" }.to_string(),
+                Value::Null => String::new(),
+                Value::Number(_) => write(value),
+                Value::String(value) => value.clone(),
+            })
+        }
+        JsonCommand::Type(StringJsonType {
+            json_string,
+            member_index,
+        }) => {
+            let document = parse(json_string)?;
+            Ok(type_name(lookup(&document, member_index)?).to_string())
+        }
+        JsonCommand::Member(StringJsonMember {
+            json_string,
+            member_index,
+            index,
+        }) => {
+            let document = parse(json_string)?;
+            let path = member_index.as_deref().unwrap_or_default();
+            let Value::Object(object) = lookup(&document, path)? else {
+                return Err(error(
+                    path,
+                    format!(
+                        "MEMBER needs to be called with an element of type OBJECT, got {}",
+                        type_name(lookup(&document, path)?)
+                    ),
+                ));
+            };
+            let position = parse_index(path, index)?;
+            object
+                .keys()
+                .nth(position)
+                .cloned()
+                .ok_or_else(|| error(path, format!("index out of range: {position}")))
+        }
+        JsonCommand::Length(StringJsonLength {
+            json_string,
+            member_index,
+        }) => {
+            let document = parse(json_string)?;
+            let path = member_index.as_deref().unwrap_or_default();
+            match lookup(&document, path)? {
+                Value::Array(array) => Ok(array.len().to_string()),
+                Value::Object(object) => Ok(object.len().to_string()),
+                value => Err(error(
+                    path,
+                    format!(
+                        "LENGTH needs to be called with an element of type ARRAY or OBJECT, got {}",
+                        type_name(value)
+                    ),
+                )),
+            }
+        }
+        JsonCommand::Remove(StringJsonRemove {
+            json_string,
+            member_index,
+        }) => {
+            let mut document = parse(json_string)?;
+            let (last, parent_path) = split_path(member_index)?;
+            match lookup_mut(&mut document, parent_path)? {
+                Value::Object(object) => {
+                    object.remove(&last.to_string());
+                }
+                Value::Array(array) => {
+                    let position = parse_index(member_index, last)?;
+                    if position < array.len() {
+                        array.remove(position);
+                    }
+                }
+                value => return Err(container_error(member_index, last, value)),
+            }
+            Ok(write(&document))
+        }
+        JsonCommand::Set(StringJsonSet {
+            json_string,
+            member_index,
+            value,
+        }) => {
+            let mut document = parse(json_string)?;
+            let new_value = parse(value)?;
+            let (last, parent_path) = split_path(member_index)?;
+            match lookup_mut(&mut document, parent_path)? {
+                Value::Object(object) => {
+                    object.insert(last.to_string(), new_value);
+                }
+                Value::Array(array) => {
+                    let position = parse_index(member_index, last)?;
+                    if position < array.len() {
+                        array[position] = new_value;
+                    } else {
+                        array.push(new_value);
+                    }
+                }
+                value => return Err(container_error(member_index, last, value)),
+            }
+            Ok(write(&document))
+        }
+        JsonCommand::Equal(StringJsonEqual {
+            json_string1,
+            json_string2,
+        }) => {
+            let equal = parse(json_string1)? == parse(json_string2)?;
+            Ok(if equal { "ON" } else { "OFF" }.to_string())
+        }
+    }
+}
+
+fn error(path: &[Token], message: String) -> JsonError {
+    JsonError {
+        path: path.iter().map(Token::to_string).collect(),
+        message,
+    }
+}
+
+fn container_error(path: &[Token], key: &Token, value: &Value) -> JsonError {
+    error(
+        path,
+        format!(
+            "invalid path '{}', need element of OBJECT or ARRAY type to lookup '{key}' got {}",
+            path.iter()
+                .map(Token::to_string)
+                .collect::<Vec<_>>()
+                .join(" "),
+            type_name(value)
+        ),
+    )
+}
+
+fn parse(json: &Token) -> Result<Value> {
+    serde_json::from_slice(json.as_bytes()).map_err(|e| JsonError {
+        path: vec![],
+        message: format!("failed parsing json string: {e}"),
+    })
+}
+
+fn parse_index(path: &[Token], index: &Token) -> Result<usize> {
+    index
+        .to_string()
+        .parse()
+        .map_err(|_| error(path, format!("expected an array index, got: '{index}'")))
+}
+
+fn split_path<'p, 't>(path: &'p [Token<'t>]) -> Result<(&'p Token<'t>, &'p [Token<'t>])> {
+    path.split_last()
+        .ok_or_else(|| error(path, "missing member or index".to_string()))
+}
+
+fn lookup<'v>(document: &'v Value, path: &[Token]) -> Result<&'v Value> {
+    let mut value = document;
+    for (position, key) in path.iter().enumerate() {
+        let current = &path[..=position];
+        value = match value {
+            Value::Object(object) => object
+                .get(&key.to_string())
+                .ok_or_else(|| error(current, format!("member '{key}' not found")))?,
+            Value::Array(array) => {
+                let index = parse_index(current, key)?;
+                array.get(index).ok_or_else(|| {
+                    error(
+                        current,
+                        format!("expected an index less than {} got '{index}'", array.len()),
+                    )
+                })?
+            }
+            _ => return Err(container_error(current, key, value)),
+        };
+    }
+    Ok(value)
+}
+
+fn lookup_mut<'v>(document: &'v mut Value, path: &[Token]) -> Result<&'v mut Value> {
+    // the immutable lookup reports errors, the mutable one only follows the path
+    lookup(document, path)?;
+    let mut value = document;
+    for key in path {
+        value = match value {
+            Value::Object(object) => object.get_mut(&key.to_string()).expect("checked path"),
+            Value::Array(array) => {
+                &mut array[key.to_string().parse::<usize>().expect("checked path")]
+            }
+            _ => unreachable!("checked path"),
+        };
+    }
+    Ok(value)
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "NULL",
+        Value::Bool(_) => "BOOLEAN",
+        Value::Number(_) => "NUMBER",
+        Value::String(_) => "STRING",
+        Value::Array(_) => "ARRAY",
+        Value::Object(_) => "OBJECT",
+    }
+}
+
+/// Right margin of single line arrays of the CMake JSON writer.
+const RIGHT_MARGIN: usize = 74;
+
+/// Serializes a value the way CMake's JSON writer does: two spaces
+/// indentation, ` : ` separators and short arrays on a single line.
+pub(crate) fn write(value: &Value) -> String {
+    let mut output = String::new();
+    write_value(value, 0, &mut output);
+    output
+}
+
+fn write_value(value: &Value, depth: usize, output: &mut String) {
+    match value {
+        Value::Null => output.push_str("null"),
+        Value::Bool(value) => output.push_str(if *value { "true" } else { "false" }),
+        Value::Number(number) => match number.as_f64() {
+            Some(real) if number.is_f64() => output.push_str(&format_real(real)),
+            _ => output.push_str(&number.to_string()),
+        },
+        Value::String(value) => write_string(value, output),
+        Value::Array(array) if array.is_empty() => output.push_str("[]"),
+        Value::Array(array) => {
+            let multiline = array.len() * 3 >= RIGHT_MARGIN
+                || array.iter().any(|child| match child {
+                    Value::Array(array) => !array.is_empty(),
+                    Value::Object(object) => !object.is_empty(),
+                    _ => false,
+                });
+            let children: Vec<String> = array
+                .iter()
+                .map(|child| {
+                    let mut output = String::new();
+                    write_value(child, depth + 1, &mut output);
+                    output
+                })
+                .collect();
+            let length =
+                4 + (array.len() - 1) * 2 + children.iter().map(String::len).sum::<usize>();
+            if multiline || length >= RIGHT_MARGIN {
+                output.push_str("[\n");
+                for (index, child) in children.iter().enumerate() {
+                    output.push_str(&indent(depth + 1));
+                    output.push_str(child);
+                    if index + 1 < children.len() {
+                        output.push(',');
+                    }
+                    output.push('\n');
+                }
+                output.push_str(&indent(depth));
+                output.push(']');
+            } else {
+                output.push_str("[ ");
+                output.push_str(&children.join(", "));
+                output.push_str(" ]");
+            }
+        }
+        Value::Object(object) if object.is_empty() => output.push_str("{}"),
+        Value::Object(object) => {
+            output.push_str("{\n");
+            for (index, (key, child)) in object.iter().enumerate() {
+                output.push_str(&indent(depth + 1));
+                write_string(key, output);
+                output.push_str(" : ");
+                write_value(child, depth + 1, output);
+                if index + 1 < object.len() {
+                    output.push(',');
+                }
+                output.push('\n');
+            }
+            output.push_str(&indent(depth));
+            output.push('}');
+        }
+    }
+}
+
+fn indent(depth: usize) -> String {
+    "  ".repeat(depth)
+}
+
+fn write_string(value: &str, output: &mut String) {
+    output.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\u{8}' => output.push_str("\\b"),
+            '\u{c}' => output.push_str("\\f"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            ' '..='\u{7f}' => output.push(ch),
+            _ => {
+                let mut units = [0; 2];
+                for unit in ch.encode_utf16(&mut units) {
+                    output.push_str(&format!("\\u{unit:04x}"));
+                }
+            }
+        }
+    }
+    output.push('"');
+}
+
+/// Formats a real number with 17 significant digits as `%.17g` does,
+/// keeping a decimal point.
+fn format_real(value: f64) -> String {
+    let scientific = format!("{value:.16e}");
+    let (mantissa, exponent) = scientific.split_once('e').expect("exponent");
+    let exponent: i32 = exponent.parse().expect("exponent");
+    let mut formatted = if !(-4..17).contains(&exponent) {
+        let mantissa = trim_fraction(mantissa);
+        let sign = if exponent < 0 { '-' } else { '+' };
+        format!("{mantissa}e{sign}{:02}", exponent.abs())
+    } else {
+        let decimals = (16 - exponent).max(0) as usize;
+        trim_fraction(&format!("{value:.decimals$}")).to_string()
+    };
+    if !formatted.contains(['.', 'e']) {
+        formatted.push_str(".0");
+    }
+    formatted
+}
+
+fn trim_fraction(value: &str) -> &str {
+    if value.contains('.') {
+        value.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        value
+    }
+}
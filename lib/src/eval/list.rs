@@ -0,0 +1,623 @@
+use std::{cmp::Ordering, fmt};
+
+use crate::{
+    analysis::strip_genex,
+    command::scripting::list::{
+        FilterMode, SortCase, SortCompare, SortOrder, TransformAction, TransformSelector,
+    },
+    eval::{Regex, RegexError},
+    Token,
+};
+
+/// CMake list: a string of elements separated with `;`.
+///
+/// Splitting follows CMake: `\;` is an escaped semicolon and semicolons
+/// inside square brackets do not separate elements. Empty elements are kept
+/// the way the `list()` command keeps them.
+///
+/// ```
+/// use cmake_parser::eval::CMakeList;
+///
+/// let list = CMakeList::parse("a;b\\;c;[d;e];;f");
+/// assert_eq!(list.items(), ["a", "b;c", "[d;e]", "", "f"]);
+/// assert_eq!(list.to_string(), "a;b;c;[d;e];;f");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CMakeList {
+    items: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ListError {
+    #[error("index: {index} out of range (-{length}, {})", *.length as i64 - 1)]
+    IndexOutOfRange { index: i64, length: usize },
+    #[error("invalid index: {0}")]
+    InvalidIndex(String),
+    #[error("begin index: {begin} is out of range 0 - {length}")]
+    SublistBegin { begin: i64, length: usize },
+    #[error("length: {0} should be -1 or greater")]
+    SublistLength(i64),
+    #[error("start index ({start}) is greater than stop index ({stop})")]
+    Range { start: i64, stop: i64 },
+    #[error("step ({0}) should be greater than 0")]
+    Step(i64),
+    #[error(transparent)]
+    Regex(#[from] RegexError),
+}
+
+impl CMakeList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Splits a value into elements, an empty value is an empty list.
+    pub fn parse(value: &str) -> Self {
+        if value.is_empty() {
+            return Self::default();
+        }
+        let mut items = vec![];
+        let mut item = String::new();
+        let mut nesting = 0i32;
+        let mut chars = value.chars().peekable();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\\' if chars.peek() == Some(&';') => {
+                    chars.next();
+                    item.push(';');
+                }
+                '[' => {
+                    nesting += 1;
+                    item.push(ch);
+                }
+                ']' => {
+                    nesting -= 1;
+                    item.push(ch);
+                }
+                ';' if nesting == 0 => items.push(std::mem::take(&mut item)),
+                _ => item.push(ch),
+            }
+        }
+        items.push(item);
+        Self { items }
+    }
+
+    /// Splits a value dropping empty elements as CMake does for command
+    /// arguments.
+    pub fn parse_non_empty(value: &str) -> Self {
+        let mut list = Self::parse(value);
+        list.items.retain(|item| !item.is_empty());
+        list
+    }
+
+    pub fn items(&self) -> &[String] {
+        &self.items
+    }
+
+    pub fn into_items(self) -> Vec<String> {
+        self.items
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, String> {
+        self.items.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// `list(GET)`, negative indices count from the end.
+    pub fn get(&self, indices: &[i64]) -> Result<CMakeList, ListError> {
+        let items = indices
+            .iter()
+            .map(|&index| Ok(self.items[self.index(index)?].clone()))
+            .collect::<Result<_, ListError>>()?;
+        Ok(Self { items })
+    }
+
+    /// `list(JOIN)`.
+    pub fn join(&self, glue: &str) -> String {
+        self.items.join(glue)
+    }
+
+    /// `list(SUBLIST)`, `-1` length takes the rest of the list.
+    pub fn sublist(&self, begin: i64, length: i64) -> Result<CMakeList, ListError> {
+        if begin < 0 || begin as usize > self.items.len() {
+            return Err(ListError::SublistBegin {
+                begin,
+                length: self.items.len(),
+            });
+        }
+        if length < -1 {
+            return Err(ListError::SublistLength(length));
+        }
+        let begin = begin as usize;
+        let end = if length == -1 {
+            self.items.len()
+        } else {
+            (begin + length as usize).min(self.items.len())
+        };
+        Ok(Self {
+            items: self.items[begin..end].to_vec(),
+        })
+    }
+
+    /// `list(FIND)`, `None` stands for `-1`.
+    pub fn find(&self, value: &str) -> Option<usize> {
+        self.items.iter().position(|item| item == value)
+    }
+
+    /// `list(APPEND)`.
+    pub fn append<I: Into<String>>(&mut self, items: impl IntoIterator<Item = I>) {
+        self.items.extend(items.into_iter().map(Into::into));
+    }
+
+    /// `list(PREPEND)`.
+    pub fn prepend<I: Into<String>>(&mut self, items: impl IntoIterator<Item = I>) {
+        self.items
+            .splice(0..0, items.into_iter().map(Into::into).collect::<Vec<_>>());
+    }
+
+    /// `list(INSERT)`, the index may be equal to the length of the list.
+    pub fn insert<I: Into<String>>(
+        &mut self,
+        index: i64,
+        items: impl IntoIterator<Item = I>,
+    ) -> Result<(), ListError> {
+        let length = self.items.len() as i64;
+        let position = if index < 0 { index + length } else { index };
+        if position < 0 || position > length {
+            return Err(ListError::IndexOutOfRange {
+                index,
+                length: self.items.len(),
+            });
+        }
+        let position = position as usize;
+        self.items.splice(
+            position..position,
+            items.into_iter().map(Into::into).collect::<Vec<_>>(),
+        );
+        Ok(())
+    }
+
+    /// `list(POP_BACK)` of one element.
+    pub fn pop_back(&mut self) -> Option<String> {
+        self.items.pop()
+    }
+
+    /// `list(POP_FRONT)` of one element.
+    pub fn pop_front(&mut self) -> Option<String> {
+        (!self.items.is_empty()).then(|| self.items.remove(0))
+    }
+
+    /// `list(REMOVE_ITEM)`.
+    pub fn remove_item<S: AsRef<str>>(&mut self, values: &[S]) {
+        self.items
+            .retain(|item| !values.iter().any(|value| value.as_ref() == item));
+    }
+
+    /// `list(REMOVE_AT)`.
+    pub fn remove_at(&mut self, indices: &[i64]) -> Result<(), ListError> {
+        let mut positions = indices
+            .iter()
+            .map(|&index| self.index(index))
+            .collect::<Result<Vec<_>, _>>()?;
+        positions.sort_unstable();
+        positions.dedup();
+        for position in positions.into_iter().rev() {
+            self.items.remove(position);
+        }
+        Ok(())
+    }
+
+    /// `list(REMOVE_DUPLICATES)`, the first occurrence is kept.
+    pub fn remove_duplicates(&mut self) {
+        let mut seen = std::collections::HashSet::new();
+        self.items.retain(|item| seen.insert(item.clone()));
+    }
+
+    /// `list(FILTER)`.
+    pub fn filter(&mut self, mode: &FilterMode, regex: &str) -> Result<(), ListError> {
+        let regex = Regex::new(regex)?;
+        let include = matches!(mode, FilterMode::Include);
+        self.items.retain(|item| regex.is_match(item) == include);
+        Ok(())
+    }
+
+    /// `list(TRANSFORM)`.
+    pub fn transform(
+        &mut self,
+        action: &TransformAction,
+        selector: Option<&TransformSelector>,
+    ) -> Result<(), ListError> {
+        let selected: Vec<bool> = match selector {
+            None => vec![true; self.items.len()],
+            Some(TransformSelector::At(at)) => {
+                let mut selected = vec![false; self.items.len()];
+                selected[self.index(parse_index(&at.index)?)?] = true;
+                selected
+            }
+            Some(TransformSelector::For(range)) => {
+                let start = parse_index(&range.start)?;
+                let stop = parse_index(&range.stop)?;
+                let step = range
+                    .step
+                    .as_ref()
+                    .map(parse_index)
+                    .transpose()?
+                    .unwrap_or(1);
+                let (first, last) = (self.index(start)?, self.index(stop)?);
+                if first > last {
+                    return Err(ListError::Range { start, stop });
+                }
+                if step <= 0 {
+                    return Err(ListError::Step(step));
+                }
+                let mut selected = vec![false; self.items.len()];
+                for position in (first..=last).step_by(step as usize) {
+                    selected[position] = true;
+                }
+                selected
+            }
+            Some(TransformSelector::Regex(regex)) => {
+                let regex = Regex::new(&regex.regular_expression.to_string())?;
+                self.items.iter().map(|item| regex.is_match(item)).collect()
+            }
+        };
+
+        let replace = match action {
+            TransformAction::Replace(replace) => Some((
+                Regex::new(&replace.regular_expression.to_string())?,
+                replace.replace_expression.to_string(),
+            )),
+            _ => None,
+        };
+
+        for (item, _) in self
+            .items
+            .iter_mut()
+            .zip(selected)
+            .filter(|(_, selected)| *selected)
+        {
+            *item = match action {
+                TransformAction::Append(suffix) => format!("{item}{suffix}"),
+                TransformAction::Prepend(prefix) => format!("{prefix}{item}"),
+                TransformAction::ToLower => item.to_ascii_lowercase(),
+                TransformAction::ToUpper => item.to_ascii_uppercase(),
+                TransformAction::Strip => item
+                    .trim_matches(|ch: char| ch.is_ascii_whitespace())
+                    .to_string(),
+                TransformAction::GenexStrip => strip_genex(item),
+                TransformAction::Replace(_) => {
+                    let (regex, replacement) = replace.as_ref().expect("compiled regex");
+                    regex.replace_all(item, replacement)?
+                }
+            };
+        }
+        Ok(())
+    }
+
+    /// `list(REVERSE)`.
+    pub fn reverse(&mut self) {
+        self.items.reverse();
+    }
+
+    /// `list(SORT)`, options which are not set default to `STRING`,
+    /// `SENSITIVE` and `ASCENDING`.
+    pub fn sort(
+        &mut self,
+        compare: Option<&SortCompare>,
+        case: Option<&SortCase>,
+        order: Option<&SortOrder>,
+    ) {
+        let insensitive = matches!(case, Some(SortCase::Insensitive));
+        let key = |item: &String| -> String {
+            let item = match compare {
+                Some(SortCompare::FileBasename) => item
+                    .rsplit_once('/')
+                    .map_or(item.as_str(), |(_, name)| name),
+                _ => item.as_str(),
+            };
+            if insensitive {
+                item.to_ascii_lowercase()
+            } else {
+                item.to_string()
+            }
+        };
+        let natural = matches!(compare, Some(SortCompare::Natural));
+        self.items.sort_by(|left, right| {
+            let (left, right) = (key(left), key(right));
+            if natural {
+                compare_natural(&left, &right)
+            } else {
+                left.cmp(&right)
+            }
+        });
+        if matches!(order, Some(SortOrder::Descending)) {
+            self.items.reverse();
+        }
+    }
+
+    fn index(&self, index: i64) -> Result<usize, ListError> {
+        let length = self.items.len() as i64;
+        let position = if index < 0 { index + length } else { index };
+        if position < 0 || position >= length {
+            return Err(ListError::IndexOutOfRange {
+                index,
+                length: self.items.len(),
+            });
+        }
+        Ok(position as usize)
+    }
+}
+
+impl fmt::Display for CMakeList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.items.join(";"))
+    }
+}
+
+impl From<Vec<String>> for CMakeList {
+    fn from(items: Vec<String>) -> Self {
+        Self { items }
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for CMakeList {
+    fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+        Self {
+            items: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a CMakeList {
+    type Item = &'a String;
+    type IntoIter = std::slice::Iter<'a, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl IntoIterator for CMakeList {
+    type Item = String;
+    type IntoIter = std::vec::IntoIter<String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+/// Parses an index argument of a `list()` command.
+pub fn parse_index(token: &Token) -> Result<i64, ListError> {
+    let index = token.to_string();
+    index
+        .trim()
+        .parse()
+        .map_err(|_| ListError::InvalidIndex(index))
+}
+
+/// Compares strings treating digit sequences as numbers the way
+/// `strverscmp()` does.
+fn compare_natural(left: &str, right: &str) -> Ordering {
+    let (left, right) = (left.as_bytes(), right.as_bytes());
+    let Some(index) = left.iter().zip(right).position(|(l, r)| l != r) else {
+        return left.len().cmp(&right.len());
+    };
+    let start = left[..index]
+        .iter()
+        .rposition(|byte| !byte.is_ascii_digit())
+        .map_or(0, |position| position + 1);
+    let digit = |bytes: &[u8], index: usize| bytes.get(index).is_some_and(u8::is_ascii_digit);
+    let in_number = start < index || (digit(left, index) && digit(right, index));
+    // numbers with leading zeros are compared as fractions
+    if in_number && left[start] != b'0' && right[start] != b'0' {
+        let run = |bytes: &[u8]| {
+            bytes[index..]
+                .iter()
+                .take_while(|byte| byte.is_ascii_digit())
+                .count()
+        };
+        let ordering = run(left).cmp(&run(right));
+        if ordering.is_ne() {
+            return ordering;
+        }
+    }
+    left[index].cmp(&right[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn list(value: &str) -> CMakeList {
+        CMakeList::parse(value)
+    }
+
+    #[test]
+    fn list_parse() {
+        assert_eq!(list("").items(), Vec::<String>::new());
+        assert_eq!(list(";").items(), ["", ""]);
+        assert_eq!(list("a;;b").items(), ["a", "", "b"]);
+        assert_eq!(list("a\\;b;c\\d").items(), ["a;b", "c\\d"]);
+        assert_eq!(list("[[a;b]];c").items(), ["[[a;b]]", "c"]);
+        // an unbalanced `]` disables splitting as in CMake
+        assert_eq!(list("a];b;[c").items(), ["a];b;[c"]);
+        assert_eq!(CMakeList::parse_non_empty(";a;;b;").items(), ["a", "b"]);
+        assert_eq!(list("a;b\\;c").to_string(), "a;b;c");
+    }
+
+    #[test]
+    fn list_reading() {
+        let l = list("a;b;c;d");
+        assert_eq!(l.get(&[0, -1]).unwrap().items(), ["a", "d"]);
+        assert_eq!(
+            l.get(&[4]),
+            Err(ListError::IndexOutOfRange {
+                index: 4,
+                length: 4
+            })
+        );
+        assert_eq!(
+            l.get(&[-5]).unwrap_err().to_string(),
+            "index: -5 out of range (-4, 3)"
+        );
+        assert_eq!(l.join(", "), "a, b, c, d");
+        assert_eq!(l.sublist(1, 2).unwrap().items(), ["b", "c"]);
+        assert_eq!(l.sublist(2, -1).unwrap().items(), ["c", "d"]);
+        assert_eq!(l.sublist(3, 10).unwrap().items(), ["d"]);
+        assert_eq!(l.sublist(4, 1).unwrap().items(), Vec::<String>::new());
+        assert!(l.sublist(5, 1).is_err());
+        assert!(l.sublist(0, -2).is_err());
+        assert_eq!(l.find("c"), Some(2));
+        assert_eq!(l.find("x"), None);
+        assert_eq!(parse_index(&Token::from(b"-2")), Ok(-2));
+        assert_eq!(
+            parse_index(&Token::from(b"two")),
+            Err(ListError::InvalidIndex("two".to_string()))
+        );
+    }
+
+    #[test]
+    fn list_modification() {
+        let mut l = list("b;c");
+        l.append(["d", "e"]);
+        l.prepend(["a"]);
+        assert_eq!(l.to_string(), "a;b;c;d;e");
+        l.insert(-1, ["x", "y"]).unwrap();
+        l.insert(7, ["z"]).unwrap();
+        assert!(l.insert(9, ["z"]).is_err());
+        assert_eq!(l.to_string(), "a;b;c;d;x;y;e;z");
+        assert_eq!(l.pop_back(), Some("z".to_string()));
+        assert_eq!(l.pop_front(), Some("a".to_string()));
+        l.remove_item(&["x", "y"]);
+        assert_eq!(l.to_string(), "b;c;d;e");
+        l.remove_at(&[0, -1, 0]).unwrap();
+        assert_eq!(l.to_string(), "c;d");
+
+        let mut l = list("a;b;a;c;b");
+        l.remove_duplicates();
+        assert_eq!(l.to_string(), "a;b;c");
+
+        let mut l = list("main.c;util.h;util.c;README");
+        l.filter(&FilterMode::Include, "\\.c$").unwrap();
+        assert_eq!(l.to_string(), "main.c;util.c");
+        let mut l = list("main.c;util.h");
+        l.filter(&FilterMode::Exclude, "\\.c$").unwrap();
+        assert_eq!(l.to_string(), "util.h");
+        assert!(l.filter(&FilterMode::Exclude, "(").is_err());
+    }
+
+    #[test]
+    fn list_transform() {
+        use crate::command::scripting::list::{
+            ReplaceAction, SelectorAt, SelectorFor, SelectorRegex,
+        };
+
+        let transform = |value: &str, action, selector: Option<TransformSelector>| {
+            let mut l = list(value);
+            l.transform(&action, selector.as_ref())
+                .map(|_| l.to_string())
+        };
+
+        assert_eq!(
+            transform("a;b", TransformAction::Append(Token::from(b".c")), None),
+            Ok("a.c;b.c".to_string())
+        );
+        assert_eq!(
+            transform(" a ;B", TransformAction::Strip, None),
+            Ok("a;B".to_string())
+        );
+        assert_eq!(
+            transform(
+                "a;b;c;d;e",
+                TransformAction::ToUpper,
+                Some(TransformSelector::For(SelectorFor {
+                    start: Token::from(b"1"),
+                    stop: Token::from(b"-1"),
+                    step: Some(Token::from(b"2")),
+                }))
+            ),
+            Ok("a;B;c;D;e".to_string())
+        );
+        assert_eq!(
+            transform(
+                "a;b",
+                TransformAction::Prepend(Token::from(b"-")),
+                Some(TransformSelector::At(SelectorAt {
+                    index: Token::from(b"-1"),
+                }))
+            ),
+            Ok("a;-b".to_string())
+        );
+        assert_eq!(
+            transform(
+                "lib.a;lib.so;x",
+                TransformAction::Replace(ReplaceAction {
+                    regular_expression: Token::from(b"^lib\\.(.*)$"),
+                    replace_expression: Token::from(b"\\1"),
+                }),
+                Some(TransformSelector::Regex(SelectorRegex {
+                    regular_expression: Token::from(b"^lib"),
+                }))
+            ),
+            Ok("a;so;x".to_string())
+        );
+        assert_eq!(
+            transform(
+                "a$<$<CONFIG:Debug>:b>c;$<x",
+                TransformAction::GenexStrip,
+                None
+            ),
+            Ok("ac;$<x".to_string())
+        );
+        assert_eq!(
+            transform(
+                "a;b",
+                TransformAction::ToLower,
+                Some(TransformSelector::For(SelectorFor {
+                    start: Token::from(b"1"),
+                    stop: Token::from(b"0"),
+                    step: None,
+                }))
+            ),
+            Err(ListError::Range { start: 1, stop: 0 })
+        );
+    }
+
+    #[test]
+    fn list_sort() {
+        let sort = |value: &str, compare, case, order| {
+            let mut l = list(value);
+            l.sort(compare, case, order);
+            l.to_string()
+        };
+
+        assert_eq!(sort("b;C;a", None, None, None), "C;a;b");
+        assert_eq!(
+            sort("b;C;a", None, Some(&SortCase::Insensitive), None),
+            "a;b;C"
+        );
+        assert_eq!(
+            sort("b;C;a", None, None, Some(&SortOrder::Descending)),
+            "b;a;C"
+        );
+        assert_eq!(
+            sort("z/a.c;a/b.c", Some(&SortCompare::FileBasename), None, None),
+            "z/a.c;a/b.c"
+        );
+        assert_eq!(
+            sort("v10;v9;v1.2;v1.10", Some(&SortCompare::Natural), None, None),
+            "v1.2;v1.10;v9;v10"
+        );
+        assert_eq!(
+            sort("a10;a9", Some(&SortCompare::String), None, None),
+            "a10;a9"
+        );
+    }
+}
@@ -0,0 +1,380 @@
+use crate::{
+    command::scripting::{math::Format, Math},
+    eval::Variables,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MathError {
+    #[error("math cannot parse the expression: \"{expression}\": {reason}.")]
+    Parse { expression: String, reason: String },
+    #[error("divide by zero")]
+    DivideByZero,
+}
+
+/// Expression of a `math(EXPR)` command.
+///
+/// Values are 64-bit signed integers, arithmetic wraps around on overflow
+/// as it does in CMake.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MathExpression {
+    Number(i64),
+    Unary(UnaryOperator, Box<MathExpression>),
+    Binary(BinaryOperator, Box<MathExpression>, Box<MathExpression>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnaryOperator {
+    Plus,
+    Minus,
+    Not,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinaryOperator {
+    Or,
+    Xor,
+    And,
+    ShiftLeft,
+    ShiftRight,
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+}
+
+impl BinaryOperator {
+    /// Operators grouped by precedence, from the loosest binding.
+    const PRECEDENCE: [&'static [BinaryOperator]; 6] = [
+        &[Self::Or],
+        &[Self::Xor],
+        &[Self::And],
+        &[Self::ShiftLeft, Self::ShiftRight],
+        &[Self::Add, Self::Subtract],
+        &[Self::Multiply, Self::Divide, Self::Modulo],
+    ];
+}
+
+impl MathExpression {
+    /// Parses an expression with the grammar of `math(EXPR)`.
+    ///
+    /// ```
+    /// use cmake_parser::eval::MathExpression;
+    ///
+    /// let expression = MathExpression::parse("(1 << 4) | 0xA").unwrap();
+    /// assert_eq!(expression.evaluate(), Ok(26));
+    /// ```
+    pub fn parse(expression: &str) -> Result<Self, MathError> {
+        let error = |reason: String| MathError::Parse {
+            expression: expression.to_string(),
+            reason,
+        };
+        let tokens = tokenize(expression).map_err(error)?;
+        let mut parser = Parser {
+            tokens: &tokens,
+            position: 0,
+        };
+        let parsed = parser.binary(0).map_err(error)?;
+        match parser.peek() {
+            None => Ok(parsed),
+            Some(token) => Err(error(format!("syntax error, unexpected {token}"))),
+        }
+    }
+
+    pub fn evaluate(&self) -> Result<i64, MathError> {
+        Ok(match self {
+            Self::Number(number) => *number,
+            Self::Unary(operator, operand) => {
+                let operand = operand.evaluate()?;
+                match operator {
+                    UnaryOperator::Plus => operand,
+                    UnaryOperator::Minus => operand.wrapping_neg(),
+                    UnaryOperator::Not => !operand,
+                }
+            }
+            Self::Binary(operator, left, right) => {
+                let left = left.evaluate()?;
+                let right = right.evaluate()?;
+                match operator {
+                    BinaryOperator::Or => left | right,
+                    BinaryOperator::Xor => left ^ right,
+                    BinaryOperator::And => left & right,
+                    BinaryOperator::ShiftLeft => left.wrapping_shl(right as u32),
+                    BinaryOperator::ShiftRight => left.wrapping_shr(right as u32),
+                    BinaryOperator::Add => left.wrapping_add(right),
+                    BinaryOperator::Subtract => left.wrapping_sub(right),
+                    BinaryOperator::Multiply => left.wrapping_mul(right),
+                    BinaryOperator::Divide if right == 0 => return Err(MathError::DivideByZero),
+                    BinaryOperator::Divide => left.wrapping_div(right),
+                    BinaryOperator::Modulo if right == 0 => return Err(MathError::DivideByZero),
+                    BinaryOperator::Modulo => left.wrapping_rem(right),
+                }
+            }
+        })
+    }
+}
+
+/// Runs a `math(EXPR)` command setting the output variable.
+pub fn math(command: &Math, variables: &mut Variables) -> Result<(), MathError> {
+    let expression = command.expression.to_string();
+    let value = MathExpression::parse(&expression)?
+        .evaluate()
+        .map_err(|e| MathError::Parse {
+            expression,
+            reason: e.to_string(),
+        })?;
+    let formatted = match command.output_format {
+        Some(Format::Hexadecimal) => format!("0x{value:x}"),
+        Some(Format::Decimal) | None => value.to_string(),
+    };
+    variables.set(command.variable.to_string(), formatted);
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MathToken {
+    Number(i64),
+    Operator(BinaryOperator),
+    Not,
+    Open,
+    Close,
+}
+
+impl std::fmt::Display for MathToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(number) => write!(f, "number {number}"),
+            Self::Operator(operator) => write!(f, "'{}'", operator_symbol(*operator)),
+            Self::Not => write!(f, "'~'"),
+            Self::Open => write!(f, "'('"),
+            Self::Close => write!(f, "')'"),
+        }
+    }
+}
+
+fn operator_symbol(operator: BinaryOperator) -> &'static str {
+    match operator {
+        BinaryOperator::Or => "|",
+        BinaryOperator::Xor => "^",
+        BinaryOperator::And => "&",
+        BinaryOperator::ShiftLeft => "<<",
+        BinaryOperator::ShiftRight => ">>",
+        BinaryOperator::Add => "+",
+        BinaryOperator::Subtract => "-",
+        BinaryOperator::Multiply => "*",
+        BinaryOperator::Divide => "/",
+        BinaryOperator::Modulo => "%",
+    }
+}
+
+fn tokenize(expression: &str) -> Result<Vec<MathToken>, String> {
+    let bytes = expression.as_bytes();
+    let mut tokens = vec![];
+    let mut position = 0;
+    while let Some(&byte) = bytes.get(position) {
+        let rest = &bytes[position..];
+        let (token, length) = match byte {
+            b' ' | b'\t' => {
+                position += 1;
+                continue;
+            }
+            b'0' if rest.len() > 2
+                && matches!(rest[1], b'x' | b'X')
+                && rest[2].is_ascii_hexdigit() =>
+            {
+                let length = 2 + rest[2..]
+                    .iter()
+                    .take_while(|b| b.is_ascii_hexdigit())
+                    .count();
+                let digits = &expression[position + 2..position + length];
+                // hexadecimal literals are read as unsigned and reinterpreted
+                let number = u64::from_str_radix(digits, 16)
+                    .map_err(|_| "Number out of range".to_string())?;
+                (MathToken::Number(number as i64), length)
+            }
+            b'0'..=b'9' => {
+                let length = rest.iter().take_while(|b| b.is_ascii_digit()).count();
+                let number = expression[position..position + length]
+                    .parse()
+                    .map_err(|_| "Number out of range".to_string())?;
+                (MathToken::Number(number), length)
+            }
+            b'<' if rest.starts_with(b"<<") => (MathToken::Operator(BinaryOperator::ShiftLeft), 2),
+            b'>' if rest.starts_with(b">>") => (MathToken::Operator(BinaryOperator::ShiftRight), 2),
+            b'|' => (MathToken::Operator(BinaryOperator::Or), 1),
+            b'^' => (MathToken::Operator(BinaryOperator::Xor), 1),
+            b'&' => (MathToken::Operator(BinaryOperator::And), 1),
+            b'+' => (MathToken::Operator(BinaryOperator::Add), 1),
+            b'-' => (MathToken::Operator(BinaryOperator::Subtract), 1),
+            b'*' => (MathToken::Operator(BinaryOperator::Multiply), 1),
+            b'/' => (MathToken::Operator(BinaryOperator::Divide), 1),
+            b'%' => (MathToken::Operator(BinaryOperator::Modulo), 1),
+            b'~' => (MathToken::Not, 1),
+            b'(' => (MathToken::Open, 1),
+            b')' => (MathToken::Close, 1),
+            _ => {
+                let ch = expression[position..].chars().next().expect("character");
+                return Err(format!("syntax error, unexpected '{ch}'"));
+            }
+        };
+        tokens.push(token);
+        position += length;
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: &'a [MathToken],
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<MathToken> {
+        self.tokens.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Result<MathToken, String> {
+        let token = self
+            .peek()
+            .ok_or_else(|| "syntax error, unexpected end of file".to_string())?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    /// Parses left associative operators of the given precedence level.
+    fn binary(&mut self, level: usize) -> Result<MathExpression, String> {
+        let Some(operators) = BinaryOperator::PRECEDENCE.get(level) else {
+            return self.unary();
+        };
+        let mut left = self.binary(level + 1)?;
+        while let Some(MathToken::Operator(operator)) = self.peek() {
+            if !operators.contains(&operator) {
+                break;
+            }
+            self.position += 1;
+            let right = self.binary(level + 1)?;
+            left = MathExpression::Binary(operator, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<MathExpression, String> {
+        let operator = match self.next()? {
+            MathToken::Number(number) => return Ok(MathExpression::Number(number)),
+            MathToken::Open => {
+                let expression = self.binary(0)?;
+                return match self.next()? {
+                    MathToken::Close => Ok(expression),
+                    token => Err(format!("syntax error, unexpected {token}")),
+                };
+            }
+            MathToken::Operator(BinaryOperator::Add) => UnaryOperator::Plus,
+            MathToken::Operator(BinaryOperator::Subtract) => UnaryOperator::Minus,
+            MathToken::Not => UnaryOperator::Not,
+            token => return Err(format!("syntax error, unexpected {token}")),
+        };
+        Ok(MathExpression::Unary(operator, Box::new(self.unary()?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    fn run(src: &[u8]) -> Result<Variables, MathError> {
+        let cmakelists = parse_cmakelists(src).unwrap();
+        let doc = Doc::from(cmakelists);
+        let mut variables = Variables::new();
+        for command in doc.commands().unwrap() {
+            let Command::Math(command) = command else {
+                unreachable!("only math() commands are expected");
+            };
+            math(&command, &mut variables)?;
+        }
+        Ok(variables)
+    }
+
+    #[test]
+    fn math_expr() {
+        let variables = run(include_bytes!("../../../fixture/eval/math")).unwrap();
+        assert_eq!(
+            variables.iter().collect::<Vec<_>>(),
+            vec![
+                ("bits", "-6"),
+                ("hex", "0x3e8"),
+                ("max", "9223372036854775807"),
+                ("modulo", "0"),
+                ("negative", "-1"),
+                ("negative_hex", "0xffffffffffffffff"),
+                ("precedence", "14"),
+                ("shift", "80"),
+                ("truncate", "-3"),
+                ("version", "30502"),
+                ("wrap", "-9223372036854775808"),
+            ]
+        );
+    }
+
+    #[test]
+    fn math_expression() {
+        use BinaryOperator::*;
+        assert_eq!(
+            MathExpression::parse("-1 + 2 * ~3").unwrap(),
+            MathExpression::Binary(
+                Add,
+                Box::new(MathExpression::Unary(
+                    UnaryOperator::Minus,
+                    Box::new(MathExpression::Number(1))
+                )),
+                Box::new(MathExpression::Binary(
+                    Multiply,
+                    Box::new(MathExpression::Number(2)),
+                    Box::new(MathExpression::Unary(
+                        UnaryOperator::Not,
+                        Box::new(MathExpression::Number(3))
+                    )),
+                )),
+            )
+        );
+        assert_eq!(
+            MathExpression::parse("8 - 4 - 2").unwrap().evaluate(),
+            Ok(2)
+        );
+        assert_eq!(
+            MathExpression::parse("1 << 2 + 1").unwrap().evaluate(),
+            Ok(8)
+        );
+        assert_eq!(
+            MathExpression::parse("6 & 3 ^ 1 | 8").unwrap().evaluate(),
+            Ok(11)
+        );
+    }
+
+    #[test]
+    fn math_errors() {
+        let parse_error = |expression: &str| {
+            let Err(MathError::Parse { reason, .. }) = MathExpression::parse(expression) else {
+                panic!("{expression} should not parse");
+            };
+            reason
+        };
+        assert_eq!(parse_error(""), "syntax error, unexpected end of file");
+        assert_eq!(parse_error("1 +"), "syntax error, unexpected end of file");
+        assert_eq!(parse_error("(1"), "syntax error, unexpected end of file");
+        assert_eq!(parse_error("1 2"), "syntax error, unexpected number 2");
+        assert_eq!(parse_error("2 ** 3"), "syntax error, unexpected '*'");
+        assert_eq!(parse_error("a + 1"), "syntax error, unexpected 'a'");
+        assert_eq!(parse_error("9223372036854775808"), "Number out of range");
+        assert_eq!(parse_error("0x10000000000000000"), "Number out of range");
+        assert_eq!(
+            MathExpression::parse("1 % (2 - 2)").unwrap().evaluate(),
+            Err(MathError::DivideByZero)
+        );
+        assert_eq!(
+            run(b"math(EXPR out \"10 / 0\")\n").unwrap_err().to_string(),
+            "math cannot parse the expression: \"10 / 0\": divide by zero."
+        );
+    }
+}
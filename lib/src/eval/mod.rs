@@ -0,0 +1,31 @@
+//! Evaluation of CMake commands and values.
+
+mod configure;
+#[cfg(feature = "eval")]
+mod file;
+mod filesystem;
+#[cfg(feature = "eval")]
+mod hash;
+#[cfg(feature = "eval")]
+mod json;
+mod list;
+mod math;
+mod path;
+mod regex;
+#[cfg(feature = "eval")]
+mod string;
+mod variables;
+mod version;
+
+pub use configure::{configure, configure_file, ConfigureOptions, Lookup};
+#[cfg(feature = "eval")]
+pub use file::{AccessKind, FileAccess, FileError, FileEvaluator};
+pub use filesystem::{DiskFileSystem, EntryKind, FileSystem, MemoryFileSystem};
+pub use list::{parse_index, CMakeList, ListError};
+pub use math::{math, BinaryOperator, MathError, MathExpression, UnaryOperator};
+pub use path::{cmake_path, GenericPath, PathError, PathStyle};
+pub use regex::{Captures, Regex, RegexError};
+#[cfg(feature = "eval")]
+pub use string::{format_timestamp, string, StringError};
+pub use variables::Variables;
+pub use version::{CMakeVersion, VersionBound, VersionError, VersionRange, VersionRequirement};
@@ -0,0 +1,970 @@
+use std::fmt;
+
+use crate::{
+    command::scripting::{
+        cmake_path::{
+            CMakePathDecomposition, CMakePathGeneration, CMakePathHashing, CMakePathModification,
+            CMakePathNativeConversion, CMakePathQuery, CompareOperation, ConvertToPathList,
+            NativeConversionConvert, NativeConversionPath, PathComponent, PathComponentExtension,
+            PathComponentStem, PathGenerationAbsolute, PathGenerationNormal,
+            PathGenerationRelative, PathModificationAppend, PathModificationAppendString,
+            PathModificationRemoveExtension, PathModificationRemoveFilename,
+            PathModificationReplaceExtension, PathModificationReplaceFilename, PathModificationSet,
+            PathQuery, PathQueryCompare, PathQueryPrefix,
+        },
+        CMakePath,
+    },
+    eval::Variables,
+    Token,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PathError {
+    #[error("undefined variable for input path: {0}")]
+    UndefinedVariable(String),
+}
+
+/// Path conventions of the platform CMake runs on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PathStyle {
+    /// No root names, `/` separators and `:` separated path lists.
+    #[default]
+    Posix,
+    /// Drive letter and `//server` root names, `\` native separators and
+    /// `;` separated path lists.
+    Windows,
+}
+
+/// Path in CMake's generic format with the purely lexical operations of
+/// `cmake_path()`.
+///
+/// ```
+/// use cmake_parser::eval::{GenericPath, PathStyle};
+///
+/// let path = GenericPath::new("/usr/lib/libfoo.so.1", PathStyle::Posix);
+/// assert_eq!(path.parent_path(), "/usr/lib");
+/// assert_eq!(path.stem(false), "libfoo");
+/// assert_eq!(path.extension(true), ".1");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct GenericPath {
+    path: String,
+    style: PathStyle,
+}
+
+impl GenericPath {
+    /// Creates a path, native `\` separators are converted on Windows.
+    pub fn new(path: impl Into<String>, style: PathStyle) -> Self {
+        let mut path = path.into();
+        if style == PathStyle::Windows {
+            path = path.replace('\\', "/");
+        }
+        Self { path, style }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.path
+    }
+
+    pub fn style(&self) -> PathStyle {
+        self.style
+    }
+
+    pub fn root_name(&self) -> &str {
+        &self.path[..self.root_name_end()]
+    }
+
+    pub fn root_directory(&self) -> &str {
+        let start = self.root_name_end();
+        if self.path[start..].starts_with('/') {
+            &self.path[start..=start]
+        } else {
+            ""
+        }
+    }
+
+    pub fn root_path(&self) -> &str {
+        &self.path[..self.root_name_end() + self.root_directory().len()]
+    }
+
+    pub fn relative_part(&self) -> &str {
+        &self.path[self.relative_start()..]
+    }
+
+    pub fn filename(&self) -> &str {
+        &self.path[self.filename_start()..]
+    }
+
+    pub fn parent_path(&self) -> &str {
+        let relative_start = self.relative_start();
+        if relative_start == self.path.len() {
+            return &self.path;
+        }
+        let parent = self.path[..self.filename_start()].trim_end_matches('/');
+        if parent.len() < relative_start {
+            &self.path[..relative_start]
+        } else {
+            parent
+        }
+    }
+
+    /// Extension of the filename: from the leftmost period, or from the last
+    /// one with `last_only`. A leading period of the filename is not an
+    /// extension.
+    pub fn extension(&self, last_only: bool) -> &str {
+        let filename = self.filename();
+        &filename[extension_start(filename, last_only)..]
+    }
+
+    pub fn stem(&self, last_only: bool) -> &str {
+        let filename = self.filename();
+        &filename[..extension_start(filename, last_only)]
+    }
+
+    pub fn is_absolute(&self) -> bool {
+        match self.style {
+            PathStyle::Posix => !self.root_directory().is_empty(),
+            PathStyle::Windows => !self.root_name().is_empty() && !self.root_directory().is_empty(),
+        }
+    }
+
+    pub fn is_relative(&self) -> bool {
+        !self.is_absolute()
+    }
+
+    /// Whether the elements of this path start the elements of `path`.
+    pub fn is_prefix(&self, path: &GenericPath) -> bool {
+        let prefix = self.elements();
+        let path = path.elements();
+        let common = prefix
+            .iter()
+            .zip(&path)
+            .take_while(|(prefix, path)| prefix == path)
+            .count();
+        common == prefix.len()
+            || (common + 1 == prefix.len() && prefix[common].is_empty() && common == path.len())
+    }
+
+    /// Appends `path` with a directory separator, replacing this path when
+    /// `path` is absolute.
+    pub fn append(&mut self, path: &str) {
+        let path = Self::new(path, self.style);
+        if path.is_absolute()
+            || (!path.root_name().is_empty() && path.root_name() != self.root_name())
+        {
+            self.path = path.path;
+        } else if !path.root_directory().is_empty() {
+            self.path.truncate(self.root_name_end());
+            self.path.push_str(&path.path[path.root_name_end()..]);
+        } else {
+            if !self.filename().is_empty()
+                || (self.root_directory().is_empty() && self.is_absolute())
+            {
+                self.path.push('/');
+            }
+            self.path.push_str(&path.path[path.root_name_end()..]);
+        }
+    }
+
+    pub fn append_string(&mut self, string: &str) {
+        self.path = Self::new(format!("{}{string}", self.path), self.style).path;
+    }
+
+    pub fn remove_filename(&mut self) {
+        self.path.truncate(self.filename_start());
+    }
+
+    /// Replaces the filename, a path without filename is left unchanged.
+    pub fn replace_filename(&mut self, filename: &str) {
+        if !self.filename().is_empty() {
+            self.remove_filename();
+            self.append(filename);
+        }
+    }
+
+    pub fn remove_extension(&mut self, last_only: bool) {
+        if !self.extension(last_only).is_empty() {
+            self.replace_extension(last_only, "");
+        }
+    }
+
+    /// Replaces the extension, a period is added when `extension` does not
+    /// start with one.
+    pub fn replace_extension(&mut self, last_only: bool, extension: &str) {
+        let start = self.filename_start();
+        self.path
+            .truncate(start + extension_start(&self.path[start..], last_only));
+        if !extension.is_empty() {
+            if !extension.starts_with('.') {
+                self.path.push('.');
+            }
+            self.path.push_str(extension);
+        }
+    }
+
+    /// Lexically normalized path: redundant separators, `.` and resolvable
+    /// `..` elements are removed.
+    pub fn normal(&self) -> GenericPath {
+        if self.path.is_empty() {
+            return self.clone();
+        }
+        let has_root_directory = !self.root_directory().is_empty();
+        let mut names: Vec<&str> = vec![];
+        let mut trailing = false;
+        let relative = self.relative_part();
+        for name in relative.split('/') {
+            match name {
+                "" | "." => trailing = true,
+                ".." => match names.last() {
+                    Some(&last) if last != ".." => {
+                        names.pop();
+                        trailing = true;
+                    }
+                    None if has_root_directory => trailing = true,
+                    _ => {
+                        names.push(name);
+                        trailing = false;
+                    }
+                },
+                _ => {
+                    names.push(name);
+                    trailing = false;
+                }
+            }
+        }
+        if names.last() == Some(&"..") {
+            trailing = false;
+        }
+        let mut path = self.root_path().to_string();
+        path.push_str(&names.join("/"));
+        if trailing && !names.is_empty() {
+            path.push('/');
+        }
+        if path.is_empty() {
+            path.push('.');
+        }
+        Self {
+            path,
+            style: self.style,
+        }
+    }
+
+    /// This path expressed relative to `base` as `std::filesystem`'s
+    /// `lexically_relative` does, empty when there is no such path.
+    pub fn relative_to(&self, base: &GenericPath) -> GenericPath {
+        let empty = Self {
+            path: String::new(),
+            style: self.style,
+        };
+        if self.root_name() != base.root_name()
+            || self.is_absolute() != base.is_absolute()
+            || (self.root_directory().is_empty() && !base.root_directory().is_empty())
+        {
+            return empty;
+        }
+        let elements = self.elements();
+        let base_elements = base.elements();
+        let common = elements
+            .iter()
+            .zip(&base_elements)
+            .take_while(|(element, base)| element == base)
+            .count();
+        if common == elements.len() && common == base_elements.len() {
+            return Self::new(".", self.style);
+        }
+        let mut parents = 0isize;
+        for element in &base_elements[common..] {
+            match *element {
+                ".." => parents -= 1,
+                "" | "." => {}
+                _ => parents += 1,
+            }
+        }
+        if parents < 0 {
+            return empty;
+        }
+        if parents == 0 && common == elements.len() {
+            return Self::new(".", self.style);
+        }
+        let mut relative = empty;
+        for _ in 0..parents {
+            relative.append("..");
+        }
+        for element in &elements[common..] {
+            relative.append(element);
+        }
+        relative
+    }
+
+    /// This path prefixed with `base` when it is relative.
+    pub fn absolute(&self, base: &GenericPath) -> GenericPath {
+        if self.is_absolute() {
+            return self.clone();
+        }
+        let mut absolute = base.clone();
+        absolute.append(&self.path);
+        absolute
+    }
+
+    /// Path with the native directory separators.
+    pub fn native(&self) -> String {
+        match self.style {
+            PathStyle::Posix => self.path.clone(),
+            PathStyle::Windows => self.path.replace('/', "\\"),
+        }
+    }
+
+    fn root_name_end(&self) -> usize {
+        let bytes = self.path.as_bytes();
+        match self.style {
+            PathStyle::Posix => 0,
+            PathStyle::Windows
+                if bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' =>
+            {
+                2
+            }
+            PathStyle::Windows
+                if bytes.len() > 2 && bytes.starts_with(b"//") && bytes[2] != b'/' =>
+            {
+                bytes[2..]
+                    .iter()
+                    .position(|&b| b == b'/')
+                    .map_or(bytes.len(), |position| position + 2)
+            }
+            PathStyle::Windows => 0,
+        }
+    }
+
+    fn relative_start(&self) -> usize {
+        let start = self.root_name_end();
+        start
+            + self.path[start..]
+                .bytes()
+                .take_while(|&b| b == b'/')
+                .count()
+    }
+
+    fn filename_start(&self) -> usize {
+        let start = self.relative_start();
+        self.path[start..]
+            .rfind('/')
+            .map_or(start, |position| start + position + 1)
+    }
+
+    /// Root name, root directory, the names of the relative part and an
+    /// empty name for a trailing separator.
+    fn elements(&self) -> Vec<&str> {
+        let mut elements = vec![];
+        if !self.root_name().is_empty() {
+            elements.push(self.root_name());
+        }
+        if !self.root_directory().is_empty() {
+            elements.push(self.root_directory());
+        }
+        let relative = self.relative_part();
+        if !relative.is_empty() {
+            elements.extend(relative.split('/').filter(|name| !name.is_empty()));
+            if relative.ends_with('/') {
+                elements.push("");
+            }
+        }
+        elements
+    }
+}
+
+impl fmt::Display for GenericPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.path)
+    }
+}
+
+fn extension_start(filename: &str, last_only: bool) -> usize {
+    if filename.is_empty() || filename == "." || filename == ".." {
+        return filename.len();
+    }
+    let position = if last_only {
+        filename.rfind('.').filter(|&position| position > 0)
+    } else {
+        let skip = usize::from(filename.starts_with('.'));
+        filename[skip..].find('.').map(|position| position + skip)
+    };
+    position.unwrap_or(filename.len())
+}
+
+/// Runs a `cmake_path()` command updating the variables.
+///
+/// Paths are only manipulated lexically, `RELATIVE_PATH` and `ABSOLUTE_PATH`
+/// default to `CMAKE_CURRENT_SOURCE_DIR` as their base directory. `HASH`
+/// yields a value stable for equal normalized paths, which is not the one
+/// CMake computes.
+pub fn cmake_path(
+    command: &CMakePath,
+    variables: &mut Variables,
+    style: PathStyle,
+) -> Result<(), PathError> {
+    let input = |path_var: &Token| -> Result<GenericPath, PathError> {
+        let name = path_var.to_string();
+        variables
+            .get(&name)
+            .map(|path| GenericPath::new(path, style))
+            .ok_or(PathError::UndefinedVariable(name))
+    };
+    let literal = |token: &Token| GenericPath::new(token.to_string(), style);
+    let (output, value) = match command {
+        CMakePath::Decomposition(CMakePathDecomposition {
+            path_var,
+            component,
+        }) => {
+            let path = input(path_var)?;
+            let (out_var, value) = match component {
+                PathComponent::RootName(out_var) => (out_var, path.root_name()),
+                PathComponent::RootDirectory(out_var) => (out_var, path.root_directory()),
+                PathComponent::RootPath(out_var) => (out_var, path.root_path()),
+                PathComponent::Filename(out_var) => (out_var, path.filename()),
+                PathComponent::Extension(PathComponentExtension { last_only, out_var }) => {
+                    (out_var, path.extension(*last_only))
+                }
+                PathComponent::Stem(PathComponentStem { last_only, out_var }) => {
+                    (out_var, path.stem(*last_only))
+                }
+                PathComponent::RelativePart(out_var) => (out_var, path.relative_part()),
+                PathComponent::ParentPath(out_var) => (out_var, path.parent_path()),
+            };
+            (out_var, value.to_string())
+        }
+        CMakePath::Query(query) => {
+            let (out_var, value) = match query {
+                CMakePathQuery::Compare(PathQueryCompare {
+                    input1,
+                    operation,
+                    input2,
+                    out_var,
+                }) => {
+                    let equal = literal(input1).elements() == literal(input2).elements();
+                    (out_var, equal == (*operation == CompareOperation::Equal))
+                }
+                CMakePathQuery::IsPrefix(PathQueryPrefix {
+                    path_var,
+                    input: prefixed,
+                    normalize,
+                    out_var,
+                }) => {
+                    let (path, prefixed) = (input(path_var)?, literal(prefixed));
+                    let is_prefix = if *normalize {
+                        path.normal().is_prefix(&prefixed.normal())
+                    } else {
+                        path.is_prefix(&prefixed)
+                    };
+                    (out_var, is_prefix)
+                }
+                CMakePathQuery::HasRootName(query) => {
+                    query_path(query, input, |path| !path.root_name().is_empty())?
+                }
+                CMakePathQuery::HasRootDirectory(query) => {
+                    query_path(query, input, |path| !path.root_directory().is_empty())?
+                }
+                CMakePathQuery::HasRootPath(query) => {
+                    query_path(query, input, |path| !path.root_path().is_empty())?
+                }
+                CMakePathQuery::HasFilename(query) => {
+                    query_path(query, input, |path| !path.filename().is_empty())?
+                }
+                CMakePathQuery::HasExtension(query) => {
+                    query_path(query, input, |path| !path.extension(false).is_empty())?
+                }
+                CMakePathQuery::HasStem(query) => {
+                    query_path(query, input, |path| !path.stem(false).is_empty())?
+                }
+                CMakePathQuery::HasRelativePart(query) => {
+                    query_path(query, input, |path| !path.relative_part().is_empty())?
+                }
+                CMakePathQuery::HasParentPath(query) => {
+                    query_path(query, input, |path| !path.parent_path().is_empty())?
+                }
+                CMakePathQuery::IsAbsolute(query) => {
+                    query_path(query, input, GenericPath::is_absolute)?
+                }
+                CMakePathQuery::IsRelative(query) => {
+                    query_path(query, input, GenericPath::is_relative)?
+                }
+            };
+            (out_var, if value { "ON" } else { "OFF" }.to_string())
+        }
+        CMakePath::Modification(modification) => match modification {
+            CMakePathModification::Set(PathModificationSet {
+                path_var,
+                normalize,
+                input,
+            }) => {
+                let path = literal(input);
+                let path = if *normalize { path.normal() } else { path };
+                (path_var, path.path)
+            }
+            CMakePathModification::Append(PathModificationAppend {
+                path_var,
+                inputs,
+                out_var,
+            }) => {
+                let mut path = input(path_var).unwrap_or_else(|_| GenericPath::new("", style));
+                for input in inputs.iter().flatten() {
+                    path.append(&input.to_string());
+                }
+                (out_var.as_ref().unwrap_or(path_var), path.path)
+            }
+            CMakePathModification::AppendString(PathModificationAppendString {
+                path_var,
+                inputs,
+                out_var,
+            }) => {
+                let mut path = input(path_var).unwrap_or_else(|_| GenericPath::new("", style));
+                for input in inputs.iter().flatten() {
+                    path.append_string(&input.to_string());
+                }
+                (out_var.as_ref().unwrap_or(path_var), path.path)
+            }
+            CMakePathModification::RemoveFilename(PathModificationRemoveFilename {
+                path_var,
+                out_var,
+            }) => {
+                let mut path = input(path_var)?;
+                path.remove_filename();
+                (out_var.as_ref().unwrap_or(path_var), path.path)
+            }
+            CMakePathModification::ReplaceFilename(PathModificationReplaceFilename {
+                path_var,
+                input: filename,
+                out_var,
+            }) => {
+                let mut path = input(path_var)?;
+                path.replace_filename(&filename.to_string());
+                (out_var.as_ref().unwrap_or(path_var), path.path)
+            }
+            CMakePathModification::RemoveExtension(PathModificationRemoveExtension {
+                path_var,
+                last_only,
+                out_var,
+            }) => {
+                let mut path = input(path_var)?;
+                path.remove_extension(*last_only);
+                (out_var.as_ref().unwrap_or(path_var), path.path)
+            }
+            CMakePathModification::ReplaceExtension(PathModificationReplaceExtension {
+                path_var,
+                last_only,
+                input: extension,
+                out_var,
+            }) => {
+                let mut path = input(path_var)?;
+                path.replace_extension(*last_only, &extension.to_string());
+                (out_var.as_ref().unwrap_or(path_var), path.path)
+            }
+        },
+        CMakePath::Generation(generation) => {
+            let base = |base: &Option<Token>| {
+                base.as_ref().map(literal).unwrap_or_else(|| {
+                    GenericPath::new(
+                        variables
+                            .get("CMAKE_CURRENT_SOURCE_DIR")
+                            .unwrap_or_default(),
+                        style,
+                    )
+                })
+            };
+            match generation {
+                CMakePathGeneration::Normal(PathGenerationNormal { path_var, out_var }) => (
+                    out_var.as_ref().unwrap_or(path_var),
+                    input(path_var)?.normal().path,
+                ),
+                CMakePathGeneration::Relative(PathGenerationRelative {
+                    path_var,
+                    input: base_directory,
+                    out_var,
+                }) => (
+                    out_var.as_ref().unwrap_or(path_var),
+                    input(path_var)?.relative_to(&base(base_directory)).path,
+                ),
+                CMakePathGeneration::Absolute(PathGenerationAbsolute {
+                    path_var,
+                    input: base_directory,
+                    normalize,
+                    out_var,
+                }) => {
+                    let path = input(path_var)?.absolute(&base(base_directory));
+                    let path = if *normalize { path.normal() } else { path };
+                    (out_var.as_ref().unwrap_or(path_var), path.path)
+                }
+            }
+        }
+        CMakePath::NativeConversion(conversion) => match conversion {
+            CMakePathNativeConversion::NativePath(NativeConversionPath {
+                path_var,
+                normalize,
+                out_var,
+            }) => {
+                let path = input(path_var)?;
+                let path = if *normalize { path.normal() } else { path };
+                (out_var.as_ref().unwrap_or(path_var), path.native())
+            }
+            CMakePathNativeConversion::Convert(NativeConversionConvert {
+                input,
+                to,
+                out_var,
+                normalize,
+            }) => {
+                let input = input.to_string();
+                let separator = path_list_separator(style);
+                let convert = |path: &str| {
+                    let path = GenericPath::new(path, style);
+                    if *normalize {
+                        path.normal()
+                    } else {
+                        path
+                    }
+                };
+                let paths = match to {
+                    ConvertToPathList::CMake => input
+                        .split(separator)
+                        .filter(|path| !path.is_empty())
+                        .map(|path| convert(path).path)
+                        .collect::<Vec<_>>()
+                        .join(";"),
+                    ConvertToPathList::Native => input
+                        .split(';')
+                        .filter(|path| !path.is_empty())
+                        .map(|path| convert(path).native())
+                        .collect::<Vec<_>>()
+                        .join(&separator.to_string()),
+                };
+                (out_var, paths)
+            }
+        },
+        CMakePath::Hashing(CMakePathHashing { path_var, out_var }) => {
+            let path = input(path_var)?.normal();
+            // FNV-1a over the elements, equal paths have equal hashes
+            let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+            for element in path.elements() {
+                for byte in element.bytes().chain([0]) {
+                    hash ^= u64::from(byte);
+                    hash = hash.wrapping_mul(0x0100_0000_01b3);
+                }
+            }
+            (out_var, hash.to_string())
+        }
+    };
+    variables.set(output.to_string(), value);
+    Ok(())
+}
+
+/// Separator of native path lists.
+pub(crate) fn path_list_separator(style: PathStyle) -> char {
+    match style {
+        PathStyle::Posix => ':',
+        PathStyle::Windows => ';',
+    }
+}
+
+fn query_path<'q, 't>(
+    query: &'q PathQuery<'t>,
+    input: impl Fn(&Token) -> Result<GenericPath, PathError>,
+    predicate: impl Fn(&GenericPath) -> bool,
+) -> Result<(&'q Token<'t>, bool), PathError> {
+    Ok((&query.out_var, predicate(&input(&query.path_var)?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    fn run(src: &[u8], style: PathStyle) -> Variables {
+        let cmakelists = parse_cmakelists(src).unwrap();
+        let doc = Doc::from(cmakelists);
+        let mut variables = Variables::from_iter([("CMAKE_CURRENT_SOURCE_DIR", "/src/app")]);
+        for command in doc.commands().unwrap() {
+            let Command::CMakePath(command) = command else {
+                unreachable!("only cmake_path() commands are expected");
+            };
+            cmake_path(&command, &mut variables, style).unwrap();
+        }
+        variables
+    }
+
+    fn values<'v>(variables: &'v Variables, names: &[&str]) -> Vec<Option<&'v str>> {
+        names.iter().map(|name| variables.get(name)).collect()
+    }
+
+    #[test]
+    fn decomposition() {
+        let components = |path: &str, style| {
+            let path = GenericPath::new(path, style);
+            [
+                path.root_name().to_string(),
+                path.root_directory().to_string(),
+                path.root_path().to_string(),
+                path.relative_part().to_string(),
+                path.filename().to_string(),
+                path.parent_path().to_string(),
+                path.stem(false).to_string(),
+                path.extension(false).to_string(),
+                path.stem(true).to_string(),
+                path.extension(true).to_string(),
+            ]
+        };
+        use PathStyle::*;
+        assert_eq!(
+            components("/a/b/c.tar.gz", Posix),
+            [
+                "",
+                "/",
+                "/",
+                "a/b/c.tar.gz",
+                "c.tar.gz",
+                "/a/b",
+                "c",
+                ".tar.gz",
+                "c.tar",
+                ".gz"
+            ]
+        );
+        assert_eq!(
+            components("a//b/", Posix),
+            ["", "", "", "a//b/", "", "a//b", "", "", "", ""]
+        );
+        assert_eq!(
+            components("/", Posix),
+            ["", "/", "/", "", "", "/", "", "", "", ""]
+        );
+        assert_eq!(
+            components("//a", Posix),
+            ["", "/", "/", "a", "a", "//", "a", "", "a", ""]
+        );
+        assert_eq!(
+            components(".profile.d", Posix),
+            [
+                "",
+                "",
+                "",
+                ".profile.d",
+                ".profile.d",
+                "",
+                ".profile",
+                ".d",
+                ".profile",
+                ".d"
+            ]
+        );
+        assert_eq!(
+            components("a/..", Posix),
+            ["", "", "", "a/..", "..", "a", "..", "", "..", ""]
+        );
+        assert_eq!(
+            components("C:\\dir\\file.txt", Windows),
+            [
+                "C:",
+                "/",
+                "C:/",
+                "dir/file.txt",
+                "file.txt",
+                "C:/dir",
+                "file",
+                ".txt",
+                "file",
+                ".txt"
+            ]
+        );
+        assert_eq!(
+            components("//server/share", Windows),
+            [
+                "//server",
+                "/",
+                "//server/",
+                "share",
+                "share",
+                "//server/",
+                "share",
+                "",
+                "share",
+                ""
+            ]
+        );
+        assert_eq!(
+            components("C:", Windows),
+            ["C:", "", "C:", "", "", "C:", "", "", "", ""]
+        );
+        assert!(GenericPath::new("/a", Posix).is_absolute());
+        assert!(GenericPath::new("/a", Windows).is_relative());
+        assert!(GenericPath::new("C:/a", Windows).is_absolute());
+    }
+
+    #[test]
+    fn generation() {
+        let normal = |path: &str| GenericPath::new(path, PathStyle::Posix).normal().path;
+        assert_eq!(normal("a/./b/.."), "a/");
+        assert_eq!(normal("a/.."), ".");
+        assert_eq!(normal("/../a//b/"), "/a/b/");
+        assert_eq!(normal("../a/../../b"), "../../b");
+        assert_eq!(normal("a/../../"), "..");
+        assert_eq!(normal(""), "");
+
+        let relative = |path: &str, base: &str| {
+            GenericPath::new(path, PathStyle::Posix)
+                .relative_to(&GenericPath::new(base, PathStyle::Posix))
+                .path
+        };
+        assert_eq!(relative("/a/d", "/a/b/c"), "../../d");
+        assert_eq!(relative("/a/b/c", "/a/d"), "../b/c");
+        assert_eq!(relative("a/b/c", "a"), "b/c");
+        assert_eq!(relative("a/b/c", "a/b/c/x/y"), "../..");
+        assert_eq!(relative("a/b/c", "a/b/c"), ".");
+        assert_eq!(relative("a/b", "c/d"), "../../a/b");
+        assert_eq!(relative("a", "/a"), "");
+        assert_eq!(relative("a", "../.."), "");
+    }
+
+    #[test]
+    fn cmake_path_posix() {
+        let variables = run(
+            include_bytes!("../../../fixture/eval/cmake_path"),
+            PathStyle::Posix,
+        );
+        assert_eq!(
+            values(
+                &variables,
+                &[
+                    "root_name",
+                    "root_directory",
+                    "filename",
+                    "extension",
+                    "last_extension",
+                    "stem",
+                    "last_stem",
+                    "parent",
+                    "has_root_name",
+                    "is_absolute",
+                    "is_prefix",
+                    "is_prefix_normalized",
+                    "equal",
+                    "not_equal",
+                    "appended",
+                    "replaced",
+                    "append_string",
+                    "no_filename",
+                    "new_filename",
+                    "unchanged",
+                    "no_extension",
+                    "no_last_extension",
+                    "new_extension",
+                    "normal",
+                    "relative",
+                    "relative_source",
+                    "absolute",
+                    "native",
+                    "cmake_list",
+                    "native_list",
+                ]
+            ),
+            [
+                Some(""),
+                Some("/"),
+                Some("libfoo.so.1"),
+                Some(".so.1"),
+                Some(".1"),
+                Some("libfoo"),
+                Some("libfoo.so"),
+                Some("/usr/lib"),
+                Some("OFF"),
+                Some("ON"),
+                Some("OFF"),
+                Some("ON"),
+                Some("ON"),
+                Some("ON"),
+                Some("/opt/app/lib/cmake"),
+                Some("/etc"),
+                Some("/opt/app-1.0"),
+                Some("/usr/lib/"),
+                Some("/usr/lib/libbar.a"),
+                Some("/usr/lib/"),
+                Some("/usr/lib/libfoo"),
+                Some("/usr/lib/libfoo.so"),
+                Some("/usr/lib/libfoo.dylib"),
+                Some("/usr/share/doc/"),
+                Some("../lib/libfoo.so.1"),
+                Some("../../usr/share"),
+                Some("/src/app/include/../lib"),
+                Some("/usr/lib/libfoo.so.1"),
+                Some("/usr/bin;/bin"),
+                Some("/usr/bin:/bin"),
+            ]
+            .to_vec()
+        );
+        let hash = |path: &str| {
+            let mut variables = Variables::from_iter([("path", path)]);
+            run_hash(&mut variables);
+            variables.get("hash").unwrap().to_string()
+        };
+        assert_eq!(hash("a/./b"), hash("a//b"));
+        assert_ne!(hash("a/b"), hash("a/c"));
+    }
+
+    fn run_hash(variables: &mut Variables) {
+        let cmakelists = parse_cmakelists(b"cmake_path(HASH path hash)\n").unwrap();
+        let doc = Doc::from(cmakelists);
+        let Ok(Command::CMakePath(command)) = doc.to_commands_iter().next().unwrap() else {
+            unreachable!()
+        };
+        cmake_path(&command, variables, PathStyle::Posix).unwrap();
+    }
+
+    #[test]
+    fn cmake_path_windows() {
+        let variables = run(
+            br#"cmake_path(SET path "C:\\Program Files\\App\\bin\\app.exe")
+cmake_path(GET path ROOT_NAME root_name)
+cmake_path(GET path PARENT_PATH parent)
+cmake_path(IS_ABSOLUTE path is_absolute)
+cmake_path(NATIVE_PATH path native)
+cmake_path(CONVERT "C:\\a;D:\\b" TO_CMAKE_PATH_LIST cmake_list)
+cmake_path(APPEND path "D:/other" OUTPUT_VARIABLE other_drive)
+"#,
+            PathStyle::Windows,
+        );
+        assert_eq!(
+            values(
+                &variables,
+                &[
+                    "path",
+                    "root_name",
+                    "parent",
+                    "is_absolute",
+                    "native",
+                    "cmake_list",
+                    "other_drive"
+                ]
+            ),
+            [
+                Some("C:/Program Files/App/bin/app.exe"),
+                Some("C:"),
+                Some("C:/Program Files/App/bin"),
+                Some("ON"),
+                Some("C:\\Program Files\\App\\bin\\app.exe"),
+                Some("C:/a;D:/b"),
+                Some("D:/other"),
+            ]
+            .to_vec()
+        );
+    }
+
+    #[test]
+    fn cmake_path_undefined() {
+        let cmakelists = parse_cmakelists(b"cmake_path(GET missing FILENAME out)\n").unwrap();
+        let doc = Doc::from(cmakelists);
+        let Ok(Command::CMakePath(command)) = doc.to_commands_iter().next().unwrap() else {
+            unreachable!()
+        };
+        assert_eq!(
+            cmake_path(&command, &mut Variables::new(), PathStyle::Posix),
+            Err(PathError::UndefinedVariable("missing".to_string()))
+        );
+    }
+}
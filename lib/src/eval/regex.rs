@@ -0,0 +1,724 @@
+/// Regular expression with the syntax and matching rules of CMake.
+///
+/// Supported syntax is `^`, `$`, `.`, `[...]`, `[^...]`, `*`, `+`, `?`, `|`
+/// and `(...)` groups. `\` makes the following character literal. Quantifiers
+/// are greedy and there are no lazy quantifiers, counted repetitions or
+/// character class escapes. Up to 9 groups can be captured.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regex {
+    pattern: String,
+    program: Vec<Instruction>,
+    groups: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RegexError {
+    #[error("failed to compile regex \"{pattern}\": {reason}")]
+    Compile {
+        pattern: String,
+        reason: &'static str,
+    },
+    #[error("regex \"{0}\" matched an empty string")]
+    EmptyMatch(String),
+    #[error("replace expression \"{expression}\" refers to a group {group} which does not exist")]
+    MissingGroup { expression: String, group: usize },
+    #[error("unknown escape \"{escape}\" in replace expression \"{expression}\"")]
+    UnknownEscape { expression: String, escape: String },
+    #[error("replace expression \"{0}\" ends in a backslash")]
+    TrailingBackslash(String),
+}
+
+/// Positions of the whole match and of the groups in the searched text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Captures<'s> {
+    text: &'s str,
+    groups: Vec<Option<(usize, usize)>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node {
+    Char(char),
+    Any,
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+    Start,
+    End,
+    Group(usize, Vec<Vec<Node>>),
+    Repeat {
+        node: Box<Node>,
+        min: usize,
+        max: Option<usize>,
+    },
+}
+
+/// Maximum number of groups including the whole match.
+const MAX_GROUPS: usize = 10;
+
+impl Regex {
+    pub fn new(pattern: &str) -> Result<Self, RegexError> {
+        let mut parser = Parser {
+            pattern,
+            chars: pattern.chars().collect(),
+            position: 0,
+            groups: 1,
+        };
+        let root = parser.alternatives()?;
+        if parser.position < parser.chars.len() {
+            return Err(parser.error("unmatched ()"));
+        }
+        let mut program = vec![];
+        compile_alternatives(&mut program, &root);
+        program.push(Instruction::Match);
+        Ok(Self {
+            pattern: pattern.to_string(),
+            program,
+            groups: parser.groups,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Number of groups including the whole match.
+    pub fn groups(&self) -> usize {
+        self.groups
+    }
+
+    pub fn is_match(&self, text: &str) -> bool {
+        self.find(text).is_some()
+    }
+
+    /// The leftmost match.
+    pub fn find<'s>(&self, text: &'s str) -> Option<Captures<'s>> {
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        let (_, groups) = self.matcher(&chars, text.len()).search(0)?;
+        Some(Captures { text, groups })
+    }
+
+    /// All non-overlapping matches as `string(REGEX MATCHALL)` finds them.
+    ///
+    /// `^` matches at the start of each search.
+    pub fn find_all<'s>(&self, text: &'s str) -> Result<Vec<Captures<'s>>, RegexError> {
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        let mut matcher = self.matcher(&chars, text.len());
+        let mut matches = vec![];
+        let mut base = 0;
+        while base < chars.len() {
+            let Some((position, groups)) = matcher.search(base) else {
+                break;
+            };
+            let (start, end) = groups[0].expect("whole match");
+            if start == end {
+                return Err(RegexError::EmptyMatch(self.pattern.clone()));
+            }
+            matches.push(Captures { text, groups });
+            base = position;
+        }
+        Ok(matches)
+    }
+
+    /// Replaces all matches as `string(REGEX REPLACE)` does.
+    ///
+    /// `\0` to `\9` in the replacement refer to the groups of the match,
+    /// `\n` is a newline and `\\` a backslash. Other escapes are errors.
+    pub fn replace_all(&self, text: &str, replacement: &str) -> Result<String, RegexError> {
+        let pieces = self.replacement(replacement)?;
+        let matches = self.find_all(text)?;
+        Ok(substitute(text, &matches, &pieces))
+    }
+
+    /// Replaces the matches found by [`Regex::find_all`] in `text`, for
+    /// callers which need the matches as well.
+    pub fn replace_matches(
+        &self,
+        text: &str,
+        matches: &[Captures],
+        replacement: &str,
+    ) -> Result<String, RegexError> {
+        let pieces = self.replacement(replacement)?;
+        Ok(substitute(text, matches, &pieces))
+    }
+
+    fn matcher<'c>(&'c self, chars: &'c [(usize, char)], end: usize) -> Matcher<'c> {
+        Matcher {
+            program: &self.program,
+            chars,
+            end,
+            first: 0,
+            slots: vec![None; self.groups * 2],
+            visited: vec![0; self.program.len() * (chars.len() + 1)],
+            generation: 0,
+        }
+    }
+
+    fn replacement(&self, replacement: &str) -> Result<Vec<Replacement>, RegexError> {
+        let mut pieces = vec![];
+        let mut text = String::new();
+        let mut chars = replacement.chars().peekable();
+        while let Some(ch) = chars.next() {
+            match (ch, chars.peek().and_then(|next| next.to_digit(10))) {
+                ('\\', Some(group)) => {
+                    chars.next();
+                    let group = group as usize;
+                    if group >= self.groups {
+                        return Err(RegexError::MissingGroup {
+                            expression: replacement.to_string(),
+                            group,
+                        });
+                    }
+                    if !text.is_empty() {
+                        pieces.push(Replacement::Text(std::mem::take(&mut text)));
+                    }
+                    pieces.push(Replacement::Group(group));
+                }
+                ('\\', None) => match chars.next() {
+                    Some('n') => text.push('\n'),
+                    Some('\\') => text.push('\\'),
+                    Some(escaped) => {
+                        return Err(RegexError::UnknownEscape {
+                            expression: replacement.to_string(),
+                            escape: format!("\\{escaped}"),
+                        })
+                    }
+                    None => return Err(RegexError::TrailingBackslash(replacement.to_string())),
+                },
+                _ => text.push(ch),
+            }
+        }
+        if !text.is_empty() {
+            pieces.push(Replacement::Text(text));
+        }
+        Ok(pieces)
+    }
+}
+
+enum Replacement {
+    Text(String),
+    Group(usize),
+}
+
+fn substitute(text: &str, matches: &[Captures], pieces: &[Replacement]) -> String {
+    let mut result = String::new();
+    let mut base = 0;
+    for captures in matches {
+        let (start, end) = captures.groups[0].expect("whole match");
+        result.push_str(&text[base..start]);
+        for piece in pieces {
+            match piece {
+                Replacement::Text(text) => result.push_str(text),
+                Replacement::Group(group) => {
+                    result.push_str(captures.get(*group).unwrap_or_default())
+                }
+            }
+        }
+        base = end;
+    }
+    result.push_str(&text[base..]);
+    result
+}
+
+impl<'s> Captures<'s> {
+    /// Text of the group, `0` is the whole match.
+    pub fn get(&self, group: usize) -> Option<&'s str> {
+        self.groups
+            .get(group)
+            .copied()
+            .flatten()
+            .map(|(start, end)| &self.text[start..end])
+    }
+
+    /// Byte range of the group in the searched text.
+    pub fn range(&self, group: usize) -> Option<std::ops::Range<usize>> {
+        self.groups
+            .get(group)
+            .copied()
+            .flatten()
+            .map(|(start, end)| start..end)
+    }
+
+    /// Number of groups including the whole match.
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+}
+
+struct Parser<'p> {
+    pattern: &'p str,
+    chars: Vec<char>,
+    position: usize,
+    groups: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, reason: &'static str) -> RegexError {
+        RegexError::Compile {
+            pattern: self.pattern.to_string(),
+            reason,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let ch = self.peek();
+        self.position += 1;
+        ch
+    }
+
+    fn alternatives(&mut self) -> Result<Vec<Vec<Node>>, RegexError> {
+        let mut alternatives = vec![self.sequence()?];
+        while self.peek() == Some('|') {
+            self.position += 1;
+            alternatives.push(self.sequence()?);
+        }
+        Ok(alternatives)
+    }
+
+    fn sequence(&mut self) -> Result<Vec<Node>, RegexError> {
+        let mut nodes = vec![];
+        while let Some(ch) = self.peek() {
+            if ch == '|' || ch == ')' {
+                break;
+            }
+            self.position += 1;
+            let node = match ch {
+                '^' => Node::Start,
+                '$' => Node::End,
+                '.' => Node::Any,
+                '[' => self.class()?,
+                '(' => {
+                    let group = self.groups;
+                    if group == MAX_GROUPS {
+                        return Err(self.error("too many ()"));
+                    }
+                    self.groups += 1;
+                    let alternatives = self.alternatives()?;
+                    if self.next() != Some(')') {
+                        return Err(self.error("unmatched ()"));
+                    }
+                    Node::Group(group, alternatives)
+                }
+                '*' | '+' | '?' => return Err(self.error("?+* follows nothing")),
+                '\\' => Node::Char(self.next().ok_or_else(|| self.error("trailing \\"))?),
+                _ => Node::Char(ch),
+            };
+            let node = match self.peek() {
+                Some(quantifier @ ('*' | '+' | '?')) => {
+                    if matches!(node, Node::Start | Node::End) {
+                        return Err(self.error("?+* follows nothing"));
+                    }
+                    self.position += 1;
+                    if matches!(self.peek(), Some('*' | '+' | '?')) {
+                        return Err(self.error("nested *?+"));
+                    }
+                    let (min, max) = match quantifier {
+                        '*' => (0, None),
+                        '+' => (1, None),
+                        _ => (0, Some(1)),
+                    };
+                    Node::Repeat {
+                        node: Box::new(node),
+                        min,
+                        max,
+                    }
+                }
+                _ => node,
+            };
+            nodes.push(node);
+        }
+        Ok(nodes)
+    }
+
+    fn class(&mut self) -> Result<Node, RegexError> {
+        let negated = self.peek() == Some('^');
+        if negated {
+            self.position += 1;
+        }
+        let mut ranges = vec![];
+        let mut first = true;
+        loop {
+            let ch = self.next().ok_or_else(|| self.error("unmatched []"))?;
+            if ch == ']' && !first {
+                break;
+            }
+            first = false;
+            if self.peek() == Some('-')
+                && !matches!(self.chars.get(self.position + 1), Some(']') | None)
+            {
+                self.position += 1;
+                let end = self.next().ok_or_else(|| self.error("unmatched []"))?;
+                if end < ch {
+                    return Err(self.error("invalid [] range"));
+                }
+                ranges.push((ch, end));
+            } else {
+                ranges.push((ch, ch));
+            }
+        }
+        Ok(Node::Class { negated, ranges })
+    }
+}
+
+/// Instruction of the backtracking matcher a pattern compiles to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Instruction {
+    /// Consumes a character matching a `Char`, `Any` or `Class` node.
+    Single(Node),
+    Start,
+    End,
+    /// Continues at the first target, and at the second one on failure.
+    Split(usize, usize),
+    Jump(usize),
+    /// Records the position in a group slot.
+    Save(usize),
+    Match,
+}
+
+fn compile_alternatives(program: &mut Vec<Instruction>, alternatives: &[Vec<Node>]) {
+    let mut jumps = vec![];
+    for (index, alternative) in alternatives.iter().enumerate() {
+        if index + 1 == alternatives.len() {
+            compile_sequence(program, alternative);
+            break;
+        }
+        let split = program.len();
+        program.push(Instruction::Split(split + 1, 0));
+        compile_sequence(program, alternative);
+        jumps.push(program.len());
+        program.push(Instruction::Jump(0));
+        program[split] = Instruction::Split(split + 1, program.len());
+    }
+    let end = program.len();
+    for jump in jumps {
+        program[jump] = Instruction::Jump(end);
+    }
+}
+
+fn compile_sequence(program: &mut Vec<Instruction>, nodes: &[Node]) {
+    for node in nodes {
+        compile_node(program, node);
+    }
+}
+
+fn compile_node(program: &mut Vec<Instruction>, node: &Node) {
+    match node {
+        Node::Start => program.push(Instruction::Start),
+        Node::End => program.push(Instruction::End),
+        Node::Group(group, alternatives) => {
+            program.push(Instruction::Save(group * 2));
+            compile_alternatives(program, alternatives);
+            program.push(Instruction::Save(group * 2 + 1));
+        }
+        Node::Repeat { node, min, max } => {
+            let start = program.len();
+            match (min, max) {
+                (0, None) => {
+                    program.push(Instruction::Split(start + 1, 0));
+                    compile_node(program, node);
+                    program.push(Instruction::Jump(start));
+                    program[start] = Instruction::Split(start + 1, program.len());
+                }
+                (_, None) => {
+                    compile_node(program, node);
+                    program.push(Instruction::Split(start, program.len() + 1));
+                }
+                _ => {
+                    program.push(Instruction::Split(start + 1, 0));
+                    compile_node(program, node);
+                    program[start] = Instruction::Split(start + 1, program.len());
+                }
+            }
+        }
+        _ => program.push(Instruction::Single(node.clone())),
+    }
+}
+
+/// Byte ranges of the groups, `None` for groups which did not participate.
+type Groups = Vec<Option<(usize, usize)>>;
+
+/// Undoable step of the search.
+enum Job {
+    Try { pc: usize, position: usize },
+    Restore { slot: usize, value: Option<usize> },
+}
+
+/// Backtracking search with an explicit stack. A state of an instruction
+/// and a position is tried at most once, which bounds the work by the
+/// program length times the text length and ends empty loop iterations.
+struct Matcher<'c> {
+    program: &'c [Instruction],
+    chars: &'c [(usize, char)],
+    end: usize,
+    /// Position the current search started at, where `^` matches.
+    first: usize,
+    /// Start and end positions of the groups.
+    slots: Vec<Option<usize>>,
+    /// Generation of the search which visited a state, so a new search does
+    /// not have to clear the whole table.
+    visited: Vec<usize>,
+    generation: usize,
+}
+
+impl Matcher<'_> {
+    fn offset(&self, position: usize) -> usize {
+        self.chars
+            .get(position)
+            .map(|(offset, _)| *offset)
+            .unwrap_or(self.end)
+    }
+
+    /// The leftmost match at or after `first` with the position it ends at
+    /// and the byte ranges of the groups.
+    fn search(&mut self, first: usize) -> Option<(usize, Groups)> {
+        self.first = first;
+        self.generation += 1;
+        self.slots.fill(None);
+        (first..=self.chars.len()).find_map(|start| {
+            let end = self.run(start)?;
+            let mut groups: Vec<_> = self
+                .slots
+                .chunks(2)
+                .map(|slots| match slots {
+                    [Some(start), Some(end)] => Some((self.offset(*start), self.offset(*end))),
+                    _ => None,
+                })
+                .collect();
+            groups[0] = Some((self.offset(start), self.offset(end)));
+            Some((end, groups))
+        })
+    }
+
+    /// End of the match starting at `start`. States which failed for an
+    /// earlier start of the same search fail again, so `visited` is kept between
+    /// starts.
+    fn run(&mut self, start: usize) -> Option<usize> {
+        let mut jobs = vec![Job::Try {
+            pc: 0,
+            position: start,
+        }];
+        while let Some(job) = jobs.pop() {
+            let (mut pc, mut position) = match job {
+                Job::Try { pc, position } => (pc, position),
+                Job::Restore { slot, value } => {
+                    self.slots[slot] = value;
+                    continue;
+                }
+            };
+            loop {
+                let state = pc * (self.chars.len() + 1) + position;
+                if self.visited[state] == self.generation {
+                    break;
+                }
+                self.visited[state] = self.generation;
+                match &self.program[pc] {
+                    Instruction::Single(node) => {
+                        if !self.single(node, position) {
+                            break;
+                        }
+                        position += 1;
+                    }
+                    Instruction::Start if position != self.first => break,
+                    Instruction::End if position != self.chars.len() => break,
+                    Instruction::Start | Instruction::End => (),
+                    Instruction::Split(first, second) => {
+                        jobs.push(Job::Try {
+                            pc: *second,
+                            position,
+                        });
+                        pc = *first;
+                        continue;
+                    }
+                    Instruction::Jump(target) => {
+                        pc = *target;
+                        continue;
+                    }
+                    Instruction::Save(slot) => {
+                        jobs.push(Job::Restore {
+                            slot: *slot,
+                            value: self.slots[*slot],
+                        });
+                        self.slots[*slot] = Some(position);
+                    }
+                    Instruction::Match => return Some(position),
+                }
+                pc += 1;
+            }
+        }
+        None
+    }
+
+    fn single(&self, node: &Node, position: usize) -> bool {
+        let Some(&(_, ch)) = self.chars.get(position) else {
+            return false;
+        };
+        match node {
+            Node::Char(expected) => ch == *expected,
+            Node::Any => true,
+            Node::Class { negated, ranges } => {
+                ranges
+                    .iter()
+                    .any(|(start, end)| (*start..=*end).contains(&ch))
+                    != *negated
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn groups<'s>(pattern: &str, text: &'s str) -> Option<Vec<Option<&'s str>>> {
+        let captures = Regex::new(pattern).unwrap().find(text)?;
+        Some(
+            (0..captures.len())
+                .map(|group| captures.get(group))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn regex_find() {
+        assert_eq!(groups("b+", "abbbc"), Some(vec![Some("bbb")]));
+        assert_eq!(groups("^b", "abc"), None);
+        assert_eq!(groups("c$", "abc"), Some(vec![Some("c")]));
+        assert_eq!(
+            groups("([a-z]+)-([0-9]*)", "x: abc-123"),
+            Some(vec![Some("abc-123"), Some("abc"), Some("123")])
+        );
+        assert_eq!(groups("a(x)?b", "ab"), Some(vec![Some("ab"), None]));
+        assert_eq!(
+            groups("(a|ab)(c|bcd)", "abcd"),
+            Some(vec![Some("abcd"), Some("a"), Some("bcd")])
+        );
+        assert_eq!(groups("[^.]+\\.txt", "a.b.txt"), Some(vec![Some("b.txt")]));
+        assert_eq!(groups("[]-]+", "a-]-b"), Some(vec![Some("-]-")]));
+        assert_eq!(groups("(a*)*b", "aab"), Some(vec![Some("aab"), Some("aa")]));
+        assert_eq!(groups(".*é", "aéb"), Some(vec![Some("aé")]));
+    }
+
+    #[test]
+    fn regex_long_input() {
+        let text = "a".repeat(20_000);
+        assert!(!Regex::new("a*b").unwrap().is_match(&text));
+        assert_eq!(
+            Regex::new("a+$").unwrap().find(&text).unwrap().range(0),
+            Some(0..20_000)
+        );
+
+        let text = format!("{}c", "ab".repeat(10_000));
+        assert_eq!(
+            groups("(a|b)*c", &text).map(|groups| groups[1]),
+            Some(Some("b"))
+        );
+        assert_eq!(Regex::new("(a*)*x").unwrap().find_all(&text), Ok(vec![]));
+
+        let text = "ab;".repeat(50_000);
+        let matches = Regex::new("a(x)?b").unwrap().find_all(&text).unwrap();
+        assert_eq!(matches.len(), 50_000);
+        assert_eq!(matches[49_999].range(0), Some(149_997..149_999));
+        assert_eq!(
+            Regex::new("[^;]+")
+                .unwrap()
+                .replace_all(&text, "c")
+                .unwrap(),
+            "c;".repeat(50_000)
+        );
+    }
+
+    #[test]
+    fn regex_find_all_groups() {
+        let regex = Regex::new("(x)?([a-z])").unwrap();
+        let groups: Vec<Vec<_>> = regex
+            .find_all("xa b")
+            .unwrap()
+            .iter()
+            .map(|captures| {
+                (0..captures.len())
+                    .map(|group| captures.get(group))
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            groups,
+            vec![
+                vec![Some("xa"), Some("x"), Some("a")],
+                vec![Some("b"), None, Some("b")],
+            ]
+        );
+    }
+
+    #[test]
+    fn regex_errors() {
+        assert_eq!(
+            Regex::new("a(b"),
+            Err(RegexError::Compile {
+                pattern: "a(b".to_string(),
+                reason: "unmatched ()"
+            })
+        );
+        assert!(Regex::new("a)").is_err());
+        assert!(Regex::new("[ab").is_err());
+        assert!(Regex::new("*a").is_err());
+        assert!(Regex::new("a**").is_err());
+        assert!(Regex::new("(((((((((())))))))))").is_err());
+        assert!(Regex::new("((((((((()))))))))").is_ok());
+    }
+
+    #[test]
+    fn regex_replace_all() {
+        let regex = Regex::new("([a-z]+)=([0-9]+)").unwrap();
+        assert_eq!(
+            regex.replace_all("a=1, b=2", "\\2:\\1").unwrap(),
+            "1:a, 2:b"
+        );
+        assert_eq!(
+            Regex::new("^a").unwrap().replace_all("aaa", "b").unwrap(),
+            "bbb"
+        );
+        assert_eq!(
+            Regex::new("x*").unwrap().replace_all("abc", "-"),
+            Err(RegexError::EmptyMatch("x*".to_string()))
+        );
+        assert_eq!(
+            regex.replace_all("a=1", "\\3"),
+            Err(RegexError::MissingGroup {
+                expression: "\\3".to_string(),
+                group: 3
+            })
+        );
+
+        assert_eq!(regex.replace_all("a=1", "\\1\\n\\\\").unwrap(), "a\n\\");
+        assert_eq!(
+            regex.replace_all("a=1", "\\x"),
+            Err(RegexError::UnknownEscape {
+                expression: "\\x".to_string(),
+                escape: "\\x".to_string()
+            })
+        );
+        assert_eq!(
+            regex.replace_all("a=1", "a\\"),
+            Err(RegexError::TrailingBackslash("a\\".to_string()))
+        );
+
+        let matches: Vec<_> = Regex::new("[0-9]+")
+            .unwrap()
+            .find_all("a1b22c333")
+            .unwrap()
+            .iter()
+            .map(|captures| (captures.get(0).unwrap(), captures.range(0).unwrap()))
+            .collect();
+        assert_eq!(matches, vec![("1", 1..2), ("22", 3..5), ("333", 6..9)]);
+    }
+}
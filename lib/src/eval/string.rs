@@ -0,0 +1,837 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{
+    analysis::{c_identifier, strip_genex},
+    command::common::HashAlgorithm,
+    command::scripting::string::{
+        String as StringCommand, StringComparison, StringGeneration, StringManipulation,
+        StringRegex, StringSearchAndReplace, UuidHashAlgorithm,
+    },
+    eval::{
+        configure,
+        hash::{hash, hex},
+        json::json,
+        ConfigureOptions, Regex, RegexError, Variables,
+    },
+    Token,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum StringError {
+    #[error(transparent)]
+    Regex(#[from] RegexError),
+    #[error("{0} is not a valid number")]
+    InvalidNumber(String),
+    #[error("begin index: {begin} is out of range 0 - {length}")]
+    SubstringBegin { begin: i64, length: usize },
+    #[error("end index: {0} is out of range -1 - string length")]
+    SubstringLength(i64),
+    #[error("repeat count is not a positive number: {0}")]
+    RepeatCount(i64),
+    #[error("character with code {0} does not exist")]
+    Ascii(i64),
+    #[error("invalid RANDOM {0}")]
+    Random(&'static str),
+    #[error("malformed UUID namespace: {0}")]
+    Uuid(String),
+    #[error("{0}")]
+    Json(String),
+}
+
+/// Number of `CMAKE_MATCH_<n>` variables.
+const MATCH_VARIABLES: usize = 10;
+
+/// Runs a `string()` command updating the variables.
+///
+/// Arguments are used as they are written, variable references in them are
+/// expected to be expanded already. Regular expression commands set
+/// `CMAKE_MATCH_<n>` and `CMAKE_MATCH_COUNT` as CMake does.
+///
+/// ```
+/// use cmake_parser::{eval, parse_cmakelists, Command, Doc};
+///
+/// let cmakelists = parse_cmakelists(br#"string(REGEX REPLACE "([0-9]+)" "<\\1>" out "a1b22")
+/// "#).unwrap();
+/// let doc = Doc::from(cmakelists);
+/// let Ok(Command::String(string)) = doc.to_commands_iter().next().unwrap() else {
+///     unreachable!()
+/// };
+/// let mut variables = eval::Variables::new();
+/// eval::string(&string, &mut variables).unwrap();
+/// assert_eq!(variables.get("out"), Some("a<1>b<22>"));
+/// assert_eq!(variables.get("CMAKE_MATCH_1"), Some("22"));
+/// ```
+pub fn string(command: &StringCommand, variables: &mut Variables) -> Result<(), StringError> {
+    match command {
+        StringCommand::SearchAndReplace(command) => search_and_replace(command, variables),
+        StringCommand::Manipulation(command) => manipulation(command, variables),
+        StringCommand::Comparison(command) => {
+            let (string1, string2, output_variable) = match command {
+                StringComparison::Less(c) => (&c.string1, &c.string2, &c.output_variable),
+                StringComparison::Greater(c) => (&c.string1, &c.string2, &c.output_variable),
+                StringComparison::Equal(c) => (&c.string1, &c.string2, &c.output_variable),
+                StringComparison::NotEqual(c) => (&c.string1, &c.string2, &c.output_variable),
+                StringComparison::LessEqual(c) => (&c.string1, &c.string2, &c.output_variable),
+                StringComparison::GreaterEqual(c) => (&c.string1, &c.string2, &c.output_variable),
+            };
+            let ordering = string1.as_bytes().cmp(string2.as_bytes());
+            let result = match command {
+                StringComparison::Less(_) => ordering.is_lt(),
+                StringComparison::Greater(_) => ordering.is_gt(),
+                StringComparison::Equal(_) => ordering.is_eq(),
+                StringComparison::NotEqual(_) => ordering.is_ne(),
+                StringComparison::LessEqual(_) => ordering.is_le(),
+                StringComparison::GreaterEqual(_) => ordering.is_ge(),
+            };
+            set(variables, output_variable, if result { "1" } else { "0" });
+            Ok(())
+        }
+        StringCommand::Hash(command) => {
+            let digest = hash(&command.hash_algorithm, command.input.as_bytes());
+            set(variables, &command.output_variable, digest);
+            Ok(())
+        }
+        StringCommand::Generation(command) => generation(command, variables),
+        StringCommand::Json(command) => match json(&command.command) {
+            Ok(value) => {
+                set(variables, &command.output_variable, value);
+                if let Some(error_variable) = &command.error_variable {
+                    set(variables, error_variable, "NOTFOUND");
+                }
+                Ok(())
+            }
+            Err(error) => {
+                let Some(error_variable) = &command.error_variable else {
+                    return Err(StringError::Json(error.message));
+                };
+                let mut path = error.path.join("-");
+                path.push_str("-NOTFOUND");
+                set(variables, &command.output_variable, path);
+                set(variables, error_variable, error.message);
+                Ok(())
+            }
+        },
+    }
+}
+
+fn search_and_replace(
+    command: &StringSearchAndReplace,
+    variables: &mut Variables,
+) -> Result<(), StringError> {
+    match command {
+        StringSearchAndReplace::Find(find) => {
+            let string = find.string.to_string();
+            let substring = find.substring.to_string();
+            let position = if find.reverse {
+                string.rfind(&substring)
+            } else {
+                string.find(&substring)
+            };
+            let position = position.map_or_else(|| "-1".to_string(), |p| p.to_string());
+            set(variables, &find.output_variable, position);
+        }
+        StringSearchAndReplace::Replace(replace) => {
+            let input = concat(&replace.input);
+            let match_string = replace.match_string.to_string();
+            let output = if match_string.is_empty() {
+                input
+            } else {
+                input.replace(&match_string, &replace.replace_string.to_string())
+            };
+            set(variables, &replace.output_variable, output);
+        }
+        StringSearchAndReplace::Regex(regex) => {
+            clear_matches(variables);
+            match regex {
+                StringRegex::Match(m) => {
+                    let regex = Regex::new(&m.regular_expression.to_string())?;
+                    let input = concat(&m.input);
+                    let output = match regex.find(&input) {
+                        Some(captures) => {
+                            let matched = captures.get(0).unwrap_or_default();
+                            if matched.is_empty() {
+                                return Err(
+                                    RegexError::EmptyMatch(regex.as_str().to_string()).into()
+                                );
+                            }
+                            store_matches(variables, &captures);
+                            matched.to_string()
+                        }
+                        None => String::new(),
+                    };
+                    set(variables, &m.output_variable, output);
+                }
+                StringRegex::MatchAll(m) => {
+                    let regex = Regex::new(&m.regular_expression.to_string())?;
+                    let input = concat(&m.input);
+                    let matches = regex.find_all(&input)?;
+                    if let Some(last) = matches.last() {
+                        store_matches(variables, last);
+                    }
+                    let output: Vec<_> = matches
+                        .iter()
+                        .map(|captures| captures.get(0).unwrap_or_default())
+                        .collect();
+                    set(variables, &m.output_variable, output.join(";"));
+                }
+                StringRegex::Replace(r) => {
+                    let regex = Regex::new(&r.regular_expression.to_string())?;
+                    let input = concat(&r.input);
+                    let matches = regex.find_all(&input)?;
+                    let output = regex.replace_matches(
+                        &input,
+                        &matches,
+                        &r.replacement_expression.to_string(),
+                    )?;
+                    if let Some(last) = matches.last() {
+                        store_matches(variables, last);
+                    }
+                    set(variables, &r.output_variable, output);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn manipulation(
+    command: &StringManipulation,
+    variables: &mut Variables,
+) -> Result<(), StringError> {
+    match command {
+        StringManipulation::Append(append) => {
+            let name = append.string_variable.to_string();
+            let value = format!(
+                "{}{}",
+                variables.get(&name).unwrap_or_default(),
+                concat(&append.input)
+            );
+            variables.set(name, value);
+        }
+        StringManipulation::Prepend(prepend) => {
+            let name = prepend.string_variable.to_string();
+            let value = format!(
+                "{}{}",
+                concat(&prepend.input),
+                variables.get(&name).unwrap_or_default()
+            );
+            variables.set(name, value);
+        }
+        StringManipulation::Concat(c) => set(variables, &c.output_variable, concat(&c.input)),
+        StringManipulation::Join(join) => {
+            let input: Vec<_> = join.input.iter().map(Token::to_string).collect();
+            set(
+                variables,
+                &join.output_variable,
+                input.join(&join.glue.to_string()),
+            );
+        }
+        StringManipulation::ToLower(c) => set(
+            variables,
+            &c.output_variable,
+            c.string.to_string().to_ascii_lowercase(),
+        ),
+        StringManipulation::ToUpper(c) => set(
+            variables,
+            &c.output_variable,
+            c.string.to_string().to_ascii_uppercase(),
+        ),
+        StringManipulation::Length(c) => set(
+            variables,
+            &c.output_variable,
+            c.string.as_bytes().len().to_string(),
+        ),
+        StringManipulation::Substring(c) => {
+            let string = c.string.as_bytes();
+            let begin = number(&c.begin)?;
+            let length = number(&c.length)?;
+            if begin < 0 || begin as usize > string.len() {
+                return Err(StringError::SubstringBegin {
+                    begin,
+                    length: string.len(),
+                });
+            }
+            if length < -1 {
+                return Err(StringError::SubstringLength(length));
+            }
+            let begin = begin as usize;
+            let end = if length == -1 {
+                string.len()
+            } else {
+                (begin + length as usize).min(string.len())
+            };
+            set(
+                variables,
+                &c.output_variable,
+                String::from_utf8_lossy(&string[begin..end]),
+            );
+        }
+        StringManipulation::Strip(c) => set(
+            variables,
+            &c.output_variable,
+            c.string
+                .to_string()
+                .trim_matches(|ch: char| ch.is_ascii_whitespace()),
+        ),
+        StringManipulation::GenexStrip(c) => set(
+            variables,
+            &c.output_variable,
+            strip_genex(&c.string.to_string()),
+        ),
+        StringManipulation::Repeat(c) => {
+            let count = number(&c.count)?;
+            if count < 0 {
+                return Err(StringError::RepeatCount(count));
+            }
+            set(
+                variables,
+                &c.output_variable,
+                c.string.to_string().repeat(count as usize),
+            );
+        }
+    }
+    Ok(())
+}
+
+fn generation(command: &StringGeneration, variables: &mut Variables) -> Result<(), StringError> {
+    match command {
+        StringGeneration::Ascii(ascii) => {
+            let output = ascii
+                .number
+                .iter()
+                .map(|number_token| {
+                    let code = number(number_token)?;
+                    u8::try_from(code)
+                        .ok()
+                        .filter(|code| *code > 0)
+                        .map(char::from)
+                        .ok_or(StringError::Ascii(code))
+                })
+                .collect::<Result<String, _>>()?;
+            set(variables, &ascii.output_variable, output);
+        }
+        StringGeneration::Hex(c) => set(variables, &c.output_variable, hex(c.string.as_bytes())),
+        StringGeneration::Configure(c) => {
+            let output = configure(
+                &c.string.to_string(),
+                variables,
+                &ConfigureOptions {
+                    only: c.only,
+                    escape_quotes: c.escape_quotes,
+                    ..Default::default()
+                },
+            );
+            set(variables, &c.output_variable, output);
+        }
+        StringGeneration::MakeCIdentifier(c) => set(
+            variables,
+            &c.output_variable,
+            c_identifier(&c.string.to_string()),
+        ),
+        StringGeneration::Random(random) => {
+            let length = random.length.as_ref().map(number).transpose()?.unwrap_or(5);
+            if length <= 0 {
+                return Err(StringError::Random("LENGTH"));
+            }
+            let alphabet: Vec<char> = match &random.alphabet {
+                Some(alphabet) => alphabet.to_string().chars().collect(),
+                None => ('a'..='z').chain('A'..='Z').chain('0'..='9').collect(),
+            };
+            if alphabet.is_empty() {
+                return Err(StringError::Random("ALPHABET"));
+            }
+            let mut state = match &random.random_seed {
+                Some(seed) => number(seed)? as u64,
+                None => SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|time| time.as_nanos() as u64)
+                    .unwrap_or_default(),
+            };
+            let output: String = (0..length)
+                .map(|_| {
+                    // splitmix64
+                    state = state.wrapping_add(0x9e3779b97f4a7c15);
+                    let mut z = state;
+                    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+                    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+                    z ^= z >> 31;
+                    alphabet[(z % alphabet.len() as u64) as usize]
+                })
+                .collect();
+            set(variables, &random.output_variable, output);
+        }
+        StringGeneration::Timestamp(timestamp) => {
+            let (seconds, micros) = match std::env::var("SOURCE_DATE_EPOCH")
+                .ok()
+                .and_then(|epoch| epoch.parse::<i64>().ok())
+            {
+                Some(seconds) => (seconds, 0),
+                None => {
+                    let now = SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap_or_default();
+                    (now.as_secs() as i64, now.subsec_micros())
+                }
+            };
+            let format = timestamp.format_string.as_ref().map(Token::to_string);
+            set(
+                variables,
+                &timestamp.output_variable,
+                format_timestamp(seconds, micros, format.as_deref(), timestamp.utc),
+            );
+        }
+        StringGeneration::Uuid(uuid) => {
+            let namespace = parse_uuid(&uuid.namespace.to_string())
+                .ok_or_else(|| StringError::Uuid(uuid.namespace.to_string()))?;
+            let mut data = namespace.to_vec();
+            data.extend_from_slice(uuid.name.as_bytes());
+            let (algorithm, version) = match uuid.hash_algorithm {
+                UuidHashAlgorithm::MD5 => (HashAlgorithm::MD5, 3),
+                UuidHashAlgorithm::SHA1 => (HashAlgorithm::SHA1, 5),
+            };
+            let digest = hash(&algorithm, &data);
+            let mut bytes: Vec<u8> = (0..16)
+                .map(|i| u8::from_str_radix(&digest[i * 2..i * 2 + 2], 16).expect("hex digest"))
+                .collect();
+            bytes[6] = (bytes[6] & 0x0f) | (version << 4);
+            bytes[8] = (bytes[8] & 0x3f) | 0x80;
+            let hex = hex(&bytes);
+            let mut output = format!(
+                "{}-{}-{}-{}-{}",
+                &hex[0..8],
+                &hex[8..12],
+                &hex[12..16],
+                &hex[16..20],
+                &hex[20..32]
+            );
+            if uuid.upper {
+                output.make_ascii_uppercase();
+            }
+            set(variables, &uuid.output_variable, output);
+        }
+    }
+    Ok(())
+}
+
+fn set(variables: &mut Variables, name: &Token, value: impl Into<String>) {
+    variables.set(name.to_string(), value);
+}
+
+/// Multiple input arguments are concatenated without a separator.
+fn concat(input: &[Token]) -> String {
+    input.iter().map(Token::to_string).collect()
+}
+
+fn number(token: &Token) -> Result<i64, StringError> {
+    let value = token.to_string();
+    value
+        .trim()
+        .parse()
+        .map_err(|_| StringError::InvalidNumber(value))
+}
+
+fn clear_matches(variables: &mut Variables) {
+    for index in 0..MATCH_VARIABLES {
+        variables.unset(&format!("CMAKE_MATCH_{index}"));
+    }
+    variables.set("CMAKE_MATCH_COUNT", "0");
+}
+
+/// Sets `CMAKE_MATCH_<n>` for non-empty groups, `CMAKE_MATCH_COUNT` is the
+/// highest of them.
+fn store_matches(variables: &mut Variables, captures: &crate::eval::Captures) {
+    let mut highest = 0;
+    for index in 0..captures.len().min(MATCH_VARIABLES) {
+        if let Some(value) = captures.get(index).filter(|value| !value.is_empty()) {
+            variables.set(format!("CMAKE_MATCH_{index}"), value);
+            highest = index;
+        }
+    }
+    variables.set("CMAKE_MATCH_COUNT", highest.to_string());
+}
+
+fn parse_uuid(value: &str) -> Option<[u8; 16]> {
+    let digits: String = value.chars().filter(|ch| *ch != '-').collect();
+    if digits.len() != 32 || value.len() != 36 {
+        return None;
+    }
+    let mut bytes = [0; 16];
+    for (index, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(digits.get(index * 2..index * 2 + 2)?, 16).ok()?;
+    }
+    Some(bytes)
+}
+
+const WEEKDAYS: [&str; 7] = [
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+];
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Formats a Unix time as `string(TIMESTAMP)` does.
+///
+/// Time zones are not available, so local time is formatted the same way as
+/// UTC without the `Z` suffix of the default format.
+pub fn format_timestamp(seconds: i64, micros: u32, format: Option<&str>, utc: bool) -> String {
+    let default_format = if utc {
+        "%Y-%m-%dT%H:%M:%SZ"
+    } else {
+        "%Y-%m-%dT%H:%M:%S"
+    };
+    let format = format.unwrap_or(default_format);
+
+    let days = seconds.div_euclid(86400);
+    let time = seconds.rem_euclid(86400);
+    let (year, month, day) = civil_from_days(days);
+    let (hour, minute, second) = (time / 3600, time / 60 % 60, time % 60);
+    let weekday = days.rem_euclid(7) as usize;
+    let year_day = days - days_from_civil(year, 1, 1);
+    // weeks starting on Sunday, the days before the first Sunday are week 0
+    let sunday_week = (year_day + 7 - ((weekday + 4) % 7) as i64) / 7;
+
+    let mut result = String::new();
+    let mut chars = format.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '%' {
+            result.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('%') => result.push('%'),
+            Some('a') => result.push_str(&WEEKDAYS[weekday][..3]),
+            Some('A') => result.push_str(WEEKDAYS[weekday]),
+            Some('b') => result.push_str(&MONTHS[month as usize - 1][..3]),
+            Some('B') => result.push_str(MONTHS[month as usize - 1]),
+            Some('d') => result.push_str(&format!("{day:02}")),
+            Some('f') => result.push_str(&format!("{micros:06}")),
+            Some('H') => result.push_str(&format!("{hour:02}")),
+            Some('I') => result.push_str(&format!("{:02}", (hour + 11) % 12 + 1)),
+            Some('j') => result.push_str(&format!("{:03}", year_day + 1)),
+            Some('m') => result.push_str(&format!("{month:02}")),
+            Some('M') => result.push_str(&format!("{minute:02}")),
+            Some('s') => result.push_str(&seconds.to_string()),
+            Some('S') => result.push_str(&format!("{second:02}")),
+            Some('U') => result.push_str(&format!("{sunday_week:02}")),
+            Some('w') => result.push_str(&((weekday + 4) % 7).to_string()),
+            Some('y') => result.push_str(&format!("{:02}", year % 100)),
+            Some('Y') => result.push_str(&year.to_string()),
+            Some(other) => {
+                result.push('%');
+                result.push(other);
+            }
+            None => result.push('%'),
+        }
+    }
+    result
+}
+
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    fn run(src: &[u8], variables: &mut Variables) -> Result<(), StringError> {
+        let cmakelists = parse_cmakelists(src).unwrap();
+        let doc = Doc::from(cmakelists);
+        for command in doc.commands().unwrap() {
+            let Command::String(command) = command else {
+                unreachable!("only string() commands are expected");
+            };
+            string(&command, variables)?;
+        }
+        Ok(())
+    }
+
+    fn eval(src: &[u8]) -> Variables {
+        let mut variables = Variables::new();
+        run(src, &mut variables).unwrap();
+        variables
+    }
+
+    fn values<'v>(variables: &'v Variables, names: &[&str]) -> Vec<Option<&'v str>> {
+        names.iter().map(|name| variables.get(name)).collect()
+    }
+
+    #[test]
+    fn string_search_and_replace() {
+        let variables = eval(include_bytes!("../../../fixture/eval/string/search"));
+        assert_eq!(
+            values(
+                &variables,
+                &[
+                    "find",
+                    "rfind",
+                    "missing",
+                    "replace",
+                    "match",
+                    "matchall",
+                    "regex_replace"
+                ]
+            ),
+            vec![
+                Some("1"),
+                Some("3"),
+                Some("-1"),
+                Some("a-b-c"),
+                Some("v1.2"),
+                Some("1;22;333"),
+                Some("x=<1> y=<22>"),
+            ]
+        );
+        assert_eq!(
+            values(
+                &variables,
+                &[
+                    "CMAKE_MATCH_COUNT",
+                    "CMAKE_MATCH_0",
+                    "CMAKE_MATCH_1",
+                    "CMAKE_MATCH_2"
+                ]
+            ),
+            vec![Some("1"), Some("22"), Some("22"), None]
+        );
+
+        let variables = eval(
+            br#"string(REGEX MATCH "([a-z]+)(x?)([0-9]+)" out "id: abc42")
+"#,
+        );
+        assert_eq!(
+            values(
+                &variables,
+                &[
+                    "out",
+                    "CMAKE_MATCH_COUNT",
+                    "CMAKE_MATCH_1",
+                    "CMAKE_MATCH_2",
+                    "CMAKE_MATCH_3"
+                ]
+            ),
+            vec![Some("abc42"), Some("3"), Some("abc"), None, Some("42")]
+        );
+
+        let mut variables = Variables::new();
+        assert_eq!(
+            run(
+                br#"string(REGEX MATCHALL "x*" out "abc")
+"#,
+                &mut variables
+            ),
+            Err(StringError::Regex(RegexError::EmptyMatch("x*".to_string())))
+        );
+    }
+
+    #[test]
+    fn string_manipulation() {
+        let mut variables = Variables::from_iter([("var", "b")]);
+        run(
+            include_bytes!("../../../fixture/eval/string/manipulation"),
+            &mut variables,
+        )
+        .unwrap();
+        assert_eq!(
+            values(
+                &variables,
+                &[
+                    "var",
+                    "concat",
+                    "join",
+                    "lower",
+                    "upper",
+                    "length",
+                    "sub1",
+                    "sub2",
+                    "strip",
+                    "genex",
+                    "repeat",
+                    "less",
+                    "equal",
+                    "greater_equal"
+                ]
+            ),
+            vec![
+                Some("abc"),
+                Some("xyz"),
+                Some("x, y, z"),
+                Some("mixed"),
+                Some("MIXED"),
+                Some("5"),
+                Some("ell"),
+                Some("llo"),
+                Some("a b"),
+                Some("a;;c"),
+                Some("ababab"),
+                Some("1"),
+                Some("0"),
+                Some("1"),
+            ]
+        );
+
+        let mut variables = Variables::new();
+        assert_eq!(
+            run(b"string(SUBSTRING abc 4 1 out)\n", &mut variables),
+            Err(StringError::SubstringBegin {
+                begin: 4,
+                length: 3
+            })
+        );
+    }
+
+    #[test]
+    fn string_generation() {
+        let mut variables = Variables::from_iter([("NAME", "app"), ("QUOTE", "\"")]);
+        run(
+            include_bytes!("../../../fixture/eval/string/generation"),
+            &mut variables,
+        )
+        .unwrap();
+        assert_eq!(
+            values(
+                &variables,
+                &[
+                    "md5",
+                    "sha256",
+                    "sha3",
+                    "ascii",
+                    "hex",
+                    "configure",
+                    "only",
+                    "c_id",
+                    "uuid"
+                ]
+            ),
+            vec![
+                Some("900150983cd24fb0d6963f7d28e17f72"),
+                Some("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+                Some("3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"),
+                Some("AB"),
+                Some("616263"),
+                Some("app \\\"app\\\""),
+                Some("app ${NAME}"),
+                Some("_1_foo_h"),
+                Some("2ED6657D-E927-568B-95E1-2665A8AEA6A2"),
+            ]
+        );
+
+        let random = variables.get("random").unwrap();
+        assert_eq!(random.len(), 8);
+        assert!(random.chars().all(|ch| "abc".contains(ch)));
+        assert_eq!(variables.get("random"), variables.get("random_again"));
+        assert_eq!(variables.get("random_default").map(str::len), Some(5));
+    }
+
+    #[test]
+    fn string_timestamp() {
+        // 2023-03-05 14:07:09 UTC, Sunday
+        let seconds = 1678025229;
+        assert_eq!(
+            format_timestamp(seconds, 42, None, true),
+            "2023-03-05T14:07:09Z"
+        );
+        assert_eq!(
+            format_timestamp(
+                seconds,
+                42,
+                Some("%a %A %b %B %j %I %y %s %f %w %U %%"),
+                false
+            ),
+            "Sun Sunday Mar March 064 02 23 1678025229 000042 0 10 %"
+        );
+        assert_eq!(
+            format_timestamp(0, 0, Some("%Y-%m-%d %U"), true),
+            "1970-01-01 00"
+        );
+    }
+
+    #[test]
+    fn string_json() {
+        let mut variables = Variables::new();
+        run(
+            include_bytes!("../../../fixture/eval/string/json"),
+            &mut variables,
+        )
+        .unwrap();
+        assert_eq!(
+            values(
+                &variables,
+                &["name", "flag", "real", "missing", "type", "member", "length", "equal", "err"]
+            ),
+            vec![
+                Some("app"),
+                Some("ON"),
+                Some("0.10000000000000001"),
+                Some("deps-3-NOTFOUND"),
+                Some("ARRAY"),
+                Some("name"),
+                Some("3"),
+                Some("ON"),
+                Some("expected an index less than 3 got '3'"),
+            ]
+        );
+        assert_eq!(
+            variables.get("array"),
+            Some("[\n  \"a\",\n  \"b\",\n  {\n    \"c\" : null\n  }\n]")
+        );
+        assert_eq!(
+            variables.get("set"),
+            Some(
+                "{\n  \"deps\" : [ \"a\", \"b\", \"c\" ],\n  \"flag\" : true,\n  \"name\" : \"app\",\n  \"real\" : 0.10000000000000001\n}"
+            )
+        );
+        assert_eq!(
+            variables.get("removed"),
+            Some("{\n  \"deps\" : [ \"a\", \"c\" ],\n  \"flag\" : true,\n  \"name\" : \"app\",\n  \"real\" : 0.10000000000000001\n}")
+        );
+        assert_eq!(variables.get("ok_err"), Some("NOTFOUND"));
+
+        assert_eq!(
+            run(
+                br#"string(JSON out GET "{}" name)
+"#,
+                &mut variables
+            ),
+            Err(StringError::Json("member 'name' not found".to_string()))
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+/// CMake version with up to four numeric components.
+///
+/// Versions are ordered the way `VERSION_LESS` and friends compare them:
+/// missing components are zeros, so `3.20` equals `3.20.0`.
+///
+/// ```
+/// use cmake_parser::eval::CMakeVersion;
+///
+/// let version: CMakeVersion = "3.20".parse().unwrap();
+/// assert!(version < "3.20.1".parse().unwrap());
+/// assert_eq!(version, "3.20.0".parse().unwrap());
+/// assert_eq!(version.minor(), Some(20));
+/// assert_eq!(version.to_string(), "3.20");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CMakeVersion {
+    components: [u64; 4],
+    count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum VersionError {
+    #[error("{0} is not a valid version")]
+    Invalid(String),
+    #[error("the maximum of the version range {0} is less than its minimum")]
+    EmptyRange(String),
+    #[error("EXACT can not be used with the version range {0}")]
+    ExactRange(String),
+}
+
+impl CMakeVersion {
+    /// Version of the given components, at most four are kept.
+    pub const fn new(components: &[u64]) -> Self {
+        let count = if components.len() < 4 {
+            components.len()
+        } else {
+            4
+        };
+        let mut version = Self {
+            components: [0; 4],
+            count,
+        };
+        let mut index = 0;
+        while index < count {
+            version.components[index] = components[index];
+            index += 1;
+        }
+        version
+    }
+
+    /// Components the version was written with.
+    pub fn components(&self) -> &[u64] {
+        &self.components[..self.count]
+    }
+
+    pub fn major(&self) -> u64 {
+        self.components[0]
+    }
+
+    pub fn minor(&self) -> Option<u64> {
+        self.component(1)
+    }
+
+    pub fn patch(&self) -> Option<u64> {
+        self.component(2)
+    }
+
+    pub fn tweak(&self) -> Option<u64> {
+        self.component(3)
+    }
+
+    /// Version of a string as `VERSION_LESS` and friends read it: every
+    /// component counts with its leading digits, others are zeros.
+    pub(crate) fn lenient(version: &str) -> Self {
+        let components: Vec<u64> = version
+            .split('.')
+            .map(|component| {
+                let digits = component.bytes().take_while(u8::is_ascii_digit).count();
+                component[..digits].parse().unwrap_or(0)
+            })
+            .collect();
+        Self::new(&components)
+    }
+
+    fn component(&self, index: usize) -> Option<u64> {
+        (index < self.count).then_some(self.components[index])
+    }
+}
+
+impl FromStr for CMakeVersion {
+    type Err = VersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || VersionError::Invalid(s.to_string());
+        let components = s
+            .split('.')
+            .map(|component| {
+                if component.is_empty() || !component.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(error());
+                }
+                component.parse().map_err(|_| error())
+            })
+            .collect::<Result<Vec<u64>, _>>()?;
+        if components.len() > 4 {
+            return Err(error());
+        }
+        Ok(Self::new(&components))
+    }
+}
+
+/// Upper bound of a [`VersionRange`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VersionBound {
+    /// `min...max`
+    Inclusive(CMakeVersion),
+    /// `min...<max`
+    Exclusive(CMakeVersion),
+}
+
+/// Version range `min[...[<]max]` of `find_package()` and
+/// `cmake_minimum_required()`.
+///
+/// ```
+/// use cmake_parser::eval::VersionRange;
+///
+/// let range: VersionRange = "1.70...<1.85".parse().unwrap();
+/// assert!(range.contains(&"1.84.1".parse().unwrap()));
+/// assert!(!range.contains(&"1.85".parse().unwrap()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VersionRange {
+    pub min: CMakeVersion,
+    /// Upper bound, `None` for a single version which is only a minimum.
+    pub max: Option<VersionBound>,
+}
+
+impl VersionRange {
+    pub fn contains(&self, version: &CMakeVersion) -> bool {
+        *version >= self.min
+            && match self.max {
+                Some(VersionBound::Inclusive(max)) => *version <= max,
+                Some(VersionBound::Exclusive(max)) => *version < max,
+                None => true,
+            }
+    }
+}
+
+impl FromStr for VersionRange {
+    type Err = VersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((min, max)) = s.split_once("...") else {
+            return Ok(Self {
+                min: s.parse()?,
+                max: None,
+            });
+        };
+        let min = min.parse()?;
+        let max = match max.strip_prefix('<') {
+            Some(max) => VersionBound::Exclusive(max.parse()?),
+            None => VersionBound::Inclusive(max.parse()?),
+        };
+        let empty = match max {
+            VersionBound::Inclusive(max) => max < min,
+            VersionBound::Exclusive(max) => max <= min,
+        };
+        if empty {
+            return Err(VersionError::EmptyRange(s.to_string()));
+        }
+        Ok(Self {
+            min,
+            max: Some(max),
+        })
+    }
+}
+
+impl fmt::Display for VersionRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.min)?;
+        match self.max {
+            Some(VersionBound::Inclusive(max)) => write!(f, "...{max}"),
+            Some(VersionBound::Exclusive(max)) => write!(f, "...<{max}"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Version requirement of `find_package()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VersionRequirement {
+    /// `EXACT` version, compared with `VERSION_EQUAL`.
+    Exact(CMakeVersion),
+    Range(VersionRange),
+}
+
+impl VersionRequirement {
+    /// Parses a version argument with the `EXACT` option.
+    pub fn new(version: &str, exact: bool) -> Result<Self, VersionError> {
+        let range: VersionRange = version.parse()?;
+        match (exact, range.max) {
+            (false, _) => Ok(Self::Range(range)),
+            (true, None) => Ok(Self::Exact(range.min)),
+            (true, Some(_)) => Err(VersionError::ExactRange(version.to_string())),
+        }
+    }
+
+    /// Whether an available version satisfies the requirement.
+    pub fn accepts(&self, version: &CMakeVersion) -> bool {
+        match self {
+            Self::Exact(exact) => version == exact,
+            Self::Range(range) => range.contains(version),
+        }
+    }
+}
+
+impl PartialEq for CMakeVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.components == other.components
+    }
+}
+
+impl Eq for CMakeVersion {}
+
+impl PartialOrd for CMakeVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CMakeVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.components.cmp(&other.components)
+    }
+}
+
+impl Hash for CMakeVersion {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.components.hash(state);
+    }
+}
+
+impl fmt::Display for CMakeVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, component) in self.components().iter().enumerate() {
+            if index > 0 {
+                write!(f, ".")?;
+            }
+            write!(f, "{component}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn version(s: &str) -> CMakeVersion {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn version_ordering() {
+        assert!(version("3.9") < version("3.10"));
+        assert!(version("3.10.2") > version("3.10"));
+        assert!(version("1.2.3.4") > version("1.2.3"));
+        assert_eq!(version("2"), version("2.0.0.0"));
+        assert_eq!(version("03.1").to_string(), "3.1");
+        assert_eq!(version("1.2.3.4").tweak(), Some(4));
+        assert_eq!(version("1.2").patch(), None);
+
+        for invalid in ["", "1.", ".1", "1.2.3.4.5", "1.2-rc1", "v1", "1..2"] {
+            assert_eq!(
+                invalid.parse::<CMakeVersion>(),
+                Err(VersionError::Invalid(invalid.to_string()))
+            );
+        }
+
+        assert_eq!(CMakeVersion::lenient("1.2-rc1"), version("1.2"));
+        assert_eq!(CMakeVersion::lenient("v1..3"), version("0.0.3"));
+        assert_eq!(CMakeVersion::lenient(""), version("0"));
+    }
+
+    #[test]
+    fn version_range() {
+        let range = |s: &str| s.parse::<VersionRange>();
+        let boost = range("1.70...<1.85").unwrap();
+        assert!(!boost.contains(&version("1.69.9")));
+        assert!(boost.contains(&version("1.70")));
+        assert!(boost.contains(&version("1.84.0")));
+        assert!(!boost.contains(&version("1.85.0")));
+        assert_eq!(boost.to_string(), "1.70...<1.85");
+
+        let inclusive = range("3.15...3.26").unwrap();
+        assert!(inclusive.contains(&version("3.26")));
+        assert!(!inclusive.contains(&version("3.26.1")));
+        assert!(range("2.0").unwrap().contains(&version("10")));
+
+        assert_eq!(
+            range("1.2...<1.2"),
+            Err(VersionError::EmptyRange("1.2...<1.2".to_string()))
+        );
+        assert_eq!(
+            range("1.2...x"),
+            Err(VersionError::Invalid("x".to_string()))
+        );
+
+        let exact = VersionRequirement::new("1.2", true).unwrap();
+        assert!(exact.accepts(&version("1.2.0")));
+        assert!(!exact.accepts(&version("1.2.1")));
+        assert_eq!(
+            VersionRequirement::new("1.2...1.3", true),
+            Err(VersionError::ExactRange("1.2...1.3".to_string()))
+        );
+    }
+}
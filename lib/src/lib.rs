@@ -1,12 +1,16 @@
 #![doc = include_str!("../README.md")]
 
+pub mod analysis;
 mod doc;
+pub mod eval;
+pub mod lint;
 mod parser;
 
 pub use cmake_parser_derive::CMake;
 pub use doc::{
     command::{self, CommandParseError},
-    declarations_by_keywords, CMakeParse, CMakePositional, Command, CommandScope, Doc, Keyword,
-    TextNodeDeclaration, ToCommandScope, Token, TokenDeclarations,
+    declarations_by_keywords, CMakeParse, CMakePositional, Command, CommandScope, Doc, Documented,
+    Keyword, KeywordSet, TextNodeDeclaration, ToCommandScope, Token, TokenDeclarations,
+    TokenValueError, Visit, VisitMut, Visitable,
 };
-pub use parser::{parse_cmakelists, CMakeListsParseError, CMakeListsTokens};
+pub use parser::{parse_cmakelists, CMakeListsParseError, CMakeListsTokens, Position, Span};
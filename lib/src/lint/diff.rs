@@ -0,0 +1,177 @@
+use std::fmt::Write;
+
+const CONTEXT: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Unified diff between two versions of a file with three lines of
+/// context, empty if they are equal.
+///
+/// ```
+/// use cmake_parser::lint::unified_diff;
+///
+/// let diff = unified_diff("CMakeLists.txt", "a\nb\n", "a\nc\n");
+/// assert_eq!(diff, "--- a/CMakeLists.txt\n+++ b/CMakeLists.txt\n@@ -1,2 +1,2 @@\n a\n-b\n+c\n");
+/// ```
+pub fn unified_diff(path: &str, old: &str, new: &str) -> String {
+    let old: Vec<&str> = old.split_inclusive('\n').collect();
+    let new: Vec<&str> = new.split_inclusive('\n').collect();
+    let ops = diff_lines(&old, &new);
+    let changes: Vec<usize> = (0..ops.len())
+        .filter(|&index| ops[index] != Op::Equal)
+        .collect();
+    if changes.is_empty() {
+        return String::new();
+    }
+
+    let mut diff = format!("--- a/{path}\n+++ b/{path}\n");
+    // Line numbers in the old and new files before each operation.
+    let mut positions = Vec::with_capacity(ops.len() + 1);
+    let (mut old_line, mut new_line) = (0, 0);
+    for op in &ops {
+        positions.push((old_line, new_line));
+        match op {
+            Op::Equal => {
+                old_line += 1;
+                new_line += 1;
+            }
+            Op::Delete => old_line += 1,
+            Op::Insert => new_line += 1,
+        }
+    }
+    positions.push((old_line, new_line));
+
+    let mut hunk_start = 0;
+    while hunk_start < changes.len() {
+        let mut hunk_end = hunk_start;
+        while hunk_end + 1 < changes.len()
+            && changes[hunk_end + 1] - changes[hunk_end] <= 2 * CONTEXT + 1
+        {
+            hunk_end += 1;
+        }
+        let start = changes[hunk_start].saturating_sub(CONTEXT);
+        let end = (changes[hunk_end] + CONTEXT + 1).min(ops.len());
+        let (old_start, new_start) = positions[start];
+        let (old_end, new_end) = positions[end];
+        let range = |start: usize, count: usize| {
+            let start = if count == 0 { start } else { start + 1 };
+            format!("{start},{count}")
+        };
+        let _ = writeln!(
+            diff,
+            "@@ -{} +{} @@",
+            range(old_start, old_end - old_start),
+            range(new_start, new_end - new_start)
+        );
+        for index in start..end {
+            let (old_line, new_line) = positions[index];
+            let (prefix, line) = match ops[index] {
+                Op::Equal => (' ', old[old_line]),
+                Op::Delete => ('-', old[old_line]),
+                Op::Insert => ('+', new[new_line]),
+            };
+            diff.push(prefix);
+            diff.push_str(line);
+            if !line.ends_with('\n') {
+                diff.push_str("\n\\ No newline at end of file\n");
+            }
+        }
+        hunk_start = hunk_end + 1;
+    }
+    diff
+}
+
+/// Operations turning the old lines into the new ones, from their longest
+/// common subsequence.
+fn diff_lines(old: &[&str], new: &[&str]) -> Vec<Op> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    // lengths[i][j] is the length of the longest common subsequence of
+    // old_middle[i..] and new_middle[j..].
+    let width = new_middle.len() + 1;
+    let mut lengths = vec![0u32; (old_middle.len() + 1) * width];
+    for i in (0..old_middle.len()).rev() {
+        for j in (0..new_middle.len()).rev() {
+            lengths[i * width + j] = if old_middle[i] == new_middle[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut ops = vec![Op::Equal; prefix];
+    let (mut i, mut j) = (0, 0);
+    while i < old_middle.len() || j < new_middle.len() {
+        if i < old_middle.len() && j < new_middle.len() && old_middle[i] == new_middle[j] {
+            ops.push(Op::Equal);
+            i += 1;
+            j += 1;
+        } else if j == new_middle.len()
+            || i < old_middle.len() && lengths[(i + 1) * width + j] >= lengths[i * width + j + 1]
+        {
+            ops.push(Op::Delete);
+            i += 1;
+        } else {
+            ops.push(Op::Insert);
+            j += 1;
+        }
+    }
+    ops.extend(std::iter::repeat_n(Op::Equal, suffix));
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn diff() {
+        assert_eq!(unified_diff("f", "a\n", "a\n"), "");
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n13\n14\n15";
+        let new = "0\n1\n2\n3\n4\n5\n6\n7\n8\nnine\n10\n11\n12\n13\n14\n15\n";
+        assert_eq!(
+            unified_diff("dir/f", old, new),
+            "--- a/dir/f
++++ b/dir/f
+@@ -1,3 +1,4 @@
++0
+ 1
+ 2
+ 3
+@@ -6,10 +7,10 @@
+ 6
+ 7
+ 8
+-9
++nine
+ 10
+ 11
+ 12
+ 13
+ 14
+-15
+\\ No newline at end of file
++15
+"
+        );
+        assert_eq!(
+            unified_diff("f", "", "a\n"),
+            "--- a/f\n+++ b/f\n@@ -0,0 +1,1 @@\n+a\n"
+        );
+    }
+}
@@ -0,0 +1,374 @@
+use std::{collections::BTreeMap, fmt};
+
+use crate::{
+    lint::{rules, suppression::Suppressions, Fix, LintContext, Rule},
+    Doc, Span,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+/// Configuration of a rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleConfig {
+    pub enabled: bool,
+    /// Severity replacing the default severity of the rule.
+    pub severity: Option<Severity>,
+    /// Options read by the rule with [`LintContext::option`].
+    pub options: BTreeMap<String, String>,
+}
+
+/// Problem reported by a rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Identifier of the rule.
+    pub rule: &'static str,
+    pub severity: Severity,
+    /// Index of the command in the linted document.
+    pub command: usize,
+    pub span: Span,
+    pub message: String,
+    pub fix: Option<Fix>,
+}
+
+/// Rules available to a [`Linter`].
+#[derive(Default)]
+pub struct Registry {
+    rules: Vec<Box<dyn Rule>>,
+}
+
+/// Runs the enabled rules of a registry.
+///
+/// ```
+/// use cmake_parser::{lint::{Linter, RuleConfig, Severity}, parse_cmakelists, Doc};
+///
+/// let cmakelists = parse_cmakelists(b"project(demo)\nsubdirs(src)\n").unwrap();
+/// let diagnostics = Linter::default()
+///     .configure("deprecated-command", RuleConfig::default().with_severity(Severity::Error))
+///     .lint(&Doc::from(cmakelists), true);
+/// let messages: Vec<_> = diagnostics.iter().map(ToString::to_string).collect();
+/// assert_eq!(
+///     messages,
+///     [
+///         "1:1: warning: cmake_minimum_required() is missing [minimum-required-first]",
+///         "2:1: error: subdirs() is deprecated [deprecated-command]",
+///     ]
+/// );
+/// ```
+pub struct Linter {
+    registry: Registry,
+    configs: BTreeMap<String, RuleConfig>,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Info => "info",
+        })
+    }
+}
+
+impl Default for RuleConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            severity: None,
+            options: BTreeMap::new(),
+        }
+    }
+}
+
+impl RuleConfig {
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    pub fn with_option(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.options.insert(name.into(), value.into());
+        self
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: {}: {} [{}]",
+            self.span.start.line, self.span.start.column, self.severity, self.message, self.rule
+        )
+    }
+}
+
+impl Registry {
+    /// Registry with the rules of [`rules`].
+    pub fn builtin() -> Self {
+        let mut registry = Self::default();
+        registry.register(rules::DeprecatedCommand);
+        registry.register(rules::MinimumRequiredFirst);
+        registry.register(rules::MissingProject);
+        registry
+    }
+
+    /// Adds a rule, replacing the rule with the same identifier.
+    pub fn register(&mut self, rule: impl Rule + 'static) {
+        self.rules.retain(|registered| registered.id() != rule.id());
+        self.rules.push(Box::new(rule));
+    }
+
+    pub fn rules(&self) -> impl Iterator<Item = &dyn Rule> {
+        self.rules.iter().map(AsRef::as_ref)
+    }
+
+    pub fn get(&self, id: &str) -> Option<&dyn Rule> {
+        self.rules().find(|rule| rule.id() == id)
+    }
+}
+
+impl Default for Linter {
+    fn default() -> Self {
+        Self::new(Registry::builtin())
+    }
+}
+
+impl Linter {
+    pub fn new(registry: Registry) -> Self {
+        Self {
+            registry,
+            configs: BTreeMap::new(),
+        }
+    }
+
+    pub fn configure(mut self, id: impl Into<String>, config: RuleConfig) -> Self {
+        self.configs.insert(id.into(), config);
+        self
+    }
+
+    /// Diagnostics of the enabled rules ordered by position, without those
+    /// suppressed by `cmake-lint:` comments. `top_level` tells whether the
+    /// document is the top-level `CMakeLists.txt` of a project.
+    pub fn lint(&self, doc: &Doc, top_level: bool) -> Vec<Diagnostic> {
+        let commands: Vec<_> = doc.to_commands_iter().collect();
+        let names: Vec<_> = doc.names().collect();
+        let spans = doc.spans();
+        let sources: Vec<_> = doc.sources().map(String::from).collect();
+        let parents = parents(&names);
+        let text = doc.source();
+        let suppressions = Suppressions::new(&doc.line_comments(), &spans);
+        let default_config = RuleConfig::default();
+
+        let mut diagnostics = vec![];
+        for rule in self.registry.rules() {
+            let config = self.configs.get(rule.id()).unwrap_or(&default_config);
+            if !config.enabled {
+                continue;
+            }
+            let context = LintContext {
+                commands: &commands,
+                names: &names,
+                spans: &spans,
+                sources: &sources,
+                parents: &parents,
+                text: &text,
+                top_level,
+                options: &config.options,
+            };
+            diagnostics.extend(
+                rule.check(&context)
+                    .into_iter()
+                    .filter(|violation| {
+                        !suppressions.is_suppressed(
+                            rule.id(),
+                            violation.command,
+                            &spans[violation.command],
+                        )
+                    })
+                    .map(|violation| Diagnostic {
+                        rule: rule.id(),
+                        severity: config.severity.unwrap_or_else(|| rule.severity()),
+                        command: violation.command,
+                        span: spans[violation.command],
+                        message: violation.message,
+                        fix: violation.fix,
+                    }),
+            );
+        }
+        diagnostics.sort_by(|a, b| (a.span, a.rule).cmp(&(b.span, b.rule)));
+        diagnostics
+    }
+}
+
+/// Command opening the innermost block enclosing each command.
+fn parents(names: &[String]) -> Vec<Option<usize>> {
+    let mut blocks: Vec<usize> = vec![];
+    names
+        .iter()
+        .enumerate()
+        .map(|(index, name)| match name.as_str() {
+            "if" | "foreach" | "while" | "function" | "macro" | "block" => {
+                let parent = blocks.last().copied();
+                blocks.push(index);
+                parent
+            }
+            "endif" | "endforeach" | "endwhile" | "endfunction" | "endmacro" | "endblock" => {
+                blocks.pop();
+                blocks.last().copied()
+            }
+            "elseif" | "else" => blocks.len().checked_sub(2).map(|parent| blocks[parent]),
+            _ => blocks.last().copied(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lint::Violation;
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    struct EveryCommand;
+
+    impl Rule for EveryCommand {
+        fn id(&self) -> &'static str {
+            "every-command"
+        }
+
+        fn description(&self) -> &'static str {
+            "Reports every command."
+        }
+
+        fn severity(&self) -> Severity {
+            Severity::Info
+        }
+
+        fn check(&self, context: &LintContext) -> Vec<Violation> {
+            let prefix = context.option("prefix").unwrap_or("");
+            (0..context.commands().len())
+                .map(|index| Violation::new(index, format!("{prefix}{}", context.name(index))))
+                .collect()
+        }
+    }
+
+    fn lint(linter: &Linter, src: &[u8]) -> Vec<String> {
+        let cmakelists = parse_cmakelists(src).unwrap();
+        linter
+            .lint(&Doc::from(cmakelists), false)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn registry() {
+        let mut registry = Registry::builtin();
+        let ids: Vec<_> = registry.rules().map(|rule| rule.id()).collect();
+        assert_eq!(
+            ids,
+            [
+                "deprecated-command",
+                "minimum-required-first",
+                "missing-project"
+            ]
+        );
+        registry.register(EveryCommand);
+        registry.register(EveryCommand);
+        assert_eq!(registry.rules().count(), 4);
+        assert_eq!(
+            registry.get("every-command").unwrap().description(),
+            "Reports every command."
+        );
+        assert!(registry.get("unknown").is_none());
+    }
+
+    #[test]
+    fn config() {
+        let mut registry = Registry::default();
+        registry.register(EveryCommand);
+        let src = b"message(a)\n  Message(b)\n";
+
+        assert_eq!(
+            lint(&Linter::new(Registry::default()), src),
+            Vec::<String>::new()
+        );
+        let linter = Linter::new(registry);
+        assert_eq!(
+            lint(&linter, src),
+            [
+                "1:1: info: message [every-command]",
+                "2:3: info: message [every-command]"
+            ]
+        );
+        let linter = linter.configure(
+            "every-command",
+            RuleConfig::default()
+                .with_severity(Severity::Error)
+                .with_option("prefix", "> "),
+        );
+        assert_eq!(
+            lint(&linter, src),
+            [
+                "1:1: error: > message [every-command]",
+                "2:3: error: > message [every-command]"
+            ]
+        );
+        let linter = linter.configure("every-command", RuleConfig::disabled());
+        assert_eq!(lint(&linter, src), Vec::<String>::new());
+    }
+
+    #[test]
+    fn suppression() {
+        let mut registry = Registry::default();
+        registry.register(EveryCommand);
+        let linter = Linter::new(registry);
+        let src = b"message(a) # cmake-lint: disable=every-command
+message(b
+  # cmake-lint: disable=all
+)
+# cmake-lint: disable=other,every-command
+message(c)
+# cmake-lint: enable=every-command
+message(d)
+# cmake-lint: disable=all
+message(e)
+# cmake-lint: enable=every-command
+message(f)
+# cmake-lint: disable=every-command
+message(g)
+# cmake-lint: enable=all
+message(h) # cmake-lint: disable=other
+";
+        assert_eq!(
+            lint(&linter, src),
+            [
+                "8:1: info: message [every-command]",
+                "12:1: info: message [every-command]",
+                "16:1: info: message [every-command]"
+            ]
+        );
+    }
+
+    #[test]
+    fn blocks() {
+        let src = b"if(A)\nfunction(f)\nmessage(a)\nendfunction()\nelse()\nset(B 1)\nendif()\n";
+        let doc = Doc::from(parse_cmakelists(src).unwrap());
+        let names: Vec<_> = doc.names().collect();
+        assert_eq!(
+            parents(&names),
+            [None, Some(0), Some(1), Some(0), None, Some(0), None]
+        );
+    }
+}
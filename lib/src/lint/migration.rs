@@ -0,0 +1,402 @@
+use crate::{
+    command::{
+        common::CustomCommand,
+        deprecated::{InstallFiles, InstallPrograms},
+        project::{
+            install::{
+                ArtifactKindTargets, FilesInstall, InstallKind, OutputArtifactTargets,
+                TargetsInstall,
+            },
+            AddSubdirectory, Install,
+        },
+        scripting::{
+            file::{FileFilesystem, FileMakeDirectory, FileWrite, FileWriting},
+            list::{ListModification, ListRemoveItem},
+            ExecuteProcess, File, List,
+        },
+    },
+    Command, Token,
+};
+
+/// Modern commands equivalent to a deprecated command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Migration<'t> {
+    /// Replacement commands, none for deprecated commands doing nothing.
+    pub commands: Vec<Command<'t>>,
+    /// Source of the replacement commands, one per line.
+    pub source: String,
+    /// Whether the deprecated command takes effect at the end of the
+    /// directory, so the replacement commands are faithful only when no
+    /// other commands follow it.
+    pub deferred: bool,
+}
+
+/// Reason a deprecated command cannot be migrated faithfully.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MigrationError {
+    #[error("arguments are interpreted by a shell, use execute_process() with separate arguments")]
+    ShellArguments,
+    #[error("files are matched by a regular expression when configuring, use install({0})")]
+    RegexFiles(&'static str),
+    #[error("files without the extension get it appended, use install(FILES)")]
+    MissingExtension,
+    #[error("no modern equivalent, use {0}")]
+    NoEquivalent(&'static str),
+    #[error("subdirectories are added after the other commands of the directory, use add_subdirectory() after them")]
+    CommandsFollow,
+}
+
+/// Migrates a deprecated command to its modern equivalent, `None` if the
+/// command is not deprecated.
+///
+/// ```
+/// use cmake_parser::{lint::migrate, parse_cmakelists, Doc};
+///
+/// let doc = Doc::from(parse_cmakelists(b"make_directory(${dir})\n").unwrap());
+/// let commands = doc.commands().unwrap();
+/// let migration = migrate(&commands[0]).unwrap().unwrap();
+/// assert_eq!(migration.source, "file(MAKE_DIRECTORY ${dir})");
+/// ```
+pub fn migrate<'t>(command: &Command<'t>) -> Option<Result<Migration<'t>, MigrationError>> {
+    let mut migration = Migration {
+        commands: vec![],
+        source: String::new(),
+        deferred: false,
+    };
+    match command {
+        Command::ExecProgram(exec) => {
+            let mut args = vec![];
+            for arg in exec.args.iter().flatten() {
+                if arg
+                    .as_bytes()
+                    .iter()
+                    .any(|ch| b"|&;<>()$`\\\"'*?[]#~%".contains(ch))
+                {
+                    return Some(Err(MigrationError::ShellArguments));
+                }
+                args.extend(arg.split_whitespace());
+            }
+            let mut source = vec!["COMMAND".to_string(), exec.executable.to_source()];
+            source.extend(args.iter().map(Token::to_source));
+            let mut option = |name: &str, value: &Option<Token<'t>>| {
+                if let Some(value) = value {
+                    source.extend([name.to_string(), value.to_source()]);
+                }
+            };
+            option("WORKING_DIRECTORY", &exec.dir);
+            option("RESULT_VARIABLE", &exec.return_value);
+            option("OUTPUT_VARIABLE", &exec.output_variable);
+            option("ERROR_VARIABLE", &exec.output_variable);
+            let strip = exec.output_variable.is_some();
+            if strip {
+                source.extend([
+                    "OUTPUT_STRIP_TRAILING_WHITESPACE".to_string(),
+                    "ERROR_STRIP_TRAILING_WHITESPACE".to_string(),
+                ]);
+            }
+            migration.push(
+                Command::ExecuteProcess(Box::new(ExecuteProcess {
+                    commands: vec![CustomCommand {
+                        name: exec.executable.clone(),
+                        args: (!args.is_empty()).then_some(args),
+                    }],
+                    working_directory: exec.dir.clone(),
+                    result_variable: exec.return_value.clone(),
+                    output_variable: exec.output_variable.clone(),
+                    error_variable: exec.output_variable.clone(),
+                    output_strip_trailing_whitespace: strip,
+                    error_strip_trailing_whitespace: strip,
+                    ..Default::default()
+                })),
+                "execute_process",
+                source,
+            );
+        }
+        Command::InstallFiles(install) => {
+            let (dir, files) = match install.as_ref() {
+                InstallFiles::Files(files) => (&files.dir, files.files.clone()),
+                InstallFiles::Extension(files) => {
+                    if !files
+                        .files
+                        .iter()
+                        .all(|file| file.as_bytes().ends_with(files.extension.as_bytes()))
+                    {
+                        return Some(Err(MigrationError::MissingExtension));
+                    }
+                    (&files.dir, files.files.clone())
+                }
+                InstallFiles::RegEx(_) => return Some(Err(MigrationError::RegexFiles("FILES"))),
+            };
+            migration.install_files(files, dir, Install::Files, "FILES");
+        }
+        Command::InstallPrograms(install) => {
+            let (dir, files) = match install.as_ref() {
+                InstallPrograms::Files(files) => (&files.dir, files.files.clone()),
+                InstallPrograms::FilesAlt(files) => (&files.dir, files.files.clone()),
+                InstallPrograms::RegEx(_) => {
+                    return Some(Err(MigrationError::RegexFiles("PROGRAMS")))
+                }
+            };
+            migration.install_files(files, dir, Install::Programs, "PROGRAMS");
+        }
+        Command::InstallTargets(install) => {
+            let dir = destination(&install.dir);
+            let runtime_dir = install.runtime_directory.as_ref().map(destination);
+            let mut source = vec!["TARGETS".to_string()];
+            source.extend(install.targets.iter().map(Token::to_source));
+            let mut output_artifacts = vec![];
+            let mut artifact = |kind: ArtifactKindTargets<'t>, name: &str, dir: &Token<'t>| {
+                source.extend([name.to_string(), "DESTINATION".to_string(), dir.to_source()]);
+                output_artifacts.push(OutputArtifactTargets {
+                    kind: Some(kind),
+                    destination: Some(dir.clone()),
+                    permissions: None,
+                    configurations: None,
+                    component: None,
+                    namelink_component: None,
+                    optional: false,
+                    exclude_from_all: false,
+                    namelink: None,
+                });
+            };
+            artifact(
+                ArtifactKindTargets::Runtime,
+                "RUNTIME",
+                runtime_dir.as_ref().unwrap_or(&dir),
+            );
+            artifact(ArtifactKindTargets::Library, "LIBRARY", &dir);
+            artifact(ArtifactKindTargets::Archive, "ARCHIVE", &dir);
+            migration.push(
+                Command::Install(Box::new(Install::Targets(TargetsInstall {
+                    targets: install.targets.clone(),
+                    export: None,
+                    runtime_dependency: None,
+                    output_artifacts: Some(output_artifacts),
+                    includes: None,
+                }))),
+                "install",
+                source,
+            );
+        }
+        Command::MakeDirectory(make_directory) => migration.push(
+            Command::File(Box::new(File::Filesystem(FileFilesystem::MakeDirectory(
+                FileMakeDirectory {
+                    directories: Some(vec![make_directory.dir.clone()]),
+                },
+            )))),
+            "file",
+            vec!["MAKE_DIRECTORY".to_string(), make_directory.dir.to_source()],
+        ),
+        Command::Remove(remove) => {
+            let mut source = vec!["REMOVE_ITEM".to_string(), remove.var.to_source()];
+            source.extend(remove.values.iter().map(Token::to_source));
+            migration.push(
+                Command::List(Box::new(List::Modification(ListModification::RemoveItem(
+                    ListRemoveItem {
+                        list: remove.var.clone(),
+                        value: remove.values.clone(),
+                    },
+                )))),
+                "list",
+                source,
+            );
+        }
+        Command::SubdirDepends(_) => (),
+        Command::Subdirs(subdirs) => {
+            migration.deferred = true;
+            let excluded = subdirs.exclude_from_all.iter().flatten();
+            let dirs = subdirs.dirs.iter().map(|dir| (dir, false));
+            for (dir, exclude_from_all) in dirs.chain(excluded.map(|dir| (dir, true))) {
+                let mut source = vec![dir.to_source()];
+                if exclude_from_all {
+                    source.push("EXCLUDE_FROM_ALL".to_string());
+                }
+                migration.push(
+                    Command::AddSubdirectory(Box::new(AddSubdirectory {
+                        source_dir: dir.clone(),
+                        binary_dir: None,
+                        exclude_from_all,
+                        system: false,
+                    })),
+                    "add_subdirectory",
+                    source,
+                );
+            }
+        }
+        Command::WriteFile(write_file) => {
+            let write = FileWrite {
+                filename: write_file.filename.clone(),
+                content: Some(vec![write_file.content.clone()]),
+            };
+            let (writing, mode) = if write_file.append {
+                (FileWriting::Append(write), "APPEND")
+            } else {
+                (FileWriting::Write(write), "WRITE")
+            };
+            migration.push(
+                Command::File(Box::new(File::Writing(writing))),
+                "file",
+                vec![
+                    mode.to_string(),
+                    write_file.filename.to_source(),
+                    write_file.content.to_source(),
+                ],
+            );
+        }
+        Command::BuildName(_) => {
+            return Some(Err(MigrationError::NoEquivalent(
+                "${CMAKE_SYSTEM} and ${CMAKE_CXX_COMPILER}",
+            )))
+        }
+        Command::ExportLibraryDependencies(_) => {
+            return Some(Err(MigrationError::NoEquivalent(
+                "install(EXPORT) or export()",
+            )))
+        }
+        Command::LoadCommand(_) => {
+            return Some(Err(MigrationError::NoEquivalent("function() or macro()")))
+        }
+        Command::OutputRequiredFiles(_) | Command::UtilitySource(_) => {
+            return Some(Err(MigrationError::NoEquivalent("add_custom_command()")))
+        }
+        Command::QtWrapCpp(_) | Command::QtWrapUi(_) => {
+            return Some(Err(MigrationError::NoEquivalent(
+                "the AUTOMOC and AUTOUIC properties",
+            )))
+        }
+        Command::UseMangledMesa(_) => {
+            return Some(Err(MigrationError::NoEquivalent("find_package(OpenGL)")))
+        }
+        Command::VariableRequires(_) => return Some(Err(MigrationError::NoEquivalent("if()"))),
+        _ => return None,
+    }
+    Some(Ok(migration))
+}
+
+impl<'t> Migration<'t> {
+    fn push(&mut self, command: Command<'t>, name: &str, args: Vec<String>) {
+        if !self.source.is_empty() {
+            self.source.push('\n');
+        }
+        self.source.push_str(&format!("{name}({})", args.join(" ")));
+        self.commands.push(command);
+    }
+
+    fn install_files(
+        &mut self,
+        files: Vec<Token<'t>>,
+        dir: &Token<'t>,
+        install: fn(FilesInstall<'t>) -> Install<'t>,
+        mode: &str,
+    ) {
+        let dir = destination(dir);
+        let mut source = vec![mode.to_string()];
+        source.extend(files.iter().map(Token::to_source));
+        source.extend(["DESTINATION".to_string(), dir.to_source()]);
+        self.push(
+            Command::Install(Box::new(install(FilesInstall {
+                files,
+                kind: InstallKind::Destination(dir),
+                permissions: None,
+                configurations: None,
+                component: None,
+                rename: None,
+                optional: false,
+                exclude_from_all: false,
+            }))),
+            "install",
+            source,
+        );
+    }
+}
+
+/// Destination of `install()` for a directory of the deprecated commands,
+/// which is relative to the install prefix even with a leading slash.
+fn destination<'t>(dir: &Token<'t>) -> Token<'t> {
+    dir.strip_prefix(b"/").unwrap_or_else(|| dir.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        lint::{apply_fixes, Linter},
+        *,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn migrations() {
+        let src = include_bytes!("../../../fixture/lint/deprecated");
+        let doc = Doc::from(parse_cmakelists(src).unwrap());
+        let migrations: Vec<_> = doc
+            .to_commands_iter()
+            .filter_map(|command| migrate(&command.unwrap()))
+            .map(|migration| migration.map(|migration| migration.source))
+            .collect();
+        assert_eq!(
+            migrations,
+            vec![
+                Ok("execute_process(COMMAND uname -s -r WORKING_DIRECTORY . \
+                    RESULT_VARIABLE result OUTPUT_VARIABLE system ERROR_VARIABLE system \
+                    OUTPUT_STRIP_TRAILING_WHITESPACE ERROR_STRIP_TRAILING_WHITESPACE)"
+                    .to_string()),
+                Err(MigrationError::ShellArguments),
+                Ok("install(FILES a.h \"b c.h\" DESTINATION include)".to_string()),
+                Ok("install(FILES a.txt b.txt DESTINATION share)".to_string()),
+                Err(MigrationError::MissingExtension),
+                Err(MigrationError::RegexFiles("FILES")),
+                Ok("install(PROGRAMS tool.sh run.sh DESTINATION bin)".to_string()),
+                Err(MigrationError::RegexFiles("PROGRAMS")),
+                Ok("install(TARGETS app core RUNTIME DESTINATION bin \
+                    LIBRARY DESTINATION lib ARCHIVE DESTINATION lib)"
+                    .to_string()),
+                Ok("file(MAKE_DIRECTORY ${out})".to_string()),
+                Ok("add_subdirectory(a)\nadd_subdirectory(b)\nadd_subdirectory(c EXCLUDE_FROM_ALL)"
+                    .to_string()),
+                Ok("list(REMOVE_ITEM list x y)".to_string()),
+                Ok(String::new()),
+                Ok("file(APPEND out.txt \"hello\\n\")".to_string()),
+                Err(MigrationError::NoEquivalent(
+                    "${CMAKE_SYSTEM} and ${CMAKE_CXX_COMPILER}"
+                )),
+                Err(MigrationError::NoEquivalent(
+                    "the AUTOMOC and AUTOUIC properties"
+                )),
+            ]
+        );
+    }
+
+    #[test]
+    fn migrated_commands() {
+        let src = b"make_directory(out)\nsubdirs(a EXCLUDE_FROM_ALL b)\n";
+        let doc = Doc::from(parse_cmakelists(src).unwrap());
+        let commands = doc.commands().unwrap();
+        let migrate = |index| migrate(&commands[index]).unwrap().unwrap().commands;
+
+        let modern = b"file(MAKE_DIRECTORY out)\nadd_subdirectory(a)\nadd_subdirectory(b EXCLUDE_FROM_ALL)\n";
+        let modern_doc = Doc::from(parse_cmakelists(modern).unwrap());
+        let modern_commands = modern_doc.commands().unwrap();
+        assert_eq!(migrate(0), modern_commands[..1]);
+        assert_eq!(migrate(1), modern_commands[1..]);
+    }
+
+    #[test]
+    fn migration_fixes() {
+        let src = "project(demo)\nsubdirs(c)\nif(UNIX)\n  build_name(name)\n  remove(list x)\n  subdirs(a b)\nendif()\n";
+        let doc = Doc::from(parse_cmakelists(src.as_bytes()).unwrap());
+        let diagnostics = Linter::default().lint(&doc, false);
+        assert_eq!(
+            diagnostics[0].message,
+            "subdirs() is deprecated: subdirectories are added after the other commands of the directory, use add_subdirectory() after them"
+        );
+        assert_eq!(
+            diagnostics[1].message,
+            "build_name() is deprecated: no modern equivalent, use ${CMAKE_SYSTEM} and ${CMAKE_CXX_COMPILER}"
+        );
+        assert_eq!(
+            apply_fixes(src, diagnostics.iter().filter_map(|d| d.fix.as_ref())),
+            "project(demo)\nsubdirs(c)\nif(UNIX)\n  build_name(name)\n  list(REMOVE_ITEM list x)\n  add_subdirectory(a)\n  add_subdirectory(b)\nendif()\n"
+        );
+    }
+}
@@ -0,0 +1,19 @@
+//! Lint framework running [`Rule`]s over the commands of a file.
+//!
+//! Rules are looked up in a [`Registry`] and configured per rule with
+//! [`RuleConfig`]. Comments like `# cmake-lint: disable=deprecated-command`
+//! suppress rules for the command they are on, or from that line onward when
+//! they stand alone until a matching `# cmake-lint: enable=...`.
+
+mod diff;
+mod linter;
+mod migration;
+mod rule;
+pub mod rules;
+mod suppression;
+
+pub use diff::unified_diff;
+pub use linter::{Diagnostic, Linter, Registry, RuleConfig, Severity};
+pub use migration::{migrate, Migration, MigrationError};
+pub(crate) use rule::line_range;
+pub use rule::{apply_edits, apply_fixes, Edit, Fix, LintContext, Rule, Violation};
@@ -0,0 +1,190 @@
+use std::{collections::BTreeMap, ops::Range};
+
+use crate::{lint::Severity, Command, CommandParseError, Span};
+
+/// Check run by a [`Linter`](crate::lint::Linter) on the commands of a file.
+pub trait Rule {
+    /// Identifier used by configurations and suppression comments, like
+    /// `deprecated-command`.
+    fn id(&self) -> &'static str;
+
+    /// One line description of what the rule reports.
+    fn description(&self) -> &'static str;
+
+    /// Severity unless configured otherwise.
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, context: &LintContext) -> Vec<Violation>;
+}
+
+/// Commands of a linted file with their names, spans, sources and
+/// enclosing blocks.
+pub struct LintContext<'a, 't> {
+    pub(crate) commands: &'a [Result<Command<'t>, CommandParseError>],
+    pub(crate) names: &'a [String],
+    pub(crate) spans: &'a [Span],
+    pub(crate) sources: &'a [String],
+    pub(crate) parents: &'a [Option<usize>],
+    /// Source of the whole file.
+    pub(crate) text: &'a str,
+    pub(crate) top_level: bool,
+    pub(crate) options: &'a BTreeMap<String, String>,
+}
+
+/// Problem found by a rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// Index of the command in the linted file.
+    pub command: usize,
+    pub message: String,
+    pub fix: Option<Fix>,
+}
+
+/// Source edits resolving a violation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fix {
+    pub description: String,
+    /// Edits of non-overlapping ranges.
+    pub edits: Vec<Edit>,
+}
+
+/// Replacement of a byte range of the source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
+    pub range: Range<usize>,
+    pub replacement: String,
+}
+
+impl<'a, 't> LintContext<'a, 't> {
+    /// Commands in order, `Err` for those that could not be parsed.
+    pub fn commands(&self) -> &'a [Result<Command<'t>, CommandParseError>] {
+        self.commands
+    }
+
+    /// Lowercase name of a command as invoked.
+    pub fn name(&self, command: usize) -> &'a str {
+        &self.names[command]
+    }
+
+    pub fn span(&self, command: usize) -> Span {
+        self.spans[command]
+    }
+
+    /// Source of a command from its name to the closing parenthesis.
+    pub fn source(&self, command: usize) -> &'a str {
+        &self.sources[command]
+    }
+
+    /// `if()`, `foreach()`, `while()`, `function()`, `macro()` or `block()`
+    /// command opening the innermost block enclosing a command. The end of a
+    /// block and `else()` or `elseif()` are enclosed like its opening command.
+    pub fn parent(&self, command: usize) -> Option<usize> {
+        self.parents[command]
+    }
+
+    /// Number of blocks enclosing a command.
+    pub fn depth(&self, command: usize) -> usize {
+        std::iter::successors(self.parent(command), |&parent| self.parent(parent)).count()
+    }
+
+    /// Range of a command with its line when nothing else is on it, to
+    /// remove the command without leaving an empty line.
+    pub fn line_range(&self, command: usize) -> Range<usize> {
+        line_range(self.text, self.spans[command])
+    }
+
+    /// Whether the file is the top-level `CMakeLists.txt` of a project.
+    pub fn top_level(&self) -> bool {
+        self.top_level
+    }
+
+    /// Option of the rule configuration.
+    pub fn option(&self, name: &str) -> Option<&'a str> {
+        self.options.get(name).map(String::as_str)
+    }
+}
+
+impl Violation {
+    pub fn new(command: usize, message: impl Into<String>) -> Self {
+        Self {
+            command,
+            message: message.into(),
+            fix: None,
+        }
+    }
+
+    pub fn with_fix(mut self, fix: Fix) -> Self {
+        self.fix = Some(fix);
+        self
+    }
+}
+
+impl Fix {
+    pub fn new(description: impl Into<String>, edits: Vec<Edit>) -> Self {
+        Self {
+            description: description.into(),
+            edits,
+        }
+    }
+}
+
+/// Applies fixes to a source in order, skipping fixes with edits
+/// overlapping those of the fixes applied before.
+pub fn apply_fixes<'a>(source: &str, fixes: impl IntoIterator<Item = &'a Fix>) -> String {
+    let mut edits: Vec<&Edit> = vec![];
+    for fix in fixes {
+        let overlaps = fix.edits.iter().any(|edit| {
+            edits.iter().any(|applied| {
+                edit.range.start < applied.range.end && applied.range.start < edit.range.end
+                    || edit.range.start == applied.range.start
+            })
+        });
+        if !overlaps {
+            edits.extend(&fix.edits);
+        }
+    }
+    apply_edits(source, edits)
+}
+
+/// Applies non-overlapping edits to a source.
+pub fn apply_edits<'a>(source: &str, edits: impl IntoIterator<Item = &'a Edit>) -> String {
+    let mut edits: Vec<&Edit> = edits.into_iter().collect();
+    edits.sort_by_key(|edit| (edit.range.start, edit.range.end));
+    let mut fixed = String::new();
+    let mut offset = 0;
+    for edit in edits {
+        fixed.push_str(&source[offset..edit.range.start]);
+        fixed.push_str(&edit.replacement);
+        offset = edit.range.end;
+    }
+    fixed.push_str(&source[offset..]);
+    fixed
+}
+
+/// Range of a command with its line when nothing else is on it, or with
+/// the spaces before a trailing comment.
+pub(crate) fn line_range(source: &str, span: Span) -> Range<usize> {
+    let line_start = span.start.offset - (span.start.column - 1);
+    let line_end = source[span.end.offset..]
+        .find('\n')
+        .map_or(source.len(), |end| span.end.offset + end + 1);
+    let rest = &source[span.end.offset..line_end];
+    if !source[line_start..span.start.offset].trim().is_empty() {
+        span.range()
+    } else if rest.trim().is_empty() {
+        line_start..line_end
+    } else {
+        line_start..span.end.offset + (rest.len() - rest.trim_start().len())
+    }
+}
+
+impl Edit {
+    pub fn new(range: Range<usize>, replacement: impl Into<String>) -> Self {
+        Self {
+            range,
+            replacement: replacement.into(),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{
+    lint::{migrate, Edit, Fix, LintContext, MigrationError, Rule, Violation},
+    CommandScope, ToCommandScope,
+};
+
+/// Reports commands deprecated by CMake with a fix migrating them to their
+/// modern equivalent when there is a faithful one. The `allow` option lists
+/// comma-separated command names not to report.
+pub struct DeprecatedCommand;
+
+impl Rule for DeprecatedCommand {
+    fn id(&self) -> &'static str {
+        "deprecated-command"
+    }
+
+    fn description(&self) -> &'static str {
+        "Commands deprecated by CMake."
+    }
+
+    fn check(&self, context: &LintContext) -> Vec<Violation> {
+        let allowed: Vec<_> = context
+            .option("allow")
+            .unwrap_or_default()
+            .split(',')
+            .map(|name| name.trim().to_ascii_lowercase())
+            .collect();
+        let mut violations = vec![];
+        for (index, command) in context.commands().iter().enumerate() {
+            let Ok(command) = command else {
+                continue;
+            };
+            let name = context.name(index);
+            if command.to_command_scope() != CommandScope::Deprecated
+                || allowed.iter().any(|allowed| allowed == name)
+            {
+                continue;
+            }
+            let violation = match migrate(command) {
+                Some(Ok(migration)) if migration.deferred && commands_follow(context, index) => {
+                    let err = MigrationError::CommandsFollow;
+                    Violation::new(index, format!("{name}() is deprecated: {err}"))
+                }
+                Some(Ok(migration)) => {
+                    let span = context.span(index);
+                    let indent = format!("\n{}", " ".repeat(span.start.column - 1));
+                    let replacement = migration.source.replace('\n', &indent);
+                    Violation::new(index, format!("{name}() is deprecated")).with_fix(Fix::new(
+                        format!("migrate {name}()"),
+                        vec![Edit::new(span.range(), replacement)],
+                    ))
+                }
+                Some(Err(err)) => Violation::new(index, format!("{name}() is deprecated: {err}")),
+                None => Violation::new(index, format!("{name}() is deprecated")),
+            };
+            violations.push(violation);
+        }
+        violations
+    }
+}
+
+/// Whether commands other than the ends of the enclosing blocks follow a
+/// command, which would run before a deferred deprecated command.
+fn commands_follow(context: &LintContext, command: usize) -> bool {
+    (command + 1..context.commands().len())
+        .any(|index| !matches!(context.name(index), "endif" | "endforeach" | "endwhile"))
+}
@@ -0,0 +1,84 @@
+use crate::lint::{Edit, Fix, LintContext, Rule, Violation};
+
+const NAME: &str = "cmake_minimum_required";
+
+/// Reports top-level files not starting with `cmake_minimum_required()`,
+/// with a fix moving it first when it comes later.
+pub struct MinimumRequiredFirst;
+
+impl Rule for MinimumRequiredFirst {
+    fn id(&self) -> &'static str {
+        "minimum-required-first"
+    }
+
+    fn description(&self) -> &'static str {
+        "cmake_minimum_required() is the first command of the top-level CMakeLists.txt."
+    }
+
+    fn check(&self, context: &LintContext) -> Vec<Violation> {
+        if !context.top_level() || context.commands().is_empty() {
+            return vec![];
+        }
+        let Some(index) = (0..context.commands().len()).find(|&index| context.name(index) == NAME)
+        else {
+            return vec![Violation::new(0, format!("{NAME}() is missing"))];
+        };
+        if index == 0 {
+            return vec![];
+        }
+        let start = context.span(0).start.offset;
+        let fix = Fix::new(
+            format!("move {NAME}() first"),
+            vec![
+                Edit::new(start..start, format!("{}\n", context.source(index))),
+                Edit::new(context.line_range(index), ""),
+            ],
+        );
+        vec![Violation::new(index, format!("{NAME}() is not the first command")).with_fix(fix)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lint::{apply_fixes, Edit, Linter};
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn minimum_required_first() {
+        let src = b"project(demo)\ncmake_minimum_required(VERSION 3.20)\nsubdirs(a)\n";
+        let doc = Doc::from(parse_cmakelists(src).unwrap());
+        let diagnostics = Linter::default().lint(&doc, true);
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "2:1: warning: cmake_minimum_required() is not the first command [minimum-required-first]"
+        );
+        assert_eq!(
+            diagnostics[0].fix.as_ref().unwrap().edits,
+            [
+                Edit::new(0..0, "cmake_minimum_required(VERSION 3.20)\n"),
+                Edit::new(14..51, "")
+            ]
+        );
+        assert_eq!(
+            apply_fixes(
+                std::str::from_utf8(src).unwrap(),
+                diagnostics[0].fix.as_ref()
+            ),
+            "cmake_minimum_required(VERSION 3.20)\nproject(demo)\nsubdirs(a)\n"
+        );
+        assert_eq!(diagnostics[1].rule, "deprecated-command");
+
+        let doc = Doc::from(parse_cmakelists(b"project(demo)\n").unwrap());
+        let messages: Vec<_> = Linter::default()
+            .lint(&doc, true)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            messages,
+            ["1:1: warning: cmake_minimum_required() is missing [minimum-required-first]"]
+        );
+        assert!(Linter::default().lint(&doc, false).is_empty());
+    }
+}
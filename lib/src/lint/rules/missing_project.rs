@@ -0,0 +1,48 @@
+use crate::lint::{LintContext, Rule, Violation};
+
+/// Reports top-level files without `project()`.
+pub struct MissingProject;
+
+impl Rule for MissingProject {
+    fn id(&self) -> &'static str {
+        "missing-project"
+    }
+
+    fn description(&self) -> &'static str {
+        "The top-level CMakeLists.txt calls project()."
+    }
+
+    fn check(&self, context: &LintContext) -> Vec<Violation> {
+        let commands = context.commands().len();
+        if !context.top_level()
+            || commands == 0
+            || (0..commands).any(|index| context.name(index) == "project")
+        {
+            return vec![];
+        }
+        vec![Violation::new(0, "project() is missing")]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lint::Linter;
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn missing_project() {
+        let src = b"cmake_minimum_required(VERSION 3.20)\nadd_executable(a main.c)\n";
+        let doc = Doc::from(parse_cmakelists(src).unwrap());
+        let messages: Vec<_> = Linter::default()
+            .lint(&doc, true)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            messages,
+            ["1:1: warning: project() is missing [missing-project]"]
+        );
+        assert!(Linter::default().lint(&doc, false).is_empty());
+    }
+}
@@ -0,0 +1,9 @@
+//! Rules of [`Registry::builtin`](crate::lint::Registry::builtin).
+
+mod deprecated_command;
+mod minimum_required_first;
+mod missing_project;
+
+pub use deprecated_command::DeprecatedCommand;
+pub use minimum_required_first::MinimumRequiredFirst;
+pub use missing_project::MissingProject;
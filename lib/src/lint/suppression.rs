@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+
+use crate::Span;
+
+const PREFIX: &str = "cmake-lint:";
+const ALL: &str = "all";
+
+/// Rules disabled by `cmake-lint:` comments.
+#[derive(Debug, Default)]
+pub(crate) struct Suppressions {
+    /// Rules disabled for single commands by comments on their lines.
+    commands: Vec<(usize, Directive)>,
+    /// Directives of standalone comments with their offsets, in source
+    /// order.
+    regions: Vec<(usize, Directive)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Directive {
+    disable: bool,
+    rules: Vec<String>,
+}
+
+impl Suppressions {
+    pub(crate) fn new<S: AsRef<str>>(comments: &[(Span, S)], spans: &[Span]) -> Self {
+        let mut suppressions = Self::default();
+        for (span, comment) in comments {
+            let Some(directive) = Directive::parse(comment.as_ref()) else {
+                continue;
+            };
+            let command = spans.iter().position(|command| {
+                (command.start.line..=command.end.line).contains(&span.start.line)
+            });
+            match command {
+                Some(command) => suppressions.commands.push((command, directive)),
+                None => suppressions.regions.push((span.start.offset, directive)),
+            }
+        }
+        suppressions
+    }
+
+    /// Whether a rule is disabled for a command.
+    pub(crate) fn is_suppressed(&self, rule: &str, command: usize, span: &Span) -> bool {
+        let mut all = false;
+        let mut disabled = HashSet::new();
+        // Rules enabled again after `disable=all`.
+        let mut enabled = HashSet::new();
+        for (_, directive) in self
+            .regions
+            .iter()
+            .take_while(|(offset, _)| *offset < span.start.offset)
+        {
+            for id in &directive.rules {
+                match (directive.disable, id.as_str()) {
+                    (true, ALL) => {
+                        all = true;
+                        enabled.clear();
+                    }
+                    (false, ALL) => {
+                        all = false;
+                        disabled.clear();
+                        enabled.clear();
+                    }
+                    (true, id) => {
+                        disabled.insert(id);
+                        enabled.remove(id);
+                    }
+                    (false, id) => {
+                        disabled.remove(id);
+                        if all {
+                            enabled.insert(id);
+                        }
+                    }
+                }
+            }
+        }
+        (all && !enabled.contains(rule))
+            || disabled.contains(rule)
+            || self.commands.iter().any(|(index, directive)| {
+                *index == command
+                    && directive.disable
+                    && directive.rules.iter().any(|id| id == rule || id == ALL)
+            })
+    }
+}
+
+impl Directive {
+    /// Parses `cmake-lint: disable=a,b` and `cmake-lint: enable=a,b`.
+    fn parse(comment: &str) -> Option<Self> {
+        let directive = comment.trim().strip_prefix(PREFIX)?.trim();
+        let (action, rules) = directive.split_once('=')?;
+        let disable = match action.trim() {
+            "disable" => true,
+            "enable" => false,
+            _ => return None,
+        };
+        let rules: Vec<String> = rules
+            .split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(str::to_string)
+            .collect();
+        (!rules.is_empty()).then_some(Self { disable, rules })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn directive() {
+        assert_eq!(
+            Directive::parse(" cmake-lint: disable=a, b"),
+            Some(Directive {
+                disable: true,
+                rules: vec!["a".to_string(), "b".to_string()]
+            })
+        );
+        assert_eq!(
+            Directive::parse("cmake-lint:enable=all"),
+            Some(Directive {
+                disable: false,
+                rules: vec!["all".to_string()]
+            })
+        );
+        assert_eq!(Directive::parse("cmake-lint: ignore=a"), None);
+        assert_eq!(Directive::parse("cmake-lint: disable="), None);
+        assert_eq!(Directive::parse("disable=a"), None);
+    }
+}
@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use nom::{
     branch::alt,
     bytes::complete::{is_a, is_not, tag, take_until},
-    character::complete::{alpha1, alphanumeric1, char, not_line_ending, space1},
+    character::complete::{alpha1, alphanumeric1, char, not_line_ending, space0, space1},
     combinator::{consumed, map, not, opt, recognize, value},
     multi::{many0, many0_count, many1},
     sequence::{delimited, pair, preceded, tuple},
@@ -11,7 +11,7 @@ use nom::{
 
 use crate::Token;
 
-pub fn parse_cmakelists(src: &[u8]) -> Result<CMakeListsTokens, CMakeListsParseError> {
+pub fn parse_cmakelists(src: &[u8]) -> Result<CMakeListsTokens<'_>, CMakeListsParseError> {
     nom_parse_cmakelists(src)
         .map(|(_, cm)| cm)
         .map_err(From::from)
@@ -33,6 +33,184 @@ impl<'cmlist> CMakeListsTokens<'cmlist> {
             }
         })
     }
+
+    /// Spans of the command invocations from the command name to the
+    /// closing parenthesis.
+    pub(crate) fn command_spans(&self) -> Vec<Span> {
+        let locator = self.locator();
+        self.command_invocations()
+            .map(|ci| {
+                let start = locator.offset(ci.identifier);
+                let end = locator.offset(ci.source) + ci.source.len();
+                locator.span(start, end)
+            })
+            .collect()
+    }
+
+    /// Spans of the arguments of the command invocations, in the order of
+    /// their tokens.
+    pub(crate) fn argument_spans(&self) -> Vec<Vec<Span>> {
+        let locator = self.locator();
+        self.command_invocations()
+            .map(|ci| {
+                ci.arguments
+                    .sources()
+                    .into_iter()
+                    .map(|source| {
+                        let start = locator.offset(source);
+                        locator.span(start, start + source.len())
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Source the tokens were parsed from, up to the last parsed element.
+    pub(crate) fn source(&self) -> Vec<u8> {
+        self.file
+            .iter()
+            .flat_map(|file_element| file_element.source.0)
+            .copied()
+            .collect()
+    }
+
+    /// Number of source bytes covered by the parsed elements. Parsing stops
+    /// at the first element it does not recognize, so this is shorter than
+    /// the source on syntax errors.
+    pub fn parsed_len(&self) -> usize {
+        self.file
+            .iter()
+            .map(|file_element| file_element.source.0.len())
+            .sum()
+    }
+
+    /// Sources of the command invocations from the command name to the
+    /// closing parenthesis.
+    pub(crate) fn command_sources(&self) -> Vec<&'cmlist [u8]> {
+        self.command_invocations()
+            .map(|ci| &ci.source[ci.identifier.as_ptr() as usize - ci.source.as_ptr() as usize..])
+            .collect()
+    }
+
+    /// Line comments without the leading `#`, in source order.
+    pub(crate) fn line_comments(&self) -> Vec<(Span, &'cmlist [u8])> {
+        let mut comments = vec![];
+        for file_element in &self.file {
+            match &file_element.element {
+                CMakeLanguage::CommandInvocation((command_invocation, line_ending)) => {
+                    command_invocation.arguments.line_comments(&mut comments);
+                    comments.extend(line_ending.line_comment.as_ref());
+                }
+                CMakeLanguage::Formatting((_, line_ending)) => {
+                    comments.extend(line_ending.line_comment.as_ref());
+                }
+            }
+        }
+        let locator = self.locator();
+        comments
+            .into_iter()
+            .map(|LineComment(comment)| {
+                let start = locator.offset(comment);
+                (locator.span(start - 1, start + comment.len()), *comment)
+            })
+            .collect()
+    }
+
+    /// Bracket comments between commands with their content, in source
+    /// order.
+    pub(crate) fn bracket_comments(&self) -> Vec<(Span, &'cmlist [u8])> {
+        let locator = self.locator();
+        self.file
+            .iter()
+            .filter_map(|file_element| match &file_element.element {
+                CMakeLanguage::Formatting((formatting, _)) => Some(formatting),
+                CMakeLanguage::CommandInvocation(_) => None,
+            })
+            .flatten()
+            .filter_map(|formatting| match formatting {
+                Formatting::BracketComment(BracketComment(source, bracket_argument)) => {
+                    let start = locator.offset(source);
+                    Some((
+                        locator.span(start, start + source.len()),
+                        bracket_argument.bracket_content,
+                    ))
+                }
+                Formatting::Spaces => None,
+            })
+            .collect()
+    }
+
+    fn locator(&self) -> Locator {
+        let base = self
+            .file
+            .first()
+            .map_or(0, |file_element| file_element.source.0.as_ptr() as usize);
+        let mut line_starts = vec![0];
+        let mut offset = 0;
+        for file_element in &self.file {
+            let source = file_element.source.0;
+            line_starts.extend(
+                source
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &byte)| byte == b'\n')
+                    .map(|(index, _)| offset + index + 1),
+            );
+            offset += source.len();
+        }
+        Locator { base, line_starts }
+    }
+}
+
+/// Position in the source, lines and columns start at 1 and columns count
+/// bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Range of the source from `start` up to but excluding `end`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Span {
+    pub fn range(&self) -> std::ops::Range<usize> {
+        self.start.offset..self.end.offset
+    }
+}
+
+/// Maps parsed slices to positions. File elements are consecutive slices
+/// of the parsed source.
+struct Locator {
+    base: usize,
+    line_starts: Vec<usize>,
+}
+
+impl Locator {
+    fn offset(&self, slice: &[u8]) -> usize {
+        slice.as_ptr() as usize - self.base
+    }
+
+    fn position(&self, offset: usize) -> Position {
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        Position {
+            offset,
+            line,
+            column: offset - self.line_starts[line - 1] + 1,
+        }
+    }
+
+    fn span(&self, start: usize, end: usize) -> Span {
+        Span {
+            start: self.position(start),
+            end: self.position(end),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -62,14 +240,13 @@ enum CMakeLanguage<'cml> {
 #[derive(Debug)]
 enum Formatting<'f> {
     BracketComment(BracketComment<'f>),
-    Spaces(Spaces),
+    Spaces,
 }
 
 #[derive(Debug)]
 pub(crate) struct CommandInvocation<'ci> {
-    spaces_before: Vec<Spaces>,
+    source: &'ci [u8],
     pub(crate) identifier: &'ci [u8],
-    spaces_after: Vec<Spaces>,
     arguments: Arguments<'ci>,
 }
 
@@ -78,7 +255,7 @@ impl<'ci> CommandInvocation<'ci> {
         self.arguments.to_text_nodes()
     }
 
-    pub fn identifier(&self) -> Cow<[u8]> {
+    pub fn identifier(&self) -> Cow<'_, [u8]> {
         if !self.identifier.iter().any(u8::is_ascii_uppercase) {
             Cow::Borrowed(self.identifier)
         } else {
@@ -89,18 +266,18 @@ impl<'ci> CommandInvocation<'ci> {
 
 #[derive(Debug)]
 struct Arguments<'a> {
-    argument: Option<Argument<'a>>,
+    argument: Option<(&'a [u8], Argument<'a>)>,
     separated_arguments: Vec<SeparatedArguments<'a>>,
 }
 
 impl<'a> Arguments<'a> {
     pub fn to_text_nodes(&'a self) -> Vec<Token<'a>> {
         let mut text_nodes = vec![];
-        if let Some(arg_tn) = self.argument.as_ref().map(|arg| arg.to_text_node()) {
+        if let Some(arg_tn) = self.argument.as_ref().map(|(_, arg)| arg.to_text_node()) {
             text_nodes.push(arg_tn);
         }
         text_nodes.extend(self.separated_arguments.iter().filter_map(|x| {
-            if let SeparatedArguments::Single((_, Some(arg))) = x {
+            if let SeparatedArguments::Single((_, Some((_, arg)))) = x {
                 Some(arg.to_text_node())
             } else {
                 None
@@ -108,17 +285,54 @@ impl<'a> Arguments<'a> {
         }));
         text_nodes
     }
+
+    /// Sources of the arguments in the order of `to_text_nodes()`.
+    fn sources(&self) -> Vec<&'a [u8]> {
+        self.argument
+            .iter()
+            .map(|(source, _)| *source)
+            .chain(self.separated_arguments.iter().filter_map(|x| {
+                if let SeparatedArguments::Single((_, Some((source, _)))) = x {
+                    Some(*source)
+                } else {
+                    None
+                }
+            }))
+            .collect()
+    }
+
+    fn line_comments<'s>(&'s self, comments: &mut Vec<&'s LineComment<'a>>) {
+        for separated_arguments in &self.separated_arguments {
+            let (separations, arguments) = match separated_arguments {
+                SeparatedArguments::Single((separations, _)) => (separations, None),
+                SeparatedArguments::Multi((separations, arguments)) => {
+                    (separations, Some(arguments))
+                }
+            };
+            comments.extend(
+                separations
+                    .iter()
+                    .filter_map(|separation| match separation {
+                        Separation::LineEnding(line_ending) => line_ending.line_comment.as_ref(),
+                        Separation::Space => None,
+                    }),
+            );
+            if let Some(arguments) = arguments {
+                arguments.line_comments(comments);
+            }
+        }
+    }
 }
 
 #[derive(Debug)]
 enum SeparatedArguments<'a> {
-    Single((Vec<Separation<'a>>, Option<Argument<'a>>)),
+    Single((Vec<Separation<'a>>, Option<(&'a [u8], Argument<'a>)>)),
     Multi((Vec<Separation<'a>>, Box<Arguments<'a>>)),
 }
 
 #[derive(Debug)]
 enum Separation<'a> {
-    Space(Spaces),
+    Space,
     LineEnding(LineEnding<'a>),
 }
 
@@ -140,11 +354,10 @@ impl<'a> Argument<'a> {
 }
 
 #[derive(Debug)]
-struct BracketComment<'bc>(BracketArgument<'bc>);
+struct BracketComment<'bc>(&'bc [u8], BracketArgument<'bc>);
 
 #[derive(Debug)]
 struct BracketArgument<'ba> {
-    len: usize,
     bracket_content: &'ba [u8],
 }
 
@@ -174,9 +387,6 @@ struct LineEnding<'le> {
     line_comment: Option<LineComment<'le>>,
 }
 
-#[derive(Debug)]
-struct Spaces(usize);
-
 #[derive(Debug, thiserror::Error)]
 pub enum CMakeListsParseError {
     #[error("unknown")]
@@ -198,7 +408,13 @@ fn nom_parse_cmakelists(src: &[u8]) -> IResult<&[u8], CMakeListsTokens<'_>> {
 fn file_element(src: &[u8]) -> IResult<&[u8], FileElement<'_>> {
     alt((
         map(
-            consumed(tuple((command_invocation, line_ending))),
+            consumed(pair(
+                command_invocation,
+                preceded(
+                    many0(alt((value((), bracket_comment), value((), space1)))),
+                    line_ending,
+                ),
+            )),
             |(source, command_invocation)| FileElement {
                 source: Source(source),
                 element: CMakeLanguage::CommandInvocation(command_invocation),
@@ -208,7 +424,7 @@ fn file_element(src: &[u8]) -> IResult<&[u8], FileElement<'_>> {
             consumed(tuple((
                 many0(alt((
                     map(bracket_comment, Formatting::BracketComment),
-                    map(spaces, Formatting::Spaces),
+                    map(space1, |_| Formatting::Spaces),
                 ))),
                 line_ending,
             ))),
@@ -220,13 +436,12 @@ fn file_element(src: &[u8]) -> IResult<&[u8], FileElement<'_>> {
     ))(src)
 }
 
-fn command_invocation(src: &[u8]) -> IResult<&[u8], CommandInvocation> {
+fn command_invocation(src: &[u8]) -> IResult<&[u8], CommandInvocation<'_>> {
     map(
-        tuple((many0(spaces), identifier, many0(spaces), scoped_arguments)),
-        |(spaces_before, identifier, spaces_after, arguments)| CommandInvocation {
-            spaces_before,
+        consumed(tuple((space0, identifier, space0, scoped_arguments))),
+        |(source, (_, identifier, _, arguments))| CommandInvocation {
+            source,
             identifier,
-            spaces_after,
             arguments,
         },
     )(src)
@@ -238,7 +453,7 @@ fn scoped_arguments(src: &[u8]) -> IResult<&[u8], Arguments<'_>> {
 
 fn arguments(src: &[u8]) -> IResult<&[u8], Arguments<'_>> {
     map(
-        pair(opt(argument), many0(separated_arguments)),
+        pair(opt(consumed(argument)), many0(separated_arguments)),
         |(argument, separated_arguments)| Arguments {
             argument,
             separated_arguments,
@@ -249,7 +464,7 @@ fn arguments(src: &[u8]) -> IResult<&[u8], Arguments<'_>> {
 fn separated_arguments(src: &[u8]) -> IResult<&[u8], SeparatedArguments<'_>> {
     alt((
         map(
-            pair(many1(separation), opt(argument)),
+            pair(many1(separation), opt(consumed(argument))),
             SeparatedArguments::Single,
         ),
         map(
@@ -261,7 +476,7 @@ fn separated_arguments(src: &[u8]) -> IResult<&[u8], SeparatedArguments<'_>> {
 
 fn separation(src: &[u8]) -> IResult<&[u8], Separation<'_>> {
     alt((
-        map(spaces, Separation::Space),
+        map(space1, |_| Separation::Space),
         map(line_ending, Separation::LineEnding),
     ))(src)
 }
@@ -274,7 +489,7 @@ fn argument(src: &[u8]) -> IResult<&[u8], Argument<'_>> {
     ))(src)
 }
 
-fn bracket_argument(src: &[u8]) -> IResult<&[u8], BracketArgument> {
+fn bracket_argument(src: &[u8]) -> IResult<&[u8], BracketArgument<'_>> {
     let (src, _) = char('[')(src)?;
     let (src, len) = many0_count(char('='))(src)?;
     let bracket_close = format!("]{}]", "=".repeat(len));
@@ -282,13 +497,7 @@ fn bracket_argument(src: &[u8]) -> IResult<&[u8], BracketArgument> {
     let (src, _) = opt(nom::character::complete::line_ending)(src)?;
     let (src, bracket_content) = take_until(bracket_close.as_bytes())(src)?;
     let (src, _) = tag(bracket_close.as_bytes())(src)?;
-    Ok((
-        src,
-        BracketArgument {
-            len,
-            bracket_content,
-        },
-    ))
+    Ok((src, BracketArgument { bracket_content }))
 }
 
 fn quoted_argument(src: &[u8]) -> IResult<&[u8], QuotedArgument> {
@@ -321,7 +530,7 @@ fn escape_sequence(src: &[u8]) -> IResult<&[u8], &[u8]> {
     )(src)
 }
 
-fn unquoted_argument(src: &[u8]) -> IResult<&[u8], UnquotedArgument> {
+fn unquoted_argument(src: &[u8]) -> IResult<&[u8], UnquotedArgument<'_>> {
     alt((
         map(unquoted_legacy, UnquotedArgument::Legacy),
         map(many1(unquoted_element), |x| {
@@ -355,14 +564,14 @@ fn identifier(src: &[u8]) -> IResult<&[u8], &[u8]> {
     ))(src)
 }
 
-fn line_ending(src: &[u8]) -> IResult<&[u8], LineEnding> {
+fn line_ending(src: &[u8]) -> IResult<&[u8], LineEnding<'_>> {
     map(
         tuple((opt(line_comment), nom::character::complete::line_ending)),
         |(line_comment, _)| LineEnding { line_comment },
     )(src)
 }
 
-fn line_comment(src: &[u8]) -> IResult<&[u8], LineComment> {
+fn line_comment(src: &[u8]) -> IResult<&[u8], LineComment<'_>> {
     preceded(
         char('#'),
         map(
@@ -375,12 +584,11 @@ fn line_comment(src: &[u8]) -> IResult<&[u8], LineComment> {
     )(src)
 }
 
-fn bracket_comment(src: &[u8]) -> IResult<&[u8], BracketComment> {
-    map(preceded(char('#'), bracket_argument), BracketComment)(src)
-}
-
-fn spaces(src: &[u8]) -> IResult<&[u8], Spaces> {
-    map(space1, |spaces: &[u8]| Spaces(spaces.len()))(src)
+fn bracket_comment(src: &[u8]) -> IResult<&[u8], BracketComment<'_>> {
+    map(
+        consumed(preceded(char('#'), bracket_argument)),
+        |(source, bracket_argument)| BracketComment(source, bracket_argument),
+    )(src)
 }
 
 #[cfg(test)]
@@ -445,6 +653,86 @@ mod tests {
         let (_, _) = file_element(src).unwrap();
     }
 
+    #[test]
+    fn spans() {
+        use super::{Position, Span};
+
+        let cmakelists = super::parse_cmakelists(
+            b"# top\nmessage(a) # trailing\n  set(b\n    # inner\n    c)\n",
+        )
+        .unwrap();
+        let position = |offset, line, column| Position {
+            offset,
+            line,
+            column,
+        };
+        assert_eq!(
+            cmakelists.command_spans(),
+            vec![
+                Span {
+                    start: position(6, 2, 1),
+                    end: position(16, 2, 11)
+                },
+                Span {
+                    start: position(30, 3, 3),
+                    end: position(54, 5, 7)
+                },
+            ]
+        );
+        let comments: Vec<_> = cmakelists
+            .line_comments()
+            .into_iter()
+            .map(|(span, comment)| (span.range(), comment))
+            .collect();
+        assert_eq!(
+            comments,
+            vec![
+                (0..5, &b" top"[..]),
+                (17..27, &b" trailing"[..]),
+                (40..47, &b" inner"[..]),
+            ]
+        );
+        let arguments: Vec<Vec<_>> = cmakelists
+            .argument_spans()
+            .into_iter()
+            .map(|spans| spans.into_iter().map(|span| span.range()).collect())
+            .collect();
+        assert_eq!(arguments, vec![vec![14..15], vec![34..35, 52..53]]);
+    }
+
+    #[test]
+    fn bracket_comments() {
+        let cmakelists = super::parse_cmakelists(
+            b"#[[top]]
+message(a)
+  #[=[\ninner]]\n]=]\n",
+        )
+        .unwrap();
+        let comments: Vec<_> = cmakelists
+            .bracket_comments()
+            .into_iter()
+            .map(|(span, comment)| (span.range(), (span.start.line, span.end.line), comment))
+            .collect();
+        assert_eq!(
+            comments,
+            vec![
+                (0..8, (1, 1), &b"top"[..]),
+                (22..38, (3, 5), &b"inner]]\n"[..]),
+            ]
+        );
+    }
+
+    #[test]
+    fn parsed_len() {
+        let source = b"project(a)\nset(A 1)\n";
+        assert_eq!(
+            super::parse_cmakelists(source).unwrap().parsed_len(),
+            source.len()
+        );
+        let cmakelists = super::parse_cmakelists(b"project(a)\nadd_executable(a\n").unwrap();
+        assert_eq!(cmakelists.parsed_len(), 11);
+    }
+
     #[test]
     fn bracket_argument() {
         use super::bracket_argument;
@@ -0,0 +1,280 @@
+use lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionParams, CompletionResponse, Documentation,
+    MarkupContent, MarkupKind,
+};
+
+use crate::{server::Server, text::LineIndex};
+
+/// Where the cursor is in the command invocations.
+#[derive(Debug, PartialEq, Eq)]
+enum Context<'t> {
+    /// Start of a line, where a command name goes.
+    CommandName,
+    /// Inside the arguments of a command.
+    Arguments(&'t str),
+    /// Inside a comment, a quoted or bracket argument, or after a command.
+    Other,
+}
+
+/// Command names at the start of a line, and the keywords of builtin
+/// commands and of functions parsing their arguments with
+/// `cmake_parse_arguments()` inside their arguments.
+pub(crate) fn completion(server: &Server, params: CompletionParams) -> Option<CompletionResponse> {
+    let position = params.text_document_position;
+    let uri = &position.text_document.uri;
+    let text = server.text(uri)?;
+    let offset = LineIndex::new(text).offset(position.position);
+
+    let items = match context(&text[..offset]) {
+        Context::CommandName => command_names(server, uri),
+        Context::Arguments(name) => {
+            if let Some(command) = server.command(name) {
+                command
+                    .keywords
+                    .iter()
+                    .map(|keyword| keyword_item(keyword, None))
+                    .collect()
+            } else {
+                let keywords = server.signature(uri, name)?.keywords?;
+                [
+                    ("option", &keywords.options),
+                    ("one value", &keywords.one_value),
+                    ("multi value", &keywords.multi_value),
+                ]
+                .into_iter()
+                .flat_map(|(detail, keywords)| {
+                    keywords
+                        .iter()
+                        .map(move |keyword| keyword_item(keyword, Some(detail)))
+                })
+                .collect()
+            }
+        }
+        Context::Other => return None,
+    };
+    Some(CompletionResponse::Array(items))
+}
+
+fn command_names(server: &Server, uri: &lsp_types::Url) -> Vec<CompletionItem> {
+    let builtin = server.commands().iter().map(|command| CompletionItem {
+        label: command.name.to_string(),
+        kind: Some(CompletionItemKind::FUNCTION),
+        detail: command.documentation.lines().next().map(str::to_string),
+        documentation: Some(markdown(command.documentation.to_string())),
+        ..Default::default()
+    });
+    let mut items: Vec<_> = builtin.collect();
+    for (_, entry) in server.user_docs(uri) {
+        if items
+            .iter()
+            .any(|item| item.label.eq_ignore_ascii_case(&entry.name))
+        {
+            continue;
+        }
+        items.push(CompletionItem {
+            label: entry.name,
+            kind: Some(CompletionItemKind::FUNCTION),
+            detail: Some(entry.signature),
+            documentation: (!entry.documentation.is_empty()).then(|| markdown(entry.documentation)),
+            ..Default::default()
+        });
+    }
+    items
+}
+
+fn keyword_item(keyword: &str, detail: Option<&str>) -> CompletionItem {
+    CompletionItem {
+        label: keyword.to_string(),
+        kind: Some(CompletionItemKind::KEYWORD),
+        detail: detail.map(str::to_string),
+        ..Default::default()
+    }
+}
+
+fn markdown(value: String) -> Documentation {
+    Documentation::MarkupContent(MarkupContent {
+        kind: MarkupKind::Markdown,
+        value,
+    })
+}
+
+/// Context at the end of `text`.
+fn context(text: &str) -> Context<'_> {
+    let bytes = text.as_bytes();
+    let mut depth = 0;
+    let mut command = "";
+    // Start of the text after the last bracket comment.
+    let mut after_comment = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'#' => {
+                let end = match bracket_end(&text[i + 1..]) {
+                    Some(end) => end,
+                    None if text[i + 1..].starts_with('[') => None,
+                    None => text[i..].find('\n'),
+                };
+                let Some(end) = end else {
+                    return Context::Other;
+                };
+                i += end;
+                after_comment = i + 1;
+            }
+            b'[' if depth > 0 => match bracket_end(&text[i..]) {
+                Some(Some(end)) => i += end - 1,
+                Some(None) => return Context::Other,
+                None => (),
+            },
+            b'"' => {
+                i += 1;
+                loop {
+                    match bytes.get(i) {
+                        None => return Context::Other,
+                        Some(b'\\') => i += 2,
+                        Some(b'"') => break,
+                        Some(_) => i += 1,
+                    }
+                }
+            }
+            b'(' => {
+                if depth == 0 {
+                    let before = text[..i].trim_end();
+                    let start = before
+                        .char_indices()
+                        .rfind(|&(_, c)| !(c.is_alphanumeric() || c == '_'))
+                        .map_or(0, |(index, c)| index + c.len_utf8());
+                    command = &before[start..];
+                }
+                depth += 1;
+            }
+            b')' if depth > 0 => depth -= 1,
+            _ => (),
+        }
+        i += 1;
+    }
+
+    if depth > 0 {
+        Context::Arguments(command)
+    } else {
+        let line_start = text.rfind('\n').map_or(0, |index| index + 1);
+        let line = &text[line_start.max(after_comment)..];
+        if line
+            .trim_start()
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            Context::CommandName
+        } else {
+            Context::Other
+        }
+    }
+}
+
+/// For text starting with a bracket opening like `[==[`, the length up to
+/// and including the closing bracket, `Some(None)` if it is not closed.
+#[allow(clippy::option_option)]
+fn bracket_end(text: &str) -> Option<Option<usize>> {
+    let equals = text
+        .strip_prefix('[')?
+        .bytes()
+        .take_while(|&b| b == b'=')
+        .count();
+    let open = equals + 2;
+    if text.as_bytes().get(open - 1) != Some(&b'[') {
+        return None;
+    }
+    let close = format!("]{}]", "=".repeat(equals));
+    Some(
+        text[open..]
+            .find(&close)
+            .map(|index| open + index + close.len()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::TextDocumentPositionParams;
+
+    use super::*;
+    use crate::server::tests::{partial_result, position, server, work_done};
+    use pretty_assertions::assert_eq;
+
+    fn labels(server: &Server, position: TextDocumentPositionParams) -> Option<Vec<String>> {
+        let response = completion(
+            server,
+            CompletionParams {
+                text_document_position: position,
+                work_done_progress_params: work_done(),
+                partial_result_params: partial_result(),
+                context: None,
+            },
+        )?;
+        let CompletionResponse::Array(items) = response else {
+            unreachable!()
+        };
+        Some(items.into_iter().map(|item| item.label).collect())
+    }
+
+    #[test]
+    fn contexts() {
+        assert_eq!(context(""), Context::CommandName);
+        assert_eq!(context("project(a)\n  add_"), Context::CommandName);
+        assert_eq!(context("add_library(a "), Context::Arguments("add_library"));
+        assert_eq!(
+            context("if (A)\n  message(STATUS \"(\" [[)]] # )\n  "),
+            Context::Arguments("message")
+        );
+        assert_eq!(context("message(\"${A} "), Context::Other);
+        assert_eq!(context("message([=[ ]] "), Context::Other);
+        assert_eq!(context("#[[ comment\n"), Context::Other);
+        assert_eq!(context("#[[ comment ]] "), Context::CommandName);
+        assert_eq!(context("set(A B) "), Context::Other);
+        assert_eq!(context("# set("), Context::Other);
+        assert_eq!(context("messäge(\"ü\" "), Context::Arguments("messäge"));
+        assert_eq!(context("ä(a "), Context::Arguments("ä"));
+    }
+
+    #[test]
+    fn complete_non_ascii() {
+        let text = "message(STATUS \"ä\")\nmessäge(a)\nset(ü \n";
+        let server = server(&[("CMakeLists.txt", text)]);
+        assert_eq!(
+            labels(&server, position("CMakeLists.txt", text, "messäge(", 9)),
+            None
+        );
+        let keywords = labels(&server, position("CMakeLists.txt", text, "ü ", 3)).unwrap();
+        assert!(keywords.contains(&"PARENT_SCOPE".to_string()));
+    }
+
+    #[test]
+    fn complete() {
+        let helpers = "function(demo_add name)\n  cmake_parse_arguments(PARSE_ARGV 1 arg \"WIN32\" \"OUTPUT\" \"SOURCES\")\nendfunction()\n";
+        let text = "add_executable(app WIN32 main.c)\ndemo_add(app )\n";
+        let server = server(&[("helpers.cmake", helpers), ("CMakeLists.txt", text)]);
+
+        let keywords = labels(&server, position("CMakeLists.txt", text, "main.c", 0)).unwrap();
+        assert_eq!(
+            keywords,
+            [
+                "ALIAS",
+                "IMPORTED",
+                "GLOBAL",
+                "WIN32",
+                "MACOSX_BUNDLE",
+                "EXCLUDE_FROM_ALL"
+            ]
+        );
+
+        let keywords = labels(&server, position("CMakeLists.txt", text, "app )", 4)).unwrap();
+        assert_eq!(keywords, ["WIN32", "OUTPUT", "SOURCES"]);
+
+        let names = labels(&server, position("CMakeLists.txt", text, "demo", 2)).unwrap();
+        assert!(names.contains(&"add_executable".to_string()));
+        assert_eq!(names.last().map(String::as_str), Some("demo_add"));
+
+        assert_eq!(
+            labels(&server, position("CMakeLists.txt", text, ")\n", 1)),
+            None
+        );
+    }
+}
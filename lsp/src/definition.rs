@@ -0,0 +1,119 @@
+use cmake_parser::analysis::VariableIndex;
+use lsp_types::{GotoDefinitionParams, GotoDefinitionResponse, Location};
+
+use crate::{
+    server::Server,
+    text::{word_at, LineIndex},
+};
+
+/// Definitions of the function or macro called, or of the variable under
+/// the cursor. Variables defined in the current document take precedence
+/// over those of the other open documents.
+pub(crate) fn definition(
+    server: &Server,
+    params: GotoDefinitionParams,
+) -> Option<GotoDefinitionResponse> {
+    let position = params.text_document_position_params;
+    let uri = &position.text_document.uri;
+    let text = server.text(uri)?;
+    let offset = LineIndex::new(text).offset(position.position);
+
+    let word = word_at(text, offset);
+    let command = word.as_ref().and_then(|word| {
+        let is_command = server
+            .doc(uri)?
+            .spans()
+            .iter()
+            .any(|span| span.start.offset == word.start);
+        is_command.then(|| &text[word.clone()])
+    });
+    let locations: Vec<_> = if let Some(name) = command {
+        server
+            .user_docs(uri)
+            .into_iter()
+            .filter(|(_, entry)| entry.name.eq_ignore_ascii_case(name))
+            .map(|(uri, entry)| {
+                let text = server.text(&uri).unwrap_or_default();
+                Location::new(uri, LineIndex::new(text).span(entry.span))
+            })
+            .collect()
+    } else {
+        let name = VariableIndex::from_doc(server.doc(uri)?)
+            .name_at(None, offset)?
+            .to_string();
+        server
+            .documents(uri)
+            .map(|(uri, document)| {
+                let index = LineIndex::new(document.text());
+                document
+                    .doc()
+                    .map(|doc| {
+                        VariableIndex::from_doc(doc)
+                            .definitions_of(&name)
+                            .map(|definition| {
+                                Location::new(uri.clone(), index.span(definition.span))
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default()
+            })
+            .find(|locations| !locations.is_empty())
+            .unwrap_or_default()
+    };
+    (!locations.is_empty()).then_some(GotoDefinitionResponse::Array(locations))
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::{Position, Range, TextDocumentPositionParams};
+
+    use super::*;
+    use crate::server::tests::{partial_result, position, server, work_done};
+    use pretty_assertions::assert_eq;
+
+    fn locations(server: &Server, position: TextDocumentPositionParams) -> Vec<(String, Range)> {
+        let response = definition(
+            server,
+            GotoDefinitionParams {
+                text_document_position_params: position,
+                work_done_progress_params: work_done(),
+                partial_result_params: partial_result(),
+            },
+        );
+        let Some(GotoDefinitionResponse::Array(locations)) = response else {
+            return vec![];
+        };
+        locations
+            .into_iter()
+            .map(|location| (location.uri.path().to_string(), location.range))
+            .collect()
+    }
+
+    fn range(start: (u32, u32), end: (u32, u32)) -> Range {
+        Range::new(Position::new(start.0, start.1), Position::new(end.0, end.1))
+    }
+
+    #[test]
+    fn definitions() {
+        let helpers = "set(DEMO_ROOT /opt/demo)\nfunction(demo_add name)\nendfunction()\n";
+        let text = "set(SOURCES main.c)\ndemo_add(app)\nmessage(${SOURCES} ${DEMO_ROOT})\n";
+        let server = server(&[("helpers.cmake", helpers), ("CMakeLists.txt", text)]);
+
+        assert_eq!(
+            locations(&server, position("CMakeLists.txt", text, "demo_add", 1)),
+            [("/project/helpers.cmake".to_string(), range((1, 0), (1, 23)))]
+        );
+        assert_eq!(
+            locations(&server, position("CMakeLists.txt", text, "${SOURCES}", 3)),
+            [(
+                "/project/CMakeLists.txt".to_string(),
+                range((0, 4), (0, 11))
+            )]
+        );
+        assert_eq!(
+            locations(&server, position("CMakeLists.txt", text, "${DEMO_ROOT}", 2)),
+            [("/project/helpers.cmake".to_string(), range((0, 4), (0, 13)))]
+        );
+        assert!(locations(&server, position("CMakeLists.txt", text, "main.c", 0)).is_empty());
+    }
+}
@@ -0,0 +1,129 @@
+use cmake_parser::CommandParseError;
+use lsp_types::{Diagnostic, DiagnosticSeverity};
+
+use crate::{document::Document, text::LineIndex};
+
+/// Syntax errors and commands the parser rejects. Unknown commands are
+/// reported as information unless a document defines them.
+pub(crate) fn diagnostics(document: &Document, user_commands: &[String]) -> Vec<Diagnostic> {
+    let text = document.text();
+    let index = LineIndex::new(text);
+    let source = document.source();
+    let (doc, parsed) = match document.parse() {
+        Ok(parse) => parse,
+        Err(err) => {
+            return vec![diagnostic(
+                index.range(0..text.len()),
+                DiagnosticSeverity::ERROR,
+                err.to_string(),
+            )]
+        }
+    };
+
+    let mut diagnostics = vec![];
+    if !source[parsed..].trim().is_empty() {
+        let end = source[parsed..]
+            .find('\n')
+            .map_or(text.len(), |index| parsed + index);
+        diagnostics.push(diagnostic(
+            index.range(parsed..end),
+            DiagnosticSeverity::ERROR,
+            "syntax error: the rest of the file is not parsed".to_string(),
+        ));
+    }
+
+    for (command, span) in doc.to_commands_iter().zip(doc.spans()) {
+        let (severity, message) = match command {
+            Ok(_) => continue,
+            Err(CommandParseError::UnknownCommand(name)) => {
+                if user_commands.contains(&name.to_ascii_lowercase()) {
+                    continue;
+                }
+                (
+                    DiagnosticSeverity::INFORMATION,
+                    format!("unknown command: {name}"),
+                )
+            }
+            Err(err) => (DiagnosticSeverity::ERROR, err.to_string()),
+        };
+        diagnostics.push(diagnostic(index.span(span), severity, message));
+    }
+    diagnostics
+}
+
+fn diagnostic(
+    range: lsp_types::Range,
+    severity: DiagnosticSeverity,
+    message: String,
+) -> Diagnostic {
+    Diagnostic {
+        range,
+        severity: Some(severity),
+        source: Some("cmake-parser".to_string()),
+        message,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::{Position, Range};
+
+    use super::*;
+    use crate::server::tests::uri;
+    use pretty_assertions::assert_eq;
+
+    fn document(text: &str) -> Document {
+        Document::new(&uri("CMakeLists.txt"), text.to_string())
+    }
+
+    fn summary(diagnostics: Vec<Diagnostic>) -> Vec<(Range, DiagnosticSeverity, String)> {
+        diagnostics
+            .into_iter()
+            .map(|diagnostic| {
+                (
+                    diagnostic.range,
+                    diagnostic.severity.unwrap(),
+                    diagnostic.message,
+                )
+            })
+            .collect()
+    }
+
+    fn range(start: (u32, u32), end: (u32, u32)) -> Range {
+        Range::new(Position::new(start.0, start.1), Position::new(end.0, end.1))
+    }
+
+    #[test]
+    fn command_errors() {
+        let text = "cmake_minimum_required(VERSION 3.26)\nadd_executable()\nhelper(a)\nmy_module(b)\nproject(demo)";
+        assert_eq!(
+            summary(diagnostics(&document(text), &["helper".to_string()])),
+            [
+                (
+                    range((1, 0), (1, 16)),
+                    DiagnosticSeverity::ERROR,
+                    "token required".to_string()
+                ),
+                (
+                    range((3, 0), (3, 12)),
+                    DiagnosticSeverity::INFORMATION,
+                    "unknown command: my_module".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn syntax_error() {
+        let text = "project(demo)\nadd_executable(app\n  main.c\n\nset(A 1)\n";
+        assert_eq!(
+            summary(diagnostics(&document(text), &[])),
+            [(
+                range((1, 0), (1, 18)),
+                DiagnosticSeverity::ERROR,
+                "syntax error: the rest of the file is not parsed".to_string()
+            )]
+        );
+    }
+}
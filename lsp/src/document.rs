@@ -0,0 +1,86 @@
+use cmake_parser::{
+    analysis::{ReferenceDocs, Signatures},
+    CMakeListsParseError, Doc,
+};
+use lsp_types::Url;
+use self_cell::self_cell;
+
+use crate::text::{parse, source};
+
+/// Parsed document with the number of source bytes covered by the parsed
+/// elements.
+type Parse<'s> = Result<(Doc<'s>, usize), CMakeListsParseError>;
+
+self_cell!(
+    /// Source for the parser owning its parse.
+    struct Parsed {
+        owner: String,
+
+        #[covariant]
+        dependent: Parse,
+    }
+);
+
+/// Open document parsed once per change, with the functions and macros it
+/// defines.
+pub(crate) struct Document {
+    text: String,
+    parsed: Parsed,
+    signatures: Signatures,
+    docs: ReferenceDocs,
+}
+
+impl Document {
+    pub(crate) fn new(uri: &Url, text: String) -> Self {
+        let parsed = Parsed::new(source(&text).into_owned(), |source| {
+            parse(source).map(|tokens| {
+                let parsed_len = tokens.parsed_len();
+                (Doc::from(tokens), parsed_len)
+            })
+        });
+        let (signatures, docs) = match parsed.borrow_dependent() {
+            Ok((doc, _)) => (
+                Signatures::from_doc(doc),
+                ReferenceDocs::from_doc(doc, uri.path()),
+            ),
+            Err(_) => Default::default(),
+        };
+        Self {
+            text,
+            parsed,
+            signatures,
+            docs,
+        }
+    }
+
+    pub(crate) fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Source for the parser, which ends with a line ending. Offsets into it
+    /// are offsets into the text.
+    pub(crate) fn source(&self) -> &str {
+        self.parsed.borrow_owner()
+    }
+
+    /// Parsed document with the length of its parsed source.
+    pub(crate) fn parse(&self) -> Result<(&Doc<'_>, usize), &CMakeListsParseError> {
+        self.parsed
+            .borrow_dependent()
+            .as_ref()
+            .map(|(doc, parsed_len)| (doc, *parsed_len))
+    }
+
+    /// Parsed document unless it does not parse.
+    pub(crate) fn doc(&self) -> Option<&Doc<'_>> {
+        self.parse().ok().map(|(doc, _)| doc)
+    }
+
+    pub(crate) fn signatures(&self) -> &Signatures {
+        &self.signatures
+    }
+
+    pub(crate) fn docs(&self) -> &ReferenceDocs {
+        &self.docs
+    }
+}
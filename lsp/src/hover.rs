@@ -0,0 +1,90 @@
+use lsp_types::{Hover, HoverContents, HoverParams, MarkupContent, MarkupKind};
+
+use crate::{
+    server::Server,
+    text::{word_at, LineIndex},
+};
+
+/// Documentation of the builtin or user command under the cursor.
+pub(crate) fn hover(server: &Server, params: HoverParams) -> Option<Hover> {
+    let position = params.text_document_position_params;
+    let uri = &position.text_document.uri;
+    let text = server.text(uri)?;
+    let index = LineIndex::new(text);
+    let word = word_at(text, index.offset(position.position))?;
+    let is_command = server
+        .doc(uri)?
+        .spans()
+        .iter()
+        .any(|span| span.start.offset == word.start);
+    if !is_command {
+        return None;
+    }
+
+    let name = &text[word.clone()];
+    let value = match server.command(name) {
+        Some(command) => command.documentation.to_string(),
+        None => {
+            let (_, entry) = server
+                .user_docs(uri)
+                .into_iter()
+                .find(|(_, entry)| entry.name.eq_ignore_ascii_case(name))?;
+            let mut value = format!("```cmake\n{}\n```", entry.signature);
+            if !entry.documentation.is_empty() {
+                value.push_str("\n\n");
+                value.push_str(&entry.documentation);
+            }
+            value
+        }
+    };
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value,
+        }),
+        range: Some(index.range(word)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::TextDocumentPositionParams;
+
+    use super::*;
+    use crate::server::tests::{position, server, work_done};
+    use pretty_assertions::assert_eq;
+
+    fn value(server: &Server, position: TextDocumentPositionParams) -> Option<String> {
+        let hover = hover(
+            server,
+            HoverParams {
+                text_document_position_params: position,
+                work_done_progress_params: work_done(),
+            },
+        )?;
+        let HoverContents::Markup(content) = hover.contents else {
+            unreachable!()
+        };
+        Some(content.value)
+    }
+
+    #[test]
+    fn hover_commands() {
+        let helpers = "# Adds a demo target.\nfunction(demo_add name)\nendfunction()\n";
+        let text = "add_executable(app main.c)\ndemo_add(app)\n";
+        let server = server(&[("helpers.cmake", helpers), ("CMakeLists.txt", text)]);
+
+        let builtin = value(&server, position("CMakeLists.txt", text, "exec", 0)).unwrap();
+        assert!(builtin.starts_with("Add an executable to the project"));
+        assert!(builtin.contains("Reference: "));
+
+        assert_eq!(
+            value(&server, position("CMakeLists.txt", text, "demo_add", 3)).as_deref(),
+            Some("```cmake\ndemo_add(<name>)\n```\n\nAdds a demo target.")
+        );
+        assert_eq!(
+            value(&server, position("CMakeLists.txt", text, "app", 1)),
+            None
+        );
+    }
+}
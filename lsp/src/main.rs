@@ -0,0 +1,24 @@
+//! Language server for CMake files built on `cmake-parser`.
+//!
+//! Speaks LSP over stdin and stdout and provides diagnostics, completion of
+//! command names and keywords, hover documentation, document symbols and
+//! definitions of user functions and variables for the open documents.
+
+mod completion;
+mod definition;
+mod diagnostics;
+mod document;
+mod hover;
+mod server;
+mod symbols;
+mod text;
+
+use lsp_server::Connection;
+
+fn main() -> Result<(), server::Error> {
+    let (connection, io_threads) = Connection::stdio();
+    connection.initialize(serde_json::to_value(server::capabilities())?)?;
+    server::Server::default().run(&connection)?;
+    io_threads.join()?;
+    Ok(())
+}
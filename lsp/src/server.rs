@@ -0,0 +1,385 @@
+use std::collections::BTreeMap;
+
+use cmake_parser::{
+    analysis::{DocEntry, DocEntryKind, Signature},
+    command::{command_infos, CommandInfo},
+    Doc,
+};
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::{
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Notification as _,
+        PublishDiagnostics,
+    },
+    request::{Completion, DocumentSymbolRequest, GotoDefinition, HoverRequest, Request as _},
+    CompletionOptions, HoverProviderCapability, OneOf, PublishDiagnosticsParams,
+    ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind, Url,
+};
+
+use crate::{completion, definition, diagnostics, document::Document, hover, symbols};
+
+pub(crate) type Error = Box<dyn std::error::Error + Send + Sync>;
+
+pub(crate) fn capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec!["(".to_string(), " ".to_string()]),
+            ..Default::default()
+        }),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        document_symbol_provider: Some(OneOf::Left(true)),
+        definition_provider: Some(OneOf::Left(true)),
+        ..Default::default()
+    }
+}
+
+/// Open documents and the commands known to the parser.
+pub(crate) struct Server {
+    documents: BTreeMap<Url, Document>,
+    /// Lowercase names of the functions and macros defined by the open
+    /// documents.
+    user_commands: Vec<String>,
+    commands: Vec<CommandInfo>,
+}
+
+impl Default for Server {
+    fn default() -> Self {
+        Self {
+            documents: BTreeMap::new(),
+            user_commands: vec![],
+            commands: command_infos(),
+        }
+    }
+}
+
+impl Server {
+    /// Handles messages until the client shuts the server down.
+    pub(crate) fn run(&mut self, connection: &Connection) -> Result<(), Error> {
+        for message in &connection.receiver {
+            match message {
+                Message::Request(request) => {
+                    if connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    connection.sender.send(self.request(request).into())?;
+                }
+                Message::Notification(notification) => {
+                    for notification in self.notification(notification) {
+                        connection.sender.send(notification.into())?;
+                    }
+                }
+                Message::Response(_) => (),
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn request(&self, request: Request) -> Response {
+        let Request { id, method, params } = request;
+        let result = match method.as_str() {
+            Completion::METHOD => {
+                dispatch::<Completion>(params, |params| completion::completion(self, params))
+            }
+            HoverRequest::METHOD => {
+                dispatch::<HoverRequest>(params, |params| hover::hover(self, params))
+            }
+            DocumentSymbolRequest::METHOD => {
+                dispatch::<DocumentSymbolRequest>(params, |params| symbols::symbols(self, params))
+            }
+            GotoDefinition::METHOD => {
+                dispatch::<GotoDefinition>(params, |params| definition::definition(self, params))
+            }
+            _ => {
+                return Response::new_err(
+                    id,
+                    ErrorCode::MethodNotFound as i32,
+                    format!("unsupported request: {method}"),
+                )
+            }
+        };
+        match result {
+            Ok(result) => Response::new_ok(id, result),
+            Err(err) => Response::new_err(id, ErrorCode::InvalidParams as i32, err.to_string()),
+        }
+    }
+
+    /// Updates the open documents, returning the diagnostics to publish.
+    pub(crate) fn notification(&mut self, notification: Notification) -> Vec<Notification> {
+        let Notification { method, params } = notification;
+        match method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let Ok(params) = notification_params::<DidOpenTextDocument>(params) else {
+                    return vec![];
+                };
+                let document = params.text_document;
+                self.open(document.uri, document.text);
+                self.publish_diagnostics()
+            }
+            DidChangeTextDocument::METHOD => {
+                let Ok(params) = notification_params::<DidChangeTextDocument>(params) else {
+                    return vec![];
+                };
+                let Some(change) = params.content_changes.into_iter().last() else {
+                    return vec![];
+                };
+                self.open(params.text_document.uri, change.text);
+                self.publish_diagnostics()
+            }
+            DidCloseTextDocument::METHOD => {
+                let Ok(params) = notification_params::<DidCloseTextDocument>(params) else {
+                    return vec![];
+                };
+                let uri = params.text_document.uri;
+                self.documents.remove(&uri);
+                self.update_user_commands();
+                let mut notifications = self.publish_diagnostics();
+                notifications.push(publish(uri, vec![]));
+                notifications
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Parses an opened or changed document.
+    fn open(&mut self, uri: Url, text: String) {
+        let document = Document::new(&uri, text);
+        self.documents.insert(uri, document);
+        self.update_user_commands();
+    }
+
+    fn update_user_commands(&mut self) {
+        let mut names: Vec<_> = self
+            .documents
+            .values()
+            .flat_map(|document| document.signatures().signatures())
+            .map(|signature| signature.name.to_ascii_lowercase())
+            .collect();
+        names.sort();
+        names.dedup();
+        self.user_commands = names;
+    }
+
+    pub(crate) fn text(&self, uri: &Url) -> Option<&str> {
+        self.documents.get(uri).map(Document::text)
+    }
+
+    /// Parsed open document unless it does not parse.
+    pub(crate) fn doc(&self, uri: &Url) -> Option<&Doc<'_>> {
+        self.documents.get(uri)?.doc()
+    }
+
+    /// Open documents, starting with `first`.
+    pub(crate) fn documents<'a>(
+        &'a self,
+        first: &'a Url,
+    ) -> impl Iterator<Item = (&'a Url, &'a Document)> + 'a {
+        let first = self.documents.get_key_value(first);
+        first.into_iter().chain(
+            self.documents
+                .iter()
+                .filter(move |(uri, _)| Some(*uri) != first.map(|(uri, _)| uri)),
+        )
+    }
+
+    pub(crate) fn commands(&self) -> &[CommandInfo] {
+        &self.commands
+    }
+
+    pub(crate) fn command(&self, name: &str) -> Option<&CommandInfo> {
+        self.commands
+            .iter()
+            .find(|command| command.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Lowercase names of the functions and macros defined by the open
+    /// documents.
+    pub(crate) fn user_commands(&self) -> &[String] {
+        &self.user_commands
+    }
+
+    /// Documented functions and macros of the open documents, those of
+    /// `first` first.
+    pub(crate) fn user_docs(&self, first: &Url) -> Vec<(Url, DocEntry)> {
+        self.documents(first)
+            .flat_map(|(uri, document)| {
+                document
+                    .docs()
+                    .files()
+                    .iter()
+                    .flat_map(|file| &file.entries)
+                    .filter(|entry| {
+                        matches!(entry.kind, DocEntryKind::Function | DocEntryKind::Macro)
+                    })
+                    .map(|entry| (uri.clone(), entry.clone()))
+            })
+            .collect()
+    }
+
+    /// Signature of a function or macro defined by the open documents,
+    /// preferring the definitions of `first`.
+    pub(crate) fn signature(&self, first: &Url, name: &str) -> Option<Signature> {
+        self.documents(first)
+            .find_map(|(_, document)| document.signatures().get(name).cloned())
+    }
+
+    /// Diagnostics of every open document, since a definition in one
+    /// document affects the unknown commands of the others.
+    fn publish_diagnostics(&self) -> Vec<Notification> {
+        self.documents
+            .iter()
+            .map(|(uri, document)| {
+                publish(
+                    uri.clone(),
+                    diagnostics::diagnostics(document, self.user_commands()),
+                )
+            })
+            .collect()
+    }
+}
+
+fn dispatch<R: lsp_types::request::Request>(
+    params: serde_json::Value,
+    f: impl FnOnce(R::Params) -> R::Result,
+) -> Result<serde_json::Value, serde_json::Error> {
+    serde_json::from_value(params)
+        .map(f)
+        .and_then(serde_json::to_value)
+}
+
+fn notification_params<N: lsp_types::notification::Notification>(
+    params: serde_json::Value,
+) -> Result<N::Params, serde_json::Error> {
+    serde_json::from_value(params)
+}
+
+fn publish(uri: Url, diagnostics: Vec<lsp_types::Diagnostic>) -> Notification {
+    Notification::new(
+        PublishDiagnostics::METHOD.to_string(),
+        PublishDiagnosticsParams::new(uri, diagnostics, None),
+    )
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use lsp_types::{
+        DidOpenTextDocumentParams, PartialResultParams, Position, TextDocumentIdentifier,
+        TextDocumentItem, TextDocumentPositionParams, WorkDoneProgressParams,
+    };
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    pub(crate) fn uri(name: &str) -> Url {
+        Url::parse(&format!("file:///project/{name}")).unwrap()
+    }
+
+    pub(crate) fn server(documents: &[(&str, &str)]) -> Server {
+        let mut server = Server::default();
+        for (name, text) in documents {
+            server.notification(open(name, text));
+        }
+        server
+    }
+
+    pub(crate) fn open(name: &str, text: &str) -> Notification {
+        Notification::new(
+            DidOpenTextDocument::METHOD.to_string(),
+            DidOpenTextDocumentParams {
+                text_document: TextDocumentItem::new(
+                    uri(name),
+                    "cmake".to_string(),
+                    1,
+                    text.to_string(),
+                ),
+            },
+        )
+    }
+
+    /// Position of the first occurrence of `needle` in `text` plus `shift`
+    /// bytes.
+    pub(crate) fn position(
+        name: &str,
+        text: &str,
+        needle: &str,
+        shift: usize,
+    ) -> TextDocumentPositionParams {
+        let offset = text.find(needle).unwrap() + shift;
+        let line = text[..offset].matches('\n').count();
+        let line_start = text[..offset].rfind('\n').map_or(0, |index| index + 1);
+        let character = text[line_start..offset].encode_utf16().count();
+        TextDocumentPositionParams::new(
+            TextDocumentIdentifier::new(uri(name)),
+            Position::new(line as u32, character as u32),
+        )
+    }
+
+    pub(crate) fn work_done() -> WorkDoneProgressParams {
+        WorkDoneProgressParams::default()
+    }
+
+    pub(crate) fn partial_result() -> PartialResultParams {
+        PartialResultParams::default()
+    }
+
+    #[test]
+    fn publish_on_open_and_close() {
+        let mut server = server(&[("helpers.cmake", "function(helper)\nendfunction()\n")]);
+        let notifications = server.notification(open("CMakeLists.txt", "helper()\nunknown(\n"));
+        let published: Vec<PublishDiagnosticsParams> = notifications
+            .into_iter()
+            .map(|notification| serde_json::from_value(notification.params).unwrap())
+            .collect();
+        assert_eq!(
+            published
+                .iter()
+                .map(|params| (params.uri.path(), params.diagnostics.len()))
+                .collect::<Vec<_>>(),
+            [
+                ("/project/CMakeLists.txt", 1),
+                ("/project/helpers.cmake", 0)
+            ]
+        );
+        assert_eq!(server.user_commands(), ["helper"]);
+
+        server.notification(Notification::new(
+            DidChangeTextDocument::METHOD.to_string(),
+            lsp_types::DidChangeTextDocumentParams {
+                text_document: lsp_types::VersionedTextDocumentIdentifier::new(
+                    uri("helpers.cmake"),
+                    2,
+                ),
+                content_changes: vec![lsp_types::TextDocumentContentChangeEvent {
+                    range: None,
+                    range_length: None,
+                    text: "macro(other)\nendmacro()\n".to_string(),
+                }],
+            },
+        ));
+        assert_eq!(server.user_commands(), ["other"]);
+        assert!(server.signature(&uri("CMakeLists.txt"), "helper").is_none());
+
+        let notifications = server.notification(Notification::new(
+            DidCloseTextDocument::METHOD.to_string(),
+            lsp_types::DidCloseTextDocumentParams {
+                text_document: TextDocumentIdentifier::new(uri("helpers.cmake")),
+            },
+        ));
+        assert_eq!(notifications.len(), 2);
+        assert!(server.user_commands().is_empty());
+    }
+
+    #[test]
+    fn unsupported_request() {
+        let server = server(&[]);
+        let response = server.request(Request::new(
+            1.into(),
+            "workspace/symbol".to_string(),
+            serde_json::Value::Null,
+        ));
+        assert_eq!(
+            response.error.map(|error| error.code),
+            Some(ErrorCode::MethodNotFound as i32)
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use cmake_parser::{Command, Span, Token};
+use lsp_types::{DocumentSymbol, DocumentSymbolParams, DocumentSymbolResponse, SymbolKind};
+
+use crate::{server::Server, text::LineIndex};
+
+/// Targets, options, and functions and macros with the symbols defined in
+/// their bodies.
+pub(crate) fn symbols(
+    server: &Server,
+    params: DocumentSymbolParams,
+) -> Option<DocumentSymbolResponse> {
+    let uri = &params.text_document.uri;
+    let text = server.text(uri)?;
+    let index = LineIndex::new(text);
+    let symbols = server.doc(uri).map(|doc| {
+        // Symbols of the top level followed by those of each open body.
+        let mut scopes: Vec<Vec<DocumentSymbol>> = vec![vec![]];
+        for (command, span) in doc.to_commands_iter().zip(doc.spans()) {
+            let Ok(command) = command else {
+                continue;
+            };
+            let (name, kind, detail) = match &command {
+                Command::AddExecutable(command) => (&command.name, SymbolKind::CLASS, "executable"),
+                Command::AddLibrary(command) => (&command.name, SymbolKind::CLASS, "library"),
+                Command::AddCustomTarget(command) => {
+                    (&command.name, SymbolKind::CLASS, "custom target")
+                }
+                Command::Function(command) => (&command.name, SymbolKind::FUNCTION, "function"),
+                Command::Macro(command) => (&command.name, SymbolKind::FUNCTION, "macro"),
+                Command::Option(command) => {
+                    let help = command.help_text.to_string();
+                    let symbol = symbol(
+                        text,
+                        &index,
+                        span,
+                        &command.variable,
+                        SymbolKind::BOOLEAN,
+                        help,
+                    );
+                    scopes.last_mut().unwrap().push(symbol);
+                    continue;
+                }
+                Command::EndFunction(_) | Command::EndMacro(_) if scopes.len() > 1 => {
+                    let children = scopes.pop().unwrap();
+                    if let Some(parent) = scopes.last_mut().unwrap().last_mut() {
+                        parent.range.end = index.position(span.end.offset);
+                        parent.children = Some(children);
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+            let symbol = symbol(text, &index, span, name, kind, detail.to_string());
+            scopes.last_mut().unwrap().push(symbol);
+            if matches!(command, Command::Function(_) | Command::Macro(_)) {
+                scopes.push(vec![]);
+            }
+        }
+        // Bodies without an end keep their symbols at the top level.
+        scopes.into_iter().flatten().collect()
+    })?;
+    Some(DocumentSymbolResponse::Nested(symbols))
+}
+
+fn symbol(
+    text: &str,
+    index: &LineIndex,
+    span: Span,
+    name: &Token,
+    kind: SymbolKind,
+    detail: String,
+) -> DocumentSymbol {
+    let name = name.to_string();
+    let range = span.range();
+    let command = &text[range.clone()];
+    let selection = command
+        .find('(')
+        .and_then(|open| command[open..].find(&name).map(|start| open + start))
+        .map_or(range.clone(), |start| {
+            range.start + start..range.start + start + name.len()
+        });
+    #[allow(deprecated)]
+    DocumentSymbol {
+        name,
+        detail: Some(detail),
+        kind,
+        tags: None,
+        deprecated: None,
+        range: index.range(range),
+        selection_range: index.range(selection),
+        children: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::{Position, Range, TextDocumentIdentifier};
+
+    use super::*;
+    use crate::server::tests::{partial_result, server, uri, work_done};
+    use pretty_assertions::assert_eq;
+
+    fn summary(symbols: &[DocumentSymbol]) -> Vec<(String, SymbolKind, String, Vec<String>)> {
+        symbols
+            .iter()
+            .map(|symbol| {
+                (
+                    symbol.name.clone(),
+                    symbol.kind,
+                    symbol.detail.clone().unwrap(),
+                    symbol
+                        .children
+                        .iter()
+                        .flatten()
+                        .map(|child| child.name.clone())
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn document_symbols() {
+        let text = "option(DEMO_TESTS \"Build the tests\" OFF)\nfunction(demo_add name)\n  add_library(${name} STATIC demo.c)\nendfunction()\nadd_executable(app main.c)\nadd_custom_target(docs)\n";
+        let server = server(&[("CMakeLists.txt", text)]);
+        let response = symbols(
+            &server,
+            DocumentSymbolParams {
+                text_document: TextDocumentIdentifier::new(uri("CMakeLists.txt")),
+                work_done_progress_params: work_done(),
+                partial_result_params: partial_result(),
+            },
+        )
+        .unwrap();
+        let DocumentSymbolResponse::Nested(symbols) = response else {
+            unreachable!()
+        };
+        assert_eq!(
+            summary(&symbols),
+            [
+                (
+                    "DEMO_TESTS".to_string(),
+                    SymbolKind::BOOLEAN,
+                    "Build the tests".to_string(),
+                    vec![]
+                ),
+                (
+                    "demo_add".to_string(),
+                    SymbolKind::FUNCTION,
+                    "function".to_string(),
+                    vec!["${name}".to_string()]
+                ),
+                (
+                    "app".to_string(),
+                    SymbolKind::CLASS,
+                    "executable".to_string(),
+                    vec![]
+                ),
+                (
+                    "docs".to_string(),
+                    SymbolKind::CLASS,
+                    "custom target".to_string(),
+                    vec![]
+                ),
+            ]
+        );
+        let function = &symbols[1];
+        assert_eq!(
+            function.range,
+            Range::new(Position::new(1, 0), Position::new(3, 13))
+        );
+        assert_eq!(
+            function.selection_range,
+            Range::new(Position::new(1, 9), Position::new(1, 17))
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use std::{borrow::Cow, ops::Range};
+
+use cmake_parser::{parse_cmakelists, CMakeListsParseError, CMakeListsTokens, Span};
+use lsp_types::Position;
+
+/// Converts byte offsets of a document to LSP positions, which count
+/// UTF-16 code units.
+pub(crate) struct LineIndex<'s> {
+    text: &'s str,
+    line_starts: Vec<usize>,
+}
+
+impl<'s> LineIndex<'s> {
+    pub(crate) fn new(text: &'s str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(offset, _)| offset + 1))
+            .collect();
+        Self { text, line_starts }
+    }
+
+    pub(crate) fn position(&self, offset: usize) -> Position {
+        let offset = self.floor(offset);
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let start = self.line_starts[line];
+        let character = self.text[start..offset].encode_utf16().count();
+        Position::new(line as u32, character as u32)
+    }
+
+    /// Offset of a position, clamped to the end of its line.
+    pub(crate) fn offset(&self, position: Position) -> usize {
+        let Some(&start) = self.line_starts.get(position.line as usize) else {
+            return self.text.len();
+        };
+        let mut units = 0;
+        for (offset, c) in self.text[start..].char_indices() {
+            if units >= position.character as usize || c == '\n' {
+                return start + offset;
+            }
+            units += c.len_utf16();
+        }
+        self.text.len()
+    }
+
+    pub(crate) fn range(&self, range: Range<usize>) -> lsp_types::Range {
+        lsp_types::Range::new(self.position(range.start), self.position(range.end))
+    }
+
+    pub(crate) fn span(&self, span: Span) -> lsp_types::Range {
+        self.range(span.range())
+    }
+
+    fn floor(&self, offset: usize) -> usize {
+        let mut offset = offset.min(self.text.len());
+        while !self.text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        offset
+    }
+}
+
+/// Source for the parser, which needs a line ending after the last command.
+/// Offsets into it are offsets into `text`.
+pub(crate) fn source(text: &str) -> Cow<'_, str> {
+    if text.is_empty() || text.ends_with('\n') {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned(format!("{text}\n"))
+    }
+}
+
+pub(crate) fn parse(source: &str) -> Result<CMakeListsTokens<'_>, CMakeListsParseError> {
+    parse_cmakelists(source.as_bytes())
+}
+
+/// Range of the identifier at or directly before an offset.
+pub(crate) fn word_at(text: &str, offset: usize) -> Option<Range<usize>> {
+    let offset = offset.min(text.len());
+    let offset = (0..=offset)
+        .rev()
+        .find(|&offset| text.is_char_boundary(offset))
+        .unwrap_or(0);
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let start = text[..offset]
+        .char_indices()
+        .rfind(|&(_, c)| !is_word(c))
+        .map_or(0, |(index, c)| index + c.len_utf8());
+    let end = text[offset..]
+        .find(|c| !is_word(c))
+        .map_or(text.len(), |index| offset + index);
+    (start < end).then_some(start..end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn line_index() {
+        let text = "set(A \"ä𝄞\")\nmessage(${A})\n";
+        let index = LineIndex::new(text);
+        assert_eq!(index.position(0), Position::new(0, 0));
+        assert_eq!(
+            index.position(text.find(')').unwrap()),
+            Position::new(0, 11)
+        );
+        assert_eq!(index.position(text.find('m').unwrap()), Position::new(1, 0));
+        assert_eq!(index.position(text.len()), Position::new(2, 0));
+        assert_eq!(index.offset(Position::new(0, 11)), text.find(')').unwrap());
+        assert_eq!(index.offset(Position::new(1, 8)), text.find('$').unwrap());
+        assert_eq!(index.offset(Position::new(1, 99)), text.len() - 1);
+        assert_eq!(index.offset(Position::new(9, 0)), text.len());
+    }
+
+    #[test]
+    fn words() {
+        let text = "add_executable(app main.c)";
+        assert_eq!(word_at(text, 3), Some(0..14));
+        assert_eq!(word_at(text, 14), Some(0..14));
+        assert_eq!(word_at(text, 15), Some(15..18));
+        assert_eq!(word_at("( )", 1), None);
+        assert_eq!(word_at("messäge(a)", 5), Some(0..8));
+        assert_eq!(word_at("(ä)", 2), Some(1..3));
+    }
+}